@@ -50,6 +50,7 @@ pub(crate) fn route_install(
         tag: None,
         opaque_attrs: RouteOpaqueAttrs::None,
         nexthops: nexthops.clone(),
+        backup_nexthops: Default::default(),
     };
     ibus_tx.route_ip_add(msg);
 }
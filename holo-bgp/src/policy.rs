@@ -15,7 +15,7 @@ use holo_utils::policy::{
     BgpNexthop, BgpPolicyAction, BgpPolicyCondition, BgpSetCommMethod,
     BgpSetCommOptions, BgpSetMed, DefaultPolicyType, MatchSets,
     MetricModification, Policy, PolicyAction, PolicyCondition, PolicyResult,
-    PolicyType,
+    PolicyType, policy_apply,
 };
 use holo_utils::southbound::RouteOpaqueAttrs;
 use ipnetwork::IpNetwork;
@@ -117,40 +117,24 @@ pub(crate) fn redistribute_apply(
 fn process_policies(
     afi_safi: AfiSafi,
     prefix: IpNetwork,
-    mut rpinfo: RoutePolicyInfo,
+    rpinfo: RoutePolicyInfo,
     policies: &[Arc<Policy>],
     match_sets: &MatchSets,
     default_policy: DefaultPolicyType,
 ) -> PolicyResult<RoutePolicyInfo> {
-    let mut matches = false;
-
-    for stmt in policies.iter().flat_map(|policy| policy.stmts.values()) {
-        // Check if all conditions in the policy statement are satisfied.
-        if !stmt.conditions.values().all(|condition| {
+    policy_apply(
+        policies.iter().flat_map(|policy| policy.stmts.values()),
+        rpinfo,
+        default_policy,
+        |rpinfo, _stmt, condition| {
             process_stmt_condition(
-                afi_safi, &prefix, &rpinfo, condition, match_sets,
+                afi_safi, &prefix, rpinfo, condition, match_sets,
             )
-        }) {
-            continue;
-        }
-
-        matches = true;
-
-        // Process actions defined in the policy statement.
-        for action in stmt.actions.values() {
-            if !process_stmt_action(&mut rpinfo.attrs, action, match_sets) {
-                return PolicyResult::Reject;
-            }
-        }
-    }
-
-    // Check default policy if no definition in the policy chain was
-    // satisfied.
-    if !matches && default_policy == DefaultPolicyType::RejectRoute {
-        return PolicyResult::Reject;
-    }
-
-    PolicyResult::Accept(rpinfo)
+        },
+        |rpinfo, action| {
+            process_stmt_action(&mut rpinfo.attrs, action, match_sets)
+        },
+    )
 }
 
 // Processes a single condition statement within a routing policy.
@@ -180,13 +164,7 @@ fn process_stmt_condition(
         }
         // "match-prefix-set"
         PolicyCondition::MatchPrefixSet(value) => {
-            let af = prefix.address_family();
-            let set = match_sets.prefixes.get(&(value.clone(), af)).unwrap();
-            set.prefixes.iter().any(|range| {
-                prefix.ip() == range.prefix.ip()
-                    && prefix.prefix() >= range.masklen_lower
-                    && prefix.prefix() <= range.masklen_upper
-            })
+            match_sets.prefix_set_match(value, prefix)
         }
         // "match-neighbor-set"
         PolicyCondition::MatchNeighborSet(value) => {
@@ -338,21 +316,12 @@ fn process_stmt_action(
             return *accept;
         }
         // "set-metric"
-        PolicyAction::SetMetric { value, mod_type } => match mod_type {
-            MetricModification::Set => {
-                attrs.base.med = Some(*value);
-            }
-            MetricModification::Add => {
-                if let Some(med) = &mut attrs.base.med {
-                    *med = med.saturating_add(*value);
-                }
-            }
-            MetricModification::Subtract => {
-                if let Some(med) = &mut attrs.base.med {
-                    *med = med.saturating_sub(*value);
-                }
-            }
-        },
+        PolicyAction::SetMetric { value, mod_type } => {
+            attrs.base.med = match mod_type {
+                MetricModification::Set => Some(*value),
+                _ => attrs.base.med.map(|med| mod_type.apply(med, *value)),
+            };
+        }
         // "bgp-actions"
         PolicyAction::Bgp(action) => match action {
             // "set-route-origin"
@@ -18,7 +18,7 @@ use holo_utils::ip::{AddressFamilies, AddressFamily};
 use holo_utils::mac_addr::MacAddr;
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
-use holo_utils::sr::Sid;
+use holo_utils::sr::{IgpAlgoType, Sid, Srv6EndpointBehavior};
 use holo_utils::task::TimeoutTask;
 
 use crate::collections::AdjacencyId;
@@ -27,10 +27,13 @@ use crate::instance::InstanceUpView;
 use crate::interface::{Interface, InterfaceType};
 use crate::northbound::notification;
 use crate::packet::consts::PduType;
-use crate::packet::subtlvs::neighbor::{AdjSidFlags, AdjSidStlv};
+use crate::packet::subtlvs::Srv6SidStructureStlv;
+use crate::packet::subtlvs::neighbor::{
+    AdjSidFlags, AdjSidStlv, Srv6EndXSidFlags, Srv6EndXSidStlv,
+};
 use crate::packet::tlv::{ExtendedSeqNum, ThreeWayAdjState};
 use crate::packet::{AreaAddr, LanId, LevelType, SystemId};
-use crate::{sr, tasks};
+use crate::{sr, srv6, tasks};
 
 #[derive(Debug)]
 pub struct Adjacency {
@@ -53,8 +56,11 @@ pub struct Adjacency {
     pub ipv6_addrs: BTreeSet<Ipv6Addr>,
     pub bfd: AddressFamilies<Option<AdjacencyBfd>>,
     pub adj_sids: Vec<AdjacencySid>,
+    pub srv6_end_x_sid: Option<Srv6EndXSid>,
     pub last_uptime: Option<Instant>,
     pub holdtimer: Option<TimeoutTask>,
+    pub gr_helper: bool,
+    pub gr_suppress: bool,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -88,6 +94,15 @@ pub struct AdjacencySid {
     pub nbr_system_id: Option<SystemId>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+pub struct Srv6EndXSid {
+    pub func: u32,
+    pub sid: Ipv6Addr,
+    pub sid_structure: Srv6SidStructureStlv,
+    pub nbr_system_id: Option<SystemId>,
+}
+
 // ===== impl Adjacency =====
 
 impl Adjacency {
@@ -119,8 +134,11 @@ impl Adjacency {
             ipv6_addrs: Default::default(),
             bfd: Default::default(),
             adj_sids: Default::default(),
+            srv6_end_x_sid: None,
             last_uptime: None,
             holdtimer: None,
+            gr_helper: false,
+            gr_suppress: false,
         };
         Debug::AdjacencyCreate(&adj).log();
         adj
@@ -156,6 +174,13 @@ impl Adjacency {
         iface.state.event_counters.adjacency_changes += 1;
         iface.state.discontinuity_time = Utc::now();
 
+        // Stop helping the neighbor if its adjacency goes down.
+        if new_state == AdjacencyState::Down && self.gr_helper {
+            Debug::GrHelperExit(self).log();
+            self.gr_helper = false;
+            iface.state.gr_helping.remove(&self.system_id);
+        }
+
         // ISO 10589 does not require periodic CSNP transmission on
         // point-to-point interfaces. However, sending them helps prevent
         // synchronization issues, especially in mesh-group setups.
@@ -200,6 +225,15 @@ impl Adjacency {
             }
         }
 
+        // Update SRv6 End.X SID associated to this adjacency.
+        if instance.config.srv6.enabled {
+            if new_state == AdjacencyState::Up {
+                srv6::end_x_sid_add(instance, iface, self);
+            } else if self.state == AdjacencyState::Up {
+                srv6::end_x_sid_del(instance, self);
+            }
+        }
+
         // Removes BFD peers if the adjacency transitions to Down.
         if new_state == AdjacencyState::Down {
             self.bfd_clear_sessions(instance);
@@ -377,6 +411,22 @@ impl AdjacencySid {
     }
 }
 
+// ===== impl Srv6EndXSid =====
+
+impl Srv6EndXSid {
+    pub(crate) fn to_stlv(&self) -> Srv6EndXSidStlv {
+        Srv6EndXSidStlv::new(
+            Srv6EndXSidFlags::empty(),
+            IgpAlgoType::Spf,
+            0,
+            Srv6EndpointBehavior::EndX as u16,
+            self.sid,
+            self.nbr_system_id,
+            Some(self.sid_structure),
+        )
+    }
+}
+
 // ===== global functions =====
 
 // Computes the next three-way adjacency state based on the current adjacency
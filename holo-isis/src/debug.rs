@@ -18,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, debug_span};
 
 use crate::adjacency::{Adjacency, AdjacencyEvent, AdjacencyState};
+use crate::gr::GrMode;
 use crate::interface::{DisCandidate, Interface, InterfaceType};
 use crate::network::MulticastAddr;
 use crate::packet::LevelNumber;
@@ -65,6 +66,12 @@ pub enum Debug<'a> {
     SpfMaxPathMetric(&'a Vertex, &'a VertexEdge, u32),
     SpfMissingProtocolsTlv(&'a Vertex),
     SpfUnsupportedProtocol(&'a Vertex, AddressFamily),
+    // Graceful restart
+    GrStart(GrMode),
+    GrLevelSynced(LevelNumber),
+    GrExit(GrExitReason),
+    GrHelperEnter(&'a Adjacency),
+    GrHelperExit(&'a Adjacency),
     // Internal bus
     IbusRx(&'a IbusMsg),
 }
@@ -76,6 +83,14 @@ pub enum InstanceInactiveReason {
     Resetting,
 }
 
+// Reason why the graceful restart procedures have finished.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GrExitReason {
+    Completed,
+    TimedOut,
+    Disabled,
+}
+
 // Reason why IS-IS is inactive on an interface.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum InterfaceInactiveReason {
@@ -223,6 +238,31 @@ impl Debug<'_> {
                 // Parent span(s): isis-instance:spf
                 debug!(vertex = %vertex.id.lan_id.to_yang(), %protocol, "{}", self);
             }
+            Debug::GrStart(mode) => {
+                // Parent span(s): isis-instance
+                debug_span!("graceful-restart").in_scope(|| {
+                    debug!(?mode, "{}", self);
+                })
+            }
+            Debug::GrLevelSynced(level) => {
+                // Parent span(s): isis-instance
+                debug_span!("graceful-restart").in_scope(|| {
+                    debug!(%level, "{}", self);
+                })
+            }
+            Debug::GrExit(reason) => {
+                // Parent span(s): isis-instance
+                debug_span!("graceful-restart").in_scope(|| {
+                    debug!(%reason, "{}", self);
+                })
+            }
+            Debug::GrHelperEnter(adj) | Debug::GrHelperExit(adj) => {
+                // Parent span(s): isis-instance
+                debug_span!("adjacency", system_id = %adj.system_id.to_yang())
+                    .in_scope(|| {
+                        debug!("{}", self);
+                    })
+            }
             Debug::IbusRx(msg) => {
                 // Parent span(s): isis-instance
                 debug_span!("internal-bus").in_scope(|| {
@@ -326,6 +366,21 @@ impl std::fmt::Display for Debug<'_> {
             Debug::SpfUnsupportedProtocol(..) => {
                 write!(f, "unsupported protocol")
             }
+            Debug::GrStart(..) => {
+                write!(f, "starting graceful restart")
+            }
+            Debug::GrLevelSynced(..) => {
+                write!(f, "LSDB synchronization completed")
+            }
+            Debug::GrExit(..) => {
+                write!(f, "exiting graceful restart")
+            }
+            Debug::GrHelperEnter(..) => {
+                write!(f, "entering graceful restart helper mode")
+            }
+            Debug::GrHelperExit(..) => {
+                write!(f, "exiting graceful restart helper mode")
+            }
             Debug::IbusRx(..) => {
                 write!(f, "message")
             }
@@ -348,6 +403,24 @@ impl std::fmt::Display for InstanceInactiveReason {
     }
 }
 
+// ===== impl GrExitReason =====
+
+impl std::fmt::Display for GrExitReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrExitReason::Completed => {
+                write!(f, "LSDB synchronization completed")
+            }
+            GrExitReason::TimedOut => {
+                write!(f, "restart timer expired")
+            }
+            GrExitReason::Disabled => {
+                write!(f, "graceful restart disabled")
+            }
+        }
+    }
+}
+
 // ===== impl InterfaceInactiveReason =====
 
 impl std::fmt::Display for InterfaceInactiveReason {
@@ -18,7 +18,7 @@ use crate::adjacency::{Adjacency, AdjacencyEvent, AdjacencyState};
 use crate::collections::{
     AdjacencyKey, InterfaceIndex, InterfaceKey, LspEntryKey,
 };
-use crate::debug::{Debug, LspPurgeReason};
+use crate::debug::{Debug, GrExitReason, LspPurgeReason};
 use crate::error::{
     AdjacencyRejectError, Error, ExtendedSeqNumError, PduInputError,
 };
@@ -29,11 +29,13 @@ use crate::northbound::configuration::ExtendedSeqNumMode;
 use crate::northbound::notification;
 use crate::packet::consts::PduType;
 use crate::packet::error::{DecodeError, DecodeResult};
-use crate::packet::pdu::{Hello, HelloVariant, Lsp, Pdu, Snp, SnpTlvs};
+use crate::packet::pdu::{
+    Hello, HelloVariant, Lsp, LspTlvs, Pdu, Snp, SnpTlvs,
+};
 use crate::packet::tlv::{ExtendedSeqNum, ExtendedSeqNumTlv, ThreeWayAdjState};
 use crate::packet::{LanId, LevelNumber, LevelType, LspId};
 use crate::spf::SpfType;
-use crate::{adjacency, spf};
+use crate::{adjacency, gr, spf};
 
 // ===== Network PDU receipt =====
 
@@ -55,6 +57,17 @@ pub(crate) fn process_pdu(
     }
 
     // Check if the PDU was decoded successfully.
+    // Discard PDUs belonging to other IS-IS instances running on the same
+    // circuit. This is checked before any error accounting so that these
+    // PDUs aren't reported as authentication failures or the like.
+    let instance_id = match &pdu {
+        Ok(pdu) => Some(pdu.instance_id()),
+        Err(_) => Pdu::peek_instance_id(bytes.clone()),
+    };
+    if instance_id.is_some_and(|iid| iid != instance.config.instance_id) {
+        return Ok(());
+    }
+
     let pdu = match pdu {
         Ok(pdu) => pdu,
         Err(error) => {
@@ -209,6 +222,11 @@ fn process_pdu_hello(
         return Err(error);
     }
 
+    // Check whether the graceful restart can be completed.
+    if instance.state.gr.is_some() {
+        gr::check_sync(instance, arenas);
+    }
+
     Ok(())
 }
 
@@ -354,7 +372,19 @@ fn process_pdu_hello_lan(
     // Restart hold timer.
     adj.holdtimer_reset(iface, instance, hello.holdtime);
 
+    // Process graceful restart signaling.
+    let hello_update = gr::process_hello(
+        iface,
+        adj,
+        instance,
+        hello.tlvs.restart.as_ref(),
+        hello.holdtime,
+    );
+
     // Check for two-way communication.
+    //
+    // While helping a restarting neighbor, its adjacency is kept in the Up
+    // state even though the neighbor no longer reports us (RFC 8706).
     let iface_snpa = iface.system.mac_addr.unwrap();
     if adj.neighbors.contains(&iface_snpa) {
         adj.state_change(
@@ -363,7 +393,7 @@ fn process_pdu_hello_lan(
             AdjacencyEvent::HelloTwoWayRcvd,
             AdjacencyState::Up,
         );
-    } else {
+    } else if !(adj.gr_helper && adj.state == AdjacencyState::Up) {
         adj.state_change(
             iface,
             instance,
@@ -382,6 +412,11 @@ fn process_pdu_hello_lan(
         instance.tx.protocol_input.dis_election(iface.id, level);
     }
 
+    // Restart Hello Tx task if the Restart TLV needs to be updated.
+    if hello_update {
+        iface.hello_interval_start(instance, level);
+    }
+
     Ok(())
 }
 
@@ -533,6 +568,17 @@ fn process_pdu_hello_p2p(
     // Restart hold timer.
     adj.holdtimer_reset(iface, instance, hello.holdtime);
 
+    // Process graceful restart signaling.
+    if gr::process_hello(
+        iface,
+        &mut adj,
+        instance,
+        hello.tlvs.restart.as_ref(),
+        hello.holdtime,
+    ) {
+        restart_hello_tx = true;
+    }
+
     // When the Three-Way Adjacency TLV is present, update the state using
     // the RFC 5303 handshake. If the TLV is absent, fall back to two-way
     // adjacency and transition directly to Up.
@@ -548,6 +594,12 @@ fn process_pdu_hello_p2p(
                     ThreeWayAdjState::Down => {
                         return Ok(());
                     }
+                    // While helping a restarting neighbor, only the
+                    // three-way state is reset so that the neighbor can bring
+                    // its adjacency up again (RFC 8706).
+                    ThreeWayAdjState::Initializing
+                        if adj.gr_helper && adj.state == AdjacencyState::Up => {
+                    }
                     ThreeWayAdjState::Initializing => {
                         adj.state_change(
                             iface,
@@ -703,7 +755,11 @@ fn process_pdu_lsp(
                 level,
                 LanId::from((system_id, iface.state.circuit_id)),
                 None,
-                SnpTlvs::new([lsp.as_snp_entry()], ext_seqnum),
+                SnpTlvs::new(
+                    instance.config.instance_id_tlv(false),
+                    [lsp.as_snp_entry()],
+                    ext_seqnum,
+                ),
             ));
             iface.enqueue_pdu(pdu, level);
         }
@@ -711,12 +767,19 @@ fn process_pdu_lsp(
     }
 
     // Check if this is a self-originated LSP.
-    if lsp.lsp_id.system_id == system_id {
+    //
+    // While restarting, self-originated LSPs are installed as received so
+    // they can be reoriginated once the LSDB is synchronized (RFC 8706).
+    if lsp.lsp_id.system_id == system_id && !gr::is_restarting(instance) {
         if lse.is_none() {
             // Self-originated LSP not found in the LSDB, so it should be purged
             // from the network.
             lsp.set_rem_lifetime(0);
-            for iface in arenas.interfaces.iter_mut() {
+            for iface in arenas
+                .interfaces
+                .iter_mut()
+                .filter(|iface| !iface.mesh_group_blocked(None))
+            {
                 iface.srm_list_add(instance, level, lsp.clone());
             }
             return Ok(());
@@ -747,6 +810,7 @@ fn process_pdu_lsp(
     }
 
     // Compare the LSP in the database (if it exists) to the incoming LSP.
+    let lsp_id = lsp.lsp_id;
     match lse.map(|lse| lsp_compare(&lse.data, lsp.seqno, lsp.rem_lifetime)) {
         None | Some(Ordering::Less) => {
             // Record the Remaining Lifetime of the LSP at the time it was
@@ -792,13 +856,19 @@ fn process_pdu_lsp(
             }
 
             // Update LSP flooding flags for the other interfaces.
+            //
+            // LSPs received over a mesh group aren't flooded to the other
+            // members of the same mesh group (RFC 2973).
             let iface_id = iface.id;
+            let mesh_group = iface.mesh_group();
             for other_iface in arenas
                 .interfaces
                 .iter_mut()
                 .filter(|other_iface| other_iface.id != iface_id)
             {
-                other_iface.srm_list_add(instance, level, lsp.clone());
+                if !other_iface.mesh_group_blocked(mesh_group) {
+                    other_iface.srm_list_add(instance, level, lsp.clone());
+                }
                 other_iface.ssn_list_del(level, &lsp.lsp_id);
             }
         }
@@ -840,12 +910,17 @@ fn process_pdu_lsp(
             let lse = lse.unwrap();
 
             // Update LSP flooding flags for the incoming interface.
-            let lsp_id = lsp.lsp_id;
             iface.srm_list_add(instance, level, lse.data.clone());
             iface.ssn_list_del(level, &lsp_id);
         }
     }
 
+    // Check whether the graceful restart can be completed.
+    if instance.state.gr.is_some() {
+        gr::process_lsp(instance, level, &lsp_id);
+        gr::check_sync(instance, arenas);
+    }
+
     Ok(())
 }
 
@@ -1022,6 +1097,19 @@ fn process_pdu_snp(
         {
             iface.srm_list_add(instance, level, lsp.clone());
         }
+
+        // Check whether the graceful restart can be completed.
+        if instance.state.gr.is_some() {
+            let last = end == LspId::from([0xff; 8]);
+            gr::process_csnp(
+                instance,
+                arenas,
+                level,
+                lsp_entries.into_values(),
+                last,
+            );
+            gr::check_sync(instance, arenas);
+        }
     }
 
     Ok(())
@@ -1191,10 +1279,12 @@ pub(crate) fn process_send_psnp(
     }
 
     // Add as many LSP entries that will fit in a single PDU.
+    let instance_id = instance.config.instance_id_tlv(false);
     let mut lsp_entries = vec![];
     for _ in 0..SnpTlvs::max_lsp_entries(
         instance.config.lsp_mtu as usize - Snp::PSNP_HEADER_LEN as usize,
         instance.config.auth.all.method(&instance.shared.keychains),
+        instance_id.as_ref(),
         iface.config.ext_seqnum_mode.get(level).is_some(),
     ) {
         if let Some((_, lsp_entry)) =
@@ -1215,7 +1305,7 @@ pub(crate) fn process_send_psnp(
             iface.state.circuit_id,
         )),
         None,
-        SnpTlvs::new(lsp_entries, ext_seqnum),
+        SnpTlvs::new(instance_id, lsp_entries, ext_seqnum),
     ));
 
     // Enqueue PDU for transmission.
@@ -1249,9 +1339,11 @@ pub(crate) fn process_send_csnp(
     ));
 
     // Calculate maximum of LSP entries per PDU.
+    let instance_id = instance.config.instance_id_tlv(false);
     let max_lsp_entries = SnpTlvs::max_lsp_entries(
         instance.config.lsp_mtu as usize - Snp::CSNP_HEADER_LEN as usize,
         instance.config.auth.all.method(&instance.shared.keychains),
+        instance_id.as_ref(),
         iface.config.ext_seqnum_mode.get(level).is_some(),
     );
 
@@ -1263,7 +1355,7 @@ pub(crate) fn process_send_csnp(
             level,
             source,
             Some((start, end)),
-            SnpTlvs::new(lsp_entries, ext_seqnum),
+            SnpTlvs::new(instance_id.clone(), lsp_entries, ext_seqnum),
         ));
 
         // Enqueue PDU for transmission.
@@ -1305,6 +1397,22 @@ pub(crate) fn process_send_csnp(
     Ok(())
 }
 
+// ===== Request to transmit paced LSP =====
+
+pub(crate) fn process_lsp_pacing(
+    _instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    iface_key: InterfaceKey,
+) -> Result<(), Error> {
+    // Lookup interface.
+    let iface = arenas.interfaces.get_mut_by_key(&iface_key)?;
+
+    // Transmit the next SRM-flagged LSP, if any.
+    iface.lsp_tx_paced();
+
+    Ok(())
+}
+
 // ===== LSP origination event =====
 
 pub(crate) fn process_lsp_originate(
@@ -1313,10 +1421,23 @@ pub(crate) fn process_lsp_originate(
 ) -> Result<(), Error> {
     // Clear LSP origination backoff.
     instance.state.lsp_orig_backoff = None;
-    let Some(level_type) = instance.state.lsp_orig_pending.take() else {
+
+    // Defer LSP origination until the graceful restart completes.
+    if gr::is_restarting(instance) {
+        return Ok(());
+    }
+    let Some(mut level_type) = instance.state.lsp_orig_pending.take() else {
         return Ok(());
     };
 
+    // Include link performance metric changes that didn't cross the
+    // advertisement threshold.
+    for iface in arenas.interfaces.iter_mut() {
+        if iface.te_perf_metrics_sync() {
+            level_type = level_type.union(iface.config.level_type.resolved);
+        }
+    }
+
     // Originate LSPs for levels with pending requests.
     for level in instance
         .config
@@ -1351,8 +1472,11 @@ pub(crate) fn process_lsp_purge(
     // Set remaining lifetime to zero if it's not already.
     lsp.set_rem_lifetime(0);
 
-    // Remove all existing TLVs, retaining only the LSP header.
-    lsp.tlvs = Default::default();
+    // Remove all existing TLVs, retaining only the LSP header and the IID-TLV.
+    lsp.tlvs = LspTlvs {
+        instance_id: lsp.tlvs.instance_id.take(),
+        ..Default::default()
+    };
 
     // Add the POI TLV if purge originator support is enabled.
     if instance.config.purge_originator {
@@ -1376,7 +1500,11 @@ pub(crate) fn process_lsp_purge(
     lse.refresh_timer = None;
 
     // Send purged LSP to all interfaces.
-    for iface in arenas.interfaces.iter_mut() {
+    for iface in arenas
+        .interfaces
+        .iter_mut()
+        .filter(|iface| !iface.mesh_group_blocked(None))
+    {
         iface.srm_list_add(instance, level, lsp.clone());
     }
 
@@ -1417,9 +1545,32 @@ pub(crate) fn process_lsp_refresh(
 ) -> Result<(), Error> {
     // Lookup LSP entry in the LSDB.
     let lsdb = instance.state.lsdb.get(level);
-    let lsp = lsdb
+    let seqno = lsdb
         .get_by_key(&arenas.lsp_entries, &lse_key)
-        .map(|(_, lse)| &lse.data)?;
+        .map(|(_, lse)| lse.data.seqno)?;
+
+    // Advertise any pending link performance metric changes that didn't
+    // cross the advertisement threshold. If that results in a new instance
+    // of this LSP, there's nothing left to refresh.
+    let mut te_perf_changed = false;
+    for iface in arenas
+        .interfaces
+        .iter_mut()
+        .filter(|iface| iface.config.level_type.resolved.intersects(level))
+    {
+        te_perf_changed |= iface.te_perf_metrics_sync();
+    }
+    if te_perf_changed {
+        lsdb::lsp_originate_all(instance, arenas, level);
+    }
+    let lsdb = instance.state.lsdb.get(level);
+    let Ok((_, lse)) = lsdb.get_by_key(&arenas.lsp_entries, &lse_key) else {
+        return Ok(());
+    };
+    let lsp = &lse.data;
+    if lsp.seqno != seqno {
+        return Ok(());
+    }
 
     // Log LSP refresh.
     if instance.config.trace_opts.lsdb {
@@ -1453,3 +1604,57 @@ pub(crate) fn process_spf_delay_event(
     // Trigger SPF Delay FSM event.
     spf::fsm(level, event, instance, arenas)
 }
+
+// ===== LDP-IGP synchronization hold-down timeout =====
+
+pub(crate) fn process_ldp_sync_holddown_timeout(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    iface_key: InterfaceKey,
+) -> Result<(), Error> {
+    // Lookup interface.
+    let iface = arenas.interfaces.get_mut_by_key(&iface_key)?;
+
+    // Stop advertising the maximum link metric.
+    iface.state.ldp_sync_holddown_expired = true;
+    iface.state.tasks.ldp_sync_holddown = None;
+    instance.schedule_lsp_origination(iface.config.level_type.resolved);
+
+    Ok(())
+}
+
+// ===== Graceful restart timers expiry =====
+
+pub(crate) fn process_gr_t1_expiry(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    iface_key: InterfaceKey,
+) -> Result<(), Error> {
+    // Lookup interface.
+    let iface = arenas.interfaces.get_mut_by_key(&iface_key)?;
+
+    // Ignore stale timer events.
+    if iface.state.tasks.gr_t1.is_none() {
+        return Ok(());
+    }
+
+    gr::t1_expiry(iface, instance);
+    gr::check_sync(instance, arenas);
+
+    Ok(())
+}
+
+pub(crate) fn process_gr_t2_expiry(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    level: LevelNumber,
+) {
+    gr::t2_expiry(instance, arenas, level);
+}
+
+pub(crate) fn process_gr_t3_expiry(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+) {
+    gr::exit(instance, arenas, GrExitReason::TimedOut);
+}
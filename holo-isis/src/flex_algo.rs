@@ -0,0 +1,231 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+
+use holo_utils::sr::IgpAlgoType;
+use num_traits::FromPrimitive;
+
+use crate::adjacency::Adjacency;
+use crate::collections::{Arena, Interfaces, Lsdb};
+use crate::instance::InstanceUpView;
+use crate::lsdb::LspEntry;
+use crate::packet::consts::{FlexAlgoMetricType, MtId};
+use crate::packet::subtlvs::capability::FlexAlgoDefStlv;
+use crate::packet::tlv::IsReach;
+use crate::packet::{LevelNumber, SystemId};
+use crate::spf::{self, Spt, SptParams};
+
+// Flexible Algorithm parameters derived from the winning definition.
+#[derive(Debug)]
+pub struct FlexAlgoParams {
+    // Flexible Algorithm number.
+    pub algo: u8,
+    // Type of metric used for the path computation.
+    pub metric_type: FlexAlgoMetricType,
+    // Extended administrative group constraints.
+    pub exclude_any: Vec<u32>,
+    pub include_any: Vec<u32>,
+    pub include_all: Vec<u32>,
+}
+
+// ===== impl FlexAlgoParams =====
+
+impl FlexAlgoParams {
+    fn new(fad: &FlexAlgoDefStlv) -> Option<FlexAlgoParams> {
+        let metric_type = FlexAlgoMetricType::from_u8(fad.metric_type)?;
+        Some(FlexAlgoParams {
+            algo: fad.algo,
+            metric_type,
+            exclude_any: fad.exclude_any.clone(),
+            include_any: fad.include_any.clone(),
+            include_all: fad.include_all.clone(),
+        })
+    }
+
+    // Returns the cost of the given link in the algorithm's topology, or `None`
+    // if the link must be pruned.
+    pub(crate) fn link_cost(&self, reach: &IsReach) -> Option<u32> {
+        // Prune links that don't satisfy the affinity constraints. Links that
+        // only advertise the legacy administrative groups are treated as if
+        // those were the first word of the extended administrative groups.
+        let legacy;
+        let groups = match (
+            &reach.sub_tlvs.ext_admin_group,
+            &reach.sub_tlvs.admin_group,
+        ) {
+            (Some(stlv), _) => stlv.get(),
+            (None, Some(stlv)) => {
+                legacy = [stlv.get()];
+                &legacy[..]
+            }
+            (None, None) => &[],
+        };
+        if !self.admin_groups_match(groups) {
+            return None;
+        }
+
+        // Prune links that don't advertise the metric used by the algorithm.
+        match self.metric_type {
+            FlexAlgoMetricType::Igp => Some(reach.metric),
+            FlexAlgoMetricType::MinUnidirLinkDelay => reach
+                .sub_tlvs
+                .min_max_unidir_link_delay
+                .as_ref()
+                .map(|stlv| stlv.min_delay),
+            FlexAlgoMetricType::TeDefault => reach
+                .sub_tlvs
+                .te_default_metric
+                .as_ref()
+                .map(|stlv| stlv.get()),
+        }
+    }
+
+    // Checks whether a link with the given extended administrative groups
+    // satisfies the affinity constraints.
+    //
+    // Words missing from either side are considered to be zero.
+    pub(crate) fn admin_groups_match(&self, groups: &[u32]) -> bool {
+        let group_word = |i: usize| groups.get(i).copied().unwrap_or(0);
+
+        // The link must not have any of the excluded groups.
+        if self
+            .exclude_any
+            .iter()
+            .enumerate()
+            .any(|(i, word)| word & group_word(i) != 0)
+        {
+            return false;
+        }
+
+        // The link must have at least one of the included groups.
+        if self.include_any.iter().any(|word| *word != 0)
+            && !self
+                .include_any
+                .iter()
+                .enumerate()
+                .any(|(i, word)| word & group_word(i) != 0)
+        {
+            return false;
+        }
+
+        // The link must have all of the included groups.
+        if !self
+            .include_all
+            .iter()
+            .enumerate()
+            .all(|(i, word)| word & group_word(i) == *word)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    // Checks whether the given router advertises support for the algorithm.
+    pub(crate) fn is_participating(
+        &self,
+        system_id: SystemId,
+        lsdb: &Lsdb,
+        lsp_entries: &Arena<LspEntry>,
+    ) -> bool {
+        let algo = IgpAlgoType::FlexAlgo(self.algo);
+        lsdb.iter_for_system_id(lsp_entries, system_id)
+            .map(|lse| &lse.data)
+            .filter(|lsp| lsp.rem_lifetime != 0)
+            .filter(|lsp| lsp.seqno != 0)
+            .filter_map(|lsp| lsp.tlvs.sr_algos())
+            .any(|sr_algos| sr_algos.get().contains(&algo))
+    }
+}
+
+// ===== global functions =====
+
+// Computes the shortest-path trees of all configured Flexible Algorithms.
+//
+// Algorithms without a usable definition are skipped, in which case the local
+// router doesn't participate in them.
+pub(crate) fn compute_spts(
+    level: LevelNumber,
+    instance: &InstanceUpView<'_>,
+    interfaces: &Interfaces,
+    adjacencies: &Arena<Adjacency>,
+    lsp_entries: &Arena<LspEntry>,
+) -> BTreeMap<u8, Spt> {
+    let mut spts = BTreeMap::new();
+
+    if !instance.config.sr.enabled {
+        return spts;
+    }
+
+    let system_id = instance.config.system_id.unwrap();
+    for algo in instance.config.flex_algos.keys() {
+        // Select the winning definition.
+        let Some(fad) = fad_select(level, *algo, instance, lsp_entries) else {
+            continue;
+        };
+
+        // Stop participating in the algorithm if the winning definition has
+        // elements that aren't supported (RFC 9350).
+        if fad.is_unsupported() {
+            continue;
+        }
+        let Some(params) = FlexAlgoParams::new(fad) else {
+            continue;
+        };
+
+        // Compute the algorithm's shortest-path tree.
+        let mut spt_params = SptParams::new(system_id, false, None);
+        spt_params.flex_algo = Some(params);
+        let spt = spf::compute_spt(
+            level,
+            MtId::Standard,
+            &spt_params,
+            instance,
+            interfaces,
+            adjacencies,
+            lsp_entries,
+        );
+        spts.insert(*algo, spt);
+    }
+
+    spts
+}
+
+// ===== helper functions =====
+
+// Selects the Flexible Algorithm Definition to use for the given algorithm.
+//
+// Only definitions advertised by routers reachable in the algorithm 0
+// topology are considered. The definition with the highest priority wins,
+// with ties broken in favor of the highest System ID.
+fn fad_select<'a>(
+    level: LevelNumber,
+    algo: u8,
+    instance: &'a InstanceUpView<'_>,
+    lsp_entries: &'a Arena<LspEntry>,
+) -> Option<&'a FlexAlgoDefStlv> {
+    let lsdb = instance.state.lsdb.get(level);
+    instance
+        .state
+        .spt
+        .standard
+        .get(level)
+        .values()
+        .filter(|vertex| !vertex.id.lan_id.is_pseudonode())
+        .filter_map(|vertex| {
+            let system_id = vertex.id.lan_id.system_id;
+            lsdb.iter_for_system_id(lsp_entries, system_id)
+                .map(|lse| &lse.data)
+                .filter(|lsp| lsp.rem_lifetime != 0)
+                .filter(|lsp| lsp.seqno != 0)
+                .flat_map(|lsp| lsp.tlvs.flex_algo_defs())
+                .find(|fad| fad.algo == algo)
+                .map(|fad| (fad, system_id))
+        })
+        .max_by_key(|(fad, system_id)| (fad.priority, *system_id))
+        .map(|(fad, _)| fad)
+}
@@ -0,0 +1,405 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use holo_utils::protocol::Protocol;
+use holo_utils::task::TimeoutTask;
+
+use crate::adjacency::{Adjacency, AdjacencyState};
+use crate::debug::{Debug, GrExitReason};
+use crate::instance::{InstanceArenas, InstanceUpView};
+use crate::interface::{Interface, InterfaceType};
+use crate::lsdb::{self, LspEntryFlags, lsp_compare};
+use crate::packet::tlv::{LspEntry, RestartFlags, RestartTlv};
+use crate::packet::{LevelNumber, LevelType, Levels, LspId};
+use crate::spf::{self, SpfType};
+use crate::tasks::messages::input::SendCsnpMsg;
+use crate::{ibus, tasks};
+
+// Interval at which IIHs with the RR flag are retransmitted until an
+// acknowledgment is received.
+pub const T1_TIMEOUT: u64 = 3;
+// Number of T1 expirations before giving up on an interface.
+pub const T1_MAX_EXPIRATIONS: u8 = 10;
+// Maximum time allowed for the LSDB synchronization of each level.
+pub const T2_TIMEOUT: u64 = 60;
+
+// Graceful restart state (RFC 8706).
+#[derive(Debug)]
+pub struct GrRestart {
+    // Whether this is a restart or a cold start.
+    pub mode: GrMode,
+    // Per-level LSDB synchronization timer.
+    pub t2: Levels<Option<TimeoutTask>>,
+    // Overall restart timer.
+    pub t3: Option<TimeoutTask>,
+    // LSPs announced in received CSNPs that are still missing.
+    pub pending_lsps: Levels<BTreeSet<LspId>>,
+    // Whether a complete set of CSNPs was received.
+    pub csnp_rcvd: Levels<bool>,
+    // Whether the LSDB synchronization has completed.
+    pub synced: Levels<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GrMode {
+    // The router is restarting and its forwarding state was preserved.
+    Restarting,
+    // The router is starting for the first time.
+    Starting,
+}
+
+// ===== global functions =====
+
+// Returns whether the instance is restarting and hasn't finished synchronizing
+// its LSDB yet.
+pub(crate) fn is_restarting(instance: &InstanceUpView<'_>) -> bool {
+    instance
+        .state
+        .gr
+        .as_ref()
+        .is_some_and(|gr| gr.mode == GrMode::Restarting)
+}
+
+// Starts the graceful restart procedures when the instance is started.
+//
+// A restart is detected when the routes installed by the previous Holo
+// instance were preserved in the kernel.
+pub(crate) fn start(instance: &mut InstanceUpView<'_>) {
+    if !instance.config.gr.enabled {
+        return;
+    }
+
+    // Request the preservation of the installed routes across restarts.
+    ibus::tx::route_retain(&instance.tx.ibus, true);
+
+    let mode = if instance.shared.preserved_routes.contains(&Protocol::ISIS) {
+        GrMode::Restarting
+    } else {
+        GrMode::Starting
+    };
+    Debug::GrStart(mode).log();
+
+    // Start the T2 timer for each enabled level.
+    let mut t2 = Levels::<Option<TimeoutTask>>::default();
+    for level in instance.config.levels() {
+        *t2.get_mut(level) = Some(tasks::gr_t2_timer(level, instance));
+    }
+
+    // Start the T3 timer, which bounds the overall restart time.
+    let mut t3 = None;
+    if mode == GrMode::Restarting {
+        let timeout = instance.config.gr.restart_interval.unwrap_or(u16::MAX);
+        t3 = Some(tasks::gr_t3_timer(timeout, instance));
+    } else {
+        // Nothing was preserved for a cold start.
+        ibus::tx::route_stale_sweep(&instance.tx.ibus);
+    }
+
+    instance.state.gr = Some(GrRestart {
+        mode,
+        t2,
+        t3,
+        pending_lsps: Default::default(),
+        csnp_rcvd: Default::default(),
+        synced: Default::default(),
+    });
+}
+
+// Finishes the graceful restart procedures.
+pub(crate) fn exit(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    reason: GrExitReason,
+) {
+    let Some(gr) = instance.state.gr.take() else {
+        return;
+    };
+
+    Debug::GrExit(reason).log();
+
+    // Stop the T1 timers and update the Hello PDUs so they no longer carry the
+    // RR and SA flags.
+    for iface in arenas
+        .interfaces
+        .iter_mut()
+        .filter(|iface| iface.state.active && !iface.is_passive())
+    {
+        iface.state.tasks.gr_t1 = None;
+        iface.state.gr_t1_expirations = 0;
+        iface.hello_interval_start(instance, LevelType::All);
+    }
+
+    if gr.mode == GrMode::Restarting {
+        let system_id = instance.config.system_id.unwrap();
+        for level in instance.config.levels() {
+            // Originate the local LSPs that were deferred during the restart.
+            lsdb::lsp_originate_all(instance, arenas, level);
+
+            // Self-originated LSPs received from the neighbors and not changed
+            // by the origination above need to be refreshed so that they're
+            // owned by us again.
+            let lsdb = instance.state.lsdb.get(level);
+            for lse in lsdb
+                .iter_for_system_id(&arenas.lsp_entries, system_id)
+                .filter(|lse| lse.refresh_timer.is_none())
+                .filter(|lse| !lse.flags.contains(LspEntryFlags::PURGED))
+            {
+                instance.tx.protocol_input.lsp_refresh(level, lse.id);
+            }
+
+            // Compute the routes that were deferred during the restart.
+            instance.state.spf_sched.get_mut(level).spf_type = SpfType::Full;
+            let event = spf::fsm::Event::ConfigChange;
+            if let Err(error) = spf::fsm(level, event, instance, arenas) {
+                error.log();
+            }
+        }
+    }
+
+    // Remove the preserved routes that weren't reinstalled.
+    ibus::tx::route_stale_sweep(&instance.tx.ibus);
+}
+
+// Checks whether the LSDB synchronization has completed for all levels.
+pub(crate) fn check_sync(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+) {
+    let Some(gr) = &mut instance.state.gr else {
+        return;
+    };
+
+    for level in instance.config.levels() {
+        if *gr.synced.get(level) {
+            continue;
+        }
+
+        // Wait until all neighbors have acknowledged the restart.
+        if arenas
+            .interfaces
+            .iter()
+            .filter(|iface| iface.config.level_type.resolved.intersects(level))
+            .any(|iface| iface.state.tasks.gr_t1.is_some())
+        {
+            continue;
+        }
+
+        // Wait until all LSPs announced in the received CSNPs are received.
+        if !*gr.csnp_rcvd.get(level) || !gr.pending_lsps.get(level).is_empty() {
+            continue;
+        }
+
+        level_synced(gr, level);
+    }
+
+    if instance.config.levels().all(|level| *gr.synced.get(level)) {
+        exit(instance, arenas, GrExitReason::Completed);
+    }
+}
+
+// Processes the Restart TLV of a received Hello PDU.
+//
+// Returns whether the Hello PDUs sent on the interface need to be updated.
+pub(crate) fn process_hello(
+    iface: &mut Interface,
+    adj: &mut Adjacency,
+    instance: &mut InstanceUpView<'_>,
+    restart: Option<&RestartTlv>,
+    holdtime: u16,
+) -> bool {
+    let mut hello_update = false;
+    let flags = restart.map(|restart| restart.flags).unwrap_or_default();
+
+    // Suppress the adjacency from the local LSPs while the neighbor is
+    // starting.
+    let suppress = flags.contains(RestartFlags::SA);
+    if adj.gr_suppress != suppress {
+        adj.gr_suppress = suppress;
+        instance.schedule_lsp_origination(adj.level_usage);
+    }
+
+    // Helper mode.
+    if flags.contains(RestartFlags::RR) && instance.config.gr.helper_enabled {
+        if !adj.gr_helper {
+            Debug::GrHelperEnter(adj).log();
+            adj.gr_helper = true;
+            iface.state.gr_helping.insert(adj.system_id, holdtime);
+            hello_update = true;
+
+            // Send CSNPs so that the restarting neighbor can synchronize its
+            // LSDB.
+            if adj.state == AdjacencyState::Up {
+                for level in adj.level_usage {
+                    let msg = SendCsnpMsg {
+                        iface_key: iface.id.into(),
+                        level,
+                    };
+                    let _ = instance.tx.protocol_input.send_csnp.send(msg);
+                }
+            }
+        }
+    } else if adj.gr_helper {
+        Debug::GrHelperExit(adj).log();
+        adj.gr_helper = false;
+        iface.state.gr_helping.remove(&adj.system_id);
+        hello_update = true;
+    }
+
+    // Restarting mode: check if the neighbor acknowledged the restart.
+    if let Some(restart) = restart
+        && restart.flags.contains(RestartFlags::RA)
+        && restart
+            .restarting_nbr
+            .is_none_or(|nbr| nbr == instance.config.system_id.unwrap())
+        && let Some(gr) = &mut instance.state.gr
+        && iface.state.tasks.gr_t1.is_some()
+    {
+        iface.state.tasks.gr_t1 = None;
+        hello_update = true;
+
+        // Limit the restart time to the lowest remaining time received from
+        // the neighbors.
+        if let Some(rem_time) = restart.rem_time
+            && let Some(t3) = &mut gr.t3
+        {
+            let rem_time = Duration::from_secs(rem_time.into());
+            if rem_time < t3.remaining() {
+                t3.reset(Some(rem_time));
+            }
+        }
+    }
+
+    hello_update
+}
+
+// Records the LSPs announced in a received CSNP that are missing or outdated
+// in the local LSDB.
+pub(crate) fn process_csnp(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &InstanceArenas,
+    level: LevelNumber,
+    lsp_entries: impl Iterator<Item = LspEntry>,
+    last: bool,
+) {
+    let Some(gr) = &mut instance.state.gr else {
+        return;
+    };
+    if *gr.synced.get(level) {
+        return;
+    }
+
+    let lsdb = instance.state.lsdb.get(level);
+    for entry in lsp_entries.filter(|entry| entry.rem_lifetime != 0) {
+        let lse = lsdb
+            .get_by_lspid(&arenas.lsp_entries, &entry.lsp_id)
+            .map(|(_, lse)| lse);
+        if lse.is_none_or(|lse| {
+            lsp_compare(&lse.data, entry.seqno, entry.rem_lifetime)
+                == Ordering::Less
+        }) {
+            gr.pending_lsps.get_mut(level).insert(entry.lsp_id);
+        }
+    }
+
+    // The set of CSNPs is complete once the one covering the end of the LSP ID
+    // range is received.
+    if last {
+        *gr.csnp_rcvd.get_mut(level) = true;
+    }
+}
+
+// Records the receipt of an LSP announced in a CSNP.
+pub(crate) fn process_lsp(
+    instance: &mut InstanceUpView<'_>,
+    level: LevelNumber,
+    lsp_id: &LspId,
+) {
+    if let Some(gr) = &mut instance.state.gr {
+        gr.pending_lsps.get_mut(level).remove(lsp_id);
+    }
+}
+
+// Builds the Restart TLV to be included in the Hello PDUs of the interface.
+pub(crate) fn hello_restart_tlv(
+    iface: &Interface,
+    instance: &InstanceUpView<'_>,
+) -> Option<RestartTlv> {
+    let mut flags = RestartFlags::empty();
+    let mut rem_time = None;
+    let mut restarting_nbr = None;
+
+    // Restarting or starting router.
+    if let Some(gr) = &instance.state.gr {
+        match gr.mode {
+            GrMode::Restarting => {
+                if iface.state.tasks.gr_t1.is_some() {
+                    flags.insert(RestartFlags::RR);
+                }
+            }
+            GrMode::Starting => {
+                flags.insert(RestartFlags::SA);
+            }
+        }
+    }
+
+    // Helper router.
+    if let Some((system_id, holdtime)) = iface.state.gr_helping.iter().next() {
+        flags.insert(RestartFlags::RA);
+        rem_time = Some(*holdtime);
+        if iface.config.interface_type == InterfaceType::Broadcast {
+            restarting_nbr = Some(*system_id);
+        }
+    }
+
+    (!flags.is_empty())
+        .then(|| RestartTlv::new(flags, rem_time, restarting_nbr))
+}
+
+// ===== helper functions =====
+
+fn level_synced(gr: &mut GrRestart, level: LevelNumber) {
+    Debug::GrLevelSynced(level).log();
+    *gr.synced.get_mut(level) = true;
+    *gr.t2.get_mut(level) = None;
+}
+
+// ===== timer expiry =====
+
+// Processes the expiration of the T1 timer of an interface.
+pub(crate) fn t1_expiry(
+    iface: &mut Interface,
+    instance: &mut InstanceUpView<'_>,
+) {
+    iface.state.gr_t1_expirations += 1;
+    if iface.state.gr_t1_expirations < T1_MAX_EXPIRATIONS {
+        // Retransmit the Hello PDUs with the RR flag.
+        let task = tasks::gr_t1_timer(iface, instance);
+        iface.state.tasks.gr_t1 = Some(task);
+    } else {
+        // Give up waiting for an acknowledgment on this interface.
+        iface.state.tasks.gr_t1 = None;
+    }
+    iface.hello_interval_start(instance, LevelType::All);
+}
+
+// Processes the expiration of the T2 timer of a level.
+pub(crate) fn t2_expiry(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    level: LevelNumber,
+) {
+    let Some(gr) = &mut instance.state.gr else {
+        return;
+    };
+
+    // Proceed as if the LSDB synchronization had completed.
+    level_synced(gr, level);
+    check_sync(instance, arenas);
+}
@@ -7,12 +7,13 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 
 use holo_utils::bfd;
 use holo_utils::ip::IpNetworkKind;
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::southbound::{
     AddressMsg, InterfaceUpdateMsg, RouteKeyMsg, RouteMsg,
 };
@@ -23,6 +24,7 @@ use crate::error::Error;
 use crate::instance::Instance;
 use crate::packet::LevelType;
 use crate::route::RouteSys;
+use crate::srv6;
 
 // ===== global functions =====
 
@@ -201,6 +203,26 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
     }
 }
 
+pub(crate) fn process_policy_update(instance: &mut Instance) {
+    let Some((mut instance, _)) = instance.as_up() else {
+        return;
+    };
+
+    // Schedule LSP reorigination for all levels where the redistributed
+    // routes are subject to routing policies.
+    let levels = instance
+        .config
+        .afs
+        .values()
+        .flat_map(|af_cfg| af_cfg.redistribution.iter())
+        .filter(|(_, redistribution)| !redistribution.policies.is_empty())
+        .map(|((level, _), _)| *level)
+        .collect::<BTreeSet<_>>();
+    for level in levels {
+        instance.schedule_lsp_origination(level);
+    }
+}
+
 pub(crate) fn process_hostname_update(
     instance: &mut Instance,
     hostname: Option<String>,
@@ -291,10 +313,13 @@ pub(crate) fn process_sr_cfg_update(
     // Update SR configuration.
     instance.shared.sr_config = sr_config;
 
-    // Schedule LSP reorigination.
-    if instance.config.sr.enabled
-        && let Some((mut instance, _)) = instance.as_up()
+    // Update SRv6 SIDs and schedule LSP reorigination.
+    if (instance.config.sr.enabled || instance.config.srv6.enabled)
+        && let Some((mut instance, arenas)) = instance.as_up()
     {
+        if instance.config.srv6.enabled {
+            srv6::sids_update(&mut instance, arenas);
+        }
         instance.schedule_lsp_origination(instance.config.level_type);
     }
 }
@@ -311,3 +336,35 @@ pub(crate) fn process_msd_update(
         instance.schedule_lsp_origination(instance.config.level_type);
     }
 }
+
+pub(crate) fn process_ldp_igp_sync_update(
+    instance: &mut Instance,
+    ifname: String,
+    state: LdpIgpSyncState,
+) {
+    // Lookup interface.
+    let Some(iface) = instance.arenas.interfaces.get_mut_by_name(&ifname)
+    else {
+        return;
+    };
+    let iface_idx = iface.index;
+
+    // Update LDP-IGP synchronization state.
+    if iface.system.ldp_sync == state {
+        return;
+    }
+    iface.system.ldp_sync = state;
+
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        let iface = &mut arenas.interfaces[iface_idx];
+        if !iface.config.ldp_igp_sync || !iface.state.active {
+            return;
+        }
+
+        // Start or stop the hold-down timer.
+        iface.ldp_sync_holddown_update(&instance);
+
+        // Schedule LSP reorigination.
+        instance.schedule_lsp_origination(iface.config.level_type.resolved);
+    }
+}
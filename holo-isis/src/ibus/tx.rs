@@ -8,15 +8,17 @@
 //
 
 use std::collections::BTreeSet;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv6Addr};
 
 use holo_utils::ibus::IbusChannelsTx;
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
     LabelInstallMsg, LabelUninstallMsg, Nexthop, RouteKeyMsg, RouteKind,
-    RouteMsg, RouteOpaqueAttrs,
+    RouteMsg, RouteOpaqueAttrs, Srv6LocalSidInstallMsg,
+    Srv6LocalSidUninstallMsg,
 };
+use holo_utils::sr::Srv6EndpointBehavior;
 use ipnetwork::IpNetwork;
 
 use crate::collections::Interfaces;
@@ -58,6 +60,20 @@ pub(crate) fn route_install(
         })
         .collect::<BTreeSet<_>>();
 
+    // Fill-in backup nexthops.
+    let backup_nexthops = route
+        .backup_nexthops
+        .values()
+        .map(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            Nexthop::Address {
+                ifindex: iface.system.ifindex.unwrap(),
+                addr: nexthop.addr,
+                labels: nexthop.labels.clone(),
+            }
+        })
+        .collect::<BTreeSet<_>>();
+
     // Install route.
     let msg = RouteMsg {
         protocol: Protocol::ISIS,
@@ -74,6 +90,7 @@ pub(crate) fn route_install(
             route_type: route.route_type,
         },
         nexthops: nexthops.clone(),
+        backup_nexthops,
     };
     ibus_tx.route_ip_add(msg);
 
@@ -127,6 +144,63 @@ pub(crate) fn route_uninstall(
     }
 }
 
+pub(crate) fn route_retain(ibus_tx: &IbusChannelsTx, retain: bool) {
+    ibus_tx.route_retain(Protocol::ISIS, retain);
+}
+
+pub(crate) fn route_stale_sweep(ibus_tx: &IbusChannelsTx) {
+    ibus_tx.route_stale_sweep(Protocol::ISIS);
+}
+
+pub(crate) fn flex_algo_route_install(
+    ibus_tx: &IbusChannelsTx,
+    route: &Route,
+    interfaces: &Interfaces,
+) {
+    // Fill-in nexthops.
+    let nexthops = route
+        .nexthops
+        .values()
+        .map(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            Nexthop::Address {
+                ifindex: iface.system.ifindex.unwrap(),
+                addr: nexthop.addr,
+                labels: nexthop
+                    .sr_label
+                    .map(|label| vec![label])
+                    .unwrap_or_default(),
+            }
+        })
+        .collect::<BTreeSet<_>>();
+
+    // Install Flexible Algorithm Prefix-SID input label.
+    let msg = LabelInstallMsg {
+        protocol: Protocol::ISIS,
+        label: route.sr_label.unwrap(),
+        nexthops,
+        route: None,
+        replace: true,
+    };
+    ibus_tx.route_mpls_add(msg);
+}
+
+pub(crate) fn flex_algo_route_uninstall(
+    ibus_tx: &IbusChannelsTx,
+    route: &Route,
+) {
+    // Uninstall Flexible Algorithm Prefix-SID input label.
+    if let Some(sr_label) = route.sr_label {
+        let msg = LabelUninstallMsg {
+            protocol: Protocol::ISIS,
+            label: sr_label,
+            nexthops: BTreeSet::new(),
+            route: None,
+        };
+        ibus_tx.route_mpls_del(msg);
+    }
+}
+
 pub(crate) fn adj_sid_install(
     ibus_tx: &IbusChannelsTx,
     iface: &Interface,
@@ -157,3 +231,29 @@ pub(crate) fn adj_sid_uninstall(ibus_tx: &IbusChannelsTx, label: Label) {
     };
     ibus_tx.route_mpls_del(msg);
 }
+
+pub(crate) fn srv6_local_sid_install(
+    ibus_tx: &IbusChannelsTx,
+    sid: Ipv6Addr,
+    behavior: Srv6EndpointBehavior,
+    nexthop: Option<(u32, Ipv6Addr)>,
+) {
+    let msg = Srv6LocalSidInstallMsg {
+        protocol: Protocol::ISIS,
+        sid,
+        behavior,
+        nexthop,
+    };
+    ibus_tx.route_srv6_local_sid_add(msg);
+}
+
+pub(crate) fn srv6_local_sid_uninstall(
+    ibus_tx: &IbusChannelsTx,
+    sid: Ipv6Addr,
+) {
+    let msg = Srv6LocalSidUninstallMsg {
+        protocol: Protocol::ISIS,
+        sid,
+    };
+    ibus_tx.route_srv6_local_sid_del(msg);
+}
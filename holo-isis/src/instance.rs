@@ -7,8 +7,8 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use std::collections::{BTreeMap, VecDeque};
-use std::net::Ipv4Addr;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::time::Instant;
 
 use chrono::{DateTime, Utc};
@@ -30,19 +30,21 @@ use crate::debug::{
     Debug, InstanceInactiveReason, InterfaceInactiveReason, LspPurgeReason,
 };
 use crate::error::Error;
+use crate::gr::GrRestart;
 use crate::interface::CircuitIdAllocator;
 use crate::lsdb::{LspEntry, LspLogEntry};
 use crate::northbound::configuration::InstanceCfg;
 use crate::packet::{LevelNumber, LevelType, Levels, SystemId};
 use crate::route::{Route, RouteFlags, RouteSys, SummaryRoute};
-use crate::spf::{SpfLogEntry, SpfScheduler, Spt, Topologies};
+use crate::spf::{SpfLogEntry, SpfScheduler, Spt, Topologies, VertexId};
 use crate::tasks::messages::input::{
-    AdjHoldTimerMsg, DisElectionMsg, LspDeleteMsg, LspOriginateMsg,
-    LspPurgeMsg, LspRefreshMsg, NetRxPduMsg, SendCsnpMsg, SendPsnpMsg,
-    SpfDelayEventMsg,
+    AdjHoldTimerMsg, DisElectionMsg, GrTimerMsg, LdpSyncHolddownMsg,
+    LspDeleteMsg, LspOriginateMsg, LspPacingMsg, LspPurgeMsg, LspRefreshMsg,
+    NetRxPduMsg, SendCsnpMsg, SendPsnpMsg, SpfDelayEventMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
-use crate::{events, ibus, lsdb, spf, tasks};
+use crate::tilfa::RepairPath;
+use crate::{events, gr, ibus, lsdb, spf, srv6, tasks};
 
 #[derive(Debug)]
 pub struct Instance {
@@ -90,11 +92,22 @@ pub struct InstanceState {
     pub spf_sched: Levels<SpfScheduler>,
     // Shortest-path tree.
     pub spt: Topologies<Levels<Spt>>,
+    // TI-LFA repair paths computed from the shortest-path trees.
+    pub tilfa_repairs: Topologies<Levels<BTreeMap<VertexId, Vec<RepairPath>>>>,
+    // Flexible Algorithm shortest-path trees.
+    pub flex_algo_spt: Levels<BTreeMap<u8, Spt>>,
     // Routing table (per-level and L1/L2).
     pub rib_single: Levels<BTreeMap<IpNetwork, Route>>,
     pub rib_multi: BTreeMap<IpNetwork, Route>,
+    // Flexible Algorithm routing table (per-level and L1/L2).
+    pub flex_algo_rib_single: Levels<BTreeMap<(u8, IpNetwork), Route>>,
+    pub flex_algo_rib_multi: BTreeMap<(u8, IpNetwork), Route>,
     // Summary routes (L1 to L2).
     pub summaries: BTreeMap<IpNetwork, SummaryRoute>,
+    // Installed SRv6 End SID.
+    pub srv6_end_sid: Option<Ipv6Addr>,
+    // Function values allocated to SRv6 End.X SIDs.
+    pub srv6_end_x_funcs: BTreeSet<u32>,
     // Event counters.
     pub counters: Levels<InstanceCounters>,
     pub discontinuity_time: DateTime<Utc>,
@@ -104,6 +117,8 @@ pub struct InstanceState {
     // Log of SPF runs.
     pub spf_log: VecDeque<SpfLogEntry>,
     pub spf_log_next_id: u32,
+    // Graceful restart state.
+    pub gr: Option<GrRestart>,
 }
 
 #[derive(Debug, Default)]
@@ -141,6 +156,8 @@ pub struct ProtocolInputChannelsTx {
     pub send_psnp: UnboundedSender<SendPsnpMsg>,
     // Request to send CSNP(s).
     pub send_csnp: UnboundedSender<SendCsnpMsg>,
+    // Request to transmit paced LSP(s).
+    pub lsp_pacing: UnboundedSender<LspPacingMsg>,
     // LSP originate event.
     pub lsp_originate: UnboundedSender<LspOriginateMsg>,
     // LSP purge event.
@@ -151,6 +168,10 @@ pub struct ProtocolInputChannelsTx {
     pub lsp_refresh: UnboundedSender<LspRefreshMsg>,
     // SPF Delay FSM event.
     pub spf_delay_event: UnboundedSender<SpfDelayEventMsg>,
+    // LDP-IGP synchronization hold-down timer event.
+    pub ldp_sync_holddown: Sender<LdpSyncHolddownMsg>,
+    // Graceful restart timer event.
+    pub gr_timer: UnboundedSender<GrTimerMsg>,
 }
 
 #[derive(Debug)]
@@ -165,6 +186,8 @@ pub struct ProtocolInputChannelsRx {
     pub send_psnp: UnboundedReceiver<SendPsnpMsg>,
     // Request to send CSNP(s).
     pub send_csnp: UnboundedReceiver<SendCsnpMsg>,
+    // Request to transmit paced LSP(s).
+    pub lsp_pacing: UnboundedReceiver<LspPacingMsg>,
     // LSP originate event.
     pub lsp_originate: UnboundedReceiver<LspOriginateMsg>,
     // LSP purge event.
@@ -175,6 +198,10 @@ pub struct ProtocolInputChannelsRx {
     pub lsp_refresh: UnboundedReceiver<LspRefreshMsg>,
     // SPF Delay FSM event.
     pub spf_delay_event: UnboundedReceiver<SpfDelayEventMsg>,
+    // LDP-IGP synchronization hold-down timer event.
+    pub ldp_sync_holddown: Receiver<LdpSyncHolddownMsg>,
+    // Graceful restart timer event.
+    pub gr_timer: UnboundedReceiver<GrTimerMsg>,
 }
 
 pub struct InstanceUpView<'a> {
@@ -213,6 +240,9 @@ impl Instance {
         self.state = Some(state);
         let (mut instance, arenas) = self.as_up().unwrap();
 
+        // Start graceful restart procedures if enabled.
+        gr::start(&mut instance);
+
         // Start interfaces.
         for iface in arenas.interfaces.iter_mut() {
             iface
@@ -220,6 +250,9 @@ impl Instance {
                 .unwrap();
         }
 
+        // Install the SRv6 End SID.
+        srv6::end_sid_update(&mut instance);
+
         // Schedule initial LSP origination.
         instance.schedule_lsp_origination(LevelType::All);
     }
@@ -239,6 +272,19 @@ impl Instance {
         {
             ibus::tx::route_uninstall(&instance.tx.ibus, prefix, route);
         }
+        for route in instance
+            .state
+            .flex_algo_rib_multi
+            .values()
+            .filter(|route| route.flags.contains(RouteFlags::INSTALLED))
+        {
+            ibus::tx::flex_algo_route_uninstall(&instance.tx.ibus, route);
+        }
+
+        // Uninstall the SRv6 End SID.
+        if let Some(sid) = instance.state.srv6_end_sid {
+            ibus::tx::srv6_local_sid_uninstall(&instance.tx.ibus, sid);
+        }
 
         // Stop interfaces.
         let reason = InterfaceInactiveReason::InstanceDown;
@@ -347,6 +393,17 @@ impl ProtocolInstance for Instance {
     }
 
     fn shutdown(mut self) {
+        // The instance was unconfigured, so its routes shouldn't be preserved
+        // anymore.
+        //
+        // When Holo exits, `holo-routing` tears down its RIB (preserving the
+        // routes of the protocols that requested it) before the protocol
+        // instances are shut down, so neither this request nor the route
+        // removals below have any effect in that case.
+        if self.config.gr.enabled {
+            ibus::tx::route_retain(&self.tx.ibus, false);
+        }
+
         // Ensure instance is disabled before exiting.
         self.stop(InstanceInactiveReason::AdminDown);
         Debug::InstanceDelete.log();
@@ -376,11 +433,14 @@ impl ProtocolInstance for Instance {
         let (dis_electionp, dis_electionc) = mpsc::unbounded_channel();
         let (send_psnpp, send_psnpc) = mpsc::unbounded_channel();
         let (send_csnpp, send_csnpc) = mpsc::unbounded_channel();
+        let (lsp_pacingp, lsp_pacingc) = mpsc::unbounded_channel();
         let (lsp_originatep, lsp_originatec) = mpsc::unbounded_channel();
         let (lsp_purgep, lsp_purgec) = mpsc::unbounded_channel();
         let (lsp_deletep, lsp_deletec) = mpsc::unbounded_channel();
         let (lsp_refreshp, lsp_refreshc) = mpsc::unbounded_channel();
         let (spf_delay_eventp, spf_delay_eventc) = mpsc::unbounded_channel();
+        let (ldp_sync_holddownp, ldp_sync_holddownc) = mpsc::channel(4);
+        let (gr_timerp, gr_timerc) = mpsc::unbounded_channel();
 
         let tx = ProtocolInputChannelsTx {
             net_pdu_rx: net_pdu_rxp,
@@ -388,11 +448,14 @@ impl ProtocolInstance for Instance {
             dis_election: dis_electionp,
             send_psnp: send_psnpp,
             send_csnp: send_csnpp,
+            lsp_pacing: lsp_pacingp,
             lsp_originate: lsp_originatep,
             lsp_purge: lsp_purgep,
             lsp_delete: lsp_deletep,
             lsp_refresh: lsp_refreshp,
             spf_delay_event: spf_delay_eventp,
+            ldp_sync_holddown: ldp_sync_holddownp,
+            gr_timer: gr_timerp,
         };
         let rx = ProtocolInputChannelsRx {
             net_pdu_rx: net_pdu_rxc,
@@ -400,11 +463,14 @@ impl ProtocolInstance for Instance {
             dis_election: dis_electionc,
             send_psnp: send_psnpc,
             send_csnp: send_csnpc,
+            lsp_pacing: lsp_pacingc,
             lsp_originate: lsp_originatec,
             lsp_purge: lsp_purgec,
             lsp_delete: lsp_deletec,
             lsp_refresh: lsp_refreshc,
             spf_delay_event: spf_delay_eventc,
+            ldp_sync_holddown: ldp_sync_holddownc,
+            gr_timer: gr_timerc,
         };
 
         (tx, rx)
@@ -430,15 +496,22 @@ impl InstanceState {
             lsp_orig_pending: None,
             spf_sched: Default::default(),
             spt: Default::default(),
+            tilfa_repairs: Default::default(),
+            flex_algo_spt: Default::default(),
             rib_single: Default::default(),
             rib_multi: Default::default(),
+            flex_algo_rib_single: Default::default(),
+            flex_algo_rib_multi: Default::default(),
             summaries: Default::default(),
+            srv6_end_sid: None,
+            srv6_end_x_funcs: Default::default(),
             counters: Default::default(),
             discontinuity_time: Utc::now(),
             lsp_log: Default::default(),
             lsp_log_next_id: 0,
             spf_log: Default::default(),
             spf_log_next_id: 0,
+            gr: None,
         }
     }
 
@@ -534,6 +607,9 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.send_csnp.recv() => {
                 msg.map(ProtocolInputMsg::SendCsnp)
             }
+            msg = self.lsp_pacing.recv() => {
+                msg.map(ProtocolInputMsg::LspPacing)
+            }
             msg = self.lsp_originate.recv() => {
                 msg.map(ProtocolInputMsg::LspOriginate)
             }
@@ -549,6 +625,12 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.spf_delay_event.recv() => {
                 msg.map(ProtocolInputMsg::SpfDelayEvent)
             }
+            msg = self.ldp_sync_holddown.recv() => {
+                msg.map(ProtocolInputMsg::LdpSyncHolddown)
+            }
+            msg = self.gr_timer.recv() => {
+                msg.map(ProtocolInputMsg::GrTimer)
+            }
         }
     }
 }
@@ -673,6 +755,33 @@ fn process_ibus_msg(
             // Update all interfaces using this keychain.
             ibus::rx::process_keychain_update(instance, &keychain_name)?
         }
+        // Policy match sets update event.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition update event.
+        IbusMsg::PolicyUpd(policy) => {
+            // Update the local copy of the policy definition.
+            instance
+                .shared
+                .policies
+                .insert(policy.name.clone(), policy.clone());
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition delete event.
+        IbusMsg::PolicyDel(policy_name) => {
+            // Remove the local copy of the policy definition.
+            instance.shared.policies.remove(&policy_name);
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
         // Hostname update notification.
         IbusMsg::HostnameUpdate(hostname) => {
             ibus::rx::process_hostname_update(instance, hostname);
@@ -689,6 +798,10 @@ fn process_ibus_msg(
         IbusMsg::BierCfgUpd(bier_config) => {
             instance.shared.bier_config = bier_config;
         }
+        // LDP-IGP synchronization state update.
+        IbusMsg::LdpIgpSyncUpd { ifname, state } => {
+            ibus::rx::process_ldp_igp_sync_update(instance, ifname, state);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -757,6 +870,10 @@ fn process_protocol_msg(
                 msg.level,
             )?;
         }
+        // Request to transmit paced LSP(s).
+        ProtocolInputMsg::LspPacing(msg) => {
+            events::process_lsp_pacing(instance, arenas, msg.iface_key)?;
+        }
         // LSP origination event.
         ProtocolInputMsg::LspOriginate(_msg) => {
             events::process_lsp_originate(instance, arenas)?;
@@ -795,6 +912,26 @@ fn process_protocol_msg(
                 instance, arenas, msg.level, msg.event,
             )?
         }
+        // LDP-IGP synchronization hold-down timer event.
+        ProtocolInputMsg::LdpSyncHolddown(msg) => {
+            events::process_ldp_sync_holddown_timeout(
+                instance,
+                arenas,
+                msg.iface_key,
+            )?
+        }
+        // Graceful restart timer event.
+        ProtocolInputMsg::GrTimer(msg) => match msg {
+            GrTimerMsg::T1 { iface_key } => {
+                events::process_gr_t1_expiry(instance, arenas, iface_key)?;
+            }
+            GrTimerMsg::T2 { level } => {
+                events::process_gr_t2_expiry(instance, arenas, level);
+            }
+            GrTimerMsg::T3 => {
+                events::process_gr_t3_expiry(instance, arenas);
+            }
+        },
     }
 
     Ok(())
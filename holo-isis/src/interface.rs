@@ -14,6 +14,7 @@ use std::sync::atomic::{self, AtomicU32};
 use chrono::{DateTime, Utc};
 use holo_utils::ip::{AddressFamily, JointPrefixSetExt};
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::socket::{AsyncFd, Socket, SocketExt};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::sr::MsdType;
@@ -39,7 +40,7 @@ use crate::packet::tlv::{
 };
 use crate::packet::{LanId, LevelNumber, LevelType, Levels, LspId, SystemId};
 use crate::tasks::messages::output::NetTxPduMsg;
-use crate::{network, tasks};
+use crate::{gr, network, tasks};
 
 #[derive(Debug)]
 pub struct Interface {
@@ -59,6 +60,7 @@ pub struct InterfaceSys {
     pub msd: BTreeMap<MsdType, u8>,
     pub mac_addr: Option<MacAddr>,
     pub addr_list: JointPrefixSet<IpNetwork>,
+    pub ldp_sync: LdpIgpSyncState,
 }
 
 #[derive(Debug, Default)]
@@ -69,12 +71,16 @@ pub struct InterfaceState {
     pub lan_adjacencies: Levels<Adjacencies>,
     pub p2p_adjacency: Option<Adjacency>,
     pub dis: Levels<Option<DisCandidate>>,
-    pub srm_list: Levels<BTreeMap<LspId, IntervalTask>>,
+    pub srm_list: Levels<BTreeMap<LspId, SrmEntry>>,
     pub ssn_list: Levels<BTreeMap<LspId, LspEntry>>,
     pub ext_seqnum: (u64, Arc<AtomicU32>),
     pub event_counters: InterfaceEventCounters,
     pub packet_counters: Levels<InterfacePacketCounters>,
     pub discontinuity_time: DateTime<Utc>,
+    pub ldp_sync_holddown_expired: bool,
+    pub gr_t1_expirations: u8,
+    pub gr_helping: BTreeMap<SystemId, u16>,
+    pub te_perf: TePerfMetrics,
     pub tasks: InterfaceTasks,
 }
 
@@ -86,6 +92,18 @@ pub struct InterfaceNet {
     pub net_tx_pdup: UnboundedSender<NetTxPduMsg>,
 }
 
+// LSP flagged for transmission (SRM flag set).
+//
+// On point-to-point interfaces, all LSPs require acknowledgment, so the entry
+// is kept until the LSP is acknowledged. Retransmissions start only once the
+// LSP is first transmitted, which might be delayed by LSP pacing. On broadcast
+// interfaces, the entry is removed as soon as the LSP is transmitted.
+#[derive(Debug)]
+pub struct SrmEntry {
+    pub lsp: Lsp,
+    pub rxmt_task: Option<IntervalTask>,
+}
+
 #[derive(Debug, Default)]
 pub struct InterfaceTasks {
     pub hello_interval_p2p: Option<IntervalTask>,
@@ -93,6 +111,9 @@ pub struct InterfaceTasks {
     pub dis_initial_election: Levels<Option<TimeoutTask>>,
     pub psnp_interval: Levels<Option<IntervalTask>>,
     pub csnp_interval: Levels<Option<IntervalTask>>,
+    pub lsp_pacing: Option<IntervalTask>,
+    pub ldp_sync_holddown: Option<TimeoutTask>,
+    pub gr_t1: Option<TimeoutTask>,
 }
 
 #[derive(Debug, Default)]
@@ -116,6 +137,8 @@ pub struct InterfacePacketCounters {
     pub iih_out: Arc<AtomicU32>,
     pub lsp_in: u32,
     pub lsp_out: u32,
+    pub lsp_paced: u32,
+    pub lsp_rxmt: Arc<AtomicU32>,
     pub psnp_in: u32,
     pub psnp_out: u32,
     pub csnp_in: u32,
@@ -123,12 +146,35 @@ pub struct InterfacePacketCounters {
     pub unknown_in: u32,
 }
 
+// Link performance metrics currently advertised for the interface (RFC 8570).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TePerfMetrics {
+    pub delay: Option<u32>,
+    pub delay_anomalous: bool,
+    pub min_max_delay: Option<(u32, u32)>,
+    pub min_max_delay_anomalous: bool,
+    pub delay_variation: Option<u32>,
+    pub loss: Option<u32>,
+    pub loss_anomalous: bool,
+    pub residual_bw: Option<f32>,
+    pub available_bw: Option<f32>,
+    pub utilized_bw: Option<f32>,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum InterfaceType {
     Broadcast,
     PointToPoint,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MeshGroupState {
+    #[default]
+    Inactive,
+    Set,
+    Blocked,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct DisCandidate {
     pub priority: u8,
@@ -224,6 +270,12 @@ impl Interface {
             // Start network Tx/Rx tasks.
             self.state.net = Some(InterfaceNet::new(socket, self, instance));
 
+            // Start the graceful restart T1 timer.
+            if gr::is_restarting(instance) {
+                let task = tasks::gr_t1_timer(self, instance);
+                self.state.tasks.gr_t1 = Some(task);
+            }
+
             // Start Hello Tx task(s).
             self.hello_interval_start(instance, LevelType::All);
 
@@ -235,6 +287,9 @@ impl Interface {
                 (instance.state.boot_count, Arc::new(AtomicU32::new(0)));
         }
 
+        // Start LDP-IGP synchronization hold-down timer if necessary.
+        self.ldp_sync_holddown_update(instance);
+
         // Mark interface as active.
         self.state.active = true;
 
@@ -295,6 +350,11 @@ impl Interface {
         self.dis_initial_election_stop();
         self.psnp_interval_stop();
         self.csnp_interval_stop();
+        self.state.tasks.lsp_pacing = None;
+        self.state.tasks.ldp_sync_holddown = None;
+        self.state.tasks.gr_t1 = None;
+        self.state.gr_t1_expirations = 0;
+        self.state.gr_helping.clear();
 
         // Schedule LSP reorigination.
         instance.schedule_lsp_origination(self.config.level_type.resolved);
@@ -615,6 +675,9 @@ impl Interface {
             );
         }
 
+        // Set graceful restart information.
+        let restart = gr::hello_restart_tlv(self, instance);
+
         // Generate Hello PDU.
         let ext_seqnum = self.ext_seqnum_next(level);
         Hello::new(
@@ -625,10 +688,12 @@ impl Interface {
             variant,
             HelloTlvs::new(
                 protocols_supported,
+                instance.config.instance_id_tlv(true),
                 area_addrs,
                 multi_topology,
                 neighbors,
                 three_way_adj,
+                restart,
                 ipv4_addrs,
                 ipv6_addrs,
                 ext_seqnum,
@@ -722,6 +787,168 @@ impl Interface {
         self.state.tasks.csnp_interval = Default::default();
     }
 
+    // Starts or stops the LDP-IGP synchronization hold-down timer according to
+    // the current LDP state of the interface.
+    pub(crate) fn ldp_sync_holddown_update(
+        &mut self,
+        instance: &InstanceUpView<'_>,
+    ) {
+        self.state.ldp_sync_holddown_expired = false;
+        self.state.tasks.ldp_sync_holddown = None;
+
+        if self.config.ldp_igp_sync
+            && self.system.ldp_sync == LdpIgpSyncState::NotSynced
+            && let Some(holddown) = instance.config.ldp_igp_sync_holddown
+        {
+            let task = tasks::ldp_sync_holddown_timer(self, instance, holddown);
+            self.state.tasks.ldp_sync_holddown = Some(task);
+        }
+    }
+
+    // Returns whether the interface should advertise the maximum link metric
+    // while waiting for LDP to converge (RFC 5443).
+    pub(crate) fn ldp_sync_max_metric(&self) -> bool {
+        self.config.ldp_igp_sync
+            && self.system.ldp_sync == LdpIgpSyncState::NotSynced
+            && !self.state.ldp_sync_holddown_expired
+    }
+
+    // Returns the mesh group the interface belongs to, if any (RFC 2973).
+    pub(crate) fn mesh_group(&self) -> Option<u8> {
+        match self.config.mesh_group_state {
+            MeshGroupState::Set => self.config.mesh_group,
+            MeshGroupState::Inactive | MeshGroupState::Blocked => None,
+        }
+    }
+
+    // Checks whether LSPs received from the given mesh group, or originated
+    // locally when no mesh group is specified, must not be flooded over this
+    // interface (RFC 2973).
+    //
+    // This applies only to the initial flooding of LSPs. LSPs requested by the
+    // neighbor using SNPs are sent regardless of the mesh group configuration.
+    pub(crate) fn mesh_group_blocked(
+        &self,
+        rcvd_mesh_group: Option<u8>,
+    ) -> bool {
+        match self.config.mesh_group_state {
+            MeshGroupState::Inactive => false,
+            MeshGroupState::Set => {
+                rcvd_mesh_group.is_some()
+                    && rcvd_mesh_group == self.config.mesh_group
+            }
+            MeshGroupState::Blocked => true,
+        }
+    }
+
+    // Updates the advertised link performance metrics (RFC 8570).
+    //
+    // To avoid excessive flooding, a metric is only re-advertised right away
+    // when it changes by at least the configured threshold relative to the
+    // last advertised value, or when its Anomalous flag changes. Smaller
+    // changes are deferred until the next LSP origination (see
+    // `te_perf_metrics_sync`). Returns whether the advertised metrics were
+    // updated.
+    pub(crate) fn te_perf_metrics_update(&mut self) -> bool {
+        let threshold = self.config.te_perf.adv_threshold;
+        let adv = &self.state.te_perf;
+        let metrics = self.te_perf_metrics();
+
+        let min_delay = |m: &TePerfMetrics| m.min_max_delay.map(|(min, _)| min);
+        let max_delay = |m: &TePerfMetrics| m.min_max_delay.map(|(_, max)| max);
+        let readvertise = metrics.delay_anomalous != adv.delay_anomalous
+            || metrics.min_max_delay_anomalous != adv.min_max_delay_anomalous
+            || metrics.loss_anomalous != adv.loss_anomalous
+            || perf_metric_changed(adv.delay, metrics.delay, threshold)
+            || perf_metric_changed(
+                min_delay(adv),
+                min_delay(&metrics),
+                threshold,
+            )
+            || perf_metric_changed(
+                max_delay(adv),
+                max_delay(&metrics),
+                threshold,
+            )
+            || perf_metric_changed(
+                adv.delay_variation,
+                metrics.delay_variation,
+                threshold,
+            )
+            || perf_metric_changed(adv.loss, metrics.loss, threshold)
+            || perf_metric_changed(
+                adv.residual_bw,
+                metrics.residual_bw,
+                threshold,
+            )
+            || perf_metric_changed(
+                adv.available_bw,
+                metrics.available_bw,
+                threshold,
+            )
+            || perf_metric_changed(
+                adv.utilized_bw,
+                metrics.utilized_bw,
+                threshold,
+            );
+        if readvertise {
+            self.state.te_perf = metrics;
+        }
+
+        readvertise
+    }
+
+    // Updates the advertised link performance metrics with any pending
+    // change that didn't cross the advertisement threshold. This is called
+    // before the local LSPs are originated, so that those changes are
+    // eventually advertised. Returns whether the advertised metrics were
+    // updated.
+    pub(crate) fn te_perf_metrics_sync(&mut self) -> bool {
+        let metrics = self.te_perf_metrics();
+        if metrics == self.state.te_perf {
+            return false;
+        }
+
+        self.state.te_perf = metrics;
+        true
+    }
+
+    // Returns the link performance metrics derived from the current
+    // configuration.
+    fn te_perf_metrics(&self) -> TePerfMetrics {
+        let cfg = &self.config.te_perf;
+        let adv = &self.state.te_perf;
+
+        let min_max_delay = cfg.min_delay.zip(cfg.max_delay);
+        TePerfMetrics {
+            delay: cfg.delay,
+            delay_anomalous: perf_metric_anomalous(
+                adv.delay_anomalous,
+                cfg.delay,
+                cfg.delay_max,
+                cfg.delay_reuse,
+            ),
+            min_max_delay,
+            min_max_delay_anomalous: perf_metric_anomalous(
+                adv.min_max_delay_anomalous,
+                min_max_delay.map(|(_, max)| max),
+                cfg.delay_max,
+                cfg.delay_reuse,
+            ),
+            delay_variation: cfg.delay_variation,
+            loss: cfg.loss,
+            loss_anomalous: perf_metric_anomalous(
+                adv.loss_anomalous,
+                cfg.loss,
+                cfg.loss_max,
+                cfg.loss_reuse,
+            ),
+            residual_bw: cfg.residual_bw,
+            available_bw: cfg.available_bw,
+            utilized_bw: cfg.utilized_bw,
+        }
+    }
+
     pub(crate) fn srm_list_add(
         &mut self,
         instance: &InstanceUpView<'_>,
@@ -753,35 +980,104 @@ impl Interface {
         // transmitted by at least one".
         lsp.set_rem_lifetime(lsp.rem_lifetime().saturating_sub(1));
 
-        // For point-to-point interfaces, all LSPs require acknowledgment.
-        // Retransmissions will occur until an acknowledgment is received.
-        if self.config.interface_type == InterfaceType::PointToPoint {
-            if let Some(adj) = &self.state.p2p_adjacency
-                && adj.level_usage.intersects(level)
-            {
-                let rxmt_interval = self.config.lsp_rxmt_interval;
-                let dst = self.config.interface_type.multicast_addr(level);
-                let task = tasks::lsp_rxmt_interval(
-                    self,
-                    lsp.clone(),
-                    dst,
-                    rxmt_interval,
-                );
-                self.state.srm_list.get_mut(level).insert(lsp.lsp_id, task);
-            } else {
-                return;
-            }
+        // For point-to-point interfaces, the adjacency must be up for this
+        // level.
+        if self.config.interface_type == InterfaceType::PointToPoint
+            && !self
+                .state
+                .p2p_adjacency
+                .as_ref()
+                .is_some_and(|adj| adj.level_usage.intersects(level))
+        {
+            return;
         }
 
-        // Enqueue LSP for transmission.
+        // Set the SRM flag, replacing any older instance of the LSP.
+        let lsp_id = lsp.lsp_id;
+        let entry = SrmEntry {
+            lsp,
+            rxmt_task: None,
+        };
+        self.state.srm_list.get_mut(level).insert(lsp_id, entry);
+
+        // Transmit the LSP right away, unless another LSP was sent within the
+        // last pacing interval, in which case it will be transmitted once the
+        // pacing timer fires.
         //
-        // TODO: Implement LSP pacing.
-        self.enqueue_pdu(Pdu::Lsp(lsp), level);
+        // Pacing depends on wall-clock time, so it's bypassed in deterministic
+        // builds, like the LSP origination backoff.
+        if self.config.lsp_pacing_interval != 0
+            && !cfg!(feature = "deterministic")
+        {
+            if self.state.tasks.lsp_pacing.is_some() {
+                self.state.packet_counters.get_mut(level).lsp_paced += 1;
+                return;
+            }
+            let task = tasks::lsp_pacing_interval(self, instance);
+            self.state.tasks.lsp_pacing = Some(task);
+        }
+        self.lsp_tx(level, lsp_id);
     }
 
     pub(crate) fn srm_list_del(&mut self, level: LevelNumber, lsp_id: &LspId) {
+        self.state.srm_list.get_mut(level).remove(lsp_id);
+    }
+
+    // Returns the number of SRM-flagged LSPs that are waiting for their first
+    // transmission.
+    pub(crate) fn srm_list_pending(&self, level: LevelNumber) -> usize {
+        self.state
+            .srm_list
+            .get(level)
+            .values()
+            .filter(|entry| entry.rxmt_task.is_none())
+            .count()
+    }
+
+    // Transmits the next SRM-flagged LSP that is waiting for its first
+    // transmission. The pacing interval ends once there are no such LSPs
+    // left.
+    pub(crate) fn lsp_tx_paced(&mut self) {
+        let level = if self.srm_list_pending(LevelNumber::L1)
+            >= self.srm_list_pending(LevelNumber::L2)
+        {
+            LevelNumber::L1
+        } else {
+            LevelNumber::L2
+        };
+        match self
+            .state
+            .srm_list
+            .get(level)
+            .iter()
+            .find(|(_, entry)| entry.rxmt_task.is_none())
+            .map(|(lsp_id, _)| *lsp_id)
+        {
+            Some(lsp_id) => self.lsp_tx(level, lsp_id),
+            None => self.state.tasks.lsp_pacing = None,
+        }
+    }
+
+    // Transmits the given SRM-flagged LSP.
+    //
+    // On point-to-point interfaces, the LSP retransmission task is started.
+    // On broadcast interfaces, the SRM flag is cleared.
+    fn lsp_tx(&mut self, level: LevelNumber, lsp_id: LspId) {
+        let Some(entry) = self.state.srm_list.get(level).get(&lsp_id) else {
+            return;
+        };
+        let lsp = entry.lsp.clone();
+        self.enqueue_pdu(Pdu::Lsp(lsp.clone()), level);
+
         if self.config.interface_type == InterfaceType::PointToPoint {
-            self.state.srm_list.get_mut(level).remove(lsp_id);
+            let task = tasks::lsp_rxmt_interval(self, level, lsp);
+            if let Some(entry) =
+                self.state.srm_list.get_mut(level).get_mut(&lsp_id)
+            {
+                entry.rxmt_task = Some(task);
+            }
+        } else {
+            self.state.srm_list.get_mut(level).remove(&lsp_id);
         }
     }
 
@@ -977,3 +1273,45 @@ impl Default for CircuitIdAllocator {
         }
     }
 }
+
+// ===== helper functions =====
+
+// Returns whether the Anomalous flag of a link performance metric should be
+// set. The flag is set when the metric exceeds the maximum threshold, and
+// cleared only once it falls below the reuse threshold.
+fn perf_metric_anomalous(
+    anomalous: bool,
+    value: Option<u32>,
+    max: Option<u32>,
+    reuse: Option<u32>,
+) -> bool {
+    let (Some(value), Some(max)) = (value, max) else {
+        return false;
+    };
+    let reuse = reuse.unwrap_or(max);
+
+    if value > max {
+        true
+    } else if value < reuse {
+        false
+    } else {
+        anomalous
+    }
+}
+
+// Returns whether a link performance metric changed enough to warrant its
+// immediate re-advertisement.
+fn perf_metric_changed<T>(old: Option<T>, new: Option<T>, threshold: u8) -> bool
+where
+    T: Copy + Into<f64>,
+{
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            let (old, new) = (old.into(), new.into());
+            let delta = (new - old).abs();
+            delta > 0.0 && delta * 100.0 >= old * f64::from(threshold)
+        }
+        (None, None) => false,
+        _ => true,
+    }
+}
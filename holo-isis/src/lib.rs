@@ -17,6 +17,8 @@ pub mod collections;
 pub mod debug;
 pub mod error;
 pub mod events;
+pub mod flex_algo;
+pub mod gr;
 pub mod ibus;
 pub mod instance;
 pub mod interface;
@@ -24,7 +26,10 @@ pub mod lsdb;
 pub mod network;
 pub mod northbound;
 pub mod packet;
+pub mod policy;
 pub mod route;
 pub mod spf;
 pub mod sr;
+pub mod srv6;
 pub mod tasks;
+pub mod tilfa;
@@ -19,11 +19,14 @@ use holo_utils::bier::{
     UnderlayProtocolType,
 };
 use holo_utils::ip::{
-    AddressFamily, Ipv4NetworkExt, Ipv6NetworkExt, JointPrefixMapExt,
-    JointPrefixSetExt,
+    AddressFamily, IpNetworkKind, Ipv4NetworkExt, Ipv6NetworkExt,
+    JointPrefixMapExt, JointPrefixSetExt,
 };
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid, SidLastHopBehavior, SrCfgPrefixSid};
+use holo_utils::policy::PolicyResult;
+use holo_utils::sr::{
+    IgpAlgoType, Sid, SidLastHopBehavior, SrCfgPrefixSid, Srv6EndpointBehavior,
+};
 use holo_utils::task::TimeoutTask;
 use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use itertools::Itertools;
@@ -34,29 +37,39 @@ use crate::collections::{Arena, LspEntryId};
 use crate::debug::{Debug, LspPurgeReason};
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{Interface, InterfaceType};
-use crate::northbound::configuration::MetricType;
+use crate::northbound::configuration::{FlexAlgoCfg, MetricType};
 use crate::northbound::notification;
 use crate::packet::consts::{MtId, Nlpid};
 use crate::packet::pdu::{Lsp, LspFlags, LspTlvs, Pdu};
 use crate::packet::subtlvs::MsdStlv;
 use crate::packet::subtlvs::capability::{
-    LabelBlockEntry, NodeAdminTagStlv, SrAlgoStlv, SrCapabilitiesFlags,
-    SrCapabilitiesStlv, SrLocalBlockStlv,
+    FlexAlgoDefStlv, LabelBlockEntry, NodeAdminTagStlv, SrAlgoStlv,
+    SrCapabilitiesFlags, SrCapabilitiesStlv, SrLocalBlockStlv, Srv6CapFlags,
+    Srv6CapabilitiesStlv,
+};
+use crate::packet::subtlvs::neighbor::{
+    AdminGroupStlv, MinMaxUnidirLinkDelayStlv, PerfMetricFlags,
+    TeDefaultMetricStlv, UnidirAvailableBwStlv, UnidirDelayVariationStlv,
+    UnidirLinkDelayStlv, UnidirLinkLossStlv, UnidirResidualBwStlv,
+    UnidirUtilizedBwStlv,
 };
 use crate::packet::subtlvs::prefix::{
-    BierEncapSubStlv, BierInfoStlv, BierSubStlv, Ipv4SourceRidStlv,
-    Ipv6SourceRidStlv, PrefixAttrFlags, PrefixAttrFlagsStlv, PrefixSidFlags,
-    PrefixSidStlv,
+    AdminTagStlv, BierEncapSubStlv, BierInfoStlv, BierSubStlv,
+    Ipv4SourceRidStlv, Ipv6SourceRidStlv, PrefixAttrFlags, PrefixAttrFlagsStlv,
+    PrefixSidFlags, PrefixSidStlv, Srv6EndSidStlv,
 };
 use crate::packet::tlv::{
     IpReachTlvEntry, Ipv4Reach, Ipv4ReachStlvs, Ipv6Reach, Ipv6ReachStlvs,
     IsReach, IsReachStlvs, LegacyIpv4Reach, LegacyIsReach, MAX_NARROW_METRIC,
-    MtFlags, MultiTopologyEntry, RouterCapFlags, RouterCapTlv,
+    MAX_WIDE_LINK_METRIC, MtFlags, MultiTopologyEntry, RouterCapFlags,
+    RouterCapTlv, Srv6Locator, Srv6LocatorFlags, Srv6LocatorStlvs,
+    Srv6LocatorTlv, tlv_entries_split,
 };
 use crate::packet::{LanId, LevelNumber, LevelType, LspId};
+use crate::route::RouteSys;
 use crate::spf::{SpfType, VertexId};
 use crate::tasks::messages::input::LspPurgeMsg;
-use crate::{spf, tasks};
+use crate::{policy, spf, srv6, tasks};
 
 // LSP ZeroAge lifetime.
 pub const LSP_ZERO_AGE_LIFETIME: u64 = 60;
@@ -344,6 +357,9 @@ fn lsp_build_tlvs(
         );
     }
 
+    // Add SRv6 locator information.
+    let srv6_locator = lsp_build_tlvs_srv6_locator(instance, &mut ipv6_reach);
+
     // Swap the IPv6 reachability entries to use MT TLVs if the IPv6 unicast
     // topology is enabled.
     if instance.config.is_topology_enabled(MtId::Ipv6Unicast) {
@@ -352,6 +368,7 @@ fn lsp_build_tlvs(
 
     LspTlvs::new(
         protocols_supported,
+        instance.config.instance_id_tlv(false),
         router_cap,
         instance.config.area_addrs.clone(),
         multi_topology,
@@ -370,6 +387,7 @@ fn lsp_build_tlvs(
         ipv6_reach.into_values(),
         mt_ipv6_reach.into_values(),
         instance.config.ipv6_router_id,
+        srv6_locator,
     )
 }
 
@@ -392,6 +410,8 @@ fn lsp_build_tlvs_pseudo(
         .iter(&arenas.adjacencies)
         // Add adjacencies in the Up state.
         .filter(|adj| adj.state == AdjacencyState::Up)
+        // Skip neighbors that requested adjacency suppression (RFC 8706).
+        .filter(|adj| !adj.gr_suppress)
         .map(|adj| LanId::from((adj.system_id, 0)))
         // Add ourselves.
         .chain(std::iter::once(LanId::from((system_id, 0))))
@@ -416,6 +436,7 @@ fn lsp_build_tlvs_pseudo(
 
     LspTlvs::new(
         [],
+        instance.config.instance_id_tlv(false),
         [].into(),
         [],
         [],
@@ -434,6 +455,7 @@ fn lsp_build_tlvs_pseudo(
         [],
         [],
         None,
+        vec![],
     )
 }
 
@@ -465,7 +487,27 @@ fn lsp_build_tlvs_router_cap(
         cap.sub_tlvs.sr_cap = Some(SrCapabilitiesStlv::new(sr_cap_flags, srgb));
 
         // Add SR-Algorithm Sub-TLV.
-        cap.sub_tlvs.sr_algo = Some(SrAlgoStlv::new([IgpAlgoType::Spf].into()));
+        let sr_algos = std::iter::once(IgpAlgoType::Spf)
+            .chain(
+                instance
+                    .config
+                    .flex_algos
+                    .keys()
+                    .map(|algo| IgpAlgoType::FlexAlgo(*algo)),
+            )
+            .collect();
+        cap.sub_tlvs.sr_algo = Some(SrAlgoStlv::new(sr_algos));
+
+        // Add Flexible Algorithm Definition Sub-TLVs.
+        cap.sub_tlvs.flex_algo_defs = instance
+            .config
+            .flex_algos
+            .iter()
+            .filter(|(_, flex_algo_cfg)| flex_algo_cfg.advertise_def)
+            .map(|(algo, flex_algo_cfg)| {
+                lsp_build_fad_stlv(*algo, flex_algo_cfg)
+            })
+            .collect();
 
         // Add SR Local Block Sub-TLV.
         let mut srlb = vec![];
@@ -479,6 +521,12 @@ fn lsp_build_tlvs_router_cap(
         }
     }
 
+    // Add SRv6 Capabilities Sub-TLV.
+    if srv6::locator(instance).is_some() {
+        let srv6_cap = Srv6CapabilitiesStlv::new(Srv6CapFlags::empty());
+        cap.sub_tlvs.srv6_cap = Some(srv6_cap);
+    }
+
     // Add Node MSD Sub-TLV.
     if !instance.system.node_msd.is_empty() {
         cap.sub_tlvs.node_msd = Some(MsdStlv::from(&instance.system.node_msd));
@@ -496,6 +544,7 @@ fn lsp_build_tlvs_router_cap(
     }
 
     if cap.sub_tlvs.sr_cap.is_some()
+        || cap.sub_tlvs.srv6_cap.is_some()
         || cap.sub_tlvs.node_msd.is_some()
         || !cap.sub_tlvs.node_tags.is_empty()
     {
@@ -513,7 +562,21 @@ fn lsp_build_tlvs_is_reach(
     mt_is_reach: &mut Vec<IsReach>,
     adjacencies: &Arena<Adjacency>,
 ) {
-    let metric = iface.config.metric.get(level);
+    // Advertise the maximum link metric while LDP hasn't converged on the
+    // interface (RFC 5443).
+    let ldp_sync_max_metric = iface.ldp_sync_max_metric();
+    let topology_metric = |mt_id: MtId| {
+        if ldp_sync_max_metric {
+            MAX_WIDE_LINK_METRIC
+        } else {
+            iface.config.topology_metric(mt_id, level)
+        }
+    };
+    let metric = if ldp_sync_max_metric {
+        MAX_WIDE_LINK_METRIC
+    } else {
+        iface.config.metric.get(level)
+    };
 
     match iface.config.interface_type {
         InterfaceType::Broadcast => {
@@ -573,7 +636,7 @@ fn lsp_build_tlvs_is_reach(
                     );
                     mt_is_reach.push(IsReach {
                         neighbor: dis.lan_id,
-                        metric: iface.config.topology_metric(mt_id, level),
+                        metric: topology_metric(mt_id),
                         sub_tlvs,
                     });
                 }
@@ -586,6 +649,7 @@ fn lsp_build_tlvs_is_reach(
                 .as_ref()
                 .filter(|adj| adj.level_usage.intersects(level))
                 .filter(|adj| adj.state == AdjacencyState::Up)
+                .filter(|adj| !adj.gr_suppress)
             {
                 let neighbor = LanId::from((adj.system_id, 0));
 
@@ -640,7 +704,7 @@ fn lsp_build_tlvs_is_reach(
                     );
                     mt_is_reach.push(IsReach {
                         neighbor,
-                        metric: iface.config.topology_metric(mt_id, level),
+                        metric: topology_metric(mt_id),
                         sub_tlvs,
                     });
                 }
@@ -649,6 +713,61 @@ fn lsp_build_tlvs_is_reach(
     }
 }
 
+fn lsp_build_tlvs_srv6_locator(
+    instance: &mut InstanceUpView<'_>,
+    ipv6_reach: &mut BTreeMap<Ipv6Network, Ipv6Reach>,
+) -> Vec<Srv6LocatorTlv> {
+    let Some(locator) = srv6::locator(instance) else {
+        return vec![];
+    };
+    if !instance.config.is_af_enabled(AddressFamily::Ipv6) {
+        return vec![];
+    }
+
+    // Add SRv6 Locator TLV, including the End SID.
+    let end_sid = Srv6EndSidStlv::new(
+        Srv6EndpointBehavior::End as u16,
+        locator.sid(srv6::END_SID_FUNC),
+        Some(srv6::sid_structure(&locator)),
+    );
+    let sub_tlvs = Srv6LocatorStlvs {
+        end_sids: vec![end_sid],
+        unknown: vec![],
+    };
+    let entry = Srv6Locator {
+        metric: 0,
+        flags: Srv6LocatorFlags::empty(),
+        algo: IgpAlgoType::Spf,
+        locator: locator.prefix,
+        sub_tlvs,
+    };
+    let mt_id = if instance.config.is_topology_enabled(MtId::Ipv6Unicast) {
+        MtId::Ipv6Unicast
+    } else {
+        MtId::Standard
+    };
+    let srv6_locator = tlv_entries_split([entry])
+        .into_iter()
+        .map(|mut tlv: Srv6LocatorTlv| {
+            tlv.mt_id = mt_id as u16;
+            tlv
+        })
+        .collect();
+
+    // The locator is also advertised in the IPv6 reachability TLVs, so that
+    // routers that don't support SRv6 can still forward traffic towards it.
+    let prefix = Ipv6NetworkExt::apply_mask(&locator.prefix);
+    ipv6_reach.entry(prefix).or_insert_with(|| Ipv6Reach {
+        metric: 0,
+        up_down: false,
+        external: false,
+        prefix,
+        sub_tlvs: Default::default(),
+    });
+
+    srv6_locator
+}
+
 fn lsp_build_tlvs_ip_local(
     instance: &mut InstanceUpView<'_>,
     iface: &Interface,
@@ -670,7 +789,7 @@ fn lsp_build_tlvs_ip_local(
         for addr in iface.system.addr_list.ipv4().iter() {
             ipv4_addrs.insert(addr.ip());
 
-            let prefix = addr.apply_mask();
+            let prefix = Ipv4NetworkExt::apply_mask(addr);
             if metric_type.is_standard_enabled() {
                 ipv4_internal_reach.insert(
                     prefix,
@@ -728,7 +847,7 @@ fn lsp_build_tlvs_ip_local(
         {
             ipv6_addrs.insert(addr.ip());
 
-            let prefix = addr.apply_mask();
+            let prefix = Ipv6NetworkExt::apply_mask(addr);
             let mut prefix_attr_flags = PrefixAttrFlags::empty();
             if iface.config.node_flag
                 && iface.is_loopback()
@@ -766,7 +885,15 @@ fn lsp_build_tlvs_ip_redistributed(
     if instance.config.is_af_enabled(AddressFamily::Ipv4) {
         let metric_type = instance.config.metric_type.get(level);
         for (prefix, route) in instance.system.routes.get(level).ipv4() {
-            let prefix = prefix.apply_mask();
+            let prefix = Ipv4NetworkExt::apply_mask(prefix);
+
+            // Apply redistribution policies.
+            let Some(route) =
+                lsp_redistribute_policy_apply(instance, level, prefix, route)
+            else {
+                continue;
+            };
+
             if metric_type.is_standard_enabled() {
                 ipv4_external_reach.insert(
                     prefix,
@@ -784,12 +911,15 @@ fn lsp_build_tlvs_ip_redistributed(
             }
             if metric_type.is_wide_enabled() {
                 let prefix_attr_flags = PrefixAttrFlags::X;
-                let sub_tlvs = lsp_build_ipv4_reach_stlvs(
+                let mut sub_tlvs = lsp_build_ipv4_reach_stlvs(
                     instance,
                     prefix,
                     prefix_attr_flags,
                     false,
                 );
+                sub_tlvs.admin_tags = route
+                    .tag
+                    .map(|tag| AdminTagStlv::new(BTreeSet::from([tag])));
                 ext_ipv4_reach.insert(
                     prefix,
                     Ipv4Reach {
@@ -804,14 +934,25 @@ fn lsp_build_tlvs_ip_redistributed(
     }
     if instance.config.is_af_enabled(AddressFamily::Ipv6) {
         for (prefix, route) in instance.system.routes.get(level).ipv6() {
-            let prefix = prefix.apply_mask();
+            let prefix = Ipv6NetworkExt::apply_mask(prefix);
+
+            // Apply redistribution policies.
+            let Some(route) =
+                lsp_redistribute_policy_apply(instance, level, prefix, route)
+            else {
+                continue;
+            };
+
             let prefix_attr_flags = PrefixAttrFlags::empty();
-            let sub_tlvs = lsp_build_ipv6_reach_stlvs(
+            let mut sub_tlvs = lsp_build_ipv6_reach_stlvs(
                 instance,
                 prefix,
                 prefix_attr_flags,
                 false,
             );
+            sub_tlvs.admin_tags = route
+                .tag
+                .map(|tag| AdminTagStlv::new(BTreeSet::from([tag])));
             ipv6_reach.insert(
                 prefix,
                 Ipv6Reach {
@@ -826,6 +967,34 @@ fn lsp_build_tlvs_ip_redistributed(
     }
 }
 
+// Applies the redistribution policies configured for the route's protocol.
+//
+// Returns `None` if the route was rejected.
+fn lsp_redistribute_policy_apply(
+    instance: &InstanceUpView<'_>,
+    level: LevelNumber,
+    prefix: impl Into<IpNetwork>,
+    route: &RouteSys,
+) -> Option<RouteSys> {
+    let prefix = prefix.into();
+    let redistribution = instance
+        .config
+        .afs
+        .get(&prefix.address_family())?
+        .redistribution
+        .get(&(level, route.protocol))?;
+    match policy::redistribute_apply(
+        prefix,
+        route,
+        redistribution,
+        &instance.shared.policies,
+        &instance.shared.policy_match_sets,
+    ) {
+        PolicyResult::Accept(route) => Some(route),
+        PolicyResult::Reject => None,
+    }
+}
+
 fn lsp_build_is_reach_lan_stlvs(
     instance: &InstanceUpView<'_>,
     iface: &Interface,
@@ -848,6 +1017,23 @@ fn lsp_build_is_reach_lan_stlvs(
             .collect();
     }
 
+    // Add SRv6 LAN End.X SID Sub-TLV(s).
+    if instance.config.srv6.enabled
+        && af.is_none_or(|af| af == AddressFamily::Ipv6)
+    {
+        sub_tlvs.srv6_end_x_sids = iface
+            .state
+            .lan_adjacencies
+            .get(level)
+            .iter(adjacencies)
+            .filter_map(|adj| adj.srv6_end_x_sid.as_ref())
+            .map(|end_x_sid| end_x_sid.to_stlv())
+            .collect();
+    }
+
+    // Add Traffic Engineering link attributes.
+    lsp_build_is_reach_te_stlvs(iface, &mut sub_tlvs);
+
     // Add Link MSD Sub-TLV.
     if !iface.system.msd.is_empty() {
         sub_tlvs.link_msd = Some(MsdStlv::from(&iface.system.msd));
@@ -874,6 +1060,17 @@ fn lsp_build_is_reach_p2p_stlvs(
             .collect();
     }
 
+    // Add SRv6 End.X SID Sub-TLV.
+    if instance.config.srv6.enabled
+        && af.is_none_or(|af| af == AddressFamily::Ipv6)
+        && let Some(end_x_sid) = &adj.srv6_end_x_sid
+    {
+        sub_tlvs.srv6_end_x_sids = vec![end_x_sid.to_stlv()];
+    }
+
+    // Add Traffic Engineering link attributes.
+    lsp_build_is_reach_te_stlvs(iface, &mut sub_tlvs);
+
     // Add Link MSD Sub-TLV.
     if !iface.system.msd.is_empty() {
         sub_tlvs.link_msd = Some(MsdStlv::from(&iface.system.msd));
@@ -882,6 +1079,58 @@ fn lsp_build_is_reach_p2p_stlvs(
     sub_tlvs
 }
 
+// NOTE: the Application-Specific Link Attributes (RFC 8919) aren't supported,
+// so the legacy TE Sub-TLVs are advertised instead.
+fn lsp_build_is_reach_te_stlvs(iface: &Interface, sub_tlvs: &mut IsReachStlvs) {
+    // Add Administrative Group Sub-TLV.
+    if iface.config.te_admin_groups != 0 {
+        sub_tlvs.admin_group =
+            Some(AdminGroupStlv::new(iface.config.te_admin_groups));
+    }
+
+    // Add TE Default Metric Sub-TLV.
+    if let Some(te_metric) = iface.config.te_metric {
+        sub_tlvs.te_default_metric = Some(TeDefaultMetricStlv::new(te_metric));
+    }
+
+    // Add link performance metrics Sub-TLVs (RFC 8570).
+    let perf = &iface.state.te_perf;
+    let perf_flags = |anomalous: bool| {
+        if anomalous {
+            PerfMetricFlags::A
+        } else {
+            PerfMetricFlags::empty()
+        }
+    };
+    if let Some(delay) = perf.delay {
+        let flags = perf_flags(perf.delay_anomalous);
+        sub_tlvs.unidir_link_delay =
+            Some(UnidirLinkDelayStlv::new(flags, delay));
+    }
+    if let Some((min_delay, max_delay)) = perf.min_max_delay {
+        let flags = perf_flags(perf.min_max_delay_anomalous);
+        sub_tlvs.min_max_unidir_link_delay =
+            Some(MinMaxUnidirLinkDelayStlv::new(flags, min_delay, max_delay));
+    }
+    if let Some(variation) = perf.delay_variation {
+        sub_tlvs.unidir_delay_variation =
+            Some(UnidirDelayVariationStlv::new(variation));
+    }
+    if let Some(loss) = perf.loss {
+        let flags = perf_flags(perf.loss_anomalous);
+        sub_tlvs.unidir_link_loss = Some(UnidirLinkLossStlv::new(flags, loss));
+    }
+    if let Some(bw) = perf.residual_bw {
+        sub_tlvs.unidir_residual_bw = Some(UnidirResidualBwStlv::new(bw));
+    }
+    if let Some(bw) = perf.available_bw {
+        sub_tlvs.unidir_available_bw = Some(UnidirAvailableBwStlv::new(bw));
+    }
+    if let Some(bw) = perf.utilized_bw {
+        sub_tlvs.unidir_utilized_bw = Some(UnidirUtilizedBwStlv::new(bw));
+    }
+}
+
 fn lsp_build_ipv4_reach_stlvs(
     instance: &InstanceUpView<'_>,
     prefix: Ipv4Network,
@@ -906,16 +1155,7 @@ fn lsp_build_ipv4_reach_stlvs(
 
     // Add Prefix-SID Sub-TLV(s).
     if add_prefix_sid && instance.config.sr.enabled {
-        let algo = IgpAlgoType::Spf;
-        if let Some(prefix_sid_cfg) = instance
-            .shared
-            .sr_config
-            .prefix_sids
-            .get(&(prefix.into(), algo))
-        {
-            let prefix_sid = lsp_build_prefix_sid_stlv(prefix_sid_cfg);
-            sub_tlvs.prefix_sids.insert(algo, prefix_sid);
-        }
+        sub_tlvs.prefix_sids = lsp_build_prefix_sids(instance, prefix.into());
     }
 
     sub_tlvs
@@ -946,16 +1186,7 @@ fn lsp_build_ipv6_reach_stlvs(
 
     // Add Prefix-SID Sub-TLV(s).
     if add_prefix_sid && instance.config.sr.enabled {
-        let algo = IgpAlgoType::Spf;
-        if let Some(prefix_sid_cfg) = instance
-            .shared
-            .sr_config
-            .prefix_sids
-            .get(&(prefix.into(), algo))
-        {
-            let prefix_sid = lsp_build_prefix_sid_stlv(prefix_sid_cfg);
-            sub_tlvs.prefix_sids.insert(algo, prefix_sid);
-        }
+        sub_tlvs.prefix_sids = lsp_build_prefix_sids(instance, prefix.into());
     }
 
     // Add BIER Sub-TLV(s) if BIER is enabled and allowed to advertise.
@@ -1009,7 +1240,37 @@ fn lsp_build_ipv6_reach_stlvs(
     sub_tlvs
 }
 
-fn lsp_build_prefix_sid_stlv(prefix_sid_cfg: &SrCfgPrefixSid) -> PrefixSidStlv {
+fn lsp_build_prefix_sids(
+    instance: &InstanceUpView<'_>,
+    prefix: IpNetwork,
+) -> BTreeMap<IgpAlgoType, PrefixSidStlv> {
+    let mut prefix_sids = BTreeMap::new();
+
+    // Algorithm 0 Prefix-SID.
+    let algo = IgpAlgoType::Spf;
+    if let Some(prefix_sid_cfg) =
+        instance.shared.sr_config.prefix_sids.get(&(prefix, algo))
+    {
+        let prefix_sid = lsp_build_prefix_sid_stlv(algo, prefix_sid_cfg);
+        prefix_sids.insert(algo, prefix_sid);
+    }
+
+    // Flexible Algorithm Prefix-SIDs.
+    for (algo, flex_algo_cfg) in &instance.config.flex_algos {
+        let algo = IgpAlgoType::FlexAlgo(*algo);
+        if let Some(prefix_sid_cfg) = flex_algo_cfg.prefix_sids.get(&prefix) {
+            let prefix_sid = lsp_build_prefix_sid_stlv(algo, prefix_sid_cfg);
+            prefix_sids.insert(algo, prefix_sid);
+        }
+    }
+
+    prefix_sids
+}
+
+fn lsp_build_prefix_sid_stlv(
+    algo: IgpAlgoType,
+    prefix_sid_cfg: &SrCfgPrefixSid,
+) -> PrefixSidStlv {
     let mut flags = PrefixSidFlags::empty();
     match prefix_sid_cfg.last_hop {
         SidLastHopBehavior::ExpNull => {
@@ -1021,11 +1282,32 @@ fn lsp_build_prefix_sid_stlv(prefix_sid_cfg: &SrCfgPrefixSid) -> PrefixSidStlv {
         }
         SidLastHopBehavior::Php => (),
     }
-    let algo = IgpAlgoType::Spf;
     let sid = Sid::Index(prefix_sid_cfg.index);
     PrefixSidStlv::new(flags, algo, sid)
 }
 
+fn lsp_build_fad_stlv(
+    algo: u8,
+    flex_algo_cfg: &FlexAlgoCfg,
+) -> FlexAlgoDefStlv {
+    let mut fad = FlexAlgoDefStlv::new(
+        algo,
+        flex_algo_cfg.metric_type as u8,
+        0,
+        flex_algo_cfg.priority,
+    );
+    for (groups, cfg_groups) in [
+        (&mut fad.exclude_any, flex_algo_cfg.exclude_any),
+        (&mut fad.include_any, flex_algo_cfg.include_any),
+        (&mut fad.include_all, flex_algo_cfg.include_all),
+    ] {
+        if cfg_groups != 0 {
+            groups.push(cfg_groups);
+        }
+    }
+    fad
+}
+
 fn lsp_build_fragments(
     instance: &mut InstanceUpView<'_>,
     arenas: &InstanceArenas,
@@ -1214,12 +1496,14 @@ fn lsp_propagate_l1_to_l2(
                     l2_ipv4_internal_reach.insert(*prefix, entry);
                 }
                 if metric_type.get(LevelNumber::L2).is_wide_enabled() {
-                    let sub_tlvs = lsp_build_ipv4_reach_stlvs(
+                    let mut sub_tlvs = lsp_build_ipv4_reach_stlvs(
                         instance,
                         *prefix,
                         PrefixAttrFlags::empty(),
                         false,
                     );
+                    sub_tlvs.admin_tags = summary.admin_tag_stlv();
+                    sub_tlvs.admin_tags64 = summary.admin_tag64_stlv();
                     let entry = Ipv4Reach {
                         up_down: false,
                         metric: summary.metric(),
@@ -1233,12 +1517,14 @@ fn lsp_propagate_l1_to_l2(
                 if !instance.config.is_af_enabled(AddressFamily::Ipv6) {
                     continue;
                 }
-                let sub_tlvs = lsp_build_ipv6_reach_stlvs(
+                let mut sub_tlvs = lsp_build_ipv6_reach_stlvs(
                     instance,
                     *prefix,
                     PrefixAttrFlags::empty(),
                     false,
                 );
+                sub_tlvs.admin_tags = summary.admin_tag_stlv();
+                sub_tlvs.admin_tags64 = summary.admin_tag64_stlv();
                 let entry = Ipv6Reach {
                     metric: summary.metric(),
                     up_down: false,
@@ -1309,6 +1595,84 @@ fn propagate_ip_reach<'a, T: IpReachTlvEntry + 'a>(
     }
 }
 
+// Returns the IP prefixes whose reachability information differs between the
+// old and new versions of an LSP.
+//
+// Returns `None` if any other information relevant to route computation has
+// changed, in which case a partial route calculation isn't possible.
+fn lsp_prc_prefixes(
+    old_tlvs: &LspTlvs,
+    tlvs: &LspTlvs,
+) -> Option<BTreeSet<IpNetwork>> {
+    if old_tlvs.protocols_supported != tlvs.protocols_supported
+        || old_tlvs.router_cap != tlvs.router_cap
+        || old_tlvs.area_addrs != tlvs.area_addrs
+        || old_tlvs.multi_topology != tlvs.multi_topology
+        || old_tlvs.mt_is_reach != tlvs.mt_is_reach
+        || old_tlvs.ipv4_addrs != tlvs.ipv4_addrs
+        || old_tlvs.ipv4_router_id != tlvs.ipv4_router_id
+        || old_tlvs.ipv6_addrs != tlvs.ipv6_addrs
+        || old_tlvs.ipv6_router_id != tlvs.ipv6_router_id
+        || old_tlvs.srv6_locator != tlvs.srv6_locator
+    {
+        return None;
+    }
+
+    let mut prefixes = BTreeSet::new();
+    ip_reach_diff(
+        old_tlvs.ipv4_internal_reach(),
+        tlvs.ipv4_internal_reach(),
+        &mut prefixes,
+    );
+    ip_reach_diff(
+        old_tlvs.ipv4_external_reach(),
+        tlvs.ipv4_external_reach(),
+        &mut prefixes,
+    );
+    ip_reach_diff(
+        old_tlvs.ext_ipv4_reach(),
+        tlvs.ext_ipv4_reach(),
+        &mut prefixes,
+    );
+    ip_reach_diff(
+        old_tlvs.mt_ipv4_reach().map(|(_, reach)| reach),
+        tlvs.mt_ipv4_reach().map(|(_, reach)| reach),
+        &mut prefixes,
+    );
+    ip_reach_diff(old_tlvs.ipv6_reach(), tlvs.ipv6_reach(), &mut prefixes);
+    ip_reach_diff(
+        old_tlvs.mt_ipv6_reach_by_id(MtId::Ipv6Unicast),
+        tlvs.mt_ipv6_reach_by_id(MtId::Ipv6Unicast),
+        &mut prefixes,
+    );
+
+    Some(prefixes)
+}
+
+// Collects the prefixes whose IP reachability entries differ between the two
+// provided sets of entries.
+fn ip_reach_diff<'a, T: IpReachTlvEntry + PartialEq + 'a>(
+    old_reach: impl Iterator<Item = &'a T>,
+    reach: impl Iterator<Item = &'a T>,
+    prefixes: &mut BTreeSet<IpNetwork>,
+) {
+    let mut entries: BTreeMap<IpNetwork, (Vec<&T>, Vec<&T>)> = BTreeMap::new();
+    for old_reach in old_reach {
+        let prefix = old_reach.prefix().into();
+        entries.entry(prefix).or_default().0.push(old_reach);
+    }
+    for reach in reach {
+        let prefix = reach.prefix().into();
+        entries.entry(prefix).or_default().1.push(reach);
+    }
+    prefixes.extend(
+        entries
+            .into_iter()
+            .filter(|(_, (old_reach, reach))| old_reach != reach)
+            .map(|(prefix, _)| prefix),
+    );
+}
+
 // Adds log entry for the newly installed LSP.
 fn log_lsp(
     instance: &mut InstanceUpView<'_>,
@@ -1383,6 +1747,7 @@ pub(crate) fn install<'a>(
     // Check if the LSP content has changed.
     let mut content_change = true;
     let mut topology_change = true;
+    let mut prc_prefixes = None;
     if let Some(old_lsp) = old_lsp
         && lsp.is_expired() == old_lsp.is_expired()
         && lsp.flags == old_lsp.flags
@@ -1392,8 +1757,13 @@ pub(crate) fn install<'a>(
             topology_change = false;
         } else if old_lsp.tlvs.is_reach().eq(lsp.tlvs.is_reach())
             && old_lsp.tlvs.ext_is_reach().eq(lsp.tlvs.ext_is_reach())
+            // Flexible Algorithm participation and definitions affect the
+            // per-algorithm topologies.
+            && (instance.config.flex_algos.is_empty()
+                || old_lsp.tlvs.router_cap == lsp.tlvs.router_cap)
         {
             topology_change = false;
+            prc_prefixes = lsp_prc_prefixes(&old_lsp.tlvs, &lsp.tlvs);
         }
     }
 
@@ -1449,6 +1819,14 @@ pub(crate) fn install<'a>(
         spf_sched.schedule_time.get_or_insert_with(Instant::now);
         if topology_change {
             spf_sched.spf_type = SpfType::Full;
+        } else if let Some(prefixes) = prc_prefixes
+            && let Some(spf_prc_prefixes) = &mut spf_sched.prc_prefixes
+        {
+            // Only the routes of the affected prefixes need to be
+            // recomputed.
+            spf_prc_prefixes.extend(prefixes);
+        } else {
+            spf_sched.prc_prefixes = None;
         }
 
         instance
@@ -1526,7 +1904,11 @@ pub(crate) fn lsp_originate(
     let lse = install(instance, &mut arenas.lsp_entries, level, lsp);
 
     // Flood LSP over all interfaces.
-    for iface in arenas.interfaces.iter_mut() {
+    for iface in arenas
+        .interfaces
+        .iter_mut()
+        .filter(|iface| !iface.mesh_group_blocked(None))
+    {
         iface.srm_list_add(instance, level, lse.data.clone());
     }
 
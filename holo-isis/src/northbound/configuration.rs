@@ -22,24 +22,29 @@ use holo_utils::bfd;
 use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::keychain::{Key, Keychains};
+use holo_utils::policy::DefaultPolicyType;
 use holo_utils::protocol::Protocol;
+use holo_utils::sr::{SidLastHopBehavior, SrCfgPrefixSid};
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::{ToYang, TryFromYang};
 use ipnetwork::IpNetwork;
 use prefix_trie::joint::map::JointPrefixMap;
 
 use crate::collections::InterfaceIndex;
-use crate::debug::InterfaceInactiveReason;
+use crate::debug::{GrExitReason, InterfaceInactiveReason};
 use crate::instance::Instance;
-use crate::interface::InterfaceType;
+use crate::interface::{InterfaceType, MeshGroupState};
 use crate::northbound::notification;
 use crate::packet::auth::AuthMethod;
-use crate::packet::consts::{MtId, PduType};
+use crate::packet::consts::{FlexAlgoMetricType, MtId, PduType};
+use crate::packet::subtlvs::neighbor::UnidirLinkLossStlv;
+use crate::packet::tlv::InstanceIdTlv;
 use crate::packet::{
     AreaAddr, LevelNumber, LevelType, LevelTypeIterator, SystemId,
 };
 use crate::route::RouteFlags;
-use crate::{ibus, spf, sr};
+use crate::spf::SpfType;
+use crate::{gr, ibus, spf, sr, srv6};
 
 #[derive(Debug, Default)]
 #[derive(EnumAsInner)]
@@ -47,6 +52,8 @@ pub enum ListEntry {
     #[default]
     None,
     Summary(IpNetwork),
+    FlexAlgo(u8),
+    FlexAlgoPrefixSid(u8, IpNetwork),
     AddressFamily(AddressFamily),
     Redistribution(AddressFamily, LevelNumber, Protocol),
     Topology(MtId),
@@ -75,14 +82,19 @@ pub enum Event {
     InterfaceUpdateHelloInterval(InterfaceIndex, LevelNumber),
     InterfaceUpdateCsnpInterval(InterfaceIndex),
     InterfaceBfdChange(InterfaceIndex),
+    InterfaceLdpIgpSyncChange(InterfaceIndex),
+    InterfaceTePerfMetricsChange(InterfaceIndex),
     InterfaceUpdateTraceOptions(InterfaceIndex),
     InterfaceIbusSub(InterfaceIndex),
     ReoriginateLsps(LevelNumber),
     RefreshLsps,
     RerunSpf,
+    RerunFullSpf,
     ReinstallRoutes,
     OverloadChange(bool),
+    GrEnabledChange,
     SrEnabledChange(bool),
+    Srv6Update,
     RedistributeAdd(AddressFamily, Protocol),
     RedistributeDelete(AddressFamily, LevelNumber, Protocol),
     UpdateTraceOptions,
@@ -104,12 +116,14 @@ pub struct InstanceCfg {
     pub lsp_lifetime: u16,
     pub lsp_refresh: u16,
     pub purge_originator: bool,
+    pub gr: InstanceGrCfg,
     pub node_tags: BTreeSet<u32>,
     pub metric_type: LevelsCfgWithDefault<MetricType>,
     pub default_metric: LevelsCfgWithDefault<u32>,
     pub auth: LevelsCfg<AuthCfg>,
     pub ipv4_router_id: Option<Ipv4Addr>,
     pub ipv6_router_id: Option<Ipv6Addr>,
+    pub ldp_igp_sync_holddown: Option<u16>,
     pub max_paths: u16,
     pub afs: BTreeMap<AddressFamily, AddressFamilyCfg>,
     pub spf_initial_delay: u32,
@@ -121,13 +135,24 @@ pub struct InstanceCfg {
     pub overload_status: bool,
     pub mt: HashMap<MtId, InstanceMtCfg>,
     pub summaries: JointPrefixMap<IpNetwork, SummaryCfg>,
+    pub flex_algos: BTreeMap<u8, FlexAlgoCfg>,
     pub att_suppress: bool,
     pub att_ignore: bool,
     pub sr: InstanceSrCfg,
+    pub srv6: InstanceSrv6Cfg,
     pub bier: InstanceBierCfg,
+    pub instance_id: u16,
+    pub instance_topology_ids: BTreeSet<u16>,
     pub trace_opts: InstanceTraceOptions,
 }
 
+#[derive(Debug)]
+pub struct InstanceGrCfg {
+    pub enabled: bool,
+    pub restart_interval: Option<u16>,
+    pub helper_enabled: bool,
+}
+
 #[derive(Debug)]
 pub struct InstanceMtCfg {
     pub enabled: bool,
@@ -139,6 +164,12 @@ pub struct InstanceSrCfg {
     pub enabled: bool,
 }
 
+#[derive(Debug)]
+pub struct InstanceSrv6Cfg {
+    pub enabled: bool,
+    pub locator: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct InstanceBierCfg {
     pub mt_id: u8,
@@ -186,12 +217,29 @@ pub enum MetricType {
     Both,
 }
 
-#[derive(Debug, Default)]
-pub struct RedistributionCfg {}
+#[derive(Debug)]
+pub struct RedistributionCfg {
+    // TODO: "ordered-by user"
+    pub policies: BTreeSet<String>,
+    pub default_policy: DefaultPolicyType,
+}
 
 #[derive(Clone, Debug, Default)]
 pub struct SummaryCfg {
     pub metric: Option<u32>,
+    pub tag: Option<u32>,
+    pub tag64: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct FlexAlgoCfg {
+    pub advertise_def: bool,
+    pub priority: u8,
+    pub metric_type: FlexAlgoMetricType,
+    pub exclude_any: u32,
+    pub include_any: u32,
+    pub include_all: u32,
+    pub prefix_sids: BTreeMap<IpNetwork, SrCfgPrefixSid>,
 }
 
 #[derive(Debug)]
@@ -200,9 +248,12 @@ pub struct InterfaceCfg {
     pub level_type: InheritableConfig<LevelType>,
     pub lsp_pacing_interval: u32,
     pub lsp_rxmt_interval: u16,
+    pub lsp_rxmt_max_interval: u16,
     pub passive: bool,
     pub csnp_interval: u16,
     pub hello_padding: bool,
+    pub mesh_group_state: MeshGroupState,
+    pub mesh_group: Option<u8>,
     pub interface_type: InterfaceType,
     pub node_flag: bool,
     pub hello_auth: LevelsCfg<AuthCfg>,
@@ -212,12 +263,36 @@ pub struct InterfaceCfg {
     pub metric: LevelsCfgWithDefault<u32>,
     pub bfd_enabled: bool,
     pub bfd_params: bfd::ClientCfg,
+    pub ldp_igp_sync: bool,
+    pub lfa_candidate: LevelsCfg<bool>,
+    pub ti_lfa: LevelsCfg<bool>,
+    pub ti_lfa_node_protection: bool,
+    pub te_admin_groups: u32,
+    pub te_metric: Option<u32>,
+    pub te_perf: TePerfMetricsCfg,
     pub afs: BTreeSet<AddressFamily>,
     pub mt: HashMap<MtId, InterfaceMtCfg>,
     pub ext_seqnum_mode: LevelsCfg<Option<ExtendedSeqNumMode>>,
     pub trace_opts: InterfaceTraceOptions,
 }
 
+#[derive(Debug)]
+pub struct TePerfMetricsCfg {
+    pub delay: Option<u32>,
+    pub min_delay: Option<u32>,
+    pub max_delay: Option<u32>,
+    pub delay_variation: Option<u32>,
+    pub loss: Option<u32>,
+    pub residual_bw: Option<f32>,
+    pub available_bw: Option<f32>,
+    pub utilized_bw: Option<f32>,
+    pub adv_threshold: u8,
+    pub delay_max: Option<u32>,
+    pub delay_reuse: Option<u32>,
+    pub loss_max: Option<u32>,
+    pub loss_reuse: Option<u32>,
+}
+
 #[derive(Debug)]
 pub struct InterfaceMtCfg {
     pub enabled: bool,
@@ -378,6 +453,28 @@ fn load_callbacks() -> Callbacks<Instance> {
             let enabled = args.dnode.get_bool();
             instance.config.purge_originator = enabled;
         })
+        .path(isis::graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.gr.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::GrEnabledChange);
+        })
+        .path(isis::graceful_restart::restart_interval::PATH)
+        .modify_apply(|instance, args| {
+            // The "infinity" and "not-set" values don't bound the restart.
+            let restart_interval = args.dnode.get_string().parse().ok();
+            instance.config.gr.restart_interval = restart_interval;
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.gr.restart_interval = None;
+        })
+        .path(isis::graceful_restart::helper_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let helper_enabled = args.dnode.get_bool();
+            instance.config.gr.helper_enabled = helper_enabled;
+        })
         .path(isis::node_tags::node_tag::PATH)
         .create_apply(|instance, args| {
             let node_tag = args.dnode.get_u32_relative("tag").unwrap();
@@ -705,6 +802,42 @@ fn load_callbacks() -> Callbacks<Instance> {
             let protocol = Protocol::try_from_yang(&protocol).unwrap();
             ListEntry::Redistribution(af, level, protocol)
         })
+        .path(isis::address_families::address_family_list::redistribution::policy::PATH)
+        .create_apply(|instance, args| {
+            let (af, level, protocol) = args.list_entry.into_redistribution().unwrap();
+            let af_cfg = instance.config.afs.get_mut(&af).unwrap();
+            let redistribution = af_cfg.redistribution.get_mut(&(level, protocol)).unwrap();
+
+            let policy = args.dnode.get_string();
+            redistribution.policies.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(level));
+        })
+        .delete_apply(|instance, args| {
+            let (af, level, protocol) = args.list_entry.into_redistribution().unwrap();
+            let af_cfg = instance.config.afs.get_mut(&af).unwrap();
+            let redistribution = af_cfg.redistribution.get_mut(&(level, protocol)).unwrap();
+
+            let policy = args.dnode.get_string();
+            redistribution.policies.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(level));
+        })
+        .path(isis::address_families::address_family_list::redistribution::default_policy::PATH)
+        .modify_apply(|instance, args| {
+            let (af, level, protocol) = args.list_entry.into_redistribution().unwrap();
+            let af_cfg = instance.config.afs.get_mut(&af).unwrap();
+            let redistribution = af_cfg.redistribution.get_mut(&(level, protocol)).unwrap();
+
+            let default = args.dnode.get_string();
+            let default = DefaultPolicyType::try_from_yang(&default).unwrap();
+            redistribution.default_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(level));
+        })
         .path(isis::mpls::te_rid::ipv4_router_id::PATH)
         .modify_apply(|instance, args| {
             let addr = args.dnode.get_ipv4();
@@ -737,6 +870,26 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
         })
+        .path(isis::mpls::ldp::igp_sync::holddown::PATH)
+        .modify_apply(|instance, args| {
+            let holddown = args.dnode.get_u16();
+            instance.config.ldp_igp_sync_holddown = Some(holddown);
+
+            let event_queue = args.event_queue;
+            for iface in instance.arenas.interfaces.iter() {
+                let event = Event::InterfaceLdpIgpSyncChange(iface.index);
+                event_queue.insert(event);
+            }
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ldp_igp_sync_holddown = None;
+
+            let event_queue = args.event_queue;
+            for iface in instance.arenas.interfaces.iter() {
+                let event = Event::InterfaceLdpIgpSyncChange(iface.index);
+                event_queue.insert(event);
+            }
+        })
         .path(isis::spf_control::paths::PATH)
         .modify_apply(|instance, args| {
             let max_paths = args.dnode.get_u16();
@@ -944,6 +1097,233 @@ fn load_callbacks() -> Callbacks<Instance> {
             let event_queue = args.event_queue;
             event_queue.insert(Event::RerunSpf);
         })
+        .path(isis::inter_level_propagation_policies::level1_to_level2::summary_prefixes::tag::PATH)
+        .modify_apply(|instance, args| {
+            let prefix = args.list_entry.into_summary().unwrap();
+            let summary_cfg = instance.config.summaries.get_mut(&prefix).unwrap();
+
+            let tag = args.dnode.get_u32();
+            summary_cfg.tag = Some(tag);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .delete_apply(|instance, args| {
+            let prefix = args.list_entry.into_summary().unwrap();
+            let summary_cfg = instance.config.summaries.get_mut(&prefix).unwrap();
+
+            summary_cfg.tag = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(isis::inter_level_propagation_policies::level1_to_level2::summary_prefixes::tag64::PATH)
+        .modify_apply(|instance, args| {
+            let prefix = args.list_entry.into_summary().unwrap();
+            let summary_cfg = instance.config.summaries.get_mut(&prefix).unwrap();
+
+            let tag64 = args.dnode.get_u64();
+            summary_cfg.tag64 = Some(tag64);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .delete_apply(|instance, args| {
+            let prefix = args.list_entry.into_summary().unwrap();
+            let summary_cfg = instance.config.summaries.get_mut(&prefix).unwrap();
+
+            summary_cfg.tag64 = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(isis::flex_algos::flex_algo::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.dnode.get_u8_relative("algorithm").unwrap();
+            instance.config.flex_algos.insert(algo, FlexAlgoCfg::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            instance.config.flex_algos.remove(&algo);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let algo = dnode.get_u8_relative("algorithm").unwrap();
+            ListEntry::FlexAlgo(algo)
+        })
+        .path(isis::flex_algos::flex_algo::advertise_definition::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let advertise = args.dnode.get_bool();
+            flex_algo_cfg.advertise_def = advertise;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::priority::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let priority = args.dnode.get_u8();
+            flex_algo_cfg.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type = FlexAlgoMetricType::try_from_yang(&metric_type).unwrap();
+            flex_algo_cfg.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::affinities::exclude_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.exclude_any |= 1 << group;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.exclude_any &= !(1 << group);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::affinities::include_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.include_any |= 1 << group;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.include_any &= !(1 << group);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::affinities::include_all::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.include_all |= 1 << group;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let group = args.dnode.get_u8();
+            flex_algo_cfg.include_all &= !(1 << group);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::prefix_sid::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let prefix = args.dnode.get_prefix_relative("prefix").unwrap();
+            let index = args.dnode.get_u32_relative("index").unwrap();
+            let last_hop = args.dnode.get_string_relative("last-hop-behavior").unwrap();
+            let last_hop = SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            let prefix_sid = SrCfgPrefixSid::new(index, last_hop);
+            flex_algo_cfg.prefix_sids.insert(prefix, prefix_sid);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let (algo, prefix) = args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            flex_algo_cfg.prefix_sids.remove(&prefix);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let algo = list_entry.into_flex_algo().unwrap();
+            let prefix = dnode.get_prefix_relative("prefix").unwrap();
+            ListEntry::FlexAlgoPrefixSid(algo, prefix)
+        })
+        .path(isis::flex_algos::flex_algo::prefix_sid::index::PATH)
+        .modify_apply(|instance, args| {
+            let (algo, prefix) = args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+            let prefix_sid = flex_algo_cfg.prefix_sids.get_mut(&prefix).unwrap();
+
+            let index = args.dnode.get_u32();
+            prefix_sid.index = index;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::flex_algos::flex_algo::prefix_sid::last_hop_behavior::PATH)
+        .modify_apply(|instance, args| {
+            let (algo, prefix) = args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo_cfg = instance.config.flex_algos.get_mut(&algo).unwrap();
+            let prefix_sid = flex_algo_cfg.prefix_sids.get_mut(&prefix).unwrap();
+
+            let last_hop = args.dnode.get_string();
+            let last_hop = SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            prefix_sid.last_hop = last_hop;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
         .path(isis::trace_options::flag::PATH)
         .create_apply(|instance, args| {
             let trace_opt = args.dnode.get_string_relative("name").unwrap();
@@ -1100,6 +1480,14 @@ fn load_callbacks() -> Callbacks<Instance> {
             let lsp_rxmt_interval = args.dnode.get_u16();
             iface.config.lsp_rxmt_interval = lsp_rxmt_interval;
         })
+        .path(isis::interfaces::interface::lsp_retransmit_max_interval::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let lsp_rxmt_max_interval = args.dnode.get_u16();
+            iface.config.lsp_rxmt_max_interval = lsp_rxmt_max_interval;
+        })
         .path(isis::interfaces::interface::passive::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -1133,6 +1521,35 @@ fn load_callbacks() -> Callbacks<Instance> {
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceRestartNetwork(iface_idx));
         })
+        .path(isis::interfaces::interface::mesh_group_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let state = args.dnode.get_string();
+            let state = MeshGroupState::try_from_yang(&state).unwrap();
+            iface.config.mesh_group_state = state;
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.mesh_group_state = MeshGroupState::Inactive;
+        })
+        .path(isis::interfaces::interface::mesh_group::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let mesh_group = args.dnode.get_u8();
+            iface.config.mesh_group = Some(mesh_group);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.mesh_group = None;
+        })
         .path(isis::interfaces::interface::interface_type::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -1679,42 +2096,431 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
-        .path(isis::interfaces::interface::address_families::address_family_list::PATH)
-        .create_apply(|instance, args| {
+        .path(isis::interfaces::interface::mpls::ldp::igp_sync::PATH)
+        .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let af = args.dnode.get_string_relative("address-family").unwrap();
-            let af = AddressFamily::try_from_yang(&af).unwrap();
-            iface.config.afs.insert(af);
+            let enabled = args.dnode.get_bool();
+            iface.config.ldp_igp_sync = enabled;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
-            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+            event_queue.insert(Event::InterfaceLdpIgpSyncChange(iface_idx));
         })
-        .delete_apply(|instance, args| {
-            let (iface_idx, af) = args.list_entry.into_interface_address_family().unwrap();
+        .path(isis::interfaces::interface::fast_reroute::lfa::candidate_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            iface.config.afs.remove(&af);
+            let candidate = args.dnode.get_bool();
+            iface.config.lfa_candidate.all = candidate;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
-            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
-        })
-        .lookup(|_instance, list_entry, dnode| {
-            let iface_idx = list_entry.into_interface().unwrap();
-            let af = dnode.get_string_relative("address-family").unwrap();
-            let af = AddressFamily::try_from_yang(&af).unwrap();
-            ListEntry::InterfaceAddressFamily(iface_idx, af)
+            event_queue.insert(Event::RerunSpf);
         })
-        .path(isis::interfaces::interface::topologies::topology::PATH)
-        .create_apply(|instance, args| {
+        .path(isis::interfaces::interface::fast_reroute::lfa::level_1::candidate_enabled::PATH)
+        .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let name = args.dnode.get_string_relative("name").unwrap();
-            let mt_id = MtId::try_from_yang(&name).unwrap();
+            let candidate = args.dnode.get_bool();
+            iface.config.lfa_candidate.l1 = candidate;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(isis::interfaces::interface::fast_reroute::lfa::level_2::candidate_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let candidate = args.dnode.get_bool();
+            iface.config.lfa_candidate.l2 = candidate;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(isis::interfaces::interface::fast_reroute::lfa::ti_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ti_lfa.all = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .path(isis::interfaces::interface::fast_reroute::lfa::level_1::ti_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ti_lfa.l1 = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .path(isis::interfaces::interface::fast_reroute::lfa::level_2::ti_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ti_lfa.l2 = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .path(isis::interfaces::interface::fast_reroute::lfa::ti_lfa::selection_tie_breakers::node_protection::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.ti_lfa_node_protection = true;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.ti_lfa_node_protection = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunFullSpf);
+        })
+        .path(isis::interfaces::interface::traffic_engineering::admin_groups::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let group = args.dnode.get_u8();
+            iface.config.te_admin_groups |= 1 << group;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let group = args.dnode.get_u8();
+            iface.config.te_admin_groups &= !(1 << group);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::te_metric::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let metric = args.dnode.get_u32();
+            iface.config.te_metric = Some(metric);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_metric = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_delay::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.delay = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::min_max_unidirectional_link_delay::min_value::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.min_delay = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.min_delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::min_max_unidirectional_link_delay::max_value::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.max_delay = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.max_delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_delay_variation::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.delay_variation = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.delay_variation = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_loss::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let loss = link_loss_from_yang(&args.dnode.get_string());
+            iface.config.te_perf.loss = Some(loss);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.loss = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_residual_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bw = args.dnode.get_u64() as f32;
+            iface.config.te_perf.residual_bw = Some(bw);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.residual_bw = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_available_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bw = args.dnode.get_u64() as f32;
+            iface.config.te_perf.available_bw = Some(bw);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.available_bw = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::unidirectional_link_utilized_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bw = args.dnode.get_u64() as f32;
+            iface.config.te_perf.utilized_bw = Some(bw);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.utilized_bw = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::advertisement_threshold::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let threshold = args.dnode.get_u8();
+            iface.config.te_perf.adv_threshold = threshold;
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::anomalous_thresholds::delay_max::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.delay_max = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.delay_max = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::anomalous_thresholds::delay_reuse::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let delay = args.dnode.get_u32();
+            iface.config.te_perf.delay_reuse = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.delay_reuse = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::anomalous_thresholds::loss_max::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let loss = link_loss_from_yang(&args.dnode.get_string());
+            iface.config.te_perf.loss_max = Some(loss);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.loss_max = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::traffic_engineering::performance_metrics::anomalous_thresholds::loss_reuse::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let loss = link_loss_from_yang(&args.dnode.get_string());
+            iface.config.te_perf.loss_reuse = Some(loss);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te_perf.loss_reuse = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTePerfMetricsChange(iface_idx));
+        })
+        .path(isis::interfaces::interface::address_families::address_family_list::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let af = args.dnode.get_string_relative("address-family").unwrap();
+            let af = AddressFamily::try_from_yang(&af).unwrap();
+            iface.config.afs.insert(af);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, af) = args.list_entry.into_interface_address_family().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.afs.remove(&af);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let iface_idx = list_entry.into_interface().unwrap();
+            let af = dnode.get_string_relative("address-family").unwrap();
+            let af = AddressFamily::try_from_yang(&af).unwrap();
+            ListEntry::InterfaceAddressFamily(iface_idx, af)
+        })
+        .path(isis::interfaces::interface::topologies::topology::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let name = args.dnode.get_string_relative("name").unwrap();
+            let mt_id = MtId::try_from_yang(&name).unwrap();
             iface.config.mt.insert(mt_id, InterfaceMtCfg::default());
 
             let event_queue = args.event_queue;
@@ -2015,6 +2821,57 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
         })
+        .path(isis::srv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.srv6.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Update);
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::srv6::locator::PATH)
+        .modify_apply(|instance, args| {
+            let locator = args.dnode.get_string();
+            instance.config.srv6.locator = Some(locator);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Update);
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            instance.config.srv6.locator = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Update);
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::multi_instance::instance_id::PATH)
+        .modify_apply(|instance, args| {
+            let instance_id = args.dnode.get_u16();
+            instance.config.instance_id = instance_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceReset);
+        })
+        .path(isis::multi_instance::topology_id::PATH)
+        .create_apply(|instance, args| {
+            let topology_id = args.dnode.get_u16();
+            instance.config.instance_topology_ids.insert(topology_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceReset);
+        })
+        .delete_apply(|instance, args| {
+            let topology_id = args.dnode.get_u16();
+            instance.config.instance_topology_ids.remove(&topology_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceReset);
+        })
         .build()
 }
 
@@ -2171,6 +3028,43 @@ impl Provider for Instance {
                     },
                 );
             }
+            Event::InterfaceLdpIgpSyncChange(iface_idx) => {
+                // Request LDP-IGP synchronization updates from LDP.
+                let iface = &self.arenas.interfaces[iface_idx];
+                if iface.config.ldp_igp_sync {
+                    self.tx.ibus.ldp_igp_sync_req();
+                }
+
+                let Some((mut instance, arenas)) = self.as_up() else {
+                    return;
+                };
+                let iface = &mut arenas.interfaces[iface_idx];
+                if !iface.state.active {
+                    return;
+                }
+
+                // Restart or stop the hold-down timer.
+                iface.ldp_sync_holddown_update(&instance);
+
+                // Schedule LSP reorigination.
+                instance
+                    .schedule_lsp_origination(iface.config.level_type.resolved);
+            }
+            Event::InterfaceTePerfMetricsChange(iface_idx) => {
+                // Check whether the updated metrics need to be re-advertised.
+                let iface = &mut self.arenas.interfaces[iface_idx];
+                if !iface.te_perf_metrics_update() {
+                    return;
+                }
+
+                // Schedule LSP reorigination.
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    let iface = &arenas.interfaces[iface_idx];
+                    instance.schedule_lsp_origination(
+                        iface.config.level_type.resolved,
+                    );
+                }
+            }
             Event::InterfaceUpdateTraceOptions(iface_idx) => {
                 let iface = &mut self.arenas.interfaces[iface_idx];
                 iface.config.update_trace_options(&self.config);
@@ -2213,6 +3107,18 @@ impl Provider for Instance {
                     }
                 }
             }
+            Event::RerunFullSpf => {
+                if let Some((instance, _)) = self.as_up() {
+                    for level in instance.config.levels() {
+                        instance.state.spf_sched.get_mut(level).spf_type =
+                            SpfType::Full;
+                        instance.tx.protocol_input.spf_delay_event(
+                            level,
+                            spf::fsm::Event::ConfigChange,
+                        );
+                    }
+                }
+            }
             Event::ReinstallRoutes => {
                 if let Some((instance, arenas)) = self.as_up() {
                     for (prefix, route) in instance
@@ -2247,6 +3153,18 @@ impl Provider for Instance {
                     notification::database_overload(&instance, overload_status);
                 }
             }
+            Event::GrEnabledChange => {
+                // Request the preservation (or not) of the installed routes
+                // across restarts.
+                ibus::tx::route_retain(&self.tx.ibus, self.config.gr.enabled);
+
+                // Abort any graceful restart in progress.
+                if !self.config.gr.enabled
+                    && let Some((mut instance, arenas)) = self.as_up()
+                {
+                    gr::exit(&mut instance, arenas, GrExitReason::Disabled);
+                }
+            }
             Event::SrEnabledChange(enabled) => {
                 let Some((instance, arenas)) = self.as_up() else {
                     return;
@@ -2266,6 +3184,14 @@ impl Provider for Instance {
                     );
                 }
             }
+            Event::Srv6Update => {
+                let Some((mut instance, arenas)) = self.as_up() else {
+                    return;
+                };
+
+                // Update the SRv6 End and End.X SIDs.
+                srv6::sids_update(&mut instance, arenas);
+            }
             Event::RedistributeAdd(af, protocol) => {
                 // Subscribe to route redistribution for the given protocol and
                 // address family.
@@ -2337,6 +3263,23 @@ impl InstanceCfg {
         );
         topologies
     }
+
+    // Returns the IID-TLV to be included in the PDUs of this instance, if any.
+    //
+    // PDUs of the standard instance (IID 0) don't carry the IID-TLV. The
+    // supported ITIDs are only advertised in IIHs.
+    pub(crate) fn instance_id_tlv(&self, itids: bool) -> Option<InstanceIdTlv> {
+        if self.instance_id == 0 {
+            return None;
+        }
+
+        let itids = if itids {
+            self.instance_topology_ids.iter().copied().collect()
+        } else {
+            vec![]
+        };
+        Some(InstanceIdTlv::new(self.instance_id, itids))
+    }
 }
 
 impl InterfaceCfg {
@@ -2370,6 +3313,25 @@ impl InterfaceCfg {
         true
     }
 
+    // Checks if the interface can be used for backup paths at the specified
+    // level.
+    pub(crate) fn is_lfa_candidate(&self, level: LevelNumber) -> bool {
+        let level_cfg = match level {
+            LevelNumber::L1 => self.lfa_candidate.l1,
+            LevelNumber::L2 => self.lfa_candidate.l2,
+        };
+        self.lfa_candidate.all && level_cfg
+    }
+
+    // Checks if TI-LFA protection is enabled at the specified level.
+    pub(crate) fn is_ti_lfa_enabled(&self, level: LevelNumber) -> bool {
+        let level_cfg = match level {
+            LevelNumber::L1 => self.ti_lfa.l1,
+            LevelNumber::L2 => self.ti_lfa.l2,
+        };
+        self.ti_lfa.all || level_cfg
+    }
+
     // Returns the levels supported by the interface.
     pub(crate) fn levels(&self) -> LevelTypeIterator {
         self.level_type.resolved.into_iter()
@@ -2588,6 +3550,7 @@ impl Default for InstanceCfg {
         let overload_status = isis::overload::status::DFLT;
         let att_suppress = isis::attached_bit::suppress_advertisement::DFLT;
         let att_ignore = isis::attached_bit::ignore_reception::DFLT;
+        let instance_id = isis::multi_instance::instance_id::DFLT;
 
         InstanceCfg {
             enabled,
@@ -2598,6 +3561,7 @@ impl Default for InstanceCfg {
             lsp_lifetime,
             lsp_refresh,
             purge_originator,
+            gr: Default::default(),
             node_tags: Default::default(),
             metric_type,
             default_metric,
@@ -2605,6 +3569,7 @@ impl Default for InstanceCfg {
             max_paths,
             ipv4_router_id: None,
             ipv6_router_id: None,
+            ldp_igp_sync_holddown: None,
             afs: Default::default(),
             spf_initial_delay,
             spf_short_delay,
@@ -2615,15 +3580,31 @@ impl Default for InstanceCfg {
             overload_status,
             mt: Default::default(),
             summaries: Default::default(),
+            flex_algos: Default::default(),
             att_suppress,
             att_ignore,
             sr: Default::default(),
+            srv6: Default::default(),
             bier: Default::default(),
+            instance_id,
+            instance_topology_ids: Default::default(),
             trace_opts: Default::default(),
         }
     }
 }
 
+impl Default for InstanceGrCfg {
+    fn default() -> Self {
+        let enabled = isis::graceful_restart::enabled::DFLT;
+        let helper_enabled = isis::graceful_restart::helper_enabled::DFLT;
+        Self {
+            enabled,
+            restart_interval: None,
+            helper_enabled,
+        }
+    }
+}
+
 impl Default for InstanceMtCfg {
     fn default() -> Self {
         let enabled = isis::topologies::topology::enabled::DFLT;
@@ -2642,6 +3623,26 @@ impl Default for InstanceMtCfg {
     }
 }
 
+impl Default for FlexAlgoCfg {
+    fn default() -> FlexAlgoCfg {
+        let advertise_def =
+            isis::flex_algos::flex_algo::advertise_definition::DFLT;
+        let priority = isis::flex_algos::flex_algo::priority::DFLT;
+        let metric_type = isis::flex_algos::flex_algo::metric_type::DFLT;
+        let metric_type =
+            FlexAlgoMetricType::try_from_yang(metric_type).unwrap();
+        FlexAlgoCfg {
+            advertise_def,
+            priority,
+            metric_type,
+            exclude_any: 0,
+            include_any: 0,
+            include_all: 0,
+            prefix_sids: Default::default(),
+        }
+    }
+}
+
 impl Default for InstanceSrCfg {
     fn default() -> Self {
         let enabled = isis::segment_routing::enabled::DFLT;
@@ -2649,6 +3650,16 @@ impl Default for InstanceSrCfg {
     }
 }
 
+impl Default for InstanceSrv6Cfg {
+    fn default() -> Self {
+        let enabled = isis::srv6::enabled::DFLT;
+        Self {
+            enabled,
+            locator: None,
+        }
+    }
+}
+
 impl Default for InstanceBierCfg {
     fn default() -> Self {
         let enabled = isis::bier::bier::enable::DFLT;
@@ -2675,6 +3686,20 @@ impl Default for AddressFamilyCfg {
     }
 }
 
+impl Default for RedistributionCfg {
+    fn default() -> RedistributionCfg {
+        let default_policy =
+            isis::address_families::address_family_list::redistribution::default_policy::DFLT;
+        let default_policy =
+            DefaultPolicyType::try_from_yang(default_policy).unwrap();
+
+        RedistributionCfg {
+            policies: Default::default(),
+            default_policy,
+        }
+    }
+}
+
 impl Default for Preference {
     fn default() -> Preference {
         let internal = isis::preference::default::DFLT;
@@ -2697,6 +3722,8 @@ impl Default for InterfaceCfg {
             isis::interfaces::interface::lsp_pacing_interval::DFLT;
         let lsp_rxmt_interval =
             isis::interfaces::interface::lsp_retransmit_interval::DFLT;
+        let lsp_rxmt_max_interval =
+            isis::interfaces::interface::lsp_retransmit_max_interval::DFLT;
         let passive = isis::interfaces::interface::passive::DFLT;
         let csnp_interval = isis::interfaces::interface::csnp_interval::DFLT;
         let hello_padding =
@@ -2732,14 +3759,33 @@ impl Default for InterfaceCfg {
             l2: None,
         };
         let bfd_enabled = isis::interfaces::interface::bfd::enabled::DFLT;
+        let ldp_igp_sync =
+            isis::interfaces::interface::mpls::ldp::igp_sync::DFLT;
+        let lfa_candidate =
+            isis::interfaces::interface::fast_reroute::lfa::candidate_enabled::DFLT;
+        let lfa_candidate = LevelsCfg {
+            all: lfa_candidate,
+            l1: lfa_candidate,
+            l2: lfa_candidate,
+        };
+        let ti_lfa =
+            isis::interfaces::interface::fast_reroute::lfa::ti_lfa::enabled::DFLT;
+        let ti_lfa = LevelsCfg {
+            all: ti_lfa,
+            l1: ti_lfa,
+            l2: ti_lfa,
+        };
         InterfaceCfg {
             enabled,
             level_type,
             lsp_pacing_interval,
             lsp_rxmt_interval,
+            lsp_rxmt_max_interval,
             passive,
             csnp_interval,
             hello_padding,
+            mesh_group_state: Default::default(),
+            mesh_group: None,
             interface_type,
             node_flag,
             hello_auth: Default::default(),
@@ -2749,6 +3795,13 @@ impl Default for InterfaceCfg {
             metric,
             bfd_enabled,
             bfd_params: Default::default(),
+            ldp_igp_sync,
+            lfa_candidate,
+            ti_lfa,
+            ti_lfa_node_protection: false,
+            te_admin_groups: 0,
+            te_metric: None,
+            te_perf: Default::default(),
             afs: Default::default(),
             mt: Default::default(),
             ext_seqnum_mode: Default::default(),
@@ -2757,6 +3810,27 @@ impl Default for InterfaceCfg {
     }
 }
 
+impl Default for TePerfMetricsCfg {
+    fn default() -> TePerfMetricsCfg {
+        let adv_threshold = isis::interfaces::interface::traffic_engineering::performance_metrics::advertisement_threshold::DFLT;
+        TePerfMetricsCfg {
+            delay: None,
+            min_delay: None,
+            max_delay: None,
+            delay_variation: None,
+            loss: None,
+            residual_bw: None,
+            available_bw: None,
+            utilized_bw: None,
+            adv_threshold,
+            delay_max: None,
+            delay_reuse: None,
+            loss_max: None,
+            loss_reuse: None,
+        }
+    }
+}
+
 impl Default for InterfaceMtCfg {
     fn default() -> InterfaceMtCfg {
         let enabled =
@@ -2794,3 +3868,12 @@ impl Default for TraceOptionPacketType {
         TraceOptionPacketType { tx, rx }
     }
 }
+
+// ===== helper functions =====
+
+// Converts a link loss percentage into the units used by the Unidirectional
+// Link Loss Sub-TLV.
+fn link_loss_from_yang(loss: &str) -> u32 {
+    let loss = loss.parse::<f64>().unwrap();
+    (loss / UnidirLinkLossStlv::UNIT).round() as u32
+}
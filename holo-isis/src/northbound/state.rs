@@ -21,6 +21,7 @@ use holo_northbound::state::{
 };
 use holo_northbound::yang::control_plane_protocol::isis;
 use holo_utils::crypto::CryptoAlgo;
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::option::OptionExt;
 use holo_utils::sr::Sid;
 use holo_yang::{ToYang, ToYangBits};
@@ -32,7 +33,7 @@ use crate::instance::Instance;
 use crate::interface::Interface;
 use crate::lsdb::{LspEntry, LspLogEntry, LspLogId};
 use crate::packet::subtlvs::capability::LabelBlockEntry;
-use crate::packet::subtlvs::neighbor::AdjSidStlv;
+use crate::packet::subtlvs::neighbor::{AdjSidStlv, PerfMetricFlags};
 use crate::packet::subtlvs::prefix::{PrefixAttrFlags, PrefixSidStlv};
 use crate::packet::tlv::{
     AuthenticationTlv, IpReachTlvEntry, Ipv4Reach, Ipv6Reach, IsReach,
@@ -40,11 +41,22 @@ use crate::packet::tlv::{
     UnknownTlv,
 };
 use crate::packet::{LanId, LevelNumber, LevelType, SystemId};
-use crate::route::{Nexthop, Route};
+use crate::route::{BackupNexthop, Nexthop, Route, RouteFlags};
 use crate::spf::{SpfLogEntry, SpfScheduler};
+use crate::tilfa::ProtectedResource;
 
 pub static CALLBACKS: Lazy<Callbacks<Instance>> = Lazy::new(load_callbacks);
 
+// Per address family TI-LFA protection statistics.
+#[derive(Debug, Default)]
+pub struct ProtectionStats {
+    total: u32,
+    unprotected: u32,
+    protected: u32,
+    link_protected: u32,
+    node_protected: u32,
+}
+
 #[derive(Debug, Default)]
 #[derive(EnumAsInner)]
 pub enum ListEntry<'a> {
@@ -76,8 +88,13 @@ pub enum ListEntry<'a> {
     MtIpv6Reach(u16, &'a Ipv6Reach),
     PrefixSidStlv(&'a PrefixSidStlv),
     UnknownTlv(&'a UnknownTlv),
+    ProtectedRoute(&'a IpNetwork, &'a Route, &'a BackupNexthop),
+    UnprotectedRoute(&'a IpNetwork),
+    ProtectionStatistics,
+    ProtectionAfStatistics(AddressFamily, ProtectionStats),
     Route(&'a IpNetwork, &'a Route),
     Nexthop(&'a Nexthop),
+    BackupNexthop(&'a BackupNexthop),
     SystemCounters(LevelNumber),
     Interface(&'a Interface),
     InterfacePacketCounters(&'a Interface, LevelNumber),
@@ -116,6 +133,8 @@ fn load_callbacks() -> Callbacks<Instance> {
                 last_event_received: spf_sched.last_event_rcvd.as_ref().map(Cow::Borrowed).ignore_in_testing(),
                 next_spf_time: spf_sched.delay_timer.as_ref().map(|timer| Instant::now() + timer.remaining()).map(Cow::Owned).ignore_in_testing(),
                 last_spf_time: spf_sched.last_time.as_ref().map(Cow::Borrowed).ignore_in_testing(),
+                full_spf_runs: Some(spf_sched.full_runs).ignore_in_testing(),
+                partial_spf_runs: Some(spf_sched.partial_runs).ignore_in_testing(),
             })
         })
         .path(isis::spf_log::event::PATH)
@@ -605,6 +624,91 @@ fn load_callbacks() -> Callbacks<Instance> {
                 unreserved_bandwidth: Some(unreserved_bandwidth),
             })
         })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::UnidirectionalLinkDelay;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkDelay {
+                value: reach.sub_tlvs.unidir_link_delay.as_ref().map(|stlv| stlv.delay),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::flags::Flags;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.unidir_link_delay.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:unidirectional-link-delay-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                unidirectional_link_delay_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::MinMaxUnidirectionalLinkDelay;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            let stlv = reach.sub_tlvs.min_max_unidir_link_delay.as_ref();
+            Box::new(MinMaxUnidirectionalLinkDelay {
+                min_value: stlv.map(|stlv| stlv.min_delay),
+                max_value: stlv.map(|stlv| stlv.max_delay),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::flags::Flags;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.min_max_unidir_link_delay.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:min-max-unidirectional-link-delay-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                min_max_unidirectional_link_delay_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay_variation::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_delay_variation::UnidirectionalLinkDelayVariation;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkDelayVariation {
+                value: reach.sub_tlvs.unidir_delay_variation.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::UnidirectionalLinkLoss;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkLoss {
+                value: reach.sub_tlvs.unidir_link_loss.as_ref().map(|stlv| stlv.loss),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::flags::Flags;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.unidir_link_loss.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:unidirectional-link-loss-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                unidirectional_link_loss_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_residual_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_residual_bandwidth::UnidirectionalLinkResidualBandwidth;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkResidualBandwidth {
+                value: reach.sub_tlvs.unidir_residual_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_available_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_available_bandwidth::UnidirectionalLinkAvailableBandwidth;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkAvailableBandwidth {
+                value: reach.sub_tlvs.unidir_available_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_utilized_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unidirectional_link_utilized_bandwidth::UnidirectionalLinkUtilizedBandwidth;
+            let (_, reach) = args.list_entry.as_ext_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkUtilizedBandwidth {
+                value: reach.sub_tlvs.unidir_utilized_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
         .path(isis::database::levels::lsp::extended_is_neighbor::neighbor::instances::instance::unknown_tlvs::unknown_tlv::PATH)
         .get_iterate(|_instance, args| {
             let (_, reach) = args.parent_list_entry.as_ext_is_reach_instance().unwrap();
@@ -791,6 +895,8 @@ fn load_callbacks() -> Callbacks<Instance> {
                 readvertisement_flag: reach.prefix_attr_flags_get(PrefixAttrFlags::R),
                 ipv4_source_router_id: reach.sub_tlvs.ipv4_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
                 ipv6_source_router_id: reach.sub_tlvs.ipv6_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
+                tag: reach.sub_tlvs.admin_tags.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
+                tag64: reach.sub_tlvs.admin_tags64.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
             })
         })
         .path(isis::database::levels::lsp::extended_ipv4_reachability::prefixes::unknown_tlvs::unknown_tlv::PATH)
@@ -916,6 +1022,91 @@ fn load_callbacks() -> Callbacks<Instance> {
                 unreserved_bandwidth: Some(unreserved_bandwidth),
             })
         })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::UnidirectionalLinkDelay;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkDelay {
+                value: reach.sub_tlvs.unidir_link_delay.as_ref().map(|stlv| stlv.delay),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay::flags::Flags;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.unidir_link_delay.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:unidirectional-link-delay-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                unidirectional_link_delay_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::MinMaxUnidirectionalLinkDelay;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            let stlv = reach.sub_tlvs.min_max_unidir_link_delay.as_ref();
+            Box::new(MinMaxUnidirectionalLinkDelay {
+                min_value: stlv.map(|stlv| stlv.min_delay),
+                max_value: stlv.map(|stlv| stlv.max_delay),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::min_max_unidirectional_link_delay::flags::Flags;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.min_max_unidir_link_delay.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:min-max-unidirectional-link-delay-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                min_max_unidirectional_link_delay_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay_variation::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_delay_variation::UnidirectionalLinkDelayVariation;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkDelayVariation {
+                value: reach.sub_tlvs.unidir_delay_variation.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::UnidirectionalLinkLoss;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkLoss {
+                value: reach.sub_tlvs.unidir_link_loss.as_ref().map(|stlv| stlv.loss),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::flags::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_loss::flags::Flags;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            let iter = reach.sub_tlvs.unidir_link_loss.iter().filter(|stlv| stlv.flags.contains(PerfMetricFlags::A)).map(|_| "ietf-isis:unidirectional-link-loss-subtlv-a-flag").map(Cow::Borrowed);
+            Box::new(Flags {
+                unidirectional_link_loss_subtlv_flags: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_residual_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_residual_bandwidth::UnidirectionalLinkResidualBandwidth;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkResidualBandwidth {
+                value: reach.sub_tlvs.unidir_residual_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_available_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_available_bandwidth::UnidirectionalLinkAvailableBandwidth;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkAvailableBandwidth {
+                value: reach.sub_tlvs.unidir_available_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
+        .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_utilized_bandwidth::PATH)
+        .get_object(|_instance, args| {
+            use isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unidirectional_link_utilized_bandwidth::UnidirectionalLinkUtilizedBandwidth;
+            let (_, reach) = args.list_entry.as_mt_is_reach_instance().unwrap();
+            Box::new(UnidirectionalLinkUtilizedBandwidth {
+                value: reach.sub_tlvs.unidir_utilized_bw.as_ref().map(|stlv| stlv.get()),
+            })
+        })
         .path(isis::database::levels::lsp::mt_is_neighbor::neighbor::instances::instance::unknown_tlvs::unknown_tlv::PATH)
         .get_iterate(|_instance, args| {
             let (_, reach) = args.parent_list_entry.as_mt_is_reach_instance().unwrap();
@@ -1001,6 +1192,8 @@ fn load_callbacks() -> Callbacks<Instance> {
                 readvertisement_flag: reach.prefix_attr_flags_get(PrefixAttrFlags::R),
                 ipv4_source_router_id: reach.sub_tlvs.ipv4_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
                 ipv6_source_router_id: reach.sub_tlvs.ipv6_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
+                tag: reach.sub_tlvs.admin_tags.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
+                tag64: reach.sub_tlvs.admin_tags64.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
             })
         })
         .path(isis::database::levels::lsp::mt_extended_ipv4_reachability::prefixes::unknown_tlvs::unknown_tlv::PATH)
@@ -1069,6 +1262,8 @@ fn load_callbacks() -> Callbacks<Instance> {
                 readvertisement_flag: reach.prefix_attr_flags_get(PrefixAttrFlags::R),
                 ipv4_source_router_id: reach.sub_tlvs.ipv4_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
                 ipv6_source_router_id: reach.sub_tlvs.ipv6_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
+                tag: reach.sub_tlvs.admin_tags.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
+                tag64: reach.sub_tlvs.admin_tags64.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
             })
         })
         .path(isis::database::levels::lsp::mt_ipv6_reachability::prefixes::unknown_tlvs::unknown_tlv::PATH)
@@ -1136,6 +1331,8 @@ fn load_callbacks() -> Callbacks<Instance> {
                 readvertisement_flag: reach.prefix_attr_flags_get(PrefixAttrFlags::R),
                 ipv4_source_router_id: reach.sub_tlvs.ipv4_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
                 ipv6_source_router_id: reach.sub_tlvs.ipv6_source_rid.as_ref().map(|tlv| Cow::Borrowed(tlv.get())),
+                tag: reach.sub_tlvs.admin_tags.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
+                tag64: reach.sub_tlvs.admin_tags64.as_ref().map(|stlv| Box::new(stlv.get().iter().copied()) as _),
             })
         })
         .path(isis::database::levels::lsp::ipv6_reachability::prefixes::unknown_tlvs::unknown_tlv::PATH)
@@ -1193,6 +1390,105 @@ fn load_callbacks() -> Callbacks<Instance> {
                 received_from: lsp.tlvs.purge_originator_id.as_ref().and_then(|tlv| tlv.system_id_rcvd.map(|system_id| system_id.to_yang())),
             })
         })
+        .path(isis::protected_routes::address_family_stats::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+            let rib = instance_state.rib(instance.config.level_type);
+            let iter = rib.iter().flat_map(|(prefix, route)| route.backup_nexthops.values().map(move |nexthop| ListEntry::ProtectedRoute(prefix, route, nexthop)));
+            Some(Box::new(iter))
+        })
+        .get_object(|_instance, args| {
+            use isis::protected_routes::address_family_stats::AddressFamilyStats;
+            let (prefix, _, nexthop) = args.list_entry.as_protected_route().unwrap();
+            Box::new(AddressFamilyStats {
+                address_family: prefix.address_family().to_yang(),
+                prefix: Cow::Borrowed(prefix),
+                alternate: Cow::Borrowed(&nexthop.addr),
+                alternate_type: Some("ietf-isis:frr-alternate-tilfa".into()),
+                alternate_metric_1: Some(nexthop.metric),
+            })
+        })
+        .path(isis::protected_routes::address_family_stats::protection_available::PATH)
+        .get_object(|_instance, args| {
+            use isis::protected_routes::address_family_stats::protection_available::ProtectionAvailable;
+            let (_, _, nexthop) = args.list_entry.as_protected_route().unwrap();
+            let mut protection_types = vec!["ietf-isis:frr-protection-available-link-type"];
+            if let ProtectedResource::Node(_) = nexthop.protection {
+                protection_types.push("ietf-isis:frr-protection-available-node-type");
+            }
+            let iter = protection_types.into_iter().map(Cow::Borrowed);
+            Box::new(ProtectionAvailable {
+                protection_types: Some(Box::new(iter)),
+            })
+        })
+        .path(isis::unprotected_routes::prefixes::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+            let rib = instance_state.rib(instance.config.level_type);
+            let iter = rib
+                .iter()
+                .filter(|(_, route)| route.backup_nexthops.is_empty())
+                .filter(|(_, route)| route.is_protection_enabled(&instance.arenas.interfaces))
+                .map(|(prefix, _)| ListEntry::UnprotectedRoute(prefix));
+            Some(Box::new(iter))
+        })
+        .get_object(|_instance, args| {
+            use isis::unprotected_routes::prefixes::Prefixes;
+            let prefix = args.list_entry.as_unprotected_route().unwrap();
+            Box::new(Prefixes {
+                address_family: prefix.address_family().to_yang(),
+                prefix: Cow::Borrowed(prefix),
+            })
+        })
+        .path(isis::protection_statistics::PATH)
+        .get_iterate(|instance, _args| {
+            instance.state.as_ref()?;
+            if !instance.arenas.interfaces.iter().any(|iface| instance.config.levels().any(|level| iface.config.is_ti_lfa_enabled(level))) {
+                return None;
+            }
+            let iter = std::iter::once(ListEntry::ProtectionStatistics);
+            Some(Box::new(iter))
+        })
+        .get_object(|_instance, _args| {
+            use isis::protection_statistics::ProtectionStatistics;
+            Box::new(ProtectionStatistics {
+                frr_protection_method: "holo-isis:frr-protection-method-tilfa".into(),
+            })
+        })
+        .path(isis::protection_statistics::address_family_stats::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+            let rib = instance_state.rib(instance.config.level_type);
+            let mut stats = BTreeMap::<AddressFamily, ProtectionStats>::new();
+            for (prefix, route) in rib.iter().filter(|(_, route)| !route.flags.contains(RouteFlags::CONNECTED) && !route.nexthops.is_empty()) {
+                let af_stats = stats.entry(prefix.address_family()).or_default();
+                af_stats.total += 1;
+                if !route.backup_nexthops.is_empty() {
+                    af_stats.protected += 1;
+                    if route.backup_nexthops.values().any(|nexthop| matches!(nexthop.protection, ProtectedResource::Node(_))) {
+                        af_stats.node_protected += 1;
+                    } else {
+                        af_stats.link_protected += 1;
+                    }
+                } else if route.is_protection_enabled(&instance.arenas.interfaces) {
+                    af_stats.unprotected += 1;
+                }
+            }
+            let iter = stats.into_iter().map(|(af, stats)| ListEntry::ProtectionAfStatistics(af, stats));
+            Some(Box::new(iter))
+        })
+        .get_object(|_instance, args| {
+            use isis::protection_statistics::address_family_stats::AddressFamilyStats;
+            let (af, stats) = args.list_entry.as_protection_af_statistics().unwrap();
+            Box::new(AddressFamilyStats {
+                address_family: af.to_yang(),
+                total_routes: Some(stats.total),
+                unprotected_routes: Some(stats.unprotected),
+                protected_routes: Some(stats.protected),
+                link_protected_routes: Some(stats.link_protected),
+                node_protected_routes: Some(stats.node_protected),
+            })
+        })
         .path(isis::local_rib::route::PATH)
         .get_iterate(|instance, _args| {
             let Some(instance_state) = &instance.state else { return None };
@@ -1225,6 +1521,24 @@ fn load_callbacks() -> Callbacks<Instance> {
                 outgoing_interface: Some(Cow::Borrowed(iface.name.as_str())),
             })
         })
+        .path(isis::local_rib::route::backup_next_hops::backup_next_hop::PATH)
+        .get_iterate(|_instance, args| {
+            let (_, route) = args.parent_list_entry.as_route().unwrap();
+            let iter = route.backup_nexthops.values().map(ListEntry::BackupNexthop);
+            Some(Box::new(iter))
+        })
+        .get_object(|instance, args| {
+            use isis::local_rib::route::backup_next_hops::backup_next_hop::BackupNextHop;
+            let nexthop = args.list_entry.as_backup_nexthop().unwrap();
+            let iface = &instance.arenas.interfaces[nexthop.iface_idx];
+            Box::new(BackupNextHop {
+                next_hop: Cow::Borrowed(&nexthop.addr),
+                outgoing_interface: Some(Cow::Borrowed(iface.name.as_str())),
+                label: Some(Box::new(nexthop.labels.iter().map(|label| label.to_yang()))),
+                protection: Some(nexthop.protection.to_yang()),
+                metric: Some(nexthop.metric),
+            })
+        })
         .path(isis::system_counters::level::PATH)
         .get_iterate(|instance, _args| {
             let iter = instance.config.levels().map(ListEntry::SystemCounters);
@@ -1388,6 +1702,17 @@ fn load_callbacks() -> Callbacks<Instance> {
                 out: Some(packet_counters.lsp_out),
             })
         })
+        .path(isis::interfaces::interface::packet_counters::level::lsp_flooding::PATH)
+        .get_object(|_instance, args| {
+            use isis::interfaces::interface::packet_counters::level::lsp_flooding::LspFlooding;
+            let (iface, level) = args.list_entry.as_interface_packet_counters().unwrap();
+            let packet_counters = iface.state.packet_counters.get(*level);
+            Box::new(LspFlooding {
+                queued: Some(iface.srm_list_pending(*level) as u32),
+                paced: Some(packet_counters.lsp_paced),
+                retransmitted: Some(packet_counters.lsp_rxmt.load(atomic::Ordering::Relaxed)),
+            })
+        })
         .path(isis::interfaces::interface::packet_counters::level::psnp::PATH)
         .get_object(|_instance, args| {
             use isis::interfaces::interface::packet_counters::level::psnp::Psnp;
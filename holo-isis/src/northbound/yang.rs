@@ -14,12 +14,12 @@ use regex::Regex;
 
 use crate::adjacency::{AdjacencyEvent, AdjacencyState};
 use crate::error::AdjacencyRejectError;
-use crate::interface::InterfaceType;
+use crate::interface::{InterfaceType, MeshGroupState};
 use crate::lsdb::LspLogReason;
 use crate::northbound::configuration::{
     ExtendedSeqNumMode, InstanceTraceOption, InterfaceTraceOption, MetricType,
 };
-use crate::packet::consts::MtId;
+use crate::packet::consts::{FlexAlgoMetricType, MtId};
 use crate::packet::pdu::LspFlags;
 use crate::packet::subtlvs::capability::SrCapabilitiesFlags;
 use crate::packet::subtlvs::neighbor::AdjSidFlags;
@@ -28,6 +28,7 @@ use crate::packet::tlv::{MtFlags, RouterCapFlags};
 use crate::packet::{AreaAddr, LanId, LevelNumber, LevelType, LspId, SystemId};
 use crate::spf;
 use crate::spf::SpfType;
+use crate::tilfa::ProtectedResource;
 
 // ===== ToYang implementations =====
 
@@ -312,6 +313,15 @@ impl ToYang for SpfType {
     }
 }
 
+impl ToYang for ProtectedResource {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            ProtectedResource::Link(_) => "link".into(),
+            ProtectedResource::Node(_) => "node".into(),
+        }
+    }
+}
+
 // ===== TryFromYang implementations =====
 
 impl TryFromYang for LevelNumber {
@@ -390,6 +400,17 @@ impl TryFromYang for InterfaceType {
     }
 }
 
+impl TryFromYang for MeshGroupState {
+    fn try_from_yang(value: &str) -> Option<MeshGroupState> {
+        match value {
+            "mesh-inactive" => Some(MeshGroupState::Inactive),
+            "mesh-set" => Some(MeshGroupState::Set),
+            "mesh-blocked" => Some(MeshGroupState::Blocked),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for MetricType {
     fn try_from_yang(value: &str) -> Option<MetricType> {
         match value {
@@ -401,6 +422,19 @@ impl TryFromYang for MetricType {
     }
 }
 
+impl TryFromYang for FlexAlgoMetricType {
+    fn try_from_yang(value: &str) -> Option<FlexAlgoMetricType> {
+        match value {
+            "igp-metric" => Some(FlexAlgoMetricType::Igp),
+            "min-unidirectional-link-delay" => {
+                Some(FlexAlgoMetricType::MinUnidirLinkDelay)
+            }
+            "te-default-metric" => Some(FlexAlgoMetricType::TeDefault),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for MtId {
     fn try_from_yang(value: &str) -> Option<MtId> {
         match value {
@@ -45,6 +45,7 @@ pub enum TlvType {
     AreaAddresses = 1,
     IsReach = 2,
     Neighbors = 6,
+    InstanceId = 7,
     Padding = 8,
     LspEntries = 9,
     Authentication = 10,
@@ -52,6 +53,7 @@ pub enum TlvType {
     PurgeOriginatorId = 13,
     LspBufferSize = 14,
     ExtIsReach = 22,
+    Srv6Locator = 27,
     Ipv4InternalReach = 128,
     ProtocolsSupported = 129,
     Ipv4ExternalReach = 130,
@@ -60,6 +62,7 @@ pub enum TlvType {
     ExtIpv4Reach = 135,
     DynamicHostname = 137,
     Ipv6RouterId = 140,
+    Restart = 211,
     MtIsReach = 222,
     MultiTopology = 229,
     Ipv6Addresses = 232,
@@ -97,10 +100,20 @@ pub enum NeighborStlvType {
     MaxLinkBandwidth = 9,
     MaxResvLinkBandwidth = 10,
     UnreservedBandwidth = 11,
+    ExtAdminGroup = 14,
     LinkMsd = 15,
     TeDefaultMetric = 18,
     AdjacencySid = 31,
     LanAdjacencySid = 32,
+    UnidirLinkDelay = 33,
+    MinMaxUnidirLinkDelay = 34,
+    UnidirDelayVariation = 35,
+    UnidirLinkLoss = 36,
+    UnidirResidualBandwidth = 37,
+    UnidirAvailableBandwidth = 38,
+    UnidirUtilizedBandwidth = 39,
+    Srv6EndXSid = 43,
+    Srv6LanEndXSid = 44,
 }
 
 // IS-IS Sub-TLVs for TLVs Advertising Prefix Reachability.
@@ -111,8 +124,11 @@ pub enum NeighborStlvType {
 #[derive(FromPrimitive, ToPrimitive)]
 #[derive(Deserialize, Serialize)]
 pub enum PrefixStlvType {
+    AdminTag = 1,
+    AdminTag64 = 2,
     PrefixSid = 3,
     PrefixAttributeFlags = 4,
+    Srv6EndSid = 5,
     Ipv4SourceRouterId = 11,
     Ipv6SourceRouterId = 12,
     BierInfo = 32,
@@ -131,6 +147,47 @@ pub enum RouterCapStlvType {
     NodeAdminTag = 21,
     SrLocalBlock = 22,
     NodeMsd = 23,
+    Srv6Capabilities = 25,
+    FlexAlgoDefinition = 26,
+}
+
+// IS-IS Sub-Sub-TLVs for the Flexible Algorithm Definition Sub-TLV.
+//
+// IANA registry:
+// https://www.iana.org/assignments/isis-tlv-codepoints/isis-tlv-codepoints.xhtml
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FlexAlgoDefStlvType {
+    ExcludeAnyAdminGroup = 1,
+    IncludeAnyAdminGroup = 2,
+    IncludeAllAdminGroup = 3,
+    Flags = 4,
+    ExcludeSrlg = 5,
+}
+
+// IGP Metric-Types used by Flexible Algorithms.
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FlexAlgoMetricType {
+    Igp = 0,
+    MinUnidirLinkDelay = 1,
+    TeDefault = 2,
+}
+
+// IS-IS Sub-Sub-TLVs for SRv6 SID Sub-TLVs.
+//
+// IANA registry:
+// https://www.iana.org/assignments/isis-tlv-codepoints/isis-tlv-codepoints.xhtml
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6SidStlvType {
+    SidStructure = 1,
 }
 
 // IS-IS Sub-TLVs for Segment Identifier/Label Binding TLVs.
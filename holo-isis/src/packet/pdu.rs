@@ -29,17 +29,19 @@ use crate::packet::consts::{
     VERSION_PROTO_EXT,
 };
 use crate::packet::error::{DecodeError, DecodeResult};
-use crate::packet::subtlvs::capability::{SrAlgoStlv, SrCapabilitiesStlv};
+use crate::packet::subtlvs::capability::{
+    FlexAlgoDefStlv, SrAlgoStlv, SrCapabilitiesStlv,
+};
 use crate::packet::tlv::{
     AreaAddressesTlv, AuthenticationTlv, DynamicHostnameTlv, ExtendedSeqNum,
-    ExtendedSeqNumTlv, Ipv4AddressesTlv, Ipv4Reach, Ipv4ReachTlv,
-    Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach, Ipv6ReachTlv,
+    ExtendedSeqNumTlv, InstanceIdTlv, Ipv4AddressesTlv, Ipv4Reach,
+    Ipv4ReachTlv, Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach, Ipv6ReachTlv,
     Ipv6RouterIdTlv, IsReach, IsReachTlv, LegacyIpv4Reach, LegacyIpv4ReachTlv,
     LegacyIsReach, LegacyIsReachTlv, LspBufferSizeTlv, LspEntriesTlv, LspEntry,
     MtFlags, MultiTopologyEntry, MultiTopologyTlv, NeighborsTlv, PaddingTlv,
-    ProtocolsSupportedTlv, PurgeOriginatorIdTlv, RouterCapTlv, TLV_HDR_SIZE,
-    TLV_MAX_LEN, ThreeWayAdjTlv, Tlv, UnknownTlv, tlv_entries_split,
-    tlv_take_max,
+    ProtocolsSupportedTlv, PurgeOriginatorIdTlv, RestartTlv, RouterCapTlv,
+    Srv6Locator, Srv6LocatorTlv, TLV_HDR_SIZE, TLV_MAX_LEN, ThreeWayAdjTlv,
+    Tlv, UnknownTlv, tlv_entries_split, tlv_take_max,
 };
 use crate::packet::{
     AreaAddr, LanId, LevelNumber, LevelType, LspId, SystemId, auth,
@@ -89,10 +91,12 @@ pub enum HelloVariant {
 #[derive(Deserialize, Serialize)]
 pub struct HelloTlvs {
     pub protocols_supported: Option<ProtocolsSupportedTlv>,
+    pub instance_id: Option<InstanceIdTlv>,
     pub area_addrs: Vec<AreaAddressesTlv>,
     pub multi_topology: Vec<MultiTopologyTlv>,
     pub neighbors: Vec<NeighborsTlv>,
     pub three_way_adj: Option<ThreeWayAdjTlv>,
+    pub restart: Option<RestartTlv>,
     pub ipv4_addrs: Vec<Ipv4AddressesTlv>,
     pub ipv6_addrs: Vec<Ipv6AddressesTlv>,
     pub ext_seqnum: Option<ExtendedSeqNumTlv>,
@@ -150,6 +154,7 @@ bitflags! {
 pub struct LspTlvs {
     pub auth: Option<AuthenticationTlv>,
     pub protocols_supported: Option<ProtocolsSupportedTlv>,
+    pub instance_id: Option<InstanceIdTlv>,
     pub router_cap: Vec<RouterCapTlv>,
     pub area_addrs: Vec<AreaAddressesTlv>,
     pub multi_topology: Vec<MultiTopologyTlv>,
@@ -169,6 +174,7 @@ pub struct LspTlvs {
     pub ipv6_reach: Vec<Ipv6ReachTlv>,
     pub mt_ipv6_reach: Vec<Ipv6ReachTlv>,
     pub ipv6_router_id: Option<Ipv6RouterIdTlv>,
+    pub srv6_locator: Vec<Srv6LocatorTlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -189,6 +195,7 @@ pub struct Snp {
 )]
 #[derive(Deserialize, Serialize)]
 pub struct SnpTlvs {
+    pub instance_id: Option<InstanceIdTlv>,
     pub lsp_entries: Vec<LspEntriesTlv>,
     pub ext_seqnum: Option<ExtendedSeqNumTlv>,
     pub unknown: Vec<UnknownTlv>,
@@ -245,6 +252,47 @@ impl Pdu {
         }
     }
 
+    // Returns the Instance Identifier (IID) of the PDU.
+    //
+    // PDUs without the IID-TLV belong to the standard instance (IID 0).
+    pub(crate) fn instance_id(&self) -> u16 {
+        let tlv = match self {
+            Pdu::Hello(pdu) => &pdu.tlvs.instance_id,
+            Pdu::Lsp(pdu) => &pdu.tlvs.instance_id,
+            Pdu::Snp(pdu) => &pdu.tlvs.instance_id,
+        };
+        tlv.as_ref().map(|tlv| tlv.iid).unwrap_or(0)
+    }
+
+    // Returns the Instance Identifier (IID) of the given raw PDU, without
+    // decoding it entirely or validating its authentication.
+    //
+    // Returns `None` if the PDU header or TLVs are malformed.
+    pub(crate) fn peek_instance_id(mut buf: Bytes) -> Option<u16> {
+        // Skip the PDU header.
+        let hdr = Header::decode(&mut buf.clone()).ok()?;
+        let hdr_len = Header::fixed_header_length(hdr.pdu_type) as usize;
+        buf.advance(hdr_len);
+
+        // Look for the IID-TLV.
+        while buf.remaining() >= TLV_HDR_SIZE {
+            let tlv_type = buf.get_u8();
+            let tlv_len = buf.get_u8();
+            if tlv_len as usize > buf.remaining() {
+                return None;
+            }
+            let mut buf_tlv = buf.copy_to_bytes(tlv_len as usize);
+            if tlv_type == TlvType::InstanceId as u8 {
+                return InstanceIdTlv::decode(tlv_len, &mut buf_tlv)
+                    .ok()
+                    .map(|tlv| tlv.iid);
+            }
+        }
+
+        // PDUs without the IID-TLV belong to the standard instance.
+        Some(0)
+    }
+
     // Validates the PDU authentication.
     fn decode_auth_validate(
         mut buf_orig: BytesMut,
@@ -653,6 +701,15 @@ impl Hello {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::Restart) => {
+                    if tlvs.restart.is_some() {
+                        continue;
+                    }
+                    match RestartTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.restart = Some(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 Some(TlvType::Padding) => {
                     match PaddingTlv::decode(tlv_len, &mut buf_tlv) {
                         Ok(tlv) => tlvs.padding.push(tlv),
@@ -668,6 +725,15 @@ impl Hello {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::InstanceId) => {
+                    if tlvs.instance_id.is_some() {
+                        continue;
+                    }
+                    match InstanceIdTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.instance_id = Some(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 Some(TlvType::ExtendedSeqNum) => {
                     if tlvs.ext_seqnum.is_some() {
                         return Err(DecodeError::MultipleEsnTlvs);
@@ -760,6 +826,9 @@ impl Hello {
             if let Some(tlv) = &self.tlvs.protocols_supported {
                 tlv.encode(&mut buf);
             }
+            if let Some(tlv) = &self.tlvs.instance_id {
+                tlv.encode(&mut buf);
+            }
             for tlv in &self.tlvs.area_addrs {
                 tlv.encode(&mut buf);
             }
@@ -772,6 +841,9 @@ impl Hello {
             if let Some(tlv) = &self.tlvs.three_way_adj {
                 tlv.encode(&mut buf);
             }
+            if let Some(tlv) = &self.tlvs.restart {
+                tlv.encode(&mut buf);
+            }
             for tlv in &self.tlvs.ipv4_addrs {
                 tlv.encode(&mut buf);
             }
@@ -795,6 +867,9 @@ impl Hello {
         if let Some(tlv) = &self.tlvs.protocols_supported {
             total_tlv_len += tlv.len();
         }
+        if let Some(tlv) = &self.tlvs.instance_id {
+            total_tlv_len += tlv.len();
+        }
         for tlv in &self.tlvs.area_addrs {
             total_tlv_len += tlv.len();
         }
@@ -807,6 +882,9 @@ impl Hello {
         if let Some(tlv) = &self.tlvs.three_way_adj {
             total_tlv_len += tlv.len();
         }
+        if let Some(tlv) = &self.tlvs.restart {
+            total_tlv_len += tlv.len();
+        }
         for tlv in &self.tlvs.ipv4_addrs {
             total_tlv_len += tlv.len();
         }
@@ -837,10 +915,12 @@ impl Hello {
 impl HelloTlvs {
     pub(crate) fn new(
         protocols_supported: impl IntoIterator<Item = u8>,
+        instance_id: Option<InstanceIdTlv>,
         area_addrs: impl IntoIterator<Item = AreaAddr>,
         multi_topology: impl IntoIterator<Item = MultiTopologyEntry>,
         neighbors: impl IntoIterator<Item = MacAddr>,
         three_way_adj: Option<ThreeWayAdjTlv>,
+        restart: Option<RestartTlv>,
         ipv4_addrs: impl IntoIterator<Item = Ipv4Addr>,
         ipv6_addrs: impl IntoIterator<Item = Ipv6Addr>,
         ext_seqnum: Option<ExtendedSeqNum>,
@@ -849,10 +929,12 @@ impl HelloTlvs {
             protocols_supported: Some(ProtocolsSupportedTlv::from(
                 protocols_supported,
             )),
+            instance_id,
             area_addrs: tlv_entries_split(area_addrs),
             multi_topology: tlv_entries_split(multi_topology),
             neighbors: tlv_entries_split(neighbors),
             three_way_adj,
+            restart,
             ipv4_addrs: tlv_entries_split(ipv4_addrs),
             ipv6_addrs: tlv_entries_split(ipv6_addrs),
             ext_seqnum: ext_seqnum.map(ExtendedSeqNumTlv::new),
@@ -1020,6 +1102,15 @@ impl Lsp {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::InstanceId) => {
+                    if tlvs.instance_id.is_some() {
+                        continue;
+                    }
+                    match InstanceIdTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.instance_id = Some(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 Some(TlvType::PurgeOriginatorId) => {
                     if tlvs.purge_originator_id.is_some() {
                         continue;
@@ -1163,6 +1254,12 @@ impl Lsp {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::Srv6Locator) => {
+                    match Srv6LocatorTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.srv6_locator.push(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 _ => {
                     // Save unknown top-level TLV.
                     tlvs.unknown
@@ -1220,6 +1317,9 @@ impl Lsp {
             if let Some(tlv) = &self.tlvs.protocols_supported {
                 tlv.encode(&mut buf);
             }
+            if let Some(tlv) = &self.tlvs.instance_id {
+                tlv.encode(&mut buf);
+            }
             for tlv in &self.tlvs.router_cap {
                 tlv.encode(&mut buf);
             }
@@ -1277,6 +1377,9 @@ impl Lsp {
             if let Some(tlv) = &self.tlvs.ipv6_router_id {
                 tlv.encode(&mut buf);
             }
+            for tlv in &self.tlvs.srv6_locator {
+                tlv.encode(&mut buf);
+            }
 
             // Store LSP raw data.
             let bytes = pdu_encode_end(buf, len_pos, auth, Some(self));
@@ -1415,6 +1518,7 @@ impl Lsp {
 impl LspTlvs {
     pub(crate) fn new(
         protocols_supported: impl IntoIterator<Item = u8>,
+        instance_id: Option<InstanceIdTlv>,
         router_cap: Vec<RouterCapTlv>,
         area_addrs: impl IntoIterator<Item = AreaAddr>,
         multi_topology: impl IntoIterator<Item = MultiTopologyEntry>,
@@ -1433,12 +1537,14 @@ impl LspTlvs {
         ipv6_reach: impl IntoIterator<Item = Ipv6Reach>,
         mt_ipv6_reach: impl IntoIterator<Item = Ipv6Reach>,
         ipv6_router_id: Option<Ipv6Addr>,
+        srv6_locator: Vec<Srv6LocatorTlv>,
     ) -> Self {
         LspTlvs {
             auth: None,
             protocols_supported: Some(ProtocolsSupportedTlv::from(
                 protocols_supported,
             )),
+            instance_id,
             router_cap,
             area_addrs: tlv_entries_split(area_addrs),
             multi_topology: tlv_entries_split(multi_topology),
@@ -1470,12 +1576,19 @@ impl LspTlvs {
                 })
                 .collect(),
             ipv6_router_id: ipv6_router_id.map(Ipv6RouterIdTlv::new),
+            srv6_locator,
             unknown: Default::default(),
         }
     }
 
     pub(crate) fn next_chunk(&mut self, max_len: usize) -> Option<Self> {
         let mut rem_len = max_len;
+        // The IID-TLV is included in all LSP fragments.
+        let instance_id = self.instance_id.clone();
+        if let Some(instance_id) = &instance_id {
+            rem_len -= instance_id.len();
+        }
+        let max_len = rem_len;
         let protocols_supported = self.protocols_supported.take();
         if let Some(protocols_supported) = &protocols_supported {
             rem_len -= protocols_supported.len();
@@ -1514,6 +1627,7 @@ impl LspTlvs {
         let ipv6_addrs = tlv_take_max(&mut self.ipv6_addrs, &mut rem_len);
         let ipv6_reach = tlv_take_max(&mut self.ipv6_reach, &mut rem_len);
         let mt_ipv6_reach = tlv_take_max(&mut self.mt_ipv6_reach, &mut rem_len);
+        let srv6_locator = tlv_take_max(&mut self.srv6_locator, &mut rem_len);
         if rem_len == max_len {
             return None;
         }
@@ -1521,6 +1635,7 @@ impl LspTlvs {
         Some(LspTlvs {
             auth: None,
             protocols_supported,
+            instance_id,
             router_cap,
             area_addrs,
             multi_topology,
@@ -1540,6 +1655,7 @@ impl LspTlvs {
             ipv6_reach,
             mt_ipv6_reach,
             ipv6_router_id,
+            srv6_locator,
             unknown: Default::default(),
         })
     }
@@ -1596,6 +1712,7 @@ impl LspTlvs {
             && self.ipv6_reach.is_empty()
             && self.mt_ipv6_reach.is_empty()
             && self.ipv6_router_id.is_none()
+            && self.srv6_locator.is_empty()
     }
 
     // Returns an iterator over all supported protocols from the TLV of type 129.
@@ -1742,6 +1859,15 @@ impl LspTlvs {
             .flat_map(|tlv| tlv.list.iter())
     }
 
+    // Returns an iterator over all SRv6 locator entries from TLVs of type 27.
+    pub(crate) fn srv6_locators(
+        &self,
+    ) -> impl Iterator<Item = (u16, &Srv6Locator)> {
+        self.srv6_locator
+            .iter()
+            .flat_map(|tlv| tlv.list.iter().map(|locator| (tlv.mt_id, locator)))
+    }
+
     // Returns the first SR-Capabilities Sub-TLV found within any Router
     // Capabilities TLV.
     pub(crate) fn sr_cap(&self) -> Option<&SrCapabilitiesStlv> {
@@ -1757,6 +1883,16 @@ impl LspTlvs {
             .iter()
             .find_map(|router_cap| router_cap.sub_tlvs.sr_algo.as_ref())
     }
+
+    // Returns an iterator over all Flexible Algorithm Definition Sub-TLVs
+    // found within any Router Capabilities TLV.
+    pub(crate) fn flex_algo_defs(
+        &self,
+    ) -> impl Iterator<Item = &FlexAlgoDefStlv> {
+        self.router_cap
+            .iter()
+            .flat_map(|router_cap| router_cap.sub_tlvs.flex_algo_defs.iter())
+    }
 }
 
 // In conformance tests, we only care whether the LSP Remaining Lifetime is
@@ -1845,6 +1981,15 @@ impl Snp {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::InstanceId) => {
+                    if tlvs.instance_id.is_some() {
+                        continue;
+                    }
+                    match InstanceIdTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.instance_id = Some(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 Some(TlvType::LspEntries) => {
                     match LspEntriesTlv::decode(tlv_len, &mut buf_tlv) {
                         Ok(tlv) => tlvs.lsp_entries.push(tlv),
@@ -1904,6 +2049,9 @@ impl Snp {
             });
 
             // Encode other TLVs.
+            if let Some(tlv) = &self.tlvs.instance_id {
+                tlv.encode(&mut buf);
+            }
             for tlv in &self.tlvs.lsp_entries {
                 tlv.encode(&mut buf);
             }
@@ -1918,6 +2066,7 @@ impl Snp {
 
 impl SnpTlvs {
     pub(crate) fn new(
+        instance_id: Option<InstanceIdTlv>,
         lsp_entries: impl IntoIterator<Item = LspEntry>,
         ext_seqnum: Option<ExtendedSeqNum>,
     ) -> Self {
@@ -1932,6 +2081,7 @@ impl SnpTlvs {
             .collect();
 
         SnpTlvs {
+            instance_id,
             lsp_entries,
             ext_seqnum: ext_seqnum.map(ExtendedSeqNumTlv::new),
             unknown: Default::default(),
@@ -1943,6 +2093,7 @@ impl SnpTlvs {
     pub(crate) fn max_lsp_entries(
         mut size: usize,
         auth: Option<AuthMethod>,
+        instance_id: Option<&InstanceIdTlv>,
         ext_seqnum: bool,
     ) -> usize {
         let mut lsp_entries = 0;
@@ -1954,6 +2105,11 @@ impl SnpTlvs {
             size -= Pdu::auth_tlv_len(auth_key);
         }
 
+        // Reserve space for the IID-TLV.
+        if let Some(instance_id) = instance_id {
+            size -= instance_id.len();
+        }
+
         // Reserve space for the ESN TLV.
         if ext_seqnum {
             size -= TLV_HDR_SIZE + ExtendedSeqNumTlv::SIZE;
@@ -18,10 +18,13 @@ use holo_utils::sr::{IgpAlgoType, Sid};
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
-use crate::packet::consts::{LabelBindingStlvType, RouterCapStlvType};
+use crate::packet::consts::{
+    FlexAlgoDefStlvType, FlexAlgoMetricType, LabelBindingStlvType,
+    RouterCapStlvType,
+};
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
 use crate::packet::tlv::{
-    TLV_HDR_SIZE, TLV_MAX_LEN, tlv_encode_end, tlv_encode_start,
+    TLV_HDR_SIZE, TLV_MAX_LEN, UnknownTlv, tlv_encode_end, tlv_encode_start,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -67,6 +70,56 @@ pub struct LabelBlockEntry {
 #[derive(Deserialize, Serialize)]
 pub struct NodeAdminTagStlv(BTreeSet<u32>);
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6CapabilitiesStlv {
+    pub flags: Srv6CapFlags,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6CapFlags: u16 {
+        const O = 0x4000;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct FlexAlgoDefStlv {
+    pub algo: u8,
+    pub metric_type: u8,
+    pub calc_type: u8,
+    pub priority: u8,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_any: Vec<u32>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_any: Vec<u32>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_all: Vec<u32>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<FlexAlgoDefFlags>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<UnknownTlv>,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct FlexAlgoDefFlags: u8 {
+        const M = 0x80;
+    }
+}
+
 // ===== impl SrCapabilitiesStlv =====
 
 impl SrCapabilitiesStlv {
@@ -145,7 +198,7 @@ impl SrAlgoStlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, RouterCapStlvType::SrAlgorithm);
         for algo in &self.0 {
-            buf.put_u8(*algo as u8);
+            buf.put_u8(u8::from(*algo));
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -253,6 +306,176 @@ impl LabelBlockEntry {
     }
 }
 
+// ===== impl Srv6CapabilitiesStlv =====
+
+impl Srv6CapabilitiesStlv {
+    const MIN_SIZE: usize = 2;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (stlv_len as usize) < Self::MIN_SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let flags = buf.try_get_u16()?;
+        let flags = Srv6CapFlags::from_bits_truncate(flags);
+
+        // No Sub-Sub-TLVs are currently defined.
+
+        Ok(Srv6CapabilitiesStlv { flags })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, RouterCapStlvType::Srv6Capabilities);
+        buf.put_u16(self.flags.bits());
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        TLV_HDR_SIZE + Self::MIN_SIZE
+    }
+}
+
+// ===== impl FlexAlgoDefStlv =====
+
+impl FlexAlgoDefStlv {
+    const MIN_SIZE: usize = 4;
+    const ADMIN_GROUP_WORD_LEN: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (stlv_len as usize) < Self::MIN_SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let algo = buf.try_get_u8()?;
+        let metric_type = buf.try_get_u8()?;
+        let calc_type = buf.try_get_u8()?;
+        let priority = buf.try_get_u8()?;
+        let mut fad =
+            FlexAlgoDefStlv::new(algo, metric_type, calc_type, priority);
+
+        // Parse Sub-Sub-TLVs.
+        while buf.remaining() >= TLV_HDR_SIZE {
+            // Parse TLV type.
+            let stlv_type = buf.try_get_u8()?;
+            let stlv_etype = FlexAlgoDefStlvType::from_u8(stlv_type);
+
+            // Parse and validate TLV length.
+            let stlv_len = buf.try_get_u8()?;
+            if stlv_len as usize > buf.remaining() {
+                return Err(TlvDecodeError::InvalidLength(stlv_len));
+            }
+
+            // Parse Sub-Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_len as usize);
+            match stlv_etype {
+                Some(FlexAlgoDefStlvType::ExcludeAnyAdminGroup) => {
+                    fad.exclude_any =
+                        Self::decode_admin_groups(stlv_len, &mut buf_stlv)?;
+                }
+                Some(FlexAlgoDefStlvType::IncludeAnyAdminGroup) => {
+                    fad.include_any =
+                        Self::decode_admin_groups(stlv_len, &mut buf_stlv)?;
+                }
+                Some(FlexAlgoDefStlvType::IncludeAllAdminGroup) => {
+                    fad.include_all =
+                        Self::decode_admin_groups(stlv_len, &mut buf_stlv)?;
+                }
+                Some(FlexAlgoDefStlvType::Flags) => {
+                    // Only the first octet carries defined flags.
+                    let flags = buf_stlv.try_get_u8()?;
+                    let flags = FlexAlgoDefFlags::from_bits_truncate(flags);
+                    fad.flags = Some(flags);
+                }
+                _ => {
+                    // Save unknown (or unsupported) Sub-Sub-TLV.
+                    fad.unknown
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
+            }
+        }
+
+        Ok(fad)
+    }
+
+    fn decode_admin_groups(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Vec<u32>> {
+        // Validate the TLV length.
+        if !(stlv_len as usize).is_multiple_of(Self::ADMIN_GROUP_WORD_LEN) {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let mut groups = vec![];
+        while buf.remaining() >= Self::ADMIN_GROUP_WORD_LEN {
+            groups.push(buf.try_get_u32()?);
+        }
+
+        Ok(groups)
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, RouterCapStlvType::FlexAlgoDefinition);
+        buf.put_u8(self.algo);
+        buf.put_u8(self.metric_type);
+        buf.put_u8(self.calc_type);
+        buf.put_u8(self.priority);
+        for (stlv_type, groups) in [
+            (FlexAlgoDefStlvType::ExcludeAnyAdminGroup, &self.exclude_any),
+            (FlexAlgoDefStlvType::IncludeAnyAdminGroup, &self.include_any),
+            (FlexAlgoDefStlvType::IncludeAllAdminGroup, &self.include_all),
+        ] {
+            if groups.is_empty() {
+                continue;
+            }
+            let start_pos = tlv_encode_start(buf, stlv_type);
+            for group in groups {
+                buf.put_u32(*group);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(flags) = &self.flags {
+            let start_pos = tlv_encode_start(buf, FlexAlgoDefStlvType::Flags);
+            buf.put_u8(flags.bits());
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        let mut len = TLV_HDR_SIZE + Self::MIN_SIZE;
+        for groups in [&self.exclude_any, &self.include_any, &self.include_all]
+        {
+            if !groups.is_empty() {
+                len += TLV_HDR_SIZE + groups.len() * Self::ADMIN_GROUP_WORD_LEN;
+            }
+        }
+        if self.flags.is_some() {
+            len += TLV_HDR_SIZE + 1;
+        }
+        len
+    }
+
+    // Returns whether the definition contains any element this
+    // implementation doesn't support, in which case the router must not
+    // participate in the corresponding Flexible Algorithm.
+    pub(crate) fn is_unsupported(&self) -> bool {
+        FlexAlgoMetricType::from_u8(self.metric_type).is_none()
+            || self.calc_type != 0
+            || !self.unknown.is_empty()
+    }
+}
+
 // ===== impl NodeAdminTagStlv =====
 
 impl NodeAdminTagStlv {
@@ -16,8 +16,10 @@ use std::collections::BTreeMap;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use derive_new::new;
 use holo_utils::sr::MsdType;
+use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
+use crate::packet::consts::Srv6SidStlvType;
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
 use crate::packet::tlv::{TLV_HDR_SIZE, tlv_encode_end, tlv_encode_start};
 
@@ -26,6 +28,16 @@ use crate::packet::tlv::{TLV_HDR_SIZE, tlv_encode_end, tlv_encode_start};
 #[derive(Deserialize, Serialize)]
 pub struct MsdStlv(BTreeMap<u8, u8>);
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6SidStructureStlv {
+    pub lb_len: u8,
+    pub ln_len: u8,
+    pub fun_len: u8,
+    pub arg_len: u8,
+}
+
 // ===== impl MsdStlv =====
 
 impl MsdStlv {
@@ -72,3 +84,98 @@ impl From<&BTreeMap<MsdType, u8>> for MsdStlv {
         MsdStlv(msd)
     }
 }
+
+// ===== impl Srv6SidStructureStlv =====
+
+impl Srv6SidStructureStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let lb_len = buf.try_get_u8()?;
+        let ln_len = buf.try_get_u8()?;
+        let fun_len = buf.try_get_u8()?;
+        let arg_len = buf.try_get_u8()?;
+
+        Ok(Srv6SidStructureStlv {
+            lb_len,
+            ln_len,
+            fun_len,
+            arg_len,
+        })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, Srv6SidStlvType::SidStructure);
+        buf.put_u8(self.lb_len);
+        buf.put_u8(self.ln_len);
+        buf.put_u8(self.fun_len);
+        buf.put_u8(self.arg_len);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        TLV_HDR_SIZE + Self::SIZE
+    }
+
+    // Decodes the Sub-Sub-TLVs that trail the SRv6 SID Sub-TLVs, including
+    // the one-octet length field that precedes them.
+    pub(crate) fn decode_sstlvs(
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Option<Self>> {
+        let sstlvs_len = buf.try_get_u8()?;
+        if sstlvs_len as usize > buf.remaining() {
+            return Err(TlvDecodeError::InvalidLength(sstlvs_len));
+        }
+        let mut buf = buf.copy_to_bytes(sstlvs_len as usize);
+
+        let mut sid_structure = None;
+        while buf.remaining() >= TLV_HDR_SIZE {
+            // Parse TLV type.
+            let stlv_type = buf.try_get_u8()?;
+            let stlv_etype = Srv6SidStlvType::from_u8(stlv_type);
+
+            // Parse and validate TLV length.
+            let stlv_len = buf.try_get_u8()?;
+            if stlv_len as usize > buf.remaining() {
+                return Err(TlvDecodeError::InvalidLength(stlv_len));
+            }
+
+            // Parse Sub-Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_len as usize);
+            match stlv_etype {
+                Some(Srv6SidStlvType::SidStructure) => {
+                    let stlv = Self::decode(stlv_len, &mut buf_stlv)?;
+                    sid_structure = Some(stlv);
+                }
+                _ => {
+                    // Ignore unknown Sub-Sub-TLV.
+                }
+            }
+        }
+
+        Ok(sid_structure)
+    }
+
+    // Encodes the Sub-Sub-TLVs that trail the SRv6 SID Sub-TLVs, including
+    // the one-octet length field that precedes them.
+    pub(crate) fn encode_sstlvs(
+        sid_structure: &Option<Self>,
+        buf: &mut BytesMut,
+    ) {
+        match sid_structure {
+            Some(sid_structure) => {
+                buf.put_u8(sid_structure.len() as u8);
+                sid_structure.encode(buf);
+            }
+            None => buf.put_u8(0),
+        }
+    }
+}
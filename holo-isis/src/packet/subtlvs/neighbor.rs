@@ -7,19 +7,21 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 use bitflags::bitflags;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use derive_new::new;
 use holo_utils::bytes::{BytesExt, BytesMutExt};
 use holo_utils::mpls::Label;
-use holo_utils::sr::Sid;
+use holo_utils::sr::{IgpAlgoType, Sid};
+use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::packet::SystemId;
 use crate::packet::consts::NeighborStlvType;
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
+use crate::packet::subtlvs::Srv6SidStructureStlv;
 use crate::packet::tlv::{tlv_encode_end, tlv_encode_start};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -52,6 +54,11 @@ pub struct MaxResvLinkBwStlv(f32);
 #[derive(Deserialize, Serialize)]
 pub struct UnreservedBwStlv([f32; 8]);
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct ExtAdminGroupStlv(Vec<u32>);
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -81,6 +88,87 @@ pub struct AdjSidStlv {
     pub sid: Sid,
 }
 
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct PerfMetricFlags: u8 {
+        const A = 0x80;
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirLinkDelayStlv {
+    pub flags: PerfMetricFlags,
+    pub delay: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct MinMaxUnidirLinkDelayStlv {
+    pub flags: PerfMetricFlags,
+    pub min_delay: u32,
+    pub max_delay: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirDelayVariationStlv(u32);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirLinkLossStlv {
+    pub flags: PerfMetricFlags,
+    pub loss: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirResidualBwStlv(f32);
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirAvailableBwStlv(f32);
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct UnidirUtilizedBwStlv(f32);
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6EndXSidFlags: u8 {
+        const B = 0x80;
+        const S = 0x40;
+        const P = 0x20;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[serde_with::apply(
+    Option => #[serde(default, skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6EndXSidStlv {
+    pub flags: Srv6EndXSidFlags,
+    pub algo: IgpAlgoType,
+    pub weight: u8,
+    pub behavior: u16,
+    pub sid: Ipv6Addr,
+    pub nbr_system_id: Option<SystemId>,
+    pub sid_structure: Option<Srv6SidStructureStlv>,
+}
+
 // ===== impl AdminGroupStlv =====
 
 impl AdminGroupStlv {
@@ -271,6 +359,41 @@ impl UnreservedBwStlv {
     }
 }
 
+// ===== impl ExtAdminGroupStlv =====
+
+impl ExtAdminGroupStlv {
+    const WORD_LEN: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if !(stlv_len as usize).is_multiple_of(Self::WORD_LEN) {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let mut groups = vec![];
+        while buf.remaining() >= Self::WORD_LEN {
+            groups.push(buf.try_get_u32()?);
+        }
+
+        Ok(ExtAdminGroupStlv(groups))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, NeighborStlvType::ExtAdminGroup);
+        for word in &self.0 {
+            buf.put_u32(*word);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &[u32] {
+        &self.0
+    }
+}
+
 // ===== impl TeDefaultMetricStlv =====
 
 impl TeDefaultMetricStlv {
@@ -302,6 +425,231 @@ impl TeDefaultMetricStlv {
     }
 }
 
+// ===== impl UnidirLinkDelayStlv =====
+
+impl UnidirLinkDelayStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let flags = buf.try_get_u8()?;
+        let flags = PerfMetricFlags::from_bits_truncate(flags);
+        let delay = buf.try_get_u24()?;
+
+        Ok(UnidirLinkDelayStlv { flags, delay })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::UnidirLinkDelay);
+        buf.put_u8(self.flags.bits());
+        buf.put_u24(self.delay);
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl MinMaxUnidirLinkDelayStlv =====
+
+impl MinMaxUnidirLinkDelayStlv {
+    const SIZE: usize = 8;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let flags = buf.try_get_u8()?;
+        let flags = PerfMetricFlags::from_bits_truncate(flags);
+        let min_delay = buf.try_get_u24()?;
+        let _reserved = buf.try_get_u8()?;
+        let max_delay = buf.try_get_u24()?;
+
+        Ok(MinMaxUnidirLinkDelayStlv {
+            flags,
+            min_delay,
+            max_delay,
+        })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::MinMaxUnidirLinkDelay);
+        buf.put_u8(self.flags.bits());
+        buf.put_u24(self.min_delay);
+        buf.put_u8(0);
+        buf.put_u24(self.max_delay);
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl UnidirDelayVariationStlv =====
+
+impl UnidirDelayVariationStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let _reserved = buf.try_get_u8()?;
+        let variation = buf.try_get_u24()?;
+
+        Ok(UnidirDelayVariationStlv(variation))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::UnidirDelayVariation);
+        buf.put_u8(0);
+        buf.put_u24(self.0);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+// ===== impl UnidirLinkLossStlv =====
+
+impl UnidirLinkLossStlv {
+    const SIZE: usize = 4;
+    // Link loss is encoded in units of 0.000003%.
+    pub const UNIT: f64 = 0.000003;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let flags = buf.try_get_u8()?;
+        let flags = PerfMetricFlags::from_bits_truncate(flags);
+        let loss = buf.try_get_u24()?;
+
+        Ok(UnidirLinkLossStlv { flags, loss })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, NeighborStlvType::UnidirLinkLoss);
+        buf.put_u8(self.flags.bits());
+        buf.put_u24(self.loss);
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl UnidirResidualBwStlv =====
+
+impl UnidirResidualBwStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let bw = buf.try_get_f32()?;
+
+        Ok(UnidirResidualBwStlv(bw))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::UnidirResidualBandwidth);
+        buf.put_f32(self.0);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &f32 {
+        &self.0
+    }
+}
+
+// ===== impl UnidirAvailableBwStlv =====
+
+impl UnidirAvailableBwStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let bw = buf.try_get_f32()?;
+
+        Ok(UnidirAvailableBwStlv(bw))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::UnidirAvailableBandwidth);
+        buf.put_f32(self.0);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &f32 {
+        &self.0
+    }
+}
+
+// ===== impl UnidirUtilizedBwStlv =====
+
+impl UnidirUtilizedBwStlv {
+    const SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len as usize != Self::SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let bw = buf.try_get_f32()?;
+
+        Ok(UnidirUtilizedBwStlv(bw))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, NeighborStlvType::UnidirUtilizedBandwidth);
+        buf.put_f32(self.0);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &f32 {
+        &self.0
+    }
+}
+
 // ===== impl AdjSidStlv =====
 
 impl AdjSidStlv {
@@ -356,3 +704,73 @@ impl AdjSidStlv {
         tlv_encode_end(buf, start_pos);
     }
 }
+
+// ===== impl Srv6EndXSidStlv =====
+
+impl Srv6EndXSidStlv {
+    const MIN_SIZE: usize = 22;
+    const SYSTEM_ID_SIZE: usize = 6;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        lan: bool,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Option<Self>> {
+        // Validate the TLV length.
+        let mut min_size = Self::MIN_SIZE;
+        if lan {
+            min_size += Self::SYSTEM_ID_SIZE;
+        }
+        if (stlv_len as usize) < min_size {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let mut nbr_system_id = None;
+        if lan {
+            nbr_system_id = Some(SystemId::decode(buf)?);
+        }
+        let flags = buf.try_get_u8()?;
+        let flags = Srv6EndXSidFlags::from_bits_truncate(flags);
+        let algo = buf.try_get_u8()?;
+        let weight = buf.try_get_u8()?;
+        let behavior = buf.try_get_u16()?;
+        let sid = buf.try_get_ipv6()?;
+        let sid_structure = Srv6SidStructureStlv::decode_sstlvs(buf)?;
+
+        let algo = match IgpAlgoType::from_u8(algo) {
+            Some(algo) => algo,
+            None => {
+                // Unsupported algorithm - ignore.
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(Srv6EndXSidStlv {
+            flags,
+            algo,
+            weight,
+            behavior,
+            sid,
+            nbr_system_id,
+            sid_structure,
+        }))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let stlv_type = match self.nbr_system_id {
+            Some(_) => NeighborStlvType::Srv6LanEndXSid,
+            None => NeighborStlvType::Srv6EndXSid,
+        };
+        let start_pos = tlv_encode_start(buf, stlv_type);
+        if let Some(nbr_system_id) = &self.nbr_system_id {
+            nbr_system_id.encode(buf);
+        }
+        buf.put_u8(self.flags.bits());
+        buf.put_u8(u8::from(self.algo));
+        buf.put_u8(self.weight);
+        buf.put_u16(self.behavior);
+        buf.put_ipv6(&self.sid);
+        Srv6SidStructureStlv::encode_sstlvs(&self.sid_structure, buf);
+        tlv_encode_end(buf, start_pos);
+    }
+}
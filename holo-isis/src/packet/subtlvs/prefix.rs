@@ -7,6 +7,7 @@
 // See: https://nlnet.nl/NGI0
 //
 
+use std::collections::BTreeSet;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
 use bitflags::bitflags;
@@ -21,7 +22,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::packet::consts::{BierSubStlvType, PrefixStlvType};
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
-use crate::packet::tlv::{TLV_HDR_SIZE, tlv_encode_end, tlv_encode_start};
+use crate::packet::subtlvs::Srv6SidStructureStlv;
+use crate::packet::tlv::{
+    TLV_HDR_SIZE, TLV_MAX_LEN, tlv_encode_end, tlv_encode_start,
+};
 
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -39,6 +43,16 @@ bitflags! {
 #[derive(Deserialize, Serialize)]
 pub struct PrefixAttrFlagsStlv(PrefixAttrFlags);
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct AdminTagStlv(BTreeSet<u32>);
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct AdminTag64Stlv(BTreeSet<u64>);
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -72,6 +86,18 @@ pub struct PrefixSidStlv {
     pub sid: Sid,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[serde_with::apply(
+    Option => #[serde(default, skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6EndSidStlv {
+    pub behavior: u16,
+    pub sid: Ipv6Addr,
+    pub sid_structure: Option<Srv6SidStructureStlv>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -140,6 +166,88 @@ impl PrefixAttrFlagsStlv {
     }
 }
 
+// ===== impl AdminTagStlv =====
+
+impl AdminTagStlv {
+    pub const TAG_LEN: usize = 4;
+    pub const MAX_ENTRIES: usize = TLV_MAX_LEN / Self::TAG_LEN;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len == 0 || !(stlv_len as usize).is_multiple_of(Self::TAG_LEN) {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let mut list = BTreeSet::new();
+        while buf.remaining() >= Self::TAG_LEN {
+            let tag = buf.try_get_u32()?;
+            list.insert(tag);
+        }
+
+        Ok(AdminTagStlv(list))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, PrefixStlvType::AdminTag);
+        for tag in &self.0 {
+            buf.put_u32(*tag);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        TLV_HDR_SIZE + self.0.len() * Self::TAG_LEN
+    }
+
+    pub(crate) fn get(&self) -> &BTreeSet<u32> {
+        &self.0
+    }
+}
+
+// ===== impl AdminTag64Stlv =====
+
+impl AdminTag64Stlv {
+    pub const TAG_LEN: usize = 8;
+    pub const MAX_ENTRIES: usize = TLV_MAX_LEN / Self::TAG_LEN;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if stlv_len == 0 || !(stlv_len as usize).is_multiple_of(Self::TAG_LEN) {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let mut list = BTreeSet::new();
+        while buf.remaining() >= Self::TAG_LEN {
+            let tag = buf.try_get_u64()?;
+            list.insert(tag);
+        }
+
+        Ok(AdminTag64Stlv(list))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, PrefixStlvType::AdminTag64);
+        for tag in &self.0 {
+            buf.put_u64(*tag);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        TLV_HDR_SIZE + self.0.len() * Self::TAG_LEN
+    }
+
+    pub(crate) fn get(&self) -> &BTreeSet<u64> {
+        &self.0
+    }
+}
+
 // ===== impl Ipv4SourceRidStlv =====
 
 impl Ipv4SourceRidStlv {
@@ -245,7 +353,7 @@ impl PrefixSidStlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, PrefixStlvType::PrefixSid);
         buf.put_u8(self.flags.bits());
-        buf.put_u8(self.algo as u8);
+        buf.put_u8(u8::from(self.algo));
         match self.sid {
             Sid::Index(index) => buf.put_u32(index),
             Sid::Label(label) => buf.put_u24(label.get()),
@@ -263,6 +371,49 @@ impl PrefixSidStlv {
     }
 }
 
+// ===== impl Srv6EndSidStlv =====
+
+impl Srv6EndSidStlv {
+    const MIN_SIZE: usize = 20;
+
+    pub(crate) fn decode(
+        stlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (stlv_len as usize) < Self::MIN_SIZE {
+            return Err(TlvDecodeError::InvalidLength(stlv_len));
+        }
+
+        let _flags = buf.try_get_u8()?;
+        let behavior = buf.try_get_u16()?;
+        let sid = buf.try_get_ipv6()?;
+        let sid_structure = Srv6SidStructureStlv::decode_sstlvs(buf)?;
+
+        Ok(Srv6EndSidStlv {
+            behavior,
+            sid,
+            sid_structure,
+        })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, PrefixStlvType::Srv6EndSid);
+        // Flags.
+        buf.put_u8(0);
+        buf.put_u16(self.behavior);
+        buf.put_ipv6(&self.sid);
+        Srv6SidStructureStlv::encode_sstlvs(&self.sid_structure, buf);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        TLV_HDR_SIZE
+            + Self::MIN_SIZE
+            + self.sid_structure.as_ref().map_or(0, |stlv| stlv.len())
+    }
+}
+
 // ===== impl BierInfoStlv =====
 
 impl BierInfoStlv {
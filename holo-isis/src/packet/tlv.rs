@@ -37,11 +37,13 @@ use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
 use crate::packet::pdu::serde_lsp_rem_lifetime_filter;
 use crate::packet::subtlvs::MsdStlv;
 use crate::packet::subtlvs::capability::{
-    NodeAdminTagStlv, SrAlgoStlv, SrCapabilitiesStlv, SrLocalBlockStlv,
+    FlexAlgoDefStlv, NodeAdminTagStlv, SrAlgoStlv, SrCapabilitiesStlv,
+    SrLocalBlockStlv, Srv6CapabilitiesStlv,
 };
 use crate::packet::subtlvs::prefix::{
-    BierInfoStlv, Ipv4SourceRidStlv, Ipv6SourceRidStlv, PrefixAttrFlags,
-    PrefixAttrFlagsStlv, PrefixSidStlv,
+    AdminTag64Stlv, AdminTagStlv, BierInfoStlv, Ipv4SourceRidStlv,
+    Ipv6SourceRidStlv, PrefixAttrFlags, PrefixAttrFlagsStlv, PrefixSidStlv,
+    Srv6EndSidStlv,
 };
 use crate::packet::{AreaAddr, LanId, LspId, SystemId, subtlvs};
 
@@ -51,6 +53,8 @@ pub const TLV_HDR_SIZE: usize = 2;
 pub const TLV_MAX_LEN: usize = 255;
 // Maximum narrow metric.
 pub const MAX_NARROW_METRIC: u32 = 63;
+// Maximum wide link metric.
+pub const MAX_WIDE_LINK_METRIC: u32 = 0xFFFFFE;
 // Multi-Topology flags mask.
 pub const MT_FLAGS_MASK: u16 = 0xf000;
 // Multi-Topology ID mask.
@@ -168,6 +172,29 @@ pub enum ThreeWayAdjState {
     Down = 2,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+#[serde_with::apply(
+    Option => #[serde(default, skip_serializing_if = "Option::is_none")],
+)]
+pub struct RestartTlv {
+    pub flags: RestartFlags,
+    pub rem_time: Option<u16>,
+    pub restarting_nbr: Option<SystemId>,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct RestartFlags: u8 {
+        const RR = 0x01;
+        const RA = 0x02;
+        const SA = 0x04;
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(Deserialize, Serialize)]
 pub enum AuthenticationTlv {
@@ -176,6 +203,14 @@ pub enum AuthenticationTlv {
     Cryptographic { key_id: u16, digest: Vec<u8> },
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(new)]
+#[derive(Deserialize, Serialize)]
+pub struct InstanceIdTlv {
+    pub iid: u16,
+    pub itids: Vec<u16>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -297,9 +332,20 @@ pub struct IsReachStlvs {
     pub max_link_bw: Option<subtlvs::neighbor::MaxLinkBwStlv>,
     pub max_resv_link_bw: Option<subtlvs::neighbor::MaxResvLinkBwStlv>,
     pub unreserved_bw: Option<subtlvs::neighbor::UnreservedBwStlv>,
+    pub ext_admin_group: Option<subtlvs::neighbor::ExtAdminGroupStlv>,
     pub te_default_metric: Option<subtlvs::neighbor::TeDefaultMetricStlv>,
+    pub unidir_link_delay: Option<subtlvs::neighbor::UnidirLinkDelayStlv>,
+    pub min_max_unidir_link_delay:
+        Option<subtlvs::neighbor::MinMaxUnidirLinkDelayStlv>,
+    pub unidir_delay_variation:
+        Option<subtlvs::neighbor::UnidirDelayVariationStlv>,
+    pub unidir_link_loss: Option<subtlvs::neighbor::UnidirLinkLossStlv>,
+    pub unidir_residual_bw: Option<subtlvs::neighbor::UnidirResidualBwStlv>,
+    pub unidir_available_bw: Option<subtlvs::neighbor::UnidirAvailableBwStlv>,
+    pub unidir_utilized_bw: Option<subtlvs::neighbor::UnidirUtilizedBwStlv>,
     pub adj_sids: Vec<subtlvs::neighbor::AdjSidStlv>,
     pub link_msd: Option<MsdStlv>,
+    pub srv6_end_x_sids: Vec<subtlvs::neighbor::Srv6EndXSidStlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -355,6 +401,8 @@ pub struct Ipv4ReachStlvs {
     pub ipv4_source_rid: Option<Ipv4SourceRidStlv>,
     pub ipv6_source_rid: Option<Ipv6SourceRidStlv>,
     pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSidStlv>,
+    pub admin_tags: Option<AdminTagStlv>,
+    pub admin_tags64: Option<AdminTag64Stlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -390,10 +438,48 @@ pub struct Ipv6ReachStlvs {
     pub ipv4_source_rid: Option<Ipv4SourceRidStlv>,
     pub ipv6_source_rid: Option<Ipv6SourceRidStlv>,
     pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSidStlv>,
+    pub admin_tags: Option<AdminTagStlv>,
+    pub admin_tags64: Option<AdminTag64Stlv>,
     pub bier: Vec<BierInfoStlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocatorTlv {
+    pub mt_id: u16,
+    pub list: Vec<Srv6Locator>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6Locator {
+    pub metric: u32,
+    pub flags: Srv6LocatorFlags,
+    pub algo: IgpAlgoType,
+    pub locator: Ipv6Network,
+    pub sub_tlvs: Srv6LocatorStlvs,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6LocatorFlags: u8 {
+        const D = 0x80;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[serde_with::apply(
+    Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocatorStlvs {
+    pub end_sids: Vec<Srv6EndSidStlv>,
+    pub unknown: Vec<UnknownTlv>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -434,6 +520,8 @@ pub struct RouterCapStlvs {
     pub srlb: Option<SrLocalBlockStlv>,
     pub node_msd: Option<MsdStlv>,
     pub node_tags: Vec<NodeAdminTagStlv>,
+    pub srv6_cap: Option<Srv6CapabilitiesStlv>,
+    pub flex_algo_defs: Vec<FlexAlgoDefStlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -722,6 +810,70 @@ impl Tlv for ThreeWayAdjTlv {
     }
 }
 
+// ===== impl RestartTlv =====
+
+impl RestartTlv {
+    pub const MIN_LEN: usize = 1;
+    const REM_TIME_LEN: usize = 2;
+    const SYSTEM_ID_LEN: usize = 6;
+
+    pub(crate) fn decode(
+        tlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (tlv_len as usize) < Self::MIN_LEN {
+            return Err(TlvDecodeError::InvalidLength(tlv_len));
+        }
+
+        let flags = buf.try_get_u8()?;
+        let flags = RestartFlags::from_bits_truncate(flags);
+
+        // The Remaining Time and Restarting Neighbor System ID fields are
+        // optional.
+        let mut rem_time = None;
+        if buf.remaining() >= Self::REM_TIME_LEN {
+            rem_time = Some(buf.try_get_u16()?);
+        }
+
+        let mut restarting_nbr = None;
+        if buf.remaining() >= Self::SYSTEM_ID_LEN {
+            restarting_nbr = Some(SystemId::decode(buf)?);
+        }
+
+        Ok(RestartTlv {
+            flags,
+            rem_time,
+            restarting_nbr,
+        })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TlvType::Restart);
+        buf.put_u8(self.flags.bits());
+        if self.rem_time.is_some() || self.restarting_nbr.is_some() {
+            buf.put_u16(self.rem_time.unwrap_or(0));
+            if let Some(restarting_nbr) = &self.restarting_nbr {
+                restarting_nbr.encode(buf);
+            }
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+impl Tlv for RestartTlv {
+    fn len(&self) -> usize {
+        let mut len = TLV_HDR_SIZE + Self::MIN_LEN;
+        if self.rem_time.is_some() || self.restarting_nbr.is_some() {
+            len += Self::REM_TIME_LEN;
+            if self.restarting_nbr.is_some() {
+                len += Self::SYSTEM_ID_LEN;
+            }
+        }
+        len
+    }
+}
+
 // ===== impl AuthenticationTlv =====
 
 impl AuthenticationTlv {
@@ -807,6 +959,50 @@ impl AuthenticationTlv {
     }
 }
 
+// ===== impl InstanceIdTlv =====
+
+impl InstanceIdTlv {
+    const IID_SIZE: usize = 2;
+    const ITID_SIZE: usize = 2;
+
+    pub(crate) fn decode(
+        tlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (tlv_len as usize) < Self::IID_SIZE
+            || !(tlv_len as usize - Self::IID_SIZE)
+                .is_multiple_of(Self::ITID_SIZE)
+        {
+            return Err(TlvDecodeError::InvalidLength(tlv_len));
+        }
+
+        let iid = buf.try_get_u16()?;
+        let mut itids = vec![];
+        while buf.remaining() >= Self::ITID_SIZE {
+            let itid = buf.try_get_u16()?;
+            itids.push(itid);
+        }
+
+        Ok(InstanceIdTlv { iid, itids })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TlvType::InstanceId);
+        buf.put_u16(self.iid);
+        for itid in &self.itids {
+            buf.put_u16(*itid);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+impl Tlv for InstanceIdTlv {
+    fn len(&self) -> usize {
+        TLV_HDR_SIZE + Self::IID_SIZE + self.itids.len() * Self::ITID_SIZE
+    }
+}
+
 // ===== impl ExtendedSeqNumTlv =====
 
 impl ExtendedSeqNumTlv {
@@ -1283,9 +1479,12 @@ impl IsReachTlv {
         buf: &mut Bytes,
     ) -> TlvDecodeResult<Self> {
         use subtlvs::neighbor::{
-            AdjSidStlv, AdminGroupStlv, Ipv4InterfaceAddrStlv,
-            Ipv4NeighborAddrStlv, MaxLinkBwStlv, MaxResvLinkBwStlv,
-            TeDefaultMetricStlv, UnreservedBwStlv,
+            AdjSidStlv, AdminGroupStlv, ExtAdminGroupStlv,
+            Ipv4InterfaceAddrStlv, Ipv4NeighborAddrStlv, MaxLinkBwStlv,
+            MaxResvLinkBwStlv, MinMaxUnidirLinkDelayStlv, Srv6EndXSidStlv,
+            TeDefaultMetricStlv, UnidirAvailableBwStlv,
+            UnidirDelayVariationStlv, UnidirLinkDelayStlv, UnidirLinkLossStlv,
+            UnidirResidualBwStlv, UnidirUtilizedBwStlv, UnreservedBwStlv,
         };
 
         let mut mt_id = None;
@@ -1372,6 +1571,13 @@ impl IsReachTlv {
                             Err(error) => error.log(),
                         }
                     }
+                    Some(NeighborStlvType::ExtAdminGroup) => {
+                        match ExtAdminGroupStlv::decode(stlv_len, &mut buf_stlv)
+                        {
+                            Ok(stlv) => sub_tlvs.ext_admin_group = Some(stlv),
+                            Err(error) => error.log(),
+                        }
+                    }
                     Some(NeighborStlvType::TeDefaultMetric) => {
                         match TeDefaultMetricStlv::decode(
                             stlv_len,
@@ -1381,6 +1587,79 @@ impl IsReachTlv {
                             Err(error) => error.log(),
                         }
                     }
+                    Some(NeighborStlvType::UnidirLinkDelay) => {
+                        match UnidirLinkDelayStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => sub_tlvs.unidir_link_delay = Some(stlv),
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::MinMaxUnidirLinkDelay) => {
+                        match MinMaxUnidirLinkDelayStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                sub_tlvs.min_max_unidir_link_delay = Some(stlv)
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::UnidirDelayVariation) => {
+                        match UnidirDelayVariationStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                sub_tlvs.unidir_delay_variation = Some(stlv)
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::UnidirLinkLoss) => {
+                        match UnidirLinkLossStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => sub_tlvs.unidir_link_loss = Some(stlv),
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::UnidirResidualBandwidth) => {
+                        match UnidirResidualBwStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                sub_tlvs.unidir_residual_bw = Some(stlv)
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::UnidirAvailableBandwidth) => {
+                        match UnidirAvailableBwStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                sub_tlvs.unidir_available_bw = Some(stlv)
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::UnidirUtilizedBandwidth) => {
+                        match UnidirUtilizedBwStlv::decode(
+                            stlv_len,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                sub_tlvs.unidir_utilized_bw = Some(stlv)
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
                     Some(NeighborStlvType::AdjacencySid) => {
                         match AdjSidStlv::decode(stlv_len, false, &mut buf_stlv)
                         {
@@ -1412,6 +1691,34 @@ impl IsReachTlv {
                             Err(error) => error.log(),
                         }
                     }
+                    Some(NeighborStlvType::Srv6EndXSid) => {
+                        match Srv6EndXSidStlv::decode(
+                            stlv_len,
+                            false,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                if let Some(stlv) = stlv {
+                                    sub_tlvs.srv6_end_x_sids.push(stlv);
+                                }
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
+                    Some(NeighborStlvType::Srv6LanEndXSid) => {
+                        match Srv6EndXSidStlv::decode(
+                            stlv_len,
+                            true,
+                            &mut buf_stlv,
+                        ) {
+                            Ok(stlv) => {
+                                if let Some(stlv) = stlv {
+                                    sub_tlvs.srv6_end_x_sids.push(stlv);
+                                }
+                            }
+                            Err(error) => error.log(),
+                        }
+                    }
                     _ => {
                         // Save unknown Sub-TLV.
                         sub_tlvs.unknown.push(UnknownTlv::new(
@@ -1470,15 +1777,42 @@ impl IsReachTlv {
             if let Some(stlv) = &entry.sub_tlvs.unreserved_bw {
                 stlv.encode(buf);
             }
+            if let Some(stlv) = &entry.sub_tlvs.ext_admin_group {
+                stlv.encode(buf);
+            }
             if let Some(stlv) = &entry.sub_tlvs.te_default_metric {
                 stlv.encode(buf);
             }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_link_delay {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.min_max_unidir_link_delay {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_delay_variation {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_link_loss {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_residual_bw {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_available_bw {
+                stlv.encode(buf);
+            }
+            if let Some(stlv) = &entry.sub_tlvs.unidir_utilized_bw {
+                stlv.encode(buf);
+            }
             for stlv in &entry.sub_tlvs.adj_sids {
                 stlv.encode(buf);
             }
             if let Some(stlv) = &entry.sub_tlvs.link_msd {
                 stlv.encode(NeighborStlvType::LinkMsd as u8, buf);
             }
+            for stlv in &entry.sub_tlvs.srv6_end_x_sids {
+                stlv.encode(buf);
+            }
             // Rewrite Sub-TLVs length field.
             buf[subtlvs_len_pos] = (buf.len() - 1 - subtlvs_len_pos) as u8;
         }
@@ -1781,6 +2115,22 @@ impl Ipv4ReachTlv {
                                 Err(error) => error.log(),
                             }
                         }
+                        Some(PrefixStlvType::AdminTag) => {
+                            match AdminTagStlv::decode(stlv_len, &mut buf_stlv)
+                            {
+                                Ok(stlv) => sub_tlvs.admin_tags = Some(stlv),
+                                Err(error) => error.log(),
+                            }
+                        }
+                        Some(PrefixStlvType::AdminTag64) => {
+                            match AdminTag64Stlv::decode(
+                                stlv_len,
+                                &mut buf_stlv,
+                            ) {
+                                Ok(stlv) => sub_tlvs.admin_tags64 = Some(stlv),
+                                Err(error) => error.log(),
+                            }
+                        }
                         _ => {
                             // Save unknown Sub-TLV.
                             sub_tlvs.unknown.push(UnknownTlv::new(
@@ -1832,7 +2182,9 @@ impl Ipv4ReachTlv {
             }
             let has_subtlvs = entry.sub_tlvs.prefix_attr_flags.is_some()
                 || entry.sub_tlvs.ipv4_source_rid.is_some()
-                || entry.sub_tlvs.ipv6_source_rid.is_some();
+                || entry.sub_tlvs.ipv6_source_rid.is_some()
+                || entry.sub_tlvs.admin_tags.is_some()
+                || entry.sub_tlvs.admin_tags64.is_some();
             if has_subtlvs {
                 control |= Self::CONTROL_SUBTLVS;
             }
@@ -1860,6 +2212,12 @@ impl Ipv4ReachTlv {
                 for stlv in entry.sub_tlvs.prefix_sids.values() {
                     stlv.encode(buf);
                 }
+                if let Some(stlv) = &entry.sub_tlvs.admin_tags {
+                    stlv.encode(buf);
+                }
+                if let Some(stlv) = &entry.sub_tlvs.admin_tags64 {
+                    stlv.encode(buf);
+                }
 
                 // Rewrite Sub-TLVs length field.
                 buf[subtlvs_len_pos] = (buf.len() - 1 - subtlvs_len_pos) as u8;
@@ -1937,6 +2295,15 @@ impl IpReachTlvEntry for Ipv4Reach {
 // ===== impl Ipv4ReachStlvs =====
 
 impl Ipv4ReachStlvs {
+    // Returns the 32-bit administrative tag used for the installed route.
+    //
+    // When multiple tags are present, the lowest one is selected.
+    pub(crate) fn admin_tag(&self) -> Option<u32> {
+        self.admin_tags
+            .as_ref()
+            .and_then(|stlv| stlv.get().first().copied())
+    }
+
     fn len(&self) -> usize {
         let mut len = 0;
 
@@ -1944,6 +2311,8 @@ impl Ipv4ReachStlvs {
             || self.ipv4_source_rid.is_some()
             || self.ipv6_source_rid.is_some()
             || !self.prefix_sids.is_empty()
+            || self.admin_tags.is_some()
+            || self.admin_tags64.is_some()
         {
             len += 1;
         }
@@ -1959,6 +2328,12 @@ impl Ipv4ReachStlvs {
         for stlv in self.prefix_sids.values() {
             len += stlv.len();
         }
+        if let Some(stlv) = &self.admin_tags {
+            len += stlv.len();
+        }
+        if let Some(stlv) = &self.admin_tags64 {
+            len += stlv.len();
+        }
 
         len
     }
@@ -2083,6 +2458,22 @@ impl Ipv6ReachTlv {
                                 Err(error) => error.log(),
                             }
                         }
+                        Some(PrefixStlvType::AdminTag) => {
+                            match AdminTagStlv::decode(stlv_len, &mut buf_stlv)
+                            {
+                                Ok(stlv) => sub_tlvs.admin_tags = Some(stlv),
+                                Err(error) => error.log(),
+                            }
+                        }
+                        Some(PrefixStlvType::AdminTag64) => {
+                            match AdminTag64Stlv::decode(
+                                stlv_len,
+                                &mut buf_stlv,
+                            ) {
+                                Ok(stlv) => sub_tlvs.admin_tags64 = Some(stlv),
+                                Err(error) => error.log(),
+                            }
+                        }
                         Some(PrefixStlvType::BierInfo) => {
                             match BierInfoStlv::decode(stlv_len, &mut buf_stlv)
                             {
@@ -2145,6 +2536,8 @@ impl Ipv6ReachTlv {
             let has_subtlvs = entry.sub_tlvs.prefix_attr_flags.is_some()
                 || entry.sub_tlvs.ipv4_source_rid.is_some()
                 || entry.sub_tlvs.ipv6_source_rid.is_some()
+                || entry.sub_tlvs.admin_tags.is_some()
+                || entry.sub_tlvs.admin_tags64.is_some()
                 || !entry.sub_tlvs.bier.is_empty();
             if has_subtlvs {
                 flags |= Self::FLAG_SUBTLVS;
@@ -2181,6 +2574,12 @@ impl Ipv6ReachTlv {
                 for stlv in entry.sub_tlvs.prefix_sids.values() {
                     stlv.encode(buf);
                 }
+                if let Some(stlv) = &entry.sub_tlvs.admin_tags {
+                    stlv.encode(buf);
+                }
+                if let Some(stlv) = &entry.sub_tlvs.admin_tags64 {
+                    stlv.encode(buf);
+                }
                 for stlv in &entry.sub_tlvs.bier {
                     stlv.encode(buf);
                 }
@@ -2261,6 +2660,15 @@ impl IpReachTlvEntry for Ipv6Reach {
 // ===== impl Ipv6ReachStlvs =====
 
 impl Ipv6ReachStlvs {
+    // Returns the 32-bit administrative tag used for the installed route.
+    //
+    // When multiple tags are present, the lowest one is selected.
+    pub(crate) fn admin_tag(&self) -> Option<u32> {
+        self.admin_tags
+            .as_ref()
+            .and_then(|stlv| stlv.get().first().copied())
+    }
+
     fn len(&self) -> usize {
         let mut len = 0;
 
@@ -2268,6 +2676,8 @@ impl Ipv6ReachStlvs {
             || self.ipv4_source_rid.is_some()
             || self.ipv6_source_rid.is_some()
             || !self.prefix_sids.is_empty()
+            || self.admin_tags.is_some()
+            || self.admin_tags64.is_some()
             || !self.bier.is_empty()
         {
             len += 1;
@@ -2284,6 +2694,12 @@ impl Ipv6ReachStlvs {
         for stlv in self.prefix_sids.values() {
             len += stlv.len();
         }
+        if let Some(stlv) = &self.admin_tags {
+            len += stlv.len();
+        }
+        if let Some(stlv) = &self.admin_tags64 {
+            len += stlv.len();
+        }
         for stlv in self.bier.iter() {
             len += stlv.len();
         }
@@ -2292,6 +2708,180 @@ impl Ipv6ReachStlvs {
     }
 }
 
+// ===== impl Srv6LocatorTlv =====
+
+impl Srv6LocatorTlv {
+    const ENTRY_MIN_SIZE: usize = 8;
+
+    pub(crate) fn decode(
+        _tlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        let mut list = vec![];
+
+        // Parse MT ID.
+        let mt_field = buf.try_get_u16()?;
+        let mt_id = mt_field & MT_ID_MASK;
+
+        while buf.remaining() >= Self::ENTRY_MIN_SIZE {
+            // Parse metric.
+            let metric = buf.try_get_u32()?;
+
+            // Parse flags field.
+            let flags = buf.try_get_u8()?;
+            let flags = Srv6LocatorFlags::from_bits_truncate(flags);
+
+            // Parse algorithm.
+            let algo = buf.try_get_u8()?;
+
+            // Parse locator size.
+            let plen = buf.try_get_u8()?;
+            if plen > Ipv6Network::MAX_PREFIXLEN {
+                return Err(TlvDecodeError::InvalidPrefixLength(plen));
+            }
+
+            // Parse locator (variable length).
+            let mut prefix_bytes = [0; Ipv6Addr::LENGTH];
+            let plen_wire = prefix_wire_len(plen);
+            buf.try_copy_to_slice(&mut prefix_bytes[..plen_wire])?;
+            let prefix = Ipv6Addr::from(prefix_bytes);
+
+            // Parse Sub-TLVs.
+            let mut sub_tlvs = Srv6LocatorStlvs::default();
+            let sub_tlvs_len = buf.try_get_u8()?;
+            if sub_tlvs_len as usize > buf.remaining() {
+                return Err(TlvDecodeError::InvalidLength(sub_tlvs_len));
+            }
+            let mut buf_stlvs = buf.copy_to_bytes(sub_tlvs_len as usize);
+            while buf_stlvs.remaining() >= TLV_HDR_SIZE {
+                // Parse TLV type.
+                let stlv_type = buf_stlvs.try_get_u8()?;
+                let stlv_etype = PrefixStlvType::from_u8(stlv_type);
+
+                // Parse and validate TLV length.
+                let stlv_len = buf_stlvs.try_get_u8()?;
+                if stlv_len as usize > buf_stlvs.remaining() {
+                    return Err(TlvDecodeError::InvalidLength(stlv_len));
+                }
+
+                // Parse Sub-TLV value.
+                let span = debug_span!(
+                    "sub-TLV",
+                    r#type = stlv_type,
+                    length = stlv_len
+                );
+                let _span_guard = span.enter();
+                let mut buf_stlv = buf_stlvs.copy_to_bytes(stlv_len as usize);
+                match stlv_etype {
+                    Some(PrefixStlvType::Srv6EndSid) => {
+                        match Srv6EndSidStlv::decode(stlv_len, &mut buf_stlv) {
+                            Ok(stlv) => sub_tlvs.end_sids.push(stlv),
+                            Err(error) => error.log(),
+                        }
+                    }
+                    _ => {
+                        // Save unknown Sub-TLV.
+                        sub_tlvs.unknown.push(UnknownTlv::new(
+                            stlv_type, stlv_len, buf_stlv,
+                        ));
+                    }
+                }
+            }
+
+            // Ignore unsupported algorithms.
+            let Some(algo) = IgpAlgoType::from_u8(algo) else {
+                continue;
+            };
+
+            // Ignore malformed locators.
+            let Ok(locator) = Ipv6Network::new(prefix, plen) else {
+                continue;
+            };
+
+            list.push(Srv6Locator {
+                metric,
+                flags,
+                algo,
+                locator,
+                sub_tlvs,
+            });
+        }
+
+        Ok(Srv6LocatorTlv { mt_id, list })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TlvType::Srv6Locator);
+
+        // Encode MT ID.
+        buf.put_u16(self.mt_id);
+
+        for entry in &self.list {
+            // Encode metric.
+            buf.put_u32(entry.metric);
+
+            // Encode flags field.
+            buf.put_u8(entry.flags.bits());
+
+            // Encode algorithm.
+            buf.put_u8(u8::from(entry.algo));
+
+            // Encode locator size.
+            let plen = entry.locator.prefix();
+            buf.put_u8(plen);
+
+            // Encode locator (variable length).
+            let plen_wire = prefix_wire_len(plen);
+            buf.put(&entry.locator.ip().octets()[0..plen_wire]);
+
+            // Encode Sub-TLVs.
+            let subtlvs_len_pos = buf.len();
+            buf.put_u8(0);
+            for stlv in &entry.sub_tlvs.end_sids {
+                stlv.encode(buf);
+            }
+
+            // Rewrite Sub-TLVs length field.
+            buf[subtlvs_len_pos] = (buf.len() - 1 - subtlvs_len_pos) as u8;
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+impl EntryBasedTlv for Srv6LocatorTlv {
+    type Entry = Srv6Locator;
+    const FIXED_FIELDS_LEN: usize = 2;
+
+    fn entries(&self) -> impl Iterator<Item = &Srv6Locator> {
+        self.list.iter()
+    }
+
+    fn entry_len(entry: &Srv6Locator) -> usize {
+        let plen = entry.locator.prefix();
+        Self::ENTRY_MIN_SIZE + prefix_wire_len(plen) + entry.sub_tlvs.len()
+    }
+}
+
+impl<I> From<I> for Srv6LocatorTlv
+where
+    I: IntoIterator<Item = Srv6Locator>,
+{
+    fn from(iter: I) -> Srv6LocatorTlv {
+        Srv6LocatorTlv {
+            mt_id: 0,
+            list: iter.into_iter().collect(),
+        }
+    }
+}
+
+// ===== impl Srv6LocatorStlvs =====
+
+impl Srv6LocatorStlvs {
+    fn len(&self) -> usize {
+        self.end_sids.iter().map(|stlv| stlv.len()).sum::<usize>()
+    }
+}
+
 // ===== impl Ipv4RouterIdTlv =====
 
 impl Ipv4RouterIdTlv {
@@ -2443,6 +3033,22 @@ impl RouterCapTlv {
                         Err(error) => error.log(),
                     }
                 }
+                Some(RouterCapStlvType::Srv6Capabilities) => {
+                    if sub_tlvs.srv6_cap.is_some() {
+                        continue;
+                    }
+                    match Srv6CapabilitiesStlv::decode(stlv_len, &mut buf_stlv)
+                    {
+                        Ok(stlv) => sub_tlvs.srv6_cap = Some(stlv),
+                        Err(error) => error.log(),
+                    }
+                }
+                Some(RouterCapStlvType::FlexAlgoDefinition) => {
+                    match FlexAlgoDefStlv::decode(stlv_len, &mut buf_stlv) {
+                        Ok(stlv) => sub_tlvs.flex_algo_defs.push(stlv),
+                        Err(error) => error.log(),
+                    }
+                }
                 _ => {
                     // Save unknown Sub-TLV.
                     sub_tlvs
@@ -2481,6 +3087,12 @@ impl RouterCapTlv {
         for stlv in &self.sub_tlvs.node_tags {
             stlv.encode(buf);
         }
+        if let Some(stlv) = &self.sub_tlvs.srv6_cap {
+            stlv.encode(buf);
+        }
+        for stlv in &self.sub_tlvs.flex_algo_defs {
+            stlv.encode(buf);
+        }
         tlv_encode_end(buf, start_pos);
     }
 }
@@ -2504,6 +3116,12 @@ impl Tlv for RouterCapTlv {
         for stlv in &self.sub_tlvs.node_tags {
             len += stlv.len();
         }
+        if let Some(stlv) = &self.sub_tlvs.srv6_cap {
+            len += stlv.len();
+        }
+        for stlv in &self.sub_tlvs.flex_algo_defs {
+            len += stlv.len();
+        }
 
         len
     }
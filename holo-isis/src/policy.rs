@@ -0,0 +1,42 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use holo_utils::policy::{
+    MatchSets, Policies, PolicyResult, policy_apply, route_action_apply,
+    route_condition_match,
+};
+use ipnetwork::IpNetwork;
+
+use crate::northbound::configuration::RedistributionCfg;
+use crate::route::RouteSys;
+
+// ===== global functions =====
+
+// Applies the redistribution routing policies to the provided route and
+// returns the policy result.
+pub(crate) fn redistribute_apply(
+    prefix: IpNetwork,
+    route: &RouteSys,
+    redistribution: &RedistributionCfg,
+    policies: &Policies,
+    match_sets: &MatchSets,
+) -> PolicyResult<RouteSys> {
+    let stmts = redistribution
+        .policies
+        .iter()
+        .filter_map(|policy| policies.get(policy))
+        .flat_map(|policy| policy.stmts.values());
+
+    policy_apply(
+        stmts,
+        route.clone(),
+        redistribution.default_policy,
+        |route, stmt, condition| {
+            route_condition_match(&prefix, route, stmt, condition, match_sets)
+        },
+        route_action_apply,
+    )
+}
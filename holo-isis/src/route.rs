@@ -4,13 +4,14 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::IpAddr;
 
 use bitflags::bitflags;
 use derive_new::new;
 use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::Label;
+use holo_utils::policy::PolicyRoute;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{IsisRouteType, RouteOpaqueAttrs};
 use ipnetwork::IpNetwork;
@@ -19,9 +20,12 @@ use crate::collections::{InterfaceIndex, Interfaces};
 use crate::ibus;
 use crate::instance::InstanceUpView;
 use crate::northbound::configuration::{InstanceCfg, SummaryCfg};
-use crate::packet::subtlvs::prefix::PrefixSidStlv;
+use crate::packet::subtlvs::prefix::{
+    AdminTag64Stlv, AdminTagStlv, PrefixSidStlv,
+};
 use crate::packet::{LevelNumber, LevelType, SystemId};
 use crate::spf::{Vertex, VertexNetwork};
+use crate::tilfa::ProtectedResource;
 
 // Routing table entry.
 #[derive(Clone, Debug, PartialEq)]
@@ -33,6 +37,7 @@ pub struct Route {
     pub prefix_sid: Option<PrefixSidStlv>,
     pub sr_label: Option<Label>,
     pub nexthops: BTreeMap<IpAddr, Nexthop>,
+    pub backup_nexthops: BTreeMap<IpAddr, BackupNexthop>,
     pub flags: RouteFlags,
 }
 
@@ -58,6 +63,23 @@ pub struct Nexthop {
     pub sr_label: Option<Label>,
 }
 
+// Route backup nexthop (TI-LFA).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BackupNexthop {
+    // System ID of the nexthop router.
+    pub system_id: SystemId,
+    // Nexthop interface.
+    pub iface_idx: InterfaceIndex,
+    // Nexthop address.
+    pub addr: IpAddr,
+    // Repair path label stack.
+    pub labels: Vec<Label>,
+    // Resource protected by the repair path.
+    pub protection: ProtectedResource,
+    // Metric of the repair path.
+    pub metric: u32,
+}
+
 // Route redistributed from the global RIB.
 #[derive(Clone, Debug)]
 pub struct RouteSys {
@@ -96,10 +118,11 @@ impl Route {
             route_type,
             metric: vertex.distance + vertex_network.metric,
             level,
-            tag: None,
+            tag: vertex_network.tag,
             prefix_sid: vertex_network.prefix_sid,
             sr_label: None,
             nexthops: Self::build_nexthops(vertex, vertex_network),
+            backup_nexthops: Default::default(),
             flags,
         }
     }
@@ -138,6 +161,19 @@ impl Route {
             .collect()
     }
 
+    // Checks whether any of the route's nexthops goes through an interface
+    // with TI-LFA protection enabled.
+    pub(crate) fn is_protection_enabled(
+        &self,
+        interfaces: &Interfaces,
+    ) -> bool {
+        !self.flags.contains(RouteFlags::CONNECTED)
+            && self.nexthops.values().any(|nexthop| {
+                let iface = &interfaces[nexthop.iface_idx];
+                iface.config.is_ti_lfa_enabled(self.level)
+            })
+    }
+
     pub(crate) const fn distance(&self, config: &InstanceCfg) -> u8 {
         match self.route_type {
             IsisRouteType::L2IntraArea
@@ -156,10 +192,11 @@ impl From<&SummaryRoute> for Route {
             route_type: IsisRouteType::L2IntraArea,
             metric: summary.metric(),
             level: LevelNumber::L2,
-            tag: None,
+            tag: summary.config.tag,
             prefix_sid: None,
             sr_label: None,
             nexthops: [].into(),
+            backup_nexthops: Default::default(),
             flags: RouteFlags::SUMMARY,
         }
     }
@@ -173,6 +210,41 @@ impl SummaryRoute {
     pub(crate) fn metric(&self) -> u32 {
         self.config.metric.unwrap_or(self.metric)
     }
+
+    // Returns the Administrative Tag Sub-TLV for the configured 32-bit tag.
+    pub(crate) fn admin_tag_stlv(&self) -> Option<AdminTagStlv> {
+        self.config
+            .tag
+            .map(|tag| AdminTagStlv::new(BTreeSet::from([tag])))
+    }
+
+    // Returns the 64-bit Administrative Tag Sub-TLV for the configured 64-bit
+    // tag.
+    pub(crate) fn admin_tag64_stlv(&self) -> Option<AdminTag64Stlv> {
+        self.config
+            .tag64
+            .map(|tag| AdminTag64Stlv::new(BTreeSet::from([tag])))
+    }
+}
+
+// ===== impl RouteSys =====
+
+impl PolicyRoute for RouteSys {
+    fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    fn metric_mut(&mut self) -> &mut u32 {
+        &mut self.metric
+    }
+
+    fn tag(&self) -> Option<u32> {
+        self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Option<u32> {
+        &mut self.tag
+    }
 }
 
 // ===== global functions =====
@@ -248,6 +320,79 @@ pub(crate) fn update_rib(
     *instance.state.rib_mut(instance.config.level_type) = new_rib;
 }
 
+// Updates the Flexible Algorithm RIB for the specified level.
+//
+// Flexible Algorithm routes are forwarded exclusively using their
+// algorithm-specific Prefix-SIDs, hence only MPLS label entries are installed.
+pub(crate) fn update_flex_algo_rib(
+    level: LevelNumber,
+    new_rib: BTreeMap<(u8, IpNetwork), Route>,
+    instance: &mut InstanceUpView<'_>,
+    interfaces: &Interfaces,
+) {
+    // Store the new local RIB for the current level.
+    *instance.state.flex_algo_rib_single.get_mut(level) = new_rib;
+
+    // Merge L1 and L2 local RIBs, preferring L1 routes.
+    let rib_l1 = instance.state.flex_algo_rib_single.get(LevelNumber::L1);
+    let rib_l2 = instance.state.flex_algo_rib_single.get(LevelNumber::L2);
+    let mut rib: BTreeMap<_, _> = rib_l2
+        .iter()
+        .chain(rib_l1.iter())
+        .map(|(key, route)| (*key, route.clone()))
+        .collect();
+    let mut old_rib = std::mem::take(&mut instance.state.flex_algo_rib_multi);
+
+    // Install new routes or routes that have changed.
+    for (key, route) in &mut rib {
+        let installable = !route.flags.contains(RouteFlags::CONNECTED)
+            && !route.nexthops.is_empty()
+            && route.sr_label.is_some();
+
+        if let Some(old_route) = old_rib.remove(key)
+            && old_route.flags.contains(RouteFlags::INSTALLED)
+        {
+            // Skip reinstalling the route if it hasn't changed.
+            if installable
+                && old_route.sr_label == route.sr_label
+                && old_route.nexthops == route.nexthops
+            {
+                route.flags.insert(RouteFlags::INSTALLED);
+                continue;
+            }
+
+            // Uninstall the previous input label unless it's going to be
+            // replaced.
+            if !installable || old_route.sr_label != route.sr_label {
+                ibus::tx::flex_algo_route_uninstall(
+                    &instance.tx.ibus,
+                    &old_route,
+                );
+            }
+        }
+
+        if installable {
+            ibus::tx::flex_algo_route_install(
+                &instance.tx.ibus,
+                route,
+                interfaces,
+            );
+            route.flags.insert(RouteFlags::INSTALLED);
+        }
+    }
+
+    // Uninstall routes that are no longer available.
+    for route in old_rib
+        .values()
+        .filter(|route| route.flags.contains(RouteFlags::INSTALLED))
+    {
+        ibus::tx::flex_algo_route_uninstall(&instance.tx.ibus, route);
+    }
+
+    // Store the new merged RIB.
+    instance.state.flex_algo_rib_multi = rib;
+}
+
 // ===== helper functions =====
 
 // Updates IS-IS routes in the global RIB.
@@ -271,6 +416,7 @@ fn update_global_rib(
             if old_route.metric == route.metric
                 && old_route.tag == route.tag
                 && old_route.nexthops == route.nexthops
+                && old_route.backup_nexthops == route.backup_nexthops
             {
                 if old_route.flags.contains(RouteFlags::INSTALLED) {
                     route.flags.insert(RouteFlags::INSTALLED);
@@ -25,6 +25,7 @@ use crate::adjacency::{Adjacency, AdjacencyState};
 use crate::collections::{Arena, InterfaceIndex, Interfaces, Lsdb};
 use crate::debug::Debug;
 use crate::error::Error;
+use crate::flex_algo::{self, FlexAlgoParams};
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::InterfaceType;
 use crate::lsdb::{LspEntry, LspLogId};
@@ -34,8 +35,9 @@ use crate::packet::pdu::Lsp;
 use crate::packet::subtlvs::prefix::{PrefixAttrFlags, PrefixSidStlv};
 use crate::packet::tlv::IpReachTlvEntry;
 use crate::packet::{LanId, LevelNumber, LevelType, LspId, SystemId};
-use crate::route::Route;
-use crate::{route, sr, tasks};
+use crate::route::{Route, RouteFlags};
+use crate::tilfa::{ProtectedResource, RepairPath};
+use crate::{gr, route, sr, tasks, tilfa};
 
 // Maximum size of the SPF log record.
 const SPF_LOG_MAX_SIZE: usize = 32;
@@ -75,6 +77,8 @@ pub struct Vertex {
     pub hops: u16,
     #[new(default)]
     pub nexthops: Vec<VertexNexthop>,
+    #[new(default)]
+    pub parent: Option<VertexId>,
 }
 
 // Represents a unique identifier for a vertex in the IS-IS topology graph.
@@ -109,6 +113,9 @@ pub struct VertexNexthop {
 pub struct VertexEdge {
     pub id: VertexId,
     pub cost: u32,
+    // IGP metric of the link, which might differ from the cost used by
+    // Flexible Algorithms.
+    pub igp_metric: u32,
 }
 
 // Represents an IP reachability entry attached to a vertex.
@@ -119,6 +126,27 @@ pub struct VertexNetwork {
     pub metric: u32,
     pub external: bool,
     pub prefix_sid: Option<PrefixSidStlv>,
+    pub tag: Option<u32>,
+}
+
+// Parameters of a shortest-path tree computation.
+//
+// The primary SPT is rooted at the local system and computed in the forward
+// direction without exclusions. The remaining combinations are used for
+// computing TI-LFA repair paths and Flexible Algorithm trees.
+#[derive(Debug)]
+#[derive(new)]
+pub struct SptParams {
+    // Root of the tree.
+    pub root: SystemId,
+    // Use reverse metrics, computing distances towards the root instead of
+    // from it.
+    pub reverse: bool,
+    // Resource removed from the topology.
+    pub excluded: Option<ProtectedResource>,
+    // Flexible Algorithm constraints used to prune the topology.
+    #[new(default)]
+    pub flex_algo: Option<FlexAlgoParams>,
 }
 
 // Container containing scheduling and timing information of SPF computations.
@@ -127,6 +155,11 @@ pub struct SpfScheduler {
     pub last_event_rcvd: Option<Instant>,
     pub last_time: Option<Instant>,
     pub spf_type: SpfType,
+    // Prefixes affected by the LSP changes that triggered a partial run.
+    // `None` means that all routes need to be recomputed.
+    pub prc_prefixes: Option<BTreeSet<IpNetwork>>,
+    pub full_runs: u32,
+    pub partial_runs: u32,
     pub delay_state: fsm::State,
     pub delay_timer: Option<TimeoutTask>,
     pub hold_down_timer: Option<TimeoutTask>,
@@ -235,6 +268,15 @@ pub(crate) fn fsm(
     // Update time of last SPF triggering event.
     spf_sched.last_event_rcvd = Some(Instant::now());
 
+    // Events other than LSP updates can affect any route, ruling out a partial
+    // route calculation.
+    if matches!(
+        event,
+        fsm::Event::AdjacencyChange | fsm::Event::ConfigChange
+    ) {
+        spf_sched.prc_prefixes = None;
+    }
+
     let new_fsm_state = match (spf_sched.delay_state, &event) {
         // Transition 1: IGP event while in QUIET state.
         (fsm::State::Quiet, fsm::Event::Igp) => {
@@ -419,6 +461,10 @@ pub(crate) fn fsm(
 // performed. A full run is necessary when topological changes are detected, and
 // involves recomputing the shortest-path tree (SPT). Otherwise, a partial run
 // is sufficient, and the SPT recalculation is skipped.
+//
+// When a partial run was triggered exclusively by changes to IP reachability
+// information, only the routes of the affected prefixes are recomputed
+// (Partial Route Calculation), while all other routes are preserved.
 fn compute_spf(
     level: LevelNumber,
     instance: &mut InstanceUpView<'_>,
@@ -426,6 +472,12 @@ fn compute_spf(
     adjacencies: &Arena<Adjacency>,
     lsp_entries: &Arena<LspEntry>,
 ) {
+    // Defer route computation until the graceful restart completes, keeping
+    // the preserved forwarding state in the meantime.
+    if gr::is_restarting(instance) {
+        return;
+    }
+
     let spf_sched = instance.state.spf_sched.get_mut(level);
 
     // Get time the SPF was scheduled.
@@ -439,6 +491,7 @@ fn compute_spf(
 
     // Log SPF computation start.
     let spf_type = std::mem::take(&mut spf_sched.spf_type);
+    let prc_prefixes = spf_sched.prc_prefixes.replace(Default::default());
     if instance.config.trace_opts.spf {
         Debug::SpfStart(spf_type).log();
     }
@@ -447,40 +500,135 @@ fn compute_spf(
     if spf_type == SpfType::Full {
         for mt_id in [MtId::Standard, MtId::Ipv6Unicast] {
             if instance.config.is_topology_enabled(mt_id) {
+                let system_id = instance.config.system_id.unwrap();
+                let params = SptParams::new(system_id, false, None);
                 let spt = compute_spt(
                     level,
                     mt_id,
+                    &params,
                     instance,
                     interfaces,
                     adjacencies,
                     lsp_entries,
                 );
                 *instance.state.spt.get_mut(mt_id).get_mut(level) = spt;
+
+                // Compute TI-LFA repair paths. These only depend on the
+                // topology, so they're reused in partial route calculations.
+                let repairs = tilfa::compute_repairs(
+                    level,
+                    mt_id,
+                    instance,
+                    interfaces,
+                    adjacencies,
+                    lsp_entries,
+                );
+                *instance.state.tilfa_repairs.get_mut(mt_id).get_mut(level) =
+                    repairs;
             }
         }
+
+        // Compute Flexible Algorithm shortest-path trees.
+        let spts = flex_algo::compute_spts(
+            level,
+            instance,
+            interfaces,
+            adjacencies,
+            lsp_entries,
+        );
+        *instance.state.flex_algo_spt.get_mut(level) = spts;
     }
 
+    // Get the prefixes whose routes need to be recomputed, if a partial route
+    // calculation is possible.
+    //
+    // Summary routes are always recomputed since they might override regular
+    // routes for the same prefixes.
+    let prc_prefixes = prc_prefixes
+        .filter(|_| spf_type == SpfType::RouteOnly)
+        .map(|mut prefixes| {
+            let rib = instance.state.rib_single.get(level);
+            prefixes.extend(
+                rib.iter()
+                    .filter(|(_, route)| {
+                        route.flags.contains(RouteFlags::SUMMARY)
+                    })
+                    .map(|(prefix, _)| *prefix),
+            );
+            prefixes
+        });
+
     // Compute the new RIB for the current level.
     //
     // Since multiple topologies per address family aren't currently supported,
     // a single RIB is sufficient as there's no risk of prefix overlap.
+    //
+    // In the case of a partial route calculation, the routes of the unaffected
+    // prefixes are carried over from the current RIB.
     let mut new_rib = BTreeMap::new();
+    if let Some(prefixes) = &prc_prefixes {
+        let rib = instance.state.rib_single.get(level);
+        new_rib.extend(
+            rib.iter()
+                .filter(|(prefix, _)| !prefixes.contains(prefix))
+                .map(|(prefix, route)| (*prefix, route.clone())),
+        );
+    }
     for mt_id in [MtId::Standard, MtId::Ipv6Unicast] {
         if instance.config.is_topology_enabled(mt_id) {
+            let spt = instance.state.spt.get(mt_id).get(level);
+            let repairs = instance.state.tilfa_repairs.get(mt_id).get(level);
             compute_routes(
                 level,
                 mt_id,
+                IgpAlgoType::Spf,
+                spt,
                 instance,
                 interfaces,
                 adjacencies,
                 lsp_entries,
+                repairs,
+                prc_prefixes.as_ref(),
                 &mut new_rib,
             );
         }
     }
 
+    // Compute the Flexible Algorithm routes for the current level. These
+    // routes are only used for MPLS forwarding.
+    let mut new_flex_algo_rib = BTreeMap::new();
+    if let Some(prefixes) = &prc_prefixes {
+        let rib = instance.state.flex_algo_rib_single.get(level);
+        new_flex_algo_rib.extend(
+            rib.iter()
+                .filter(|((_, prefix), _)| !prefixes.contains(prefix))
+                .map(|(key, route)| (*key, route.clone())),
+        );
+    }
+    for (algo, spt) in instance.state.flex_algo_spt.get(level) {
+        let mut rib = BTreeMap::new();
+        compute_routes(
+            level,
+            MtId::Standard,
+            IgpAlgoType::FlexAlgo(*algo),
+            spt,
+            instance,
+            interfaces,
+            adjacencies,
+            lsp_entries,
+            &Default::default(),
+            prc_prefixes.as_ref(),
+            &mut rib,
+        );
+        new_flex_algo_rib.extend(
+            rib.into_iter()
+                .map(|(prefix, route)| ((*algo, prefix), route)),
+        );
+    }
+
     // Update the local RIB and global RIB.
     route::update_rib(level, new_rib, instance, interfaces);
+    route::update_flex_algo_rib(level, new_flex_algo_rib, instance, interfaces);
 
     // If this is an L1 LSP in an L1/L2 router, schedule LSP reorigination at L2
     // to propagate updates. This happens only after SPF, as the SPT tree is
@@ -498,6 +646,10 @@ fn compute_spf(
     let end_time = Instant::now();
     let spf_sched = instance.state.spf_sched.get_mut(level);
     spf_sched.last_time = Some(end_time);
+    match spf_type {
+        SpfType::Full => spf_sched.full_runs += 1,
+        SpfType::RouteOnly => spf_sched.partial_runs += 1,
+    }
 
     // Log SPF completion and duration.
     if instance.config.trace_opts.spf {
@@ -523,22 +675,34 @@ fn compute_spf(
 // the candidate list (TENT) with the local adjacency database. However, in this
 // implementation, the local adjacency information is fetched directly from the
 // local LSP instead. This is done to ensure that the algorithm can be run with
-// any node as the root, which will be required later for implementing the
-// TI-LFA feature.
-fn compute_spt(
+// any node as the root, which is required for computing TI-LFA repair paths.
+//
+// Next-hops are only computed for trees rooted at the local system in the
+// forward direction.
+pub(crate) fn compute_spt(
     level: LevelNumber,
     mt_id: MtId,
+    params: &SptParams,
     instance: &InstanceUpView<'_>,
     interfaces: &Interfaces,
     adjacencies: &Arena<Adjacency>,
     lsp_entries: &Arena<LspEntry>,
 ) -> Spt {
     let lsdb = instance.state.lsdb.get(level);
-    let metric_type = instance.config.metric_type.get(level);
+    let flex_algo = params.flex_algo.as_ref();
+    // Flexible Algorithms only consider wide metrics.
+    let metric_type = match flex_algo {
+        Some(_) => MetricType::Wide,
+        None => instance.config.metric_type.get(level),
+    };
+    let local_system_id = instance.config.system_id.unwrap();
+    let local_root = params.root == local_system_id;
+    let primary = local_root && !params.reverse && params.excluded.is_none();
+    let trace = instance.config.trace_opts.spf && primary;
     let mut used_adjs = BTreeSet::new();
 
     // Get root vertex.
-    let root_lan_id = LanId::from((instance.config.system_id.unwrap(), 0));
+    let root_lan_id = LanId::from((params.root, 0));
     let root_vid = VertexId::new(root_lan_id);
     let root_v = Vertex::new(root_vid, 0, 0);
 
@@ -560,8 +724,11 @@ fn compute_spt(
             continue;
         };
 
-        // If the overload bit is set, we skip the links from it.
-        if !zeroth_lsp.lsp_id.is_pseudonode() && zeroth_lsp.overload_bit(mt_id)
+        // If the overload bit is set, we skip the links from it. Remote roots
+        // are exempt since the overload bit only prevents transit traffic.
+        if !zeroth_lsp.lsp_id.is_pseudonode()
+            && zeroth_lsp.overload_bit(mt_id)
+            && (local_root || vertex.id != root_vid)
         {
             continue;
         }
@@ -576,7 +743,7 @@ fn compute_spt(
             let Some(protocols_supported) =
                 &zeroth_lsp.tlvs.protocols_supported
             else {
-                if instance.config.trace_opts.spf {
+                if trace {
                     Debug::SpfMissingProtocolsTlv(vertex).log();
                 }
                 continue;
@@ -585,7 +752,7 @@ fn compute_spt(
                 if instance.config.is_af_enabled(af)
                     && !protocols_supported.contains(Nlpid::from(af))
                 {
-                    if instance.config.trace_opts.spf {
+                    if trace {
                         Debug::SpfUnsupportedProtocol(vertex, af).log();
                     }
                     continue 'spf_loop;
@@ -594,12 +761,48 @@ fn compute_spt(
         }
 
         // Iterate over all links described by the vertex's LSPs.
-        for link in
-            vertex_edges(&vertex.id, mt_id, metric_type, lsdb, lsp_entries)
-        {
+        for link in vertex_edges(
+            &vertex.id,
+            mt_id,
+            metric_type,
+            flex_algo,
+            lsdb,
+            lsp_entries,
+        ) {
             // Check if the LSPs are mutually linked.
-            if !vertex_edges(&link.id, mt_id, metric_type, lsdb, lsp_entries)
-                .any(|link| link.id == vertex.id)
+            let Some(reverse_cost) = vertex_edges(
+                &link.id,
+                mt_id,
+                metric_type,
+                flex_algo,
+                lsdb,
+                lsp_entries,
+            )
+            .filter(|rlink| rlink.id == vertex.id)
+            .map(|rlink| rlink.cost)
+            .min() else {
+                continue;
+            };
+
+            // Skip routers that don't participate in the Flexible Algorithm.
+            if let Some(flex_algo) = flex_algo
+                && !link.id.lan_id.is_pseudonode()
+                && !flex_algo.is_participating(
+                    link.id.lan_id.system_id,
+                    lsdb,
+                    lsp_entries,
+                )
+            {
+                continue;
+            }
+
+            // Skip links attached to the excluded resource.
+            if let Some(excluded) = &params.excluded
+                && excluded.contains_edge(
+                    local_system_id,
+                    vertex.id.lan_id,
+                    link.id.lan_id,
+                )
             {
                 continue;
             }
@@ -609,8 +812,14 @@ fn compute_spt(
                 continue;
             }
 
-            // Calculate distance to the link's vertex.
-            let distance = vertex.distance.saturating_add(link.cost);
+            // Calculate distance to the link's vertex. When computing a reverse
+            // SPT, the cost of the link in the opposite direction is used.
+            let cost = if params.reverse {
+                reverse_cost
+            } else {
+                link.cost
+            };
+            let distance = vertex.distance.saturating_add(cost);
 
             // Check maximum total metric value.
             let max_path_metric = match metric_type {
@@ -618,7 +827,7 @@ fn compute_spt(
                 MetricType::Standard => MAX_PATH_METRIC_STANDARD,
             };
             if distance > max_path_metric {
-                if instance.config.trace_opts.spf {
+                if trace {
                     Debug::SpfMaxPathMetric(vertex, &link, distance).log();
                 }
                 continue;
@@ -649,11 +858,17 @@ fn compute_spt(
                     }
                 }
             }
-            let cand_v = cand_list
-                .entry((distance, link.id))
-                .or_insert_with(|| Vertex::new(link.id, distance, hops));
+            let cand_v =
+                cand_list.entry((distance, link.id)).or_insert_with(|| {
+                    let mut cand_v = Vertex::new(link.id, distance, hops);
+                    cand_v.parent = Some(vertex.id);
+                    cand_v
+                });
 
             // Update vertex's nexthops.
+            if !local_root || params.reverse {
+                continue;
+            }
             if vertex.hops == 0 {
                 if !link.id.lan_id.is_pseudonode()
                     && let Some(nexthop) = compute_nexthop(
@@ -682,10 +897,14 @@ fn compute_spt(
 fn compute_routes(
     level: LevelNumber,
     mt_id: MtId,
+    algo: IgpAlgoType,
+    spt: &Spt,
     instance: &InstanceUpView<'_>,
     interfaces: &Interfaces,
     adjacencies: &Arena<Adjacency>,
     lsp_entries: &Arena<LspEntry>,
+    repairs: &BTreeMap<VertexId, Vec<RepairPath>>,
+    prefixes: Option<&BTreeSet<IpNetwork>>,
     rib: &mut BTreeMap<IpNetwork, Route>,
 ) {
     let lsdb = instance.state.lsdb.get(level);
@@ -703,7 +922,7 @@ fn compute_routes(
             }
             MtId::Ipv6Unicast => true,
         };
-    for vertex in instance.state.spt.get(mt_id).get(level).values() {
+    for vertex in spt.values() {
         // Skip if the zeroth LSP is missing.
         let Some(zeroth_lsp) = zeroth_lsp(vertex.id.lan_id, lsdb, lsp_entries)
         else {
@@ -721,9 +940,23 @@ fn compute_routes(
             metric_type,
             ipv4_enabled,
             ipv6_enabled,
+            algo,
             lsdb,
             lsp_entries,
         ) {
+            // Skip unaffected prefixes during partial route calculations.
+            if let Some(prefixes) = prefixes
+                && !prefixes.contains(&network.prefix)
+            {
+                continue;
+            }
+
+            // Flexible Algorithm routes require a Prefix-SID for the
+            // algorithm.
+            if algo != IgpAlgoType::Spf && network.prefix_sid.is_none() {
+                continue;
+            }
+
             let route = match rib.entry(network.prefix) {
                 btree_map::Entry::Vacant(v) => {
                     // If the route does not exist, create a new entry.
@@ -780,6 +1013,24 @@ fn compute_routes(
                     lsp_entries,
                 );
             }
+
+            // Update route's backup next-hops (TI-LFA), discarding the ones
+            // that became primary next-hops as a result of ECMP.
+            route
+                .backup_nexthops
+                .retain(|addr, _| !route.nexthops.contains_key(addr));
+            if let Some(repairs) = repairs.get(&vertex.id) {
+                tilfa::route_backups_update(
+                    instance,
+                    level,
+                    mt_id,
+                    vertex,
+                    &network,
+                    route,
+                    repairs,
+                    lsp_entries,
+                );
+            }
         }
     }
 }
@@ -816,7 +1067,7 @@ fn compute_nexthop(
                     .filter(|adj| adj.topologies.contains(&mt_id))
                     .filter(|adj| adj.state == AdjacencyState::Up),
                 InterfaceType::PointToPoint => {
-                    if iface.config.metric.get(level) != link.cost {
+                    if iface.config.metric.get(level) != link.igp_metric {
                         return None;
                     }
                     iface
@@ -847,6 +1098,7 @@ fn vertex_edges<'a>(
     vertex_id: &VertexId,
     mt_id: MtId,
     metric_type: MetricType,
+    flex_algo: Option<&'a FlexAlgoParams>,
     lsdb: &'a Lsdb,
     lsp_entries: &'a Arena<LspEntry>,
 ) -> impl Iterator<Item = VertexEdge> + 'a {
@@ -859,16 +1111,46 @@ fn vertex_edges<'a>(
             let mut standard_iter = None;
             let mut wide_iter = None;
             let mut mt_iter = None;
+            let mut flex_algo_iter = None;
+
+            if let Some(flex_algo) = flex_algo {
+                let pseudonode = lsp.lsp_id.is_pseudonode();
+                let iter = lsp
+                    .tlvs
+                    .ext_is_reach()
+                    .filter(|reach| reach.metric < MAX_PATH_METRIC_WIDE)
+                    .filter_map(move |reach| {
+                        let id = VertexId::new(reach.neighbor);
+                        // Links from pseudonodes don't carry TE attributes.
+                        if pseudonode {
+                            return Some(VertexEdge::new(
+                                id,
+                                reach.metric,
+                                reach.metric,
+                            ));
+                        }
 
-            if mt_id == MtId::Standard && metric_type.is_standard_enabled() {
+                        // Prune links that don't satisfy the algorithm's
+                        // constraints.
+                        let cost = flex_algo.link_cost(reach)?;
+                        Some(VertexEdge::new(id, cost, reach.metric))
+                    });
+                flex_algo_iter = Some(iter);
+            }
+            if mt_id == MtId::Standard
+                && metric_type.is_standard_enabled()
+                && flex_algo.is_none()
+            {
                 let iter = lsp.tlvs.is_reach().map(|reach| VertexEdge {
                     id: VertexId::new(reach.neighbor),
                     cost: reach.metric.into(),
+                    igp_metric: reach.metric.into(),
                 });
                 standard_iter = Some(iter);
             }
             if (mt_id == MtId::Standard || lsp.lsp_id.is_pseudonode())
                 && metric_type.is_wide_enabled()
+                && flex_algo.is_none()
             {
                 let iter = lsp
                     .tlvs
@@ -881,10 +1163,11 @@ fn vertex_edges<'a>(
                     .map(|reach| VertexEdge {
                         id: VertexId::new(reach.neighbor),
                         cost: reach.metric,
+                        igp_metric: reach.metric,
                     });
                 wide_iter = Some(iter);
             }
-            if mt_id != MtId::Standard {
+            if mt_id != MtId::Standard && flex_algo.is_none() {
                 let iter = lsp
                     .tlvs
                     .mt_is_reach_by_id(mt_id)
@@ -896,11 +1179,17 @@ fn vertex_edges<'a>(
                     .map(|reach| VertexEdge {
                         id: VertexId::new(reach.neighbor),
                         cost: reach.metric,
+                        igp_metric: reach.metric,
                     });
                 mt_iter = Some(iter);
             }
 
-            chain_option_iterators!(standard_iter, wide_iter, mt_iter)
+            chain_option_iterators!(
+                standard_iter,
+                wide_iter,
+                mt_iter,
+                flex_algo_iter
+            )
         })
 }
 
@@ -915,6 +1204,7 @@ fn vertex_networks<'a>(
     metric_type: MetricType,
     ipv4_enabled: bool,
     ipv6_enabled: bool,
+    algo: IgpAlgoType,
     lsdb: &'a Lsdb,
     lsp_entries: &'a Arena<LspEntry>,
 ) -> impl Iterator<Item = VertexNetwork> + 'a {
@@ -928,6 +1218,7 @@ fn vertex_networks<'a>(
             let mut ipv4_standard_iter = None;
             let mut ipv4_wide_iter = None;
             let mut ipv6_iter = None;
+            let mut srv6_locator_iter = None;
 
             // If the L1 LSP has the ATT bit set, add a default route if the
             // router is L1, or if the router is L1/L2 but not attached to the
@@ -943,6 +1234,7 @@ fn vertex_networks<'a>(
                         metric: 0,
                         external: false,
                         prefix_sid: None,
+                        tag: None,
                     });
                 }
                 if ipv6_enabled {
@@ -951,6 +1243,7 @@ fn vertex_networks<'a>(
                         metric: 0,
                         external: false,
                         prefix_sid: None,
+                        tag: None,
                     });
                 }
                 inter_area_defaults_iter =
@@ -967,6 +1260,7 @@ fn vertex_networks<'a>(
                                 metric: reach.metric(),
                                 external: false,
                                 prefix_sid: None,
+                                tag: None,
                             }
                         });
                     // NOTE: RFC 1195 initially restricted the IP External
@@ -979,6 +1273,7 @@ fn vertex_networks<'a>(
                                 metric: reach.metric(),
                                 external: true,
                                 prefix_sid: None,
+                                tag: None,
                             }
                         });
                     ipv4_standard_iter = Some(internal.chain(external));
@@ -992,7 +1287,7 @@ fn vertex_networks<'a>(
                         // MAX_PATH_METRIC this prefix MUST NOT be considered
                         // during the normal SPF computation".
                         .filter(|reach| reach.metric <= MAX_PATH_METRIC_WIDE)
-                        .map(|reach| {
+                        .map(move |reach| {
                             VertexNetwork {
                                 prefix: reach.prefix.into(),
                                 metric: reach.metric,
@@ -1008,8 +1303,9 @@ fn vertex_networks<'a>(
                                 prefix_sid: reach
                                     .sub_tlvs
                                     .prefix_sids
-                                    .get(&IgpAlgoType::Spf)
+                                    .get(&algo)
                                     .cloned(),
+                                tag: reach.sub_tlvs.admin_tag(),
                             }
                         });
                     ipv4_wide_iter = Some(iter);
@@ -1025,24 +1321,45 @@ fn vertex_networks<'a>(
                 } else {
                     Box::new(lsp.tlvs.ipv6_reach())
                 };
-                let iter = iter.map(|reach| VertexNetwork {
+                let iter = iter.map(move |reach| VertexNetwork {
                     prefix: reach.prefix.into(),
                     metric: reach.metric,
                     external: reach.external,
-                    prefix_sid: reach
-                        .sub_tlvs
-                        .prefix_sids
-                        .get(&IgpAlgoType::Spf)
-                        .cloned(),
+                    prefix_sid: reach.sub_tlvs.prefix_sids.get(&algo).cloned(),
+                    tag: reach.sub_tlvs.admin_tag(),
                 });
                 ipv6_iter = Some(iter);
             }
 
+            // Iterate over SRv6 locator entries.
+            //
+            // Locators are normally also advertised in the IPv6 reachability
+            // TLVs, but that isn't mandatory, so they're processed here as
+            // well. Duplicate entries are merged during route computation.
+            if ipv6_enabled && algo == IgpAlgoType::Spf {
+                let iter = lsp
+                    .tlvs
+                    .srv6_locators()
+                    .filter(move |(locator_mt_id, _)| {
+                        *locator_mt_id == mt_id as u16
+                    })
+                    .filter(|(_, locator)| locator.algo == IgpAlgoType::Spf)
+                    .map(|(_, locator)| VertexNetwork {
+                        prefix: locator.locator.into(),
+                        metric: locator.metric,
+                        external: false,
+                        prefix_sid: None,
+                        tag: None,
+                    });
+                srv6_locator_iter = Some(iter);
+            }
+
             chain_option_iterators!(
                 inter_area_defaults_iter,
                 ipv4_standard_iter,
                 ipv4_wide_iter,
                 ipv6_iter,
+                srv6_locator_iter,
             )
         })
 }
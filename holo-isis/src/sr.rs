@@ -21,10 +21,13 @@ use crate::instance::InstanceUpView;
 use crate::interface::{Interface, InterfaceType};
 use crate::lsdb::LspEntry;
 use crate::northbound::notification;
+use crate::packet::consts::MtId;
 use crate::packet::subtlvs::capability::{
     LabelBlockEntry, SrCapabilitiesFlags,
 };
+use crate::packet::subtlvs::neighbor::AdjSidFlags;
 use crate::packet::subtlvs::prefix::{PrefixSidFlags, PrefixSidStlv};
+use crate::packet::tlv::IsReach;
 use crate::packet::{LanId, LevelNumber, SystemId};
 use crate::route::Route;
 
@@ -55,7 +58,7 @@ pub(crate) fn prefix_sid_update(
         .filter(|lsp| lsp.rem_lifetime != 0)
         .filter(|lsp| lsp.seqno != 0)
         .filter_map(|lsp| lsp.tlvs.sr_algos())
-        .any(|sr_algos| sr_algos.get().contains(&IgpAlgoType::Spf))
+        .any(|sr_algos| sr_algos.get().contains(&prefix_sid.algo))
     {
         return;
     }
@@ -93,6 +96,117 @@ pub(crate) fn prefix_sid_update(
     }
 }
 
+// Resolves the Node-SID of the given router to the MPLS label expected by the
+// specified next-hop router.
+pub(crate) fn node_sid_label(
+    instance: &InstanceUpView<'_>,
+    level: LevelNumber,
+    mt_id: MtId,
+    af: AddressFamily,
+    system_id: SystemId,
+    nexthop_system_id: SystemId,
+    lsp_entries: &Arena<LspEntry>,
+) -> Option<Label> {
+    // Find the first Prefix-SID advertised with the N-Flag set.
+    let lsdb = instance.state.lsdb.get(level);
+    let prefix_sid = lsdb
+        .iter_for_system_id(lsp_entries, system_id)
+        .map(|lse| &lse.data)
+        .filter(|lsp| lsp.rem_lifetime != 0)
+        .filter(|lsp| lsp.seqno != 0)
+        .find_map(|lsp| {
+            let mut prefix_sids: Box<dyn Iterator<Item = &PrefixSidStlv>> =
+                match (af, mt_id) {
+                    (AddressFamily::Ipv4, _) => Box::new(
+                        lsp.tlvs.ext_ipv4_reach().filter_map(|reach| {
+                            reach.sub_tlvs.prefix_sids.get(&IgpAlgoType::Spf)
+                        }),
+                    ),
+                    (AddressFamily::Ipv6, MtId::Standard) => {
+                        Box::new(lsp.tlvs.ipv6_reach().filter_map(|reach| {
+                            reach.sub_tlvs.prefix_sids.get(&IgpAlgoType::Spf)
+                        }))
+                    }
+                    (AddressFamily::Ipv6, MtId::Ipv6Unicast) => Box::new(
+                        lsp.tlvs.mt_ipv6_reach_by_id(mt_id).filter_map(
+                            |reach| {
+                                reach
+                                    .sub_tlvs
+                                    .prefix_sids
+                                    .get(&IgpAlgoType::Spf)
+                            },
+                        ),
+                    ),
+                };
+            prefix_sids
+                .find(|prefix_sid| prefix_sid.flags.contains(PrefixSidFlags::N))
+                .copied()
+        })?;
+
+    prefix_sid_output_label(
+        instance,
+        level,
+        af,
+        &prefix_sid,
+        nexthop_system_id,
+        false,
+        lsp_entries,
+    )
+    .ok()
+    .filter(|label| !label.is_implicit_null())
+}
+
+// Returns the Adjacency-SID label advertised by the given router for its
+// adjacency with the specified neighbor.
+pub(crate) fn adj_sid_label(
+    instance: &InstanceUpView<'_>,
+    level: LevelNumber,
+    mt_id: MtId,
+    af: AddressFamily,
+    system_id: SystemId,
+    nbr_system_id: SystemId,
+    lsp_entries: &Arena<LspEntry>,
+) -> Option<Label> {
+    let lsdb = instance.state.lsdb.get(level);
+    lsdb.iter_for_system_id(lsp_entries, system_id)
+        .map(|lse| &lse.data)
+        .filter(|lsp| lsp.rem_lifetime != 0)
+        .filter(|lsp| lsp.seqno != 0)
+        .find_map(|lsp| {
+            let mut reach_list: Box<dyn Iterator<Item = &IsReach>> = match mt_id
+            {
+                MtId::Standard => Box::new(lsp.tlvs.ext_is_reach()),
+                MtId::Ipv6Unicast => {
+                    Box::new(lsp.tlvs.mt_is_reach_by_id(mt_id))
+                }
+            };
+            reach_list.find_map(|reach| {
+                reach
+                    .sub_tlvs
+                    .adj_sids
+                    .iter()
+                    // LAN Adjacency-SIDs carry the neighbor's System ID.
+                    .filter(|adj_sid| match adj_sid.nbr_system_id {
+                        Some(adj_nbr_system_id) => {
+                            reach.neighbor.is_pseudonode()
+                                && adj_nbr_system_id == nbr_system_id
+                        }
+                        None => {
+                            reach.neighbor == LanId::from((nbr_system_id, 0))
+                        }
+                    })
+                    .filter(|adj_sid| {
+                        adj_sid.flags.contains(AdjSidFlags::F)
+                            == (af == AddressFamily::Ipv6)
+                    })
+                    .find_map(|adj_sid| match adj_sid.sid {
+                        Sid::Label(label) => Some(label),
+                        Sid::Index(_) => None,
+                    })
+            })
+        })
+}
+
 // Adds SR Adjacency SIDs to the given adjacency.
 pub(crate) fn adj_sids_add(
     instance: &InstanceUpView<'_>,
@@ -193,7 +307,7 @@ fn prefix_sid_input_label(
 }
 
 // Resolves Prefix-SID to MPLS output label.
-fn prefix_sid_output_label(
+pub(crate) fn prefix_sid_output_label(
     instance: &InstanceUpView<'_>,
     level: LevelNumber,
     af: AddressFamily,
@@ -0,0 +1,145 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use holo_utils::ip::AddressFamily;
+use holo_utils::sr::{Srv6EndpointBehavior, Srv6LocatorCfg};
+
+use crate::adjacency::{Adjacency, AdjacencyState, Srv6EndXSid};
+use crate::ibus;
+use crate::instance::{InstanceArenas, InstanceUpView};
+use crate::interface::{Interface, InterfaceType};
+use crate::packet::subtlvs::Srv6SidStructureStlv;
+
+// Function value of the End SID.
+pub const END_SID_FUNC: u32 = 1;
+// First function value available for End.X SIDs.
+pub const END_X_SID_FUNC_FIRST: u32 = 2;
+
+// ===== global functions =====
+
+// Returns the SRv6 locator used by the instance, if SRv6 is enabled and the
+// configured locator exists.
+pub(crate) fn locator(instance: &InstanceUpView<'_>) -> Option<Srv6LocatorCfg> {
+    if !instance.config.srv6.enabled {
+        return None;
+    }
+    let name = instance.config.srv6.locator.as_ref()?;
+    instance.shared.sr_config.srv6_locators.get(name).cloned()
+}
+
+// Returns the SID Structure Sub-Sub-TLV corresponding to the given locator.
+pub(crate) fn sid_structure(locator: &Srv6LocatorCfg) -> Srv6SidStructureStlv {
+    Srv6SidStructureStlv::new(
+        locator.block_len,
+        locator.node_len,
+        locator.func_len,
+        locator.arg_len,
+    )
+}
+
+// Updates all SRv6 SIDs of the instance following a configuration change.
+pub(crate) fn sids_update(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+) {
+    // Update the End SID.
+    end_sid_update(instance);
+
+    // Recreate the End.X SIDs of all existing adjacencies.
+    for iface in arenas.interfaces.iter_mut() {
+        iface.with_adjacencies(&mut arenas.adjacencies, |iface, adj| {
+            end_x_sid_del(instance, adj);
+            if adj.state == AdjacencyState::Up {
+                end_x_sid_add(instance, iface, adj);
+            }
+        });
+    }
+}
+
+// Installs or uninstalls the End SID according to the current configuration.
+pub(crate) fn end_sid_update(instance: &mut InstanceUpView<'_>) {
+    let sid = locator(instance).map(|locator| locator.sid(END_SID_FUNC));
+    if instance.state.srv6_end_sid == sid {
+        return;
+    }
+
+    // Uninstall the previous End SID.
+    if let Some(old_sid) = instance.state.srv6_end_sid.take() {
+        ibus::tx::srv6_local_sid_uninstall(&instance.tx.ibus, old_sid);
+    }
+
+    // Install the new End SID.
+    if let Some(sid) = sid {
+        ibus::tx::srv6_local_sid_install(
+            &instance.tx.ibus,
+            sid,
+            Srv6EndpointBehavior::End,
+            None,
+        );
+        instance.state.srv6_end_sid = Some(sid);
+    }
+}
+
+// Adds an SRv6 End.X SID to the given adjacency.
+pub(crate) fn end_x_sid_add(
+    instance: &mut InstanceUpView<'_>,
+    iface: &Interface,
+    adj: &mut Adjacency,
+) {
+    let Some(locator) = locator(instance) else {
+        return;
+    };
+
+    // End.X SIDs require IPv6 to be enabled on the interface and a known
+    // neighbor address.
+    if !iface
+        .config
+        .is_af_enabled(AddressFamily::Ipv6, instance.config)
+    {
+        return;
+    }
+    let Some(nbr_addr) = adj.ipv6_addrs.first().copied() else {
+        return;
+    };
+
+    // Allocate a function value that isn't in use.
+    let func_max = u32::MAX >> (32 - u32::from(locator.func_len));
+    let Some(func) = (END_X_SID_FUNC_FIRST..=func_max)
+        .find(|func| !instance.state.srv6_end_x_funcs.contains(func))
+    else {
+        return;
+    };
+    instance.state.srv6_end_x_funcs.insert(func);
+
+    // Include neighbor System ID if the interface is broadcast.
+    let nbr_system_id = (iface.config.interface_type
+        == InterfaceType::Broadcast)
+        .then_some(adj.system_id);
+
+    // Create and install the End.X SID.
+    let sid = locator.sid(func);
+    let end_x_sid =
+        Srv6EndXSid::new(func, sid, sid_structure(&locator), nbr_system_id);
+    adj.srv6_end_x_sid = Some(end_x_sid);
+    ibus::tx::srv6_local_sid_install(
+        &instance.tx.ibus,
+        sid,
+        Srv6EndpointBehavior::EndX,
+        Some((iface.system.ifindex.unwrap(), nbr_addr)),
+    );
+}
+
+// Deletes the SRv6 End.X SID from the given adjacency.
+pub(crate) fn end_x_sid_del(
+    instance: &mut InstanceUpView<'_>,
+    adj: &mut Adjacency,
+) {
+    if let Some(end_x_sid) = adj.srv6_end_x_sid.take() {
+        // Release and uninstall the End.X SID.
+        instance.state.srv6_end_x_funcs.remove(&end_x_sid.func);
+        ibus::tx::srv6_local_sid_uninstall(&instance.tx.ibus, end_x_sid.sid);
+    }
+}
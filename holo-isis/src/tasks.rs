@@ -21,12 +21,11 @@ use crate::collections::LspEntryId;
 use crate::debug::LspPurgeReason;
 use crate::instance::InstanceUpView;
 use crate::interface::{Interface, InterfaceType};
-use crate::network::MulticastAddr;
 use crate::northbound::configuration::TraceOptionPacketResolved;
 use crate::packet::auth::AuthMethod;
 use crate::packet::pdu::{Hello, Lsp, Pdu};
 use crate::packet::{LevelNumber, LevelType, Levels};
-use crate::{lsdb, network, spf};
+use crate::{gr, lsdb, network, spf};
 
 //
 // IS-IS tasks diagram:
@@ -43,12 +42,16 @@ use crate::{lsdb, network, spf};
 //        dis_initial_election (Nx) -> |              |
 //               psnp_interval (Nx) -> |              |
 //               csnp_interval (Nx) -> |   instance   |
+//         lsp_pacing_interval (Nx) -> |              |
 //         lsp_originate_timer (Nx) -> |              | -> (Nx) lsp_rxmt_interval
 //            lsp_expiry_timer (Nx) -> |              |
 //            lsp_delete_timer (Nx) -> |              |
 //           lsp_refresh_timer (Nx) -> |              |
 //             spf_delay_timer (Nx) -> |              |
-//                                     |              |
+//     ldp_sync_holddown_timer (Nx) -> |              |
+//                 gr_t1_timer (Nx) -> |              |
+//                 gr_t2_timer (Nx) -> |              |
+//                 gr_t3_timer (1x) -> |              |
 //                                     +--------------+
 //                              ibus_tx (1x) | ^ (1x) ibus_rx
 //                                           | |
@@ -88,11 +91,14 @@ pub mod messages {
             DisElection(DisElectionMsg),
             SendPsnp(SendPsnpMsg),
             SendCsnp(SendCsnpMsg),
+            LspPacing(LspPacingMsg),
             LspOriginate(LspOriginateMsg),
             LspPurge(LspPurgeMsg),
             LspDelete(LspDeleteMsg),
             LspRefresh(LspRefreshMsg),
             SpfDelayEvent(SpfDelayEventMsg),
+            LdpSyncHolddown(LdpSyncHolddownMsg),
+            GrTimer(GrTimerMsg),
         }
 
         #[derive(Debug)]
@@ -140,6 +146,12 @@ pub mod messages {
             pub level: LevelNumber,
         }
 
+        #[derive(Debug)]
+        #[derive(Deserialize, Serialize)]
+        pub struct LspPacingMsg {
+            pub iface_key: InterfaceKey,
+        }
+
         #[derive(Debug)]
         #[derive(Deserialize, Serialize)]
         pub struct LspOriginateMsg {}
@@ -171,6 +183,20 @@ pub mod messages {
             pub level: LevelNumber,
             pub event: spf::fsm::Event,
         }
+
+        #[derive(Debug)]
+        #[derive(Deserialize, Serialize)]
+        pub struct LdpSyncHolddownMsg {
+            pub iface_key: InterfaceKey,
+        }
+
+        #[derive(Debug)]
+        #[derive(Deserialize, Serialize)]
+        pub enum GrTimerMsg {
+            T1 { iface_key: InterfaceKey },
+            T2 { level: LevelNumber },
+            T3,
+        }
     }
 
     // Output messages (main task -> child task).
@@ -474,27 +500,81 @@ pub(crate) fn csnp_interval(
     }
 }
 
+// Transmit paced IS-IS LSPs.
+pub(crate) fn lsp_pacing_interval(
+    iface: &Interface,
+    instance: &InstanceUpView<'_>,
+) -> IntervalTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let interval = iface.config.lsp_pacing_interval;
+        let iface_id = iface.id;
+        let lsp_pacingp = instance.tx.protocol_input.lsp_pacing.clone();
+        IntervalTask::new(
+            Duration::from_millis(interval.into()),
+            false,
+            move || {
+                let lsp_pacingp = lsp_pacingp.clone();
+
+                async move {
+                    let msg = messages::input::LspPacingMsg {
+                        iface_key: iface_id.into(),
+                    };
+                    let _ = lsp_pacingp.send(msg);
+                }
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        IntervalTask {}
+    }
+}
+
 // Send periodic IS-IS LSP retransmissions.
+//
+// The retransmission interval doubles after each unacknowledged
+// retransmission, up to the configured maximum.
 pub(crate) fn lsp_rxmt_interval(
     iface: &Interface,
+    level: LevelNumber,
     lsp: Lsp,
-    dst: MulticastAddr,
-    interval: u16,
 ) -> IntervalTask {
     #[cfg(not(feature = "testing"))]
     {
+        let interval = iface.config.lsp_rxmt_interval.max(1);
+        let max_backoff =
+            (iface.config.lsp_rxmt_max_interval / interval).max(1);
+        let dst = iface.config.interface_type.multicast_addr(level);
+        let lsp_rxmt_counter =
+            iface.state.packet_counters.get(level).lsp_rxmt.clone();
         let pdu = Pdu::Lsp(lsp);
         let net_tx_pdup = iface.state.net.as_ref().unwrap().net_tx_pdup.clone();
+        let mut backoff = 1;
+        let mut skip = 0;
         IntervalTask::new(
             Duration::from_secs(interval.into()),
             false,
             move || {
-                let pdu = pdu.clone();
+                // Skip this tick if the back-off period hasn't elapsed yet.
+                let msg = if skip > 0 {
+                    skip -= 1;
+                    None
+                } else {
+                    lsp_rxmt_counter.fetch_add(1, atomic::Ordering::Relaxed);
+                    backoff = std::cmp::min(backoff * 2, max_backoff);
+                    skip = backoff - 1;
+                    Some(messages::output::NetTxPduMsg {
+                        pdu: pdu.clone(),
+                        dst,
+                    })
+                };
                 let net_tx_pdup = net_tx_pdup.clone();
 
                 async move {
-                    let msg = messages::output::NetTxPduMsg { pdu, dst };
-                    let _ = net_tx_pdup.send(msg);
+                    if let Some(msg) = msg {
+                        let _ = net_tx_pdup.send(msg);
+                    }
                 }
             },
         )
@@ -626,3 +706,95 @@ pub(crate) fn spf_delay_timer(
         TimeoutTask {}
     }
 }
+
+// LDP-IGP synchronization hold-down timer task.
+pub(crate) fn ldp_sync_holddown_timer(
+    iface: &Interface,
+    instance: &InstanceUpView<'_>,
+    holddown: u16,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(holddown.into());
+        let iface_id = iface.id;
+        let ldp_sync_holddownp =
+            instance.tx.protocol_input.ldp_sync_holddown.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::LdpSyncHolddownMsg {
+                iface_key: iface_id.into(),
+            };
+            let _ = ldp_sync_holddownp.send(msg).await;
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Graceful restart T1 timer task.
+pub(crate) fn gr_t1_timer(
+    iface: &Interface,
+    instance: &InstanceUpView<'_>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(gr::T1_TIMEOUT);
+        let iface_id = iface.id;
+        let gr_timerp = instance.tx.protocol_input.gr_timer.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::GrTimerMsg::T1 {
+                iface_key: iface_id.into(),
+            };
+            let _ = gr_timerp.send(msg);
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Graceful restart T2 timer task.
+pub(crate) fn gr_t2_timer(
+    level: LevelNumber,
+    instance: &InstanceUpView<'_>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(gr::T2_TIMEOUT);
+        let gr_timerp = instance.tx.protocol_input.gr_timer.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::GrTimerMsg::T2 { level };
+            let _ = gr_timerp.send(msg);
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Graceful restart T3 timer task.
+pub(crate) fn gr_t3_timer(
+    timeout: u16,
+    instance: &InstanceUpView<'_>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(timeout.into());
+        let gr_timerp = instance.tx.protocol_input.gr_timer.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::GrTimerMsg::T3;
+            let _ = gr_timerp.send(msg);
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
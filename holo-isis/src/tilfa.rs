@@ -0,0 +1,499 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
+use holo_utils::mpls::Label;
+
+use crate::adjacency::Adjacency;
+use crate::collections::{Arena, InterfaceIndex, Interfaces};
+use crate::instance::InstanceUpView;
+use crate::interface::InterfaceType;
+use crate::lsdb::LspEntry;
+use crate::packet::consts::MtId;
+use crate::packet::{LanId, LevelNumber, SystemId};
+use crate::route::{BackupNexthop, Route};
+use crate::spf::{
+    self, Spt, SptParams, Vertex, VertexId, VertexNetwork, VertexNexthop,
+};
+use crate::sr;
+
+// Resource protected by a repair path.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ProtectedResource {
+    // Link connecting the local system to a neighbor or to a pseudonode.
+    Link(LanId),
+    // Neighbor router.
+    Node(SystemId),
+}
+
+// TI-LFA repair path towards a destination vertex.
+#[derive(Clone, Debug)]
+pub struct RepairPath {
+    // Resource protected by this repair path.
+    pub protection: ProtectedResource,
+    // Backup next-hop.
+    pub nexthop: VertexNexthop,
+    // Segments used to steer traffic to the Q-node.
+    pub segments: Vec<RepairSegment>,
+    // Router that forwards traffic to the destination over its own shortest
+    // path.
+    pub q_node: SystemId,
+    // Post-convergence distance to the destination.
+    pub distance: u32,
+}
+
+// Segment of a repair path.
+#[derive(Clone, Copy, Debug)]
+pub enum RepairSegment {
+    // Node segment towards the P-node.
+    Node(SystemId),
+    // Adjacency segment from the P-node to the Q-node.
+    Adjacency(SystemId, SystemId),
+}
+
+// Shortest-path trees used to evaluate a protected resource.
+struct ResourceSpts {
+    // Post-convergence SPT rooted at the local system.
+    post: Spt,
+    // SPT rooted at the protected node (node protection only).
+    node: Option<Spt>,
+    // Reverse SPT rooted at the local system (link protection) or at the
+    // protected node (node protection).
+    reverse: Spt,
+}
+
+// ===== impl ProtectedResource =====
+
+impl ProtectedResource {
+    // Checks whether the edge connecting the given vertices is attached to the
+    // protected resource.
+    pub(crate) fn contains_edge(
+        &self,
+        local_system_id: SystemId,
+        a: LanId,
+        b: LanId,
+    ) -> bool {
+        match self {
+            ProtectedResource::Link(remote) => {
+                let local = LanId::from((local_system_id, 0));
+                (a == local && b == *remote) || (a == *remote && b == local)
+            }
+            ProtectedResource::Node(system_id) => {
+                let node = LanId::from((*system_id, 0));
+                a == node || b == node
+            }
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Computes TI-LFA repair paths for the destinations of the primary SPT.
+//
+// A repair path is computed for each primary next-hop that goes through a
+// TI-LFA enabled interface. The repair path follows the post-convergence path,
+// which is the path the destination will be reached over once the network
+// converges following the failure of the protected resource. When node
+// protection is enabled, node-protecting repair paths are preferred, falling
+// back to link protection when none can be found.
+//
+// The resulting backup next-hops are installed alongside the primary ones,
+// taking over as soon as the primary next-hops become unusable.
+pub(crate) fn compute_repairs(
+    level: LevelNumber,
+    mt_id: MtId,
+    instance: &InstanceUpView<'_>,
+    interfaces: &Interfaces,
+    adjacencies: &Arena<Adjacency>,
+    lsp_entries: &Arena<LspEntry>,
+) -> BTreeMap<VertexId, Vec<RepairPath>> {
+    let system_id = instance.config.system_id.unwrap();
+    let spt = instance.state.spt.get(mt_id).get(level);
+    let mut repairs: BTreeMap<VertexId, Vec<RepairPath>> = BTreeMap::new();
+
+    // Group destinations by the protected primary next-hops.
+    let mut groups: BTreeMap<(InterfaceIndex, SystemId), Vec<VertexId>> =
+        BTreeMap::new();
+    for vertex in spt
+        .values()
+        .filter(|vertex| vertex.hops > 0)
+        .filter(|vertex| !vertex.id.lan_id.is_pseudonode())
+    {
+        for nexthop in vertex.nexthops.iter().filter(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            iface.config.is_ti_lfa_enabled(level)
+        }) {
+            groups
+                .entry((nexthop.iface_idx, nexthop.system_id))
+                .or_default()
+                .push(vertex.id);
+        }
+    }
+
+    // Reverse SPTs rooted at the destinations, shared by all protected
+    // resources.
+    let mut dest_rspts = BTreeMap::new();
+
+    for ((iface_idx, nbr_system_id), dests) in groups {
+        let iface = &interfaces[iface_idx];
+
+        // Get the link used by the primary next-hop.
+        let link_id = match iface.config.interface_type {
+            InterfaceType::Broadcast => {
+                let Some(dis) = iface.state.dis.get(level) else {
+                    continue;
+                };
+                dis.lan_id
+            }
+            InterfaceType::PointToPoint => LanId::from((nbr_system_id, 0)),
+        };
+
+        // Get the resources to protect, in order of preference.
+        let mut resources = vec![];
+        if iface.config.ti_lfa_node_protection {
+            resources.push(ProtectedResource::Node(nbr_system_id));
+        }
+        resources.push(ProtectedResource::Link(link_id));
+        let resources = resources
+            .into_iter()
+            .map(|resource| {
+                let spts = resource_spts(
+                    level,
+                    mt_id,
+                    resource,
+                    instance,
+                    interfaces,
+                    adjacencies,
+                    lsp_entries,
+                );
+                (resource, spts)
+            })
+            .collect::<Vec<_>>();
+
+        for dest in dests {
+            let dest_rspt = dest_rspts.entry(dest).or_insert_with(|| {
+                let params = SptParams::new(dest.lan_id.system_id, true, None);
+                spf::compute_spt(
+                    level,
+                    mt_id,
+                    &params,
+                    instance,
+                    interfaces,
+                    adjacencies,
+                    lsp_entries,
+                )
+            });
+
+            for (resource, spts) in &resources {
+                // A node can't be protected when it's the destination itself.
+                if let ProtectedResource::Node(node) = resource
+                    && *node == dest.lan_id.system_id
+                {
+                    continue;
+                }
+
+                if let Some(repair) = compute_repair(
+                    level, system_id, dest, *resource, spt, spts, dest_rspt,
+                    interfaces,
+                ) {
+                    repairs.entry(dest).or_default().push(repair);
+                    break;
+                }
+            }
+        }
+    }
+
+    repairs
+}
+
+// Updates the backup next-hops of the provided route using the repair paths
+// computed for the advertising vertex.
+pub(crate) fn route_backups_update(
+    instance: &InstanceUpView<'_>,
+    level: LevelNumber,
+    mt_id: MtId,
+    vertex: &Vertex,
+    network: &VertexNetwork,
+    route: &mut Route,
+    repairs: &[RepairPath],
+    lsp_entries: &Arena<LspEntry>,
+) {
+    let af = network.prefix.address_family();
+    let dest = vertex.id.lan_id.system_id;
+
+    for repair in repairs {
+        let addr = match af {
+            AddressFamily::Ipv4 => repair.nexthop.ipv4.map(IpAddr::V4),
+            AddressFamily::Ipv6 => repair.nexthop.ipv6.map(IpAddr::V6),
+        };
+        let Some(addr) = addr else {
+            continue;
+        };
+        if route.nexthops.contains_key(&addr)
+            || route.backup_nexthops.contains_key(&addr)
+        {
+            continue;
+        }
+
+        // Repair paths that require segments can only be used when SR is
+        // enabled.
+        if !repair.segments.is_empty() && !instance.config.sr.enabled {
+            continue;
+        }
+
+        // Resolve the repair segments to MPLS labels.
+        let Some(mut labels) = repair
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                RepairSegment::Node(p_node) => sr::node_sid_label(
+                    instance,
+                    level,
+                    mt_id,
+                    af,
+                    *p_node,
+                    repair.nexthop.system_id,
+                    lsp_entries,
+                ),
+                RepairSegment::Adjacency(p_node, q_node) => sr::adj_sid_label(
+                    instance,
+                    level,
+                    mt_id,
+                    af,
+                    *p_node,
+                    *q_node,
+                    lsp_entries,
+                ),
+            })
+            .collect::<Option<Vec<Label>>>()
+        else {
+            continue;
+        };
+
+        // Append the destination's Prefix-SID label, as expected by the
+        // Q-node.
+        if let Some(prefix_sid) = &route.prefix_sid
+            && route
+                .nexthops
+                .values()
+                .any(|nexthop| nexthop.sr_label.is_some())
+        {
+            let last_hop = repair.q_node == dest;
+            match sr::prefix_sid_output_label(
+                instance,
+                level,
+                af,
+                prefix_sid,
+                repair.q_node,
+                last_hop,
+                lsp_entries,
+            ) {
+                Ok(label) => labels.push(label),
+                Err(error) => {
+                    error.log();
+                }
+            }
+        }
+
+        route.backup_nexthops.insert(
+            addr,
+            BackupNexthop {
+                system_id: repair.nexthop.system_id,
+                iface_idx: repair.nexthop.iface_idx,
+                addr,
+                labels,
+                protection: repair.protection,
+                metric: repair.distance.saturating_add(network.metric),
+            },
+        );
+    }
+}
+
+// ===== helper functions =====
+
+// Computes the shortest-path trees required to evaluate the given protected
+// resource.
+fn resource_spts(
+    level: LevelNumber,
+    mt_id: MtId,
+    resource: ProtectedResource,
+    instance: &InstanceUpView<'_>,
+    interfaces: &Interfaces,
+    adjacencies: &Arena<Adjacency>,
+    lsp_entries: &Arena<LspEntry>,
+) -> ResourceSpts {
+    let system_id = instance.config.system_id.unwrap();
+    let compute_spt = |root, reverse, excluded| {
+        let params = SptParams::new(root, reverse, excluded);
+        spf::compute_spt(
+            level,
+            mt_id,
+            &params,
+            instance,
+            interfaces,
+            adjacencies,
+            lsp_entries,
+        )
+    };
+
+    let post = compute_spt(system_id, false, Some(resource));
+    match resource {
+        ProtectedResource::Link(_) => ResourceSpts {
+            post,
+            node: None,
+            reverse: compute_spt(system_id, true, None),
+        },
+        ProtectedResource::Node(node) => ResourceSpts {
+            post,
+            node: Some(compute_spt(node, false, None)),
+            reverse: compute_spt(node, true, None),
+        },
+    }
+}
+
+// Computes the repair path towards the given destination.
+//
+// The P-node is the farthest router along the post-convergence path that can
+// be reached from the local system without going through the protected
+// resource. The Q-node is the first router starting from the P-node whose
+// shortest paths to the destination don't go through the protected resource.
+// Only repair paths where the Q-node is the P-node itself or one of its
+// neighbors are supported.
+fn compute_repair(
+    level: LevelNumber,
+    system_id: SystemId,
+    dest: VertexId,
+    resource: ProtectedResource,
+    spt: &Spt,
+    spts: &ResourceSpts,
+    dest_rspt: &Spt,
+    interfaces: &Interfaces,
+) -> Option<RepairPath> {
+    let vertex_id =
+        |system_id: SystemId| VertexId::new(LanId::from((system_id, 0)));
+    let distance = |spt: &Spt, system_id: SystemId| {
+        spt.get(&vertex_id(system_id)).map(|vertex| vertex.distance)
+    };
+    let dest_system_id = dest.lan_id.system_id;
+
+    // Get the routers along the post-convergence path.
+    let dest_v = spts.post.get(&dest)?;
+    let mut path = vec![];
+    let mut vid = Some(dest);
+    while let Some(id) = vid {
+        let vertex = spts.post.get(&id)?;
+        if !id.lan_id.is_pseudonode() {
+            path.push(id.lan_id.system_id);
+        }
+        vid = vertex.parent;
+    }
+    path.reverse();
+
+    // Checks whether the local system's shortest paths to the given router
+    // don't go through the protected resource.
+    let in_p_space = |x: SystemId| {
+        if x == system_id {
+            return true;
+        }
+        let Some(dist_s_x) = distance(spt, x) else {
+            return false;
+        };
+        match resource {
+            ProtectedResource::Link(_) => {
+                distance(&spts.post, x) == Some(dist_s_x)
+            }
+            ProtectedResource::Node(node) => {
+                let (Some(dist_s_n), Some(dist_n_x)) = (
+                    distance(spt, node),
+                    spts.node.as_ref().and_then(|spt| distance(spt, x)),
+                ) else {
+                    return distance(&spts.post, x) == Some(dist_s_x);
+                };
+                dist_s_x < dist_s_n.saturating_add(dist_n_x)
+            }
+        }
+    };
+
+    // Checks whether the given router's shortest paths to the destination
+    // don't go through the protected resource.
+    let in_q_space = |x: SystemId| {
+        if x == dest_system_id {
+            return true;
+        }
+        let Some(dist_x_d) = distance(dest_rspt, x) else {
+            return false;
+        };
+        match resource {
+            ProtectedResource::Link(remote) => {
+                let (Some(dist_x_s), Some(cost), Some(dist_r_d)) = (
+                    distance(&spts.reverse, x),
+                    spt.get(&VertexId::new(remote)).map(|v| v.distance),
+                    dest_rspt.get(&VertexId::new(remote)).map(|v| v.distance),
+                ) else {
+                    return true;
+                };
+                dist_x_d
+                    < dist_x_s.saturating_add(cost).saturating_add(dist_r_d)
+            }
+            ProtectedResource::Node(node) => {
+                let (Some(dist_x_n), Some(dist_n_d)) =
+                    (distance(&spts.reverse, x), distance(dest_rspt, node))
+                else {
+                    return true;
+                };
+                dist_x_d < dist_x_n.saturating_add(dist_n_d)
+            }
+        }
+    };
+
+    // Find the P-node and the Q-node.
+    let p_idx = (0..path.len()).rev().find(|idx| in_p_space(path[*idx]))?;
+    let q_idx = if in_q_space(path[p_idx]) {
+        p_idx
+    } else if p_idx + 1 < path.len() && in_q_space(path[p_idx + 1]) {
+        p_idx + 1
+    } else {
+        return None;
+    };
+    let p_node = path[p_idx];
+    let q_node = path[q_idx];
+
+    // Get the segments required to reach the Q-node. A node segment isn't
+    // necessary when the P-node is directly connected.
+    let mut segments = vec![];
+    if p_idx >= 2 {
+        segments.push(RepairSegment::Node(p_node));
+    }
+    if p_idx >= 1 && q_idx != p_idx {
+        segments.push(RepairSegment::Adjacency(p_node, q_node));
+    }
+
+    // Get the backup next-hop towards the first router of the
+    // post-convergence path.
+    let first_hop = *path.get(1)?;
+    let nexthop = spts
+        .post
+        .get(&vertex_id(first_hop))?
+        .nexthops
+        .iter()
+        .filter(|nexthop| nexthop.system_id == first_hop)
+        .find(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            iface.config.is_lfa_candidate(level)
+        })?
+        .clone();
+
+    Some(RepairPath {
+        protection: resource,
+        nexthop,
+        segments,
+        q_node,
+        distance: dest_v.distance,
+    })
+}
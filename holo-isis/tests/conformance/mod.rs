@@ -22,6 +22,31 @@ async fn csnp_interval1() {
     run_test::<Instance>("csnp-interval1", "topo2-1", "rt6").await;
 }
 
+// Input:
+//  * Northbound: change the LSP pacing interval of eth-rt5 to 100 ms
+// Output: no changes
+//
+// Input:
+//  * Protocol: received two updated instances of LSP 0000.0000.0004.00-00
+//    (seqno 8 and 9) from eth-rt4
+// Output:
+//  * Protocol: send both instances to eth-rt5 (LSP pacing is bypassed in
+//    deterministic builds)
+//  * Northbound:
+//    - update 0000.0000.0004.00-00 in the database
+//    - add 0000.0000.0004.00-00 to the SSN list of eth-rt4
+//    - add 0000.0000.0004.00-00 to the SRM list of eth-rt5
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send two "lsp-received" YANG notifications
+//
+// Input:
+//  * Protocol: the LSP pacing interval has expired on eth-rt5
+// Output: no changes
+#[tokio::test]
+async fn lsp_pacing1() {
+    run_test::<Instance>("lsp-pacing1", "topo2-1", "rt6").await;
+}
+
 // Input:
 //  * Protocol: the refresh timer for L2 LSP 0000.0000.0004.00-00 has expired
 // Output:
@@ -305,6 +330,29 @@ async fn pdu_lsp_overload1() {
     run_test::<Instance>("pdu-lsp-overload1", "topo2-1", "rt6").await;
 }
 
+// Input:
+//  * Protocol: received an LSP (0000.0000.0005.00-00) from eth-rt5 where only
+//    the metric of the 5.5.5.5/32 prefix has changed
+// Output:
+//  * Protocol: send LSP 0000.0000.0005.00-00 to eth-rt4
+//  * Northbound:
+//    - update 0000.0000.0005.00-00 with the new prefix metric
+//    - add 0000.0000.0005.00-00 to the SSN list of eth-rt5
+//    - add 0000.0000.0005.00-00 to the SRM list of eth-rt4
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: SPF_TIMER expiration for L2
+// Output:
+//  * Northbound: update the metric of the 5.5.5.5/32 route in the local RIB,
+//    leaving all other routes untouched
+//  * Ibus: reinstall the 5.5.5.5/32 route with the new metric
+#[tokio::test]
+async fn pdu_lsp_prefix1() {
+    run_test::<Instance>("pdu-lsp-prefix1", "topo2-1", "rt6").await;
+}
+
 // Input:
 //  * Northbound: enable purge originator for the instance
 // Output: no changes
@@ -545,6 +593,15 @@ async fn pdu_decode_error5() {
     run_test::<Instance>("pdu-decode-error5", "topo2-1", "rt2").await;
 }
 
+// Input:
+//  * Protocol: received a PDU with an authentication error belonging to
+//    another IS-IS instance (IID 1)
+// Output: no changes
+#[tokio::test]
+async fn pdu_decode_error6() {
+    run_test::<Instance>("pdu-decode-error6", "topo2-1", "rt2").await;
+}
+
 // Input:
 //  * Northbound: enable the extended-sequence-number feature in
 //    "send-and-verify" mode on the "eth-rt2" interface
@@ -878,6 +935,67 @@ async fn nb_config_iface_metric1() {
     run_test::<Instance>("nb-config-iface-metric1", "topo2-1", "rt6").await;
 }
 
+// Input:
+//  * Northbound: add eth-rt5 and eth-rt6 to mesh group 1
+// Output: no changes
+//
+// Input:
+//  * Protocol: received an LSP (0000.0000.0009.00-00) from eth-rt6 that doesn't
+//    exist in the database
+// Output:
+//  * Protocol: send LSP 0000.0000.0009.00-00 to eth-rt2-1 and eth-rt2-2 only,
+//    since eth-rt5 belongs to the same mesh group as eth-rt6
+//  * Northbound:
+//    - add 0000.0000.0009.00-00 to the database
+//    - add 0000.0000.0009.00-00 to the SSN list of eth-rt6
+//    - add 0000.0000.0009.00-00 to the SRM list of eth-rt2-1 and eth-rt2-2
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: received a newer instance of LSP 0000.0000.0009.00-00 from
+//    eth-rt2-1, which isn't part of any mesh group
+// Output:
+//  * Protocol: send LSP 0000.0000.0009.00-00 to all other adjacencies
+//  * Northbound:
+//    - update 0000.0000.0009.00-00 in the database
+//    - add 0000.0000.0009.00-00 to the SSN list of eth-rt2-1
+//    - add 0000.0000.0009.00-00 to the SRM list of all other adjacencies
+//    - send an "lsp-received" YANG notification
+#[tokio::test]
+async fn nb_config_iface_mesh_group1() {
+    run_test::<Instance>("nb-config-iface-mesh-group1", "topo2-1", "rt4").await;
+}
+
+// Input:
+//  * Northbound: set eth-rt2-1 as mesh blocked
+// Output: no changes
+//
+// Input:
+//  * Protocol: received an LSP (0000.0000.0009.00-00) from eth-rt6 that doesn't
+//    exist in the database
+// Output:
+//  * Protocol: send LSP 0000.0000.0009.00-00 to eth-rt2-2 and eth-rt5 only
+//  * Northbound:
+//    - add 0000.0000.0009.00-00 to the database
+//    - add 0000.0000.0009.00-00 to the SSN list of eth-rt6
+//    - add 0000.0000.0009.00-00 to the SRM list of eth-rt2-2 and eth-rt5
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Northbound: change the eth-rt5 metric from 10 to 50
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies except eth-rt2-1
+//  * Northbound:
+//    - update the eth-rt5 metric in the local LSP
+//    - add the local LSP to the SRM list of all adjacencies except eth-rt2-1
+//    - send an "lsp-generation" YANG notification
+#[tokio::test]
+async fn nb_config_iface_mesh_group2() {
+    run_test::<Instance>("nb-config-iface-mesh-group2", "topo2-1", "rt4").await;
+}
+
 // Input:
 //  * Northbound: configure eth-rt5 as passive
 // Output:
@@ -1024,6 +1142,72 @@ async fn nb_config_sr_enabled1() {
     run_test::<Instance>("nb-config-sr-enabled1", "topo1-1", "rt3").await;
 }
 
+// Input:
+//  * Northbound: enable segment routing and Flexible Algorithm 128
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - add IPv4 and IPv6 Adj-SIDs for all adjacencies to the local LSP
+//    - add the local LSP to the SRM list of all adjacencies
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-generation" YANG notification
+//  * Ibus: install IPv4 and IPv6 Adj-SIDs for all adjacencies
+//
+// Input:
+//  * Ibus: SR configuration update  (SRGB, SRLB and Prefix-SIDs)
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - add a Router Capability TLV including the configured SRGB and SRLB,
+//      the SR algorithms (0 and 128) and the Flexible Algorithm Definition to
+//      the local LSP
+//    - add Prefix-SID sub-TLVs as per the configuration update
+//    - add the local LSP to the SRM list of all adjacencies
+//    - send an "lsp-generation" YANG notification
+//
+// Input:
+//  * Protocol: receive LSP from rt4 advertising a higher-priority FAD for
+//    algorithm 128 that excludes admin group 33, with the rt4-rt5 link tagged
+//    with extended admin group 33
+// Output:
+//  * Protocol: flood the received LSP to rt2 and rt6
+//  * Northbound:
+//    - add the received LSP to the LSDB
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: receive LSP from rt5 advertising Prefix-SIDs for algorithms 0
+//    and 128
+// Output:
+//  * Protocol: flood the received LSP to rt2 and rt6
+//  * Northbound:
+//    - add the received LSP to the LSDB
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus:
+//    - install routes to 4.4.4.4/32 and 5.5.5.5/32 with their algorithm 0
+//      Prefix-SIDs
+//    - install the algorithm 128 Prefix-SID of 4.4.4.4/32 only, since the
+//      rt4-rt5 link is pruned from the algorithm 128 topology
+//
+// Input:
+//  * Protocol: receive updated LSP from rt4 without extended admin groups
+// Output:
+//  * Protocol: flood the received LSP to rt2 and rt6
+//  * Northbound: send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: install the algorithm 128 Prefix-SID of 5.5.5.5/32
+#[tokio::test]
+async fn nb_config_flex_algo1() {
+    run_test::<Instance>("nb-config-flex-algo1", "topo1-1", "rt3").await;
+}
+
 // Input:
 //  * Northbound: add a summary route (1.0.0.0/8)
 // Output:
@@ -1136,6 +1320,37 @@ async fn nb_config_te_router_id2() {
     run_test::<Instance>("nb-config-te-router-id2", "topo2-1", "rt6").await;
 }
 
+// Input:
+//  * Northbound: enable TI-LFA on the eth-rt4 and eth-rt5 interfaces
+// Output:
+//  * Northbound:
+//    - add TI-LFA backup next hops to the local RIB routes that don't have
+//      ECMP next hops
+//    - report the protected and unprotected routes, along with the
+//      protection statistics
+//  * Ibus: reinstall the protected routes along with their backup next hops
+//
+// Input:
+//  * Protocol: received an updated LSP (0000.0000.0004.00-00) from eth-rt4
+//    that only adds the 10.0.99.0/24 prefix
+// Output:
+//  * Protocol: send the updated LSP to eth-rt5
+//  * Northbound:
+//    - update 0000.0000.0004.00-00 in the database
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-received" YANG notification
+//
+// Input:
+//  * Protocol: SPF_TIMER expiration for L2
+// Output:
+//  * Northbound: add a route to 10.0.99.0/24 to the local RIB, with a backup
+//    next hop via eth-rt5 reused from the previous full SPF run
+//  * Ibus: install the route to 10.0.99.0/24 along with its backup next hop
+#[tokio::test]
+async fn nb_config_tilfa1() {
+    run_test::<Instance>("nb-config-tilfa1", "topo2-1", "rt6").await;
+}
+
 // Input:
 //  * Northbound: "ietf-isis:clear-adjacency" RPC
 // Output:
@@ -1462,3 +1677,61 @@ async fn timeout_adj2() {
 async fn timeout_adj3() {
     run_test::<Instance>("timeout-adj3", "topo2-1", "rt3").await;
 }
+
+// Input:
+//  * Northbound: change the metric type to wide-only and configure a link
+//    delay of 1000 microseconds on eth-rt4
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - update IS/IP reachability TLVs in the local LSP to use wide metrics,
+//      advertising the link delay in the eth-rt4 IS reachability entry
+//    - add the local LSP to the SRM list of all adjacencies
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-generation" YANG notification
+//
+// Input:
+//  * Northbound: change the eth-rt4 link delay to 1001 microseconds
+// Output: no changes (the link delay change is below the advertisement
+// threshold)
+//
+// Input:
+//  * Northbound: change the eth-rt4 link delay to 1200 microseconds
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - update the link delay advertised in the local LSP
+//    - send an "lsp-generation" YANG notification
+#[tokio::test]
+async fn nb_config_te_perf1() {
+    run_test::<Instance>("nb-config-te-perf1", "topo2-1", "rt6").await;
+}
+
+// Input:
+//  * Northbound: change the metric type to wide-only and configure a link
+//    delay of 1000 microseconds on eth-rt4
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - update IS/IP reachability TLVs in the local LSP to use wide metrics,
+//      advertising the link delay in the eth-rt4 IS reachability entry
+//    - add the local LSP to the SRM list of all adjacencies
+//    - transition the SPF Delay FSM state from "quiet" to "short-wait"
+//    - send an "lsp-generation" YANG notification
+//
+// Input:
+//  * Northbound: change the eth-rt4 link delay to 1001 microseconds
+// Output: no changes (the link delay change is below the advertisement
+// threshold)
+//
+// Input:
+//  * Protocol: refresh the local LSP
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - update the link delay advertised in the local LSP
+//    - send an "lsp-generation" YANG notification
+#[tokio::test]
+async fn nb_config_te_perf2() {
+    run_test::<Instance>("nb-config-te-perf2", "topo2-1", "rt6").await;
+}
@@ -184,6 +184,7 @@ static LAN_HELLO1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc, 0x8e],
                 }),
+                instance_id: None,
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0x00, 0x00].as_slice())],
                 }],
@@ -195,6 +196,7 @@ static LAN_HELLO1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                     ],
                 }],
                 three_way_adj: None,
+                restart: None,
                 ipv4_addrs: vec![Ipv4AddressesTlv {
                     list: vec![ip4!("10.0.1.1")],
                 }],
@@ -368,6 +370,7 @@ static P2P_HELLO1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc, 0x8e],
                 }),
+                instance_id: None,
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0x00, 0x00].as_slice())],
                 }],
@@ -389,6 +392,7 @@ static P2P_HELLO1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                     local_circuit_id: Some(100),
                     neighbor: None,
                 }),
+                restart: None,
                 ipv4_addrs: vec![Ipv4AddressesTlv {
                     list: vec![ip4!("10.0.7.6")],
                 }],
@@ -431,6 +435,7 @@ static P2P_HELLO2_CLEAR_TEXT: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     protocols_supported: Some(ProtocolsSupportedTlv {
                         list: vec![0xcc, 0x8e],
                     }),
+                    instance_id: None,
                     area_addrs: vec![AreaAddressesTlv {
                         list: vec![AreaAddr::from(
                             [0x49, 0x00, 0x00].as_slice(),
@@ -439,6 +444,7 @@ static P2P_HELLO2_CLEAR_TEXT: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     multi_topology: vec![],
                     neighbors: vec![],
                     three_way_adj: None,
+                    restart: None,
                     ipv4_addrs: vec![Ipv4AddressesTlv {
                         list: vec![ip4!("10.0.7.6")],
                     }],
@@ -476,6 +482,7 @@ static P2P_HELLO2_HMAC_MD5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     protocols_supported: Some(ProtocolsSupportedTlv {
                         list: vec![0xcc, 0x8e],
                     }),
+                    instance_id: None,
                     area_addrs: vec![AreaAddressesTlv {
                         list: vec![AreaAddr::from(
                             [0x49, 0x00, 0x00].as_slice(),
@@ -484,6 +491,7 @@ static P2P_HELLO2_HMAC_MD5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     multi_topology: vec![],
                     neighbors: vec![],
                     three_way_adj: None,
+                    restart: None,
                     ipv4_addrs: vec![Ipv4AddressesTlv {
                         list: vec![ip4!("10.0.7.6")],
                     }],
@@ -525,6 +533,7 @@ static P2P_HELLO2_HMAC_SHA256: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     protocols_supported: Some(ProtocolsSupportedTlv {
                         list: vec![0xcc, 0x8e],
                     }),
+                    instance_id: None,
                     area_addrs: vec![AreaAddressesTlv {
                         list: vec![AreaAddr::from(
                             [0x49, 0x00, 0x00].as_slice(),
@@ -533,6 +542,7 @@ static P2P_HELLO2_HMAC_SHA256: Lazy<(Vec<u8>, Option<&Key>, Pdu)> =
                     multi_topology: vec![],
                     neighbors: vec![],
                     three_way_adj: None,
+                    restart: None,
                     ipv4_addrs: vec![Ipv4AddressesTlv {
                         list: vec![ip4!("10.0.7.6")],
                     }],
@@ -567,6 +577,7 @@ static P2P_HELLO3: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc],
                 }),
+                instance_id: None,
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0x00, 0x00].as_slice())],
                 }],
@@ -580,6 +591,7 @@ static P2P_HELLO3: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                         200,
                     )),
                 }),
+                restart: None,
                 ipv4_addrs: vec![Ipv4AddressesTlv {
                     list: vec![ip4!("10.0.7.6")],
                 }],
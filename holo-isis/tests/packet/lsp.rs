@@ -13,17 +13,20 @@ use const_addrs::{ip4, ip6, net4, net6};
 use holo_isis::packet::pdu::{Lsp, LspFlags, LspTlvs, Pdu};
 use holo_isis::packet::subtlvs::MsdStlv;
 use holo_isis::packet::subtlvs::capability::{
-    LabelBlockEntry, NodeAdminTagStlv, SrAlgoStlv, SrCapabilitiesFlags,
-    SrCapabilitiesStlv, SrLocalBlockStlv,
+    FlexAlgoDefStlv, LabelBlockEntry, NodeAdminTagStlv, SrAlgoStlv,
+    SrCapabilitiesFlags, SrCapabilitiesStlv, SrLocalBlockStlv,
 };
 use holo_isis::packet::subtlvs::neighbor::{
-    AdjSidFlags, AdjSidStlv, AdminGroupStlv, Ipv4InterfaceAddrStlv,
-    Ipv4NeighborAddrStlv, MaxLinkBwStlv, MaxResvLinkBwStlv,
-    TeDefaultMetricStlv, UnreservedBwStlv,
+    AdjSidFlags, AdjSidStlv, AdminGroupStlv, ExtAdminGroupStlv,
+    Ipv4InterfaceAddrStlv, Ipv4NeighborAddrStlv, MaxLinkBwStlv,
+    MaxResvLinkBwStlv, MinMaxUnidirLinkDelayStlv, PerfMetricFlags,
+    TeDefaultMetricStlv, UnidirAvailableBwStlv, UnidirDelayVariationStlv,
+    UnidirLinkDelayStlv, UnidirLinkLossStlv, UnidirResidualBwStlv,
+    UnidirUtilizedBwStlv, UnreservedBwStlv,
 };
 use holo_isis::packet::subtlvs::prefix::{
-    Ipv4SourceRidStlv, Ipv6SourceRidStlv, PrefixAttrFlags, PrefixAttrFlagsStlv,
-    PrefixSidFlags, PrefixSidStlv,
+    AdminTag64Stlv, AdminTagStlv, Ipv4SourceRidStlv, Ipv6SourceRidStlv,
+    PrefixAttrFlags, PrefixAttrFlagsStlv, PrefixSidFlags, PrefixSidStlv,
 };
 use holo_isis::packet::tlv::{
     AreaAddressesTlv, DynamicHostnameTlv, Ipv4AddressesTlv, Ipv4Reach,
@@ -95,6 +98,7 @@ static LSP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc],
                 }),
+                instance_id: None,
                 router_cap: vec![RouterCapTlv {
                     router_id: Some(ip4!("1.1.1.1")),
                     flags: RouterCapFlags::empty(),
@@ -153,9 +157,17 @@ static LSP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                                 12500000.0, 12500000.0, 12500000.0, 12500000.0,
                                 12500000.0, 12500000.0, 12500000.0, 12500000.0,
                             ])),
+                            ext_admin_group: None,
                             te_default_metric: Some(TeDefaultMetricStlv::new(
                                 100,
                             )),
+                            unidir_link_delay: None,
+                            min_max_unidir_link_delay: None,
+                            unidir_delay_variation: None,
+                            unidir_link_loss: None,
+                            unidir_residual_bw: None,
+                            unidir_available_bw: None,
+                            unidir_utilized_bw: None,
                             adj_sids: vec![AdjSidStlv {
                                 flags: AdjSidFlags::V | AdjSidFlags::L,
                                 weight: 0,
@@ -165,6 +177,7 @@ static LSP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                                 sid: Sid::Label(Label::new(15000)),
                             }],
                             link_msd: Some(MsdStlv::new(btreemap! { 1 => 16 })),
+                            srv6_end_x_sids: vec![],
                             unknown: vec![],
                         },
                     }],
@@ -262,6 +275,7 @@ static LSP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 }],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: Some(Ipv6RouterIdTlv::new(ip6!("2001:db8::1"))),
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             None,
@@ -298,6 +312,7 @@ static LSP2: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc],
                 }),
+                instance_id: None,
                 router_cap: vec![],
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0, 0].as_slice())],
@@ -395,6 +410,7 @@ static LSP2: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             None,
@@ -427,6 +443,7 @@ static LSP3_HMAC_MD5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc],
                 }),
+                instance_id: None,
                 router_cap: vec![],
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0, 0].as_slice())],
@@ -475,6 +492,7 @@ static LSP3_HMAC_MD5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             Some(&KEY_HMAC_MD5),
@@ -509,6 +527,7 @@ static LSP3_HMAC_SHA256: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc],
                 }),
+                instance_id: None,
                 router_cap: vec![],
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0, 0].as_slice())],
@@ -557,6 +576,7 @@ static LSP3_HMAC_SHA256: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             Some(&KEY_HMAC_SHA256),
@@ -594,6 +614,7 @@ static LSP4: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 protocols_supported: Some(ProtocolsSupportedTlv {
                     list: vec![0xcc, 0x8e],
                 }),
+                instance_id: None,
                 router_cap: vec![],
                 area_addrs: vec![AreaAddressesTlv {
                     list: vec![AreaAddr::from([0x49, 0, 0].as_slice())],
@@ -682,6 +703,7 @@ static LSP4: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                     ],
                 }],
                 ipv6_router_id: None,
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             None,
@@ -708,6 +730,7 @@ static LSP5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
             LspTlvs {
                 auth: None,
                 protocols_supported: None,
+                instance_id: None,
                 router_cap: vec![],
                 area_addrs: vec![],
                 multi_topology: vec![],
@@ -736,6 +759,250 @@ static LSP5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                srv6_locator: vec![],
+                unknown: vec![],
+            },
+            None,
+        )),
+    )
+});
+
+static LSP6: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
+    (
+        vec![
+            0x83, 0x1b, 0x01, 0x00, 0x12, 0x01, 0x00, 0x00, 0x00, 0x56, 0x04,
+            0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x06, 0x67, 0x2c, 0x01, 0x16, 0x39, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x2e, 0x21, 0x04, 0x80, 0x00,
+            0x03, 0xe8, 0x22, 0x08, 0x00, 0x00, 0x03, 0x20, 0x00, 0x00, 0x05,
+            0xdc, 0x23, 0x04, 0x00, 0x00, 0x00, 0x32, 0x24, 0x04, 0x00, 0x00,
+            0x03, 0xe8, 0x25, 0x04, 0x4b, 0x3e, 0xbc, 0x20, 0x26, 0x04, 0x4c,
+            0xee, 0x6b, 0x28, 0x27, 0x04, 0x49, 0x98, 0x96, 0x80,
+        ],
+        None,
+        Pdu::Lsp(Lsp::new(
+            LevelNumber::L1,
+            1200,
+            LspId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00]),
+            0x00000006,
+            LspFlags::IS_TYPE1,
+            LspTlvs {
+                auth: None,
+                protocols_supported: None,
+                instance_id: None,
+                router_cap: vec![],
+                area_addrs: vec![],
+                multi_topology: vec![],
+                purge_originator_id: None,
+                hostname: None,
+                lsp_buf_size: None,
+                is_reach: vec![],
+                ext_is_reach: vec![IsReachTlv {
+                    mt_id: None,
+                    list: vec![IsReach {
+                        neighbor: LanId::from([
+                            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+                        ]),
+                        metric: 10,
+                        sub_tlvs: IsReachStlvs {
+                            unidir_link_delay: Some(UnidirLinkDelayStlv::new(
+                                PerfMetricFlags::A,
+                                1000,
+                            )),
+                            min_max_unidir_link_delay: Some(
+                                MinMaxUnidirLinkDelayStlv::new(
+                                    PerfMetricFlags::empty(),
+                                    800,
+                                    1500,
+                                ),
+                            ),
+                            unidir_delay_variation: Some(
+                                UnidirDelayVariationStlv::new(50),
+                            ),
+                            unidir_link_loss: Some(UnidirLinkLossStlv::new(
+                                PerfMetricFlags::empty(),
+                                1000,
+                            )),
+                            unidir_residual_bw: Some(
+                                UnidirResidualBwStlv::new(12500000.0),
+                            ),
+                            unidir_available_bw: Some(
+                                UnidirAvailableBwStlv::new(125000000.0),
+                            ),
+                            unidir_utilized_bw: Some(
+                                UnidirUtilizedBwStlv::new(1250000.0),
+                            ),
+                            ..Default::default()
+                        },
+                    }],
+                }],
+                mt_is_reach: vec![],
+                ipv4_addrs: vec![],
+                ipv4_internal_reach: vec![],
+                ipv4_external_reach: vec![],
+                ext_ipv4_reach: vec![],
+                mt_ipv4_reach: vec![],
+                ipv4_router_id: None,
+                ipv6_addrs: vec![],
+                ipv6_reach: vec![],
+                mt_ipv6_reach: vec![],
+                ipv6_router_id: None,
+                srv6_locator: vec![],
+                unknown: vec![],
+            },
+            None,
+        )),
+    )
+});
+
+static LSP7: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
+    (
+        vec![
+            0x83, 0x1b, 0x01, 0x00, 0x12, 0x01, 0x00, 0x00, 0x00, 0x4b, 0x04,
+            0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x07, 0xa4, 0xaf, 0x01, 0x87, 0x13, 0x00, 0x00, 0x00, 0x0a,
+            0x58, 0x0a, 0x00, 0x02, 0x0a, 0x01, 0x08, 0x00, 0x00, 0x00, 0x64,
+            0x00, 0x00, 0x00, 0xc8, 0xec, 0x19, 0x00, 0x00, 0x00, 0x0a, 0x20,
+            0x40, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x02,
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8,
+        ],
+        None,
+        Pdu::Lsp(Lsp::new(
+            LevelNumber::L1,
+            1200,
+            LspId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00]),
+            0x00000007,
+            LspFlags::IS_TYPE1,
+            LspTlvs {
+                auth: None,
+                protocols_supported: None,
+                instance_id: None,
+                router_cap: vec![],
+                area_addrs: vec![],
+                multi_topology: vec![],
+                purge_originator_id: None,
+                hostname: None,
+                lsp_buf_size: None,
+                is_reach: vec![],
+                ext_is_reach: vec![],
+                mt_is_reach: vec![],
+                ipv4_addrs: vec![],
+                ipv4_internal_reach: vec![],
+                ipv4_external_reach: vec![],
+                ext_ipv4_reach: vec![Ipv4ReachTlv {
+                    mt_id: None,
+                    list: vec![Ipv4Reach {
+                        metric: 10,
+                        up_down: false,
+                        prefix: net4!("10.0.2.0/24"),
+                        sub_tlvs: Ipv4ReachStlvs {
+                            admin_tags: Some(AdminTagStlv::new(
+                                [100, 200].into(),
+                            )),
+                            ..Default::default()
+                        },
+                    }],
+                }],
+                mt_ipv4_reach: vec![],
+                ipv4_router_id: None,
+                ipv6_addrs: vec![],
+                ipv6_reach: vec![Ipv6ReachTlv {
+                    mt_id: None,
+                    list: vec![Ipv6Reach {
+                        metric: 10,
+                        up_down: false,
+                        external: false,
+                        prefix: net6!("2001:db8:2::/64"),
+                        sub_tlvs: Ipv6ReachStlvs {
+                            admin_tags64: Some(AdminTag64Stlv::new(
+                                [1000].into(),
+                            )),
+                            ..Default::default()
+                        },
+                    }],
+                }],
+                mt_ipv6_reach: vec![],
+                ipv6_router_id: None,
+                srv6_locator: vec![],
+                unknown: vec![],
+            },
+            None,
+        )),
+    )
+});
+
+static LSP8: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
+    (
+        vec![
+            0x83, 0x1b, 0x01, 0x00, 0x12, 0x01, 0x00, 0x00, 0x00, 0x53, 0x04,
+            0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x08, 0xd1, 0x92, 0x01, 0xf2, 0x1f, 0x01, 0x01, 0x01, 0x01,
+            0x00, 0x13, 0x02, 0x00, 0x80, 0x1a, 0x14, 0x80, 0x01, 0x00, 0xc8,
+            0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03,
+            0x04, 0x00, 0x00, 0x00, 0x01, 0x16, 0x15, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x0a, 0x0e, 0x08, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+        ],
+        None,
+        Pdu::Lsp(Lsp::new(
+            LevelNumber::L1,
+            1200,
+            LspId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00]),
+            0x00000008,
+            LspFlags::IS_TYPE1,
+            LspTlvs {
+                auth: None,
+                protocols_supported: None,
+                instance_id: None,
+                router_cap: vec![RouterCapTlv {
+                    router_id: Some(ip4!("1.1.1.1")),
+                    flags: RouterCapFlags::empty(),
+                    sub_tlvs: RouterCapStlvs {
+                        sr_algo: Some(SrAlgoStlv::new(
+                            [IgpAlgoType::Spf, IgpAlgoType::FlexAlgo(128)]
+                                .into(),
+                        )),
+                        flex_algo_defs: vec![FlexAlgoDefStlv {
+                            exclude_any: vec![0x00000000, 0x00000002],
+                            include_all: vec![0x00000001],
+                            ..FlexAlgoDefStlv::new(128, 1, 0, 200)
+                        }],
+                        ..Default::default()
+                    },
+                }],
+                area_addrs: vec![],
+                multi_topology: vec![],
+                purge_originator_id: None,
+                hostname: None,
+                lsp_buf_size: None,
+                is_reach: vec![],
+                ext_is_reach: vec![IsReachTlv {
+                    mt_id: None,
+                    list: vec![IsReach {
+                        neighbor: LanId::from([
+                            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+                        ]),
+                        metric: 10,
+                        sub_tlvs: IsReachStlvs {
+                            ext_admin_group: Some(ExtAdminGroupStlv::new(
+                                vec![0x00000001, 0x00000002],
+                            )),
+                            ..Default::default()
+                        },
+                    }],
+                }],
+                mt_is_reach: vec![],
+                ipv4_addrs: vec![],
+                ipv4_internal_reach: vec![],
+                ipv4_external_reach: vec![],
+                ext_ipv4_reach: vec![],
+                mt_ipv4_reach: vec![],
+                ipv4_router_id: None,
+                ipv6_addrs: vec![],
+                ipv6_reach: vec![],
+                mt_ipv6_reach: vec![],
+                ipv6_router_id: None,
+                srv6_locator: vec![],
                 unknown: vec![],
             },
             None,
@@ -818,3 +1085,39 @@ fn test_decode_lsp5() {
     let (ref bytes, ref auth, ref lsp) = *LSP5;
     test_decode_pdu(bytes, lsp, auth);
 }
+
+#[test]
+fn test_encode_lsp6() {
+    let (ref bytes, ref auth, ref lsp) = *LSP6;
+    test_encode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_decode_lsp6() {
+    let (ref bytes, ref auth, ref lsp) = *LSP6;
+    test_decode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_encode_lsp7() {
+    let (ref bytes, ref auth, ref lsp) = *LSP7;
+    test_encode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_decode_lsp7() {
+    let (ref bytes, ref auth, ref lsp) = *LSP7;
+    test_decode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_encode_lsp8() {
+    let (ref bytes, ref auth, ref lsp) = *LSP8;
+    test_encode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_decode_lsp8() {
+    let (ref bytes, ref auth, ref lsp) = *LSP8;
+    test_decode_pdu(bytes, lsp, auth);
+}
@@ -11,7 +11,7 @@ use std::sync::LazyLock as Lazy;
 
 use holo_isis::packet::pdu::{Pdu, Snp, SnpTlvs};
 use holo_isis::packet::tlv::{
-    ExtendedSeqNum, ExtendedSeqNumTlv, LspEntriesTlv, LspEntry,
+    ExtendedSeqNum, ExtendedSeqNumTlv, InstanceIdTlv, LspEntriesTlv, LspEntry,
 };
 use holo_isis::packet::{LanId, LevelNumber, LspId};
 use holo_utils::keychain::Key;
@@ -44,6 +44,7 @@ static CSNP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 LspId::from([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
             )),
             SnpTlvs {
+                instance_id: None,
                 lsp_entries: vec![LspEntriesTlv {
                     list: vec![
                         LspEntry {
@@ -100,6 +101,7 @@ static PSNP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
             LanId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00]),
             None,
             SnpTlvs {
+                instance_id: None,
                 lsp_entries: vec![LspEntriesTlv {
                     list: vec![
                         LspEntry {
@@ -143,6 +145,38 @@ static PSNP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
     )
 });
 
+static PSNP2: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
+    (
+        vec![
+            0x83, 0x11, 0x01, 0x00, 0x1a, 0x01, 0x00, 0x00, 0x00, 0x27, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x07, 0x02, 0x00, 0x01, 0x09,
+            0x10, 0x04, 0x8e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x02, 0xb0, 0x53,
+        ],
+        None,
+        Pdu::Snp(Snp::new(
+            LevelNumber::L1,
+            LanId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00]),
+            None,
+            SnpTlvs {
+                instance_id: Some(InstanceIdTlv::new(1, vec![])),
+                lsp_entries: vec![LspEntriesTlv {
+                    list: vec![LspEntry {
+                        rem_lifetime: 1166,
+                        lsp_id: LspId::from([
+                            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+                        ]),
+                        cksum: 0xb053,
+                        seqno: 2,
+                    }],
+                }],
+                ext_seqnum: None,
+                unknown: vec![],
+            },
+        )),
+    )
+});
+
 //
 // Tests.
 //
@@ -170,3 +204,15 @@ fn test_decode_psnp1() {
     let (ref bytes, ref auth, ref psnp) = *PSNP1;
     test_decode_pdu(bytes, psnp, auth);
 }
+
+#[test]
+fn test_encode_psnp2() {
+    let (ref bytes, ref auth, ref psnp) = *PSNP2;
+    test_encode_pdu(bytes, psnp, auth);
+}
+
+#[test]
+fn test_decode_psnp2() {
+    let (ref bytes, ref auth, ref psnp) = *PSNP2;
+    test_decode_pdu(bytes, psnp, auth);
+}
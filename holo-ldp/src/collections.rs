@@ -8,13 +8,13 @@ use std::collections::{BTreeMap, HashMap, btree_map, hash_map};
 use std::net::{IpAddr, Ipv4Addr};
 
 use generational_arena::{Arena, Index};
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 
 use crate::discovery::{Adjacency, AdjacencySource, TargetedNbr};
 use crate::error::Error;
 use crate::instance::InstanceUpView;
 use crate::interface::Interface;
 use crate::neighbor::{self, Neighbor};
-use crate::network;
 use crate::packet::StatusCode;
 
 pub type InterfaceId = usize;
@@ -188,7 +188,6 @@ impl Interfaces {
     }
 
     // Returns a reference to the interface corresponding to the given ifindex.
-    #[expect(unused)]
     pub(crate) fn get_by_ifindex(
         &self,
         ifindex: u32,
@@ -412,6 +411,26 @@ impl Adjacencies {
         self.lsr_id_tree.get(lsr_id)
     }
 
+    // Returns whether the given LSR-ID has at least one adjacency of the
+    // specified address family.
+    //
+    // Label bindings and addresses of an address family are only exchanged
+    // with neighbors that have a Hello adjacency of that same address family.
+    pub(crate) fn has_af(&self, lsr_id: &Ipv4Addr, af: AddressFamily) -> bool {
+        self.iter_by_lsr_id(lsr_id)
+            .into_iter()
+            .flatten()
+            .any(|adj| adj.source.addr.address_family() == af)
+    }
+
+    // Returns whether the given LSR-ID has at least one link Hello adjacency.
+    pub(crate) fn has_link(&self, lsr_id: &Ipv4Addr) -> bool {
+        self.iter_by_lsr_id(lsr_id)
+            .into_iter()
+            .flatten()
+            .any(|adj| adj.source.ifname.is_some())
+    }
+
     // Returns a list of all adjacencies associated to the given interface.
     pub(crate) fn get_by_iface(
         &self,
@@ -629,13 +648,7 @@ impl Neighbors {
         lsr_id: &Ipv4Addr,
         status_code: StatusCode,
     ) {
-        if instance
-            .state
-            .ipv4
-            .adjacencies
-            .get_by_lsr_id(lsr_id)
-            .is_none()
-        {
+        if instance.state.adjacencies.get_by_lsr_id(lsr_id).is_none() {
             let (nbr_idx, nbr) =
                 instance.state.neighbors.get_mut_by_lsr_id(lsr_id).unwrap();
 
@@ -654,19 +667,38 @@ impl Neighbors {
                 );
             }
 
+            // Keep the neighbor around while its stale label bindings are
+            // retained, unless it's being administratively removed.
+            if instance.state.neighbors[nbr_idx].gr.is_some() {
+                if status_code != StatusCode::Shutdown {
+                    return;
+                }
+                Neighbor::gr_purge_stale(instance, nbr_idx);
+            }
+
             // Unset neighbor password (if any).
             let nbr = &instance.state.neighbors[nbr_idx];
-            network::tcp::listen_socket_md5sig_update(
-                &instance.state.ipv4.session_socket,
-                &nbr.trans_addr,
-                None,
-            );
+            instance
+                .state
+                .listen_socket_md5sig_update(&nbr.trans_addr, None);
 
             // Delete neighbor.
             instance.state.neighbors.delete(nbr_idx);
         }
     }
 
+    pub(crate) fn update_trans_addr(
+        &mut self,
+        nbr_idx: NeighborIndex,
+        trans_addr: IpAddr,
+    ) {
+        let nbr = &mut self.arena[nbr_idx];
+
+        self.addr_tree.remove(&nbr.trans_addr);
+        nbr.trans_addr = trans_addr;
+        self.addr_tree.insert(nbr.trans_addr, nbr_idx);
+    }
+
     pub(crate) fn update_id(&mut self, nbr_idx: NeighborIndex, id: NeighborId) {
         let nbr = &mut self.arena[nbr_idx];
 
@@ -6,6 +6,7 @@
 
 use std::net::{IpAddr, Ipv4Addr};
 
+use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
 use tracing::{Span, debug, debug_span};
 
@@ -24,8 +25,8 @@ pub enum Debug<'a> {
     InstanceStop(InstanceInactiveReason),
     InterfaceCreate(&'a str),
     InterfaceDelete(&'a str),
-    InterfaceStart(&'a str),
-    InterfaceStop(&'a str, InterfaceInactiveReason),
+    InterfaceStart(&'a str, AddressFamily),
+    InterfaceStop(&'a str, AddressFamily, InterfaceInactiveReason),
     TargetedNbrCreate(&'a IpAddr),
     TargetedNbrDelete(&'a IpAddr),
     TargetedNbrStart(&'a IpAddr),
@@ -47,6 +48,10 @@ pub enum Debug<'a> {
     NbrMsgRx(&'a Ipv4Addr, &'a Message),
     NbrMsgTx(&'a Ipv4Addr, &'a Message),
     NbrInitBackoffTimeout(&'a Ipv4Addr),
+    NbrGrStart(&'a Ipv4Addr, &'a neighbor::GrPhase),
+    NbrGrPurge(&'a Ipv4Addr, usize),
+    NbrProtectionStart(&'a Ipv4Addr),
+    NbrProtectionTimeout(&'a Ipv4Addr),
     FecCreate(&'a Fec),
     FecDelete(&'a Fec),
     FecLabelUpdate(&'a Fec, &'a Option<Label>),
@@ -60,10 +65,11 @@ pub enum Debug<'a> {
 pub enum InstanceInactiveReason {
     AdminDown,
     MissingRouterId,
+    TransportUpdate,
 }
 
 // Reason why LDP is inactive on an interface.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum InterfaceInactiveReason {
     InstanceDown,
     AdminDown,
@@ -88,18 +94,22 @@ impl Debug<'_> {
                 // Parent span(s): ldp-instance
                 debug!(%reason, "{}", self);
             }
-            Debug::InterfaceCreate(name)
-            | Debug::InterfaceDelete(name)
-            | Debug::InterfaceStart(name) => {
+            Debug::InterfaceCreate(name) | Debug::InterfaceDelete(name) => {
                 // Parent span(s): ldp-instance
                 debug_span!("interface", %name).in_scope(|| {
                     debug!("{}", self);
                 });
             }
-            Debug::InterfaceStop(name, reason) => {
+            Debug::InterfaceStart(name, af) => {
+                // Parent span(s): ldp-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(%af, "{}", self);
+                });
+            }
+            Debug::InterfaceStop(name, af, reason) => {
                 // Parent span(s): ldp-instance
                 debug_span!("interface", %name).in_scope(|| {
-                    debug!(%reason, "{}", self);
+                    debug!(%af, %reason, "{}", self);
                 });
             }
             Debug::TargetedNbrCreate(addr)
@@ -171,12 +181,26 @@ impl Debug<'_> {
                     })
                 });
             }
-            Debug::NbrInitBackoffTimeout(lsr_id) => {
+            Debug::NbrInitBackoffTimeout(lsr_id)
+            | Debug::NbrProtectionStart(lsr_id)
+            | Debug::NbrProtectionTimeout(lsr_id) => {
                 // Parent span(s): ldp-instance
                 debug_span!("neighbor", %lsr_id).in_scope(|| {
                     debug!("{}", self);
                 });
             }
+            Debug::NbrGrStart(lsr_id, phase) => {
+                // Parent span(s): ldp-instance
+                debug_span!("neighbor", %lsr_id).in_scope(|| {
+                    debug!(?phase, "{}", self);
+                });
+            }
+            Debug::NbrGrPurge(lsr_id, stale_bindings) => {
+                // Parent span(s): ldp-instance
+                debug_span!("neighbor", %lsr_id).in_scope(|| {
+                    debug!(%stale_bindings, "{}", self);
+                });
+            }
             Debug::FecCreate(fec) | Debug::FecDelete(fec) => {
                 // Parent span(s): ldp-instance
                 debug_span!("lib", prefix = %fec.inner.prefix).in_scope(|| {
@@ -299,6 +323,18 @@ impl std::fmt::Display for Debug<'_> {
             Debug::NbrInitBackoffTimeout(..) => {
                 write!(f, "initialization backoff timer expired")
             }
+            Debug::NbrGrStart(..) => {
+                write!(f, "graceful restart phase started")
+            }
+            Debug::NbrGrPurge(..) => {
+                write!(f, "graceful restart finished")
+            }
+            Debug::NbrProtectionStart(..) => {
+                write!(f, "session protection started")
+            }
+            Debug::NbrProtectionTimeout(..) => {
+                write!(f, "session protection timer expired")
+            }
             Debug::FecCreate(..) => {
                 write!(f, "FEC created")
             }
@@ -332,6 +368,9 @@ impl std::fmt::Display for InstanceInactiveReason {
             InstanceInactiveReason::MissingRouterId => {
                 write!(f, "missing router-id")
             }
+            InstanceInactiveReason::TransportUpdate => {
+                write!(f, "transport parameters changed")
+            }
         }
     }
 }
@@ -4,12 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use holo_utils::ip::IpAddrKind;
 use holo_utils::socket::UdpSocket;
 use holo_utils::task::{IntervalTask, TimeoutTask};
 use tokio::sync::mpsc::Sender;
@@ -21,16 +22,17 @@ use crate::collections::{
 use crate::debug::Debug;
 use crate::error::IoError;
 use crate::instance::{InstanceState, InstanceUpView};
+use crate::neighbor::Neighbor;
 use crate::northbound::configuration::TargetedNbrCfg;
 use crate::northbound::notification;
-use crate::packet::Pdu;
 use crate::packet::messages::hello::{
-    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo,
-    TlvIpv4TransAddr,
+    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo, TlvDualStack,
+    TlvIpv4TransAddr, TlvIpv6TransAddr,
 };
 use crate::packet::messages::notification::StatusCode;
+use crate::packet::{AddressMessageType, Pdu};
 use crate::tasks::messages::input::AdjTimeoutMsg;
-use crate::{network, tasks};
+use crate::{interface, network, tasks};
 
 #[derive(Debug)]
 pub struct Adjacency {
@@ -75,6 +77,9 @@ pub struct TargetedNbr {
     pub configured: bool,
     // Indicates whether this is a dynamic targeted neighbor.
     pub dynamic: bool,
+    // Indicates whether this targeted neighbor protects the session of a
+    // neighbor discovered through link Hellos.
+    pub session_protection: bool,
     // Hello Tx interval task.
     pub hello_interval_task: Option<IntervalTask>,
 }
@@ -145,7 +150,7 @@ impl Adjacency {
         match &self.source.ifname {
             Some(ifname) => {
                 let (_, iface) = interfaces.get_by_name(ifname).unwrap();
-                iface.next_hello().unwrap()
+                iface.next_hello(self.source.addr.address_family()).unwrap()
             }
             None => {
                 let (_, tnbr) =
@@ -189,6 +194,7 @@ impl TargetedNbr {
             config: TargetedNbrCfg::default(),
             configured: false,
             dynamic: false,
+            session_protection: false,
             hello_interval_task: None,
         }
     }
@@ -217,7 +223,7 @@ impl TargetedNbr {
                 addr: self.addr,
             };
             if let Some((adj_idx, _)) =
-                instance.state.ipv4.adjacencies.get_by_source(&source)
+                instance.state.adjacencies.get_by_source(&source)
             {
                 adjacency_delete(instance, adj_idx, StatusCode::Shutdown);
             }
@@ -231,7 +237,7 @@ impl TargetedNbr {
     ) {
         let tnbr = &mut tneighbors[tnbr_idx];
 
-        let is_ready = tnbr.is_ready();
+        let is_ready = tnbr.is_ready(instance.state);
         let remove = tnbr.remove_check();
 
         if !tnbr.is_active() && is_ready {
@@ -254,12 +260,15 @@ impl TargetedNbr {
         self.hello_interval_task.is_some()
     }
 
-    fn is_ready(&self) -> bool {
-        self.dynamic || (self.configured && self.config.enabled)
+    fn is_ready(&self, instance_state: &InstanceState) -> bool {
+        (self.dynamic
+            || self.session_protection
+            || (self.configured && self.config.enabled))
+            && instance_state.af(self.addr.address_family()).is_some()
     }
 
     pub(crate) fn remove_check(&self) -> bool {
-        !self.dynamic && !self.configured
+        !self.dynamic && !self.configured && !self.session_protection
     }
 
     pub(crate) fn generate_hello(
@@ -272,6 +281,10 @@ impl TargetedNbr {
             flags |= HelloFlags::REQ_TARGETED;
         }
 
+        let trans_addr = instance_state
+            .trans_addr(self.addr.address_family())
+            .unwrap();
+
         HelloMsg {
             // The message ID will be overwritten later.
             msg_id: 0,
@@ -279,10 +292,12 @@ impl TargetedNbr {
                 holdtime: self.config.hello_holdtime,
                 flags,
             },
-            ipv4_addr: Some(TlvIpv4TransAddr(instance_state.ipv4.trans_addr)),
-            ipv6_addr: None,
+            ipv4_addr: Ipv4Addr::get(trans_addr).map(TlvIpv4TransAddr),
+            ipv6_addr: Ipv6Addr::get(trans_addr).map(TlvIpv6TransAddr),
             cfg_seqno: Some(TlvConfigSeqNo(instance_state.cfg_seqno)),
-            dual_stack: None,
+            dual_stack: instance_state
+                .trans_pref
+                .map(|af| TlvDualStack(af.into())),
         }
     }
 
@@ -340,7 +355,7 @@ pub(crate) fn adjacency_delete(
     adj_idx: AdjacencyIndex,
     status_code: StatusCode,
 ) {
-    let adjacencies = &mut instance.state.ipv4.adjacencies;
+    let adjacencies = &mut instance.state.adjacencies;
     let adj = &adjacencies[adj_idx];
     let lsr_id = adj.lsr_id;
     let ifname = adj.source.ifname.clone();
@@ -354,5 +369,51 @@ pub(crate) fn adjacency_delete(
         &addr,
         false,
     );
+    if let Some(ifname) = &ifname {
+        interface::igp_sync_update(instance, ifname);
+    }
+
+    // Stop using the link-local address as a next hop of the neighbor.
+    if is_link_local_source(ifname.as_deref(), &addr)
+        && let Some((nbr_idx, nbr)) =
+            instance.state.neighbors.get_by_lsr_id(&lsr_id)
+        && nbr.is_operational()
+    {
+        Neighbor::addr_list_update(
+            instance,
+            nbr_idx,
+            AddressMessageType::AddressWithdraw,
+            vec![addr],
+        );
+    }
+
+    // Protect the session while it's held up only by targeted adjacencies.
+    if ifname.is_some()
+        && instance.config.session_protection.enabled
+        && instance.state.adjacencies.get_by_lsr_id(&lsr_id).is_some()
+        && !instance.state.adjacencies.has_link(&lsr_id)
+        && let Some((_, nbr)) =
+            instance.state.neighbors.get_mut_by_lsr_id(&lsr_id)
+        && nbr.is_operational()
+    {
+        nbr.protection_start(
+            instance.config.session_protection.duration,
+            &instance.tx.protocol_input.nbr_protection_timeout,
+        );
+    }
+
     Neighbors::delete_check(instance, &lsr_id, status_code);
 }
+
+// Returns whether the given adjacency source is an IPv6 link-local address.
+//
+// LDP peers don't advertise their IPv6 link-local addresses, so the source
+// addresses of their IPv6 link Hellos are used instead to map link-local next
+// hops to the corresponding neighbors.
+pub(crate) fn is_link_local_source(
+    ifname: Option<&str>,
+    addr: &IpAddr,
+) -> bool {
+    ifname.is_some()
+        && matches!(addr, IpAddr::V6(addr) if addr.is_unicast_link_local())
+}
@@ -30,6 +30,7 @@ pub enum Error {
     TcpConnClosed(Ipv4Addr),
     TcpInvalidConnRequest(Ipv4Addr),
     TcpAdditionalTransportConn(Ipv4Addr),
+    NbrTransportPrefMismatch(Ipv4Addr),
     NbrPduDecodeError(Ipv4Addr, DecodeError),
     NbrRcvdError(Ipv4Addr, StatusCode),
     NbrSentError(Ipv4Addr, StatusCode),
@@ -80,7 +81,8 @@ impl Error {
             }
             Error::TcpConnClosed(lsr_id)
             | Error::TcpInvalidConnRequest(lsr_id)
-            | Error::TcpAdditionalTransportConn(lsr_id) => {
+            | Error::TcpAdditionalTransportConn(lsr_id)
+            | Error::NbrTransportPrefMismatch(lsr_id) => {
                 warn_span!("neighbor", %lsr_id).in_scope(|| {
                     warn!("{}", self);
                 });
@@ -141,6 +143,9 @@ impl std::fmt::Display for Error {
             Error::TcpAdditionalTransportConn(..) => {
                 write!(f, "rejecting additional transport connection")
             }
+            Error::NbrTransportPrefMismatch(..) => {
+                write!(f, "dual-stack transport preference mismatch")
+            }
             Error::NbrPduDecodeError(..) => {
                 write!(f, "failed to decode PDU")
             }
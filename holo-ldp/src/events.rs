@@ -6,15 +6,16 @@
 
 use std::collections::{VecDeque, btree_map};
 use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
 
 use chrono::Utc;
-use holo_utils::ip::IpNetworkKind;
+use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::socket::{SocketExt, TTL_MAX, TcpConnInfo, TcpStream};
 use tracing::{Span, debug_span};
 
 use crate::collections::{
-    AdjacencyId, Interfaces, NeighborId, NeighborIndex, TargetedNbrs,
+    AdjacencyId, Interfaces, NeighborId, NeighborIndex, Neighbors, TargetedNbrs,
 };
 use crate::debug::Debug;
 use crate::discovery::{self, Adjacency, AdjacencySource, TargetedNbr};
@@ -26,7 +27,7 @@ use crate::northbound::notification;
 use crate::packet::error::DecodeError;
 use crate::packet::messages::address::TlvAddressList;
 use crate::packet::messages::hello::HelloFlags;
-use crate::packet::messages::initialization::InitFlags;
+use crate::packet::messages::initialization::{FtFlags, InitFlags};
 use crate::packet::messages::label::{
     FecElem, FecElemWildcard, TypedWildcardFecElem,
 };
@@ -36,7 +37,7 @@ use crate::packet::messages::{
     NotifMsg,
 };
 use crate::packet::{AddressMessageType, LabelMessageType, Message, Pdu};
-use crate::{ibus, network};
+use crate::{ibus, interface, policy};
 
 // ===== UDP packet receipt =====
 
@@ -45,11 +46,14 @@ pub(crate) fn process_udp_pdu(
     interfaces: &mut Interfaces,
     tneighbors: &mut TargetedNbrs,
     src_addr: IpAddr,
+    ifindex: Option<u32>,
     pdu: Result<Pdu, DecodeError>,
     multicast: bool,
 ) {
     match multicast {
-        true => process_udp_pdu_multicast(instance, interfaces, src_addr, pdu),
+        true => process_udp_pdu_multicast(
+            instance, interfaces, tneighbors, src_addr, ifindex, pdu,
+        ),
         false => process_udp_pdu_unicast(instance, tneighbors, src_addr, pdu),
     }
 }
@@ -57,11 +61,20 @@ pub(crate) fn process_udp_pdu(
 fn process_udp_pdu_multicast(
     instance: &mut InstanceUpView<'_>,
     interfaces: &mut Interfaces,
+    tneighbors: &mut TargetedNbrs,
     src_addr: IpAddr,
+    ifindex: Option<u32>,
     pdu: Result<Pdu, DecodeError>,
 ) {
     // Lookup interface.
-    let (_, iface) = match interfaces.get_by_addr(&src_addr) {
+    //
+    // IPv6 link Hellos are sourced from link-local addresses, hence the
+    // interface is identified by the index of the receiving interface.
+    let iface = match ifindex {
+        Some(ifindex) => interfaces.get_by_ifindex(ifindex),
+        None => interfaces.get_by_addr(&src_addr),
+    };
+    let (_, iface) = match iface {
         Some(value) => value,
         None => return,
     };
@@ -83,7 +96,11 @@ fn process_udp_pdu_multicast(
             return;
         }
 
-        let local_addr = iface.system.local_ipv4_addr();
+        let local_addr =
+            match iface.system.local_addr(src_addr.address_family()) {
+                Some(local_addr) => local_addr,
+                None => return,
+            };
         let holdtime_adjacent = hello.params.holdtime;
         let holdtime_negotiated =
             iface.calculate_adj_holdtime(holdtime_adjacent);
@@ -91,16 +108,54 @@ fn process_udp_pdu_multicast(
         process_hello(
             instance,
             local_addr,
-            source,
+            source.clone(),
             pdu.lsr_id,
             hello,
             holdtime_adjacent,
             holdtime_negotiated,
             span,
         );
+
+        // Protect the session of the adjacent LSR using targeted Hellos.
+        if instance.config.session_protection.enabled {
+            session_protection_update(instance, tneighbors, &source);
+        }
     }
 }
 
+// Brings up a targeted Hello adjacency with the LSR reachable through the
+// given link adjacency, and stops protecting its session now that a link
+// adjacency is available again.
+fn session_protection_update(
+    instance: &mut InstanceUpView<'_>,
+    tneighbors: &mut TargetedNbrs,
+    source: &AdjacencySource,
+) {
+    let Some((_, adj)) = instance.state.adjacencies.get_by_source(source)
+    else {
+        return;
+    };
+    let lsr_id = adj.lsr_id;
+    if let Some((_, nbr)) = instance.state.neighbors.get_mut_by_lsr_id(&lsr_id)
+    {
+        nbr.protection_timeout = None;
+    }
+
+    // Find targeted neighbor (or create a new one). The targeted Hellos are
+    // sent to the LSR-ID of the adjacent LSR, which unlike the link's
+    // transport address remains reachable over alternate paths.
+    let addr = IpAddr::from(lsr_id);
+    let (tnbr_idx, tnbr) = match tneighbors.get_mut_by_addr(&addr) {
+        Some(value) => value,
+        None => tneighbors.insert(addr),
+    };
+    if tnbr.session_protection {
+        return;
+    }
+    tnbr.session_protection = true;
+    TargetedNbr::update(instance, tneighbors, tnbr_idx);
+}
+
 fn process_udp_pdu_unicast(
     instance: &mut InstanceUpView<'_>,
     tneighbors: &mut TargetedNbrs,
@@ -123,6 +178,11 @@ fn process_udp_pdu_unicast(
         if !hello.params.flags.contains(HelloFlags::TARGETED) {
             return;
         }
+        let local_addr =
+            match instance.state.trans_addr(src_addr.address_family()) {
+                Some(local_addr) => local_addr,
+                None => return,
+            };
 
         // Find targeted neighbor (or create a dynamic one if possible).
         let (tnbr_idx, tnbr) = match tneighbors.get_mut_by_addr(&src_addr) {
@@ -150,7 +210,6 @@ fn process_udp_pdu_unicast(
         }
 
         // Process hello message.
-        let local_addr = IpAddr::V4(instance.state.ipv4.trans_addr);
         let holdtime_adjacent = hello.params.holdtime;
         let holdtime_negotiated =
             tnbr.calculate_adj_holdtime(holdtime_adjacent);
@@ -178,7 +237,7 @@ fn process_udp_pdu_error(
 
     // Update hello dropped counter.
     if let Some((_, adj)) =
-        instance.state.ipv4.adjacencies.get_mut_by_source(&source)
+        instance.state.adjacencies.get_mut_by_source(&source)
     {
         adj.hello_dropped += 1;
         adj.discontinuity_time = Utc::now();
@@ -198,14 +257,30 @@ fn process_hello(
     Debug::AdjacencyHelloRx(&span, &source, &lsr_id, &hello).log();
 
     // Use implicit transport address if necessary.
-    let trans_addr = hello
-        .ipv4_addr
-        .map(|tlv| tlv.0.into())
-        .unwrap_or_else(|| source.addr);
+    let hello_af = source.addr.address_family();
+    let trans_addr = match hello_af {
+        AddressFamily::Ipv4 => hello.ipv4_addr.map(|tlv| tlv.0.into()),
+        AddressFamily::Ipv6 => hello.ipv6_addr.map(|tlv| tlv.0.into()),
+    }
+    .unwrap_or(source.addr);
+
+    // A dual-stack LSR must not establish a session with a neighbor whose
+    // transport connection preference differs from its own.
+    let peer_trans_pref =
+        hello.dual_stack.map(|tlv| AddressFamily::from(tlv.0));
+    if let Some(trans_pref) = instance.state.trans_pref
+        && let Some(peer_trans_pref) = peer_trans_pref
+        && trans_pref != peer_trans_pref
+    {
+        Error::NbrTransportPrefMismatch(lsr_id).log();
+        return;
+    }
 
     // Create new adjacency or update existing one.
+    let mut new_af = false;
+    let mut new_link_local = false;
     if let Some((_, adj)) =
-        instance.state.ipv4.adjacencies.get_mut_by_source(&source)
+        instance.state.adjacencies.get_mut_by_source(&source)
     {
         let mut shutdown_nbr = false;
 
@@ -237,10 +312,16 @@ fn process_hello(
             Neighbor::fsm(instance, nbr_idx, fsm::Event::ErrorSent);
         }
     } else {
-        let id = instance.state.ipv4.adjacencies.next_id();
+        new_af = !instance.state.adjacencies.has_af(&lsr_id, hello_af);
+        new_link_local = discovery::is_link_local_source(
+            source.ifname.as_deref(),
+            &source.addr,
+        );
+
+        let id = instance.state.adjacencies.next_id();
         let mut adj = Adjacency::new(
             id,
-            source,
+            source.clone(),
             local_addr,
             trans_addr,
             lsr_id,
@@ -257,32 +338,37 @@ fn process_hello(
             true,
         );
 
-        instance.state.ipv4.adjacencies.insert(adj);
+        let ifname = adj.source.ifname.clone();
+        instance.state.adjacencies.insert(adj);
+        if let Some(ifname) = &ifname {
+            interface::igp_sync_update(instance, ifname);
+        }
     }
 
     // Find associated neighbor or create a new one.
-    let (_, nbr) = match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
-        Some(nbr) => nbr,
-        None => {
-            let id = instance.state.neighbors.next_id();
-            let kalive_interval = instance.config.session_ka_interval;
-            let nbr = Neighbor::new(id, lsr_id, trans_addr, kalive_interval);
-
-            // The neighbor password (if any) must be set in the TCP listening
-            // socket otherwise incoming SYN requests will be rejected.
-            if let Some(password) =
-                instance.config.get_neighbor_password(nbr.lsr_id)
-            {
-                network::tcp::listen_socket_md5sig_update(
-                    &instance.state.ipv4.session_socket,
-                    &nbr.trans_addr,
-                    Some(password),
-                );
-            }
+    let (nbr_idx, nbr) =
+        match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+            Some(nbr) => nbr,
+            None => {
+                let id = instance.state.neighbors.next_id();
+                let kalive_interval = instance.config.session_ka_interval;
+                let nbr =
+                    Neighbor::new(id, lsr_id, trans_addr, kalive_interval);
+
+                // The neighbor password (if any) must be set in the TCP listening
+                // socket otherwise incoming SYN requests will be rejected.
+                if let Some(password) =
+                    instance.config.get_neighbor_password(nbr.lsr_id)
+                {
+                    instance.state.listen_socket_md5sig_update(
+                        &nbr.trans_addr,
+                        Some(password),
+                    );
+                }
 
-            instance.state.neighbors.insert(nbr)
-        }
-    };
+                instance.state.neighbors.insert(nbr)
+            }
+        };
 
     // Dynamic GTSM negotiation.
     if !hello.params.flags.contains(HelloFlags::TARGETED)
@@ -301,16 +387,76 @@ fn process_hello(
         nbr.cfg_seqno = cfg_seqno.0;
     }
 
+    if nbr.is_operational() {
+        // Start exchanging the label bindings of the new address family.
+        if new_af {
+            Neighbor::advertise_af(instance, nbr_idx, hello_af);
+        }
+
+        // Map the link-local next hops of this adjacency to the neighbor.
+        if new_link_local {
+            Neighbor::addr_list_update(
+                instance,
+                nbr_idx,
+                AddressMessageType::Address,
+                vec![source.addr],
+            );
+        }
+        return;
+    }
+
+    // Address family of the transport connection. The neighbor's preference
+    // takes precedence, followed by the local one.
+    let trans_af = peer_trans_pref
+        .or(instance.state.trans_pref)
+        .unwrap_or(hello_af);
+
+    // Switch to the transport address of the preferred address family as soon
+    // as it's learned.
+    let old_trans_addr = nbr.trans_addr;
+    if nbr.state == fsm::State::NonExistent
+        && nbr.tasks.connect.is_none()
+        && hello_af == trans_af
+        && old_trans_addr.address_family() != trans_af
+    {
+        if let Some(password) = instance.config.get_neighbor_password(lsr_id) {
+            instance
+                .state
+                .listen_socket_md5sig_update(&old_trans_addr, None);
+            instance
+                .state
+                .listen_socket_md5sig_update(&trans_addr, Some(password));
+        }
+        instance
+            .state
+            .neighbors
+            .update_trans_addr(nbr_idx, trans_addr);
+    }
+
+    // Wait for a while before falling back to a transport connection over the
+    // non-preferred address family.
+    let nbr = &instance.state.neighbors[nbr_idx];
+    let nbr_trans_af = nbr.trans_addr.address_family();
+    let max_wait =
+        Duration::from_secs(instance.config.dual_stack.max_wait.into());
+    if nbr_trans_af != trans_af && nbr.created.elapsed() < max_wait {
+        return;
+    }
+
     // Start TCP connection when playing the active role of session
     // establishment.
+    let Some(local_trans_addr) = instance.state.trans_addr(nbr_trans_af) else {
+        return;
+    };
+    let nbr = &mut instance.state.neighbors[nbr_idx];
     if nbr.state == fsm::State::NonExistent
-        && nbr.is_session_active_role(instance.state.ipv4.trans_addr)
+        && nbr.is_session_active_role(local_trans_addr)
         && nbr.tasks.connect.is_none()
         && nbr.tasks.backoff_timeout.is_none()
     {
         let password = instance.config.get_neighbor_password(nbr.lsr_id);
         nbr.connect(
-            instance.state.ipv4.trans_addr,
+            local_trans_addr,
             password,
             &instance.tx.protocol_input.tcp_connect,
         );
@@ -325,22 +471,27 @@ pub(crate) fn process_adj_timeout(
     adj_id: AdjacencyId,
 ) -> Result<(), Error> {
     // Lookup adjacency.
-    let (adj_idx, adj) = instance.state.ipv4.adjacencies.get_by_id(adj_id)?;
+    let (adj_idx, adj) = instance.state.adjacencies.get_by_id(adj_id)?;
 
     Debug::AdjacencyTimeout(&adj.source, &adj.lsr_id).log();
+    let source = adj.source.clone();
+    let lsr_id = adj.lsr_id;
 
-    // Remove the corresponding dynamic targeted neighbor, if any.
-    if adj.source.ifname.is_none()
-        && let Some((tnbr_idx, tnbr)) =
-            tneighbors.get_mut_by_addr(&adj.source.addr)
+    // Delete adjacency.
+    discovery::adjacency_delete(instance, adj_idx, StatusCode::HoldTimerExp);
+
+    // Remove the corresponding dynamic targeted neighbor, if any. The session
+    // protection also ends once the adjacent LSR is no longer reachable.
+    if source.ifname.is_none()
+        && let Some((tnbr_idx, tnbr)) = tneighbors.get_mut_by_addr(&source.addr)
     {
         tnbr.dynamic = false;
+        if !instance.state.adjacencies.has_link(&lsr_id) {
+            tnbr.session_protection = false;
+        }
         TargetedNbr::update(instance, tneighbors, tnbr_idx);
     }
 
-    // Delete adjacency.
-    discovery::adjacency_delete(instance, adj_idx, StatusCode::HoldTimerExp);
-
     Ok(())
 }
 
@@ -353,6 +504,7 @@ pub(crate) fn process_tcp_accept(
 ) {
     // Lookup neighbor.
     let source = conn_info.remote_addr;
+    let local_trans_addr = instance.state.trans_addr(source.address_family());
     let (nbr_idx, nbr) =
         match instance.state.neighbors.get_mut_by_trans_addr(&source) {
             Some(value) => value,
@@ -363,7 +515,9 @@ pub(crate) fn process_tcp_accept(
         };
 
     // Sanity checks.
-    if nbr.is_session_active_role(instance.state.ipv4.trans_addr) {
+    if local_trans_addr.is_none_or(|local_trans_addr| {
+        nbr.is_session_active_role(local_trans_addr)
+    }) {
         Error::TcpInvalidConnRequest(nbr.lsr_id).log();
         return;
     }
@@ -375,11 +529,15 @@ pub(crate) fn process_tcp_accept(
     // Enable GTSM in single-hop peering sessions.
     #[cfg(not(feature = "testing"))]
     {
-        if nbr.flags.contains(NeighborFlags::GTSM)
-            && let Err(error) = stream.set_ipv4_minttl(TTL_MAX)
-        {
-            IoError::TcpSocketError(error).log();
-            return;
+        if nbr.flags.contains(NeighborFlags::GTSM) {
+            let result = match source {
+                IpAddr::V4(_) => stream.set_ipv4_minttl(TTL_MAX),
+                IpAddr::V6(_) => stream.set_ipv6_min_hopcount(TTL_MAX),
+            };
+            if let Err(error) = result {
+                IoError::TcpSocketError(error).log();
+                return;
+            }
         }
     }
 
@@ -643,6 +801,31 @@ fn process_nbr_msg_init(
         nbr.flags.insert(NeighborFlags::CAP_UNREC_NOTIF);
     }
 
+    // Process graceful restart parameters.
+    nbr.rcvd_ft_session = msg.ft_session;
+    if nbr.gr.is_some() {
+        // Keep the stale label bindings only if the neighbor preserved its
+        // forwarding state across the restart.
+        let recovery_time = nbr
+            .rcvd_ft_session
+            .as_ref()
+            .filter(|_| instance.config.gr.enabled)
+            .filter(|tlv| tlv.flags.contains(FtFlags::LEARN))
+            .map(|tlv| tlv.recovery_time)
+            .filter(|recovery_time| *recovery_time != 0);
+        match recovery_time {
+            Some(recovery_time) => {
+                nbr.gr_recovery_start(
+                    Duration::from_millis(recovery_time.into()),
+                    &instance.tx.protocol_input.nbr_gr_timeout,
+                );
+            }
+            None => {
+                Neighbor::gr_purge_stale(instance, nbr_idx);
+            }
+        }
+    }
+
     Neighbor::fsm(instance, nbr_idx, fsm::Event::InitRcvd);
 
     Ok(())
@@ -698,57 +881,8 @@ fn process_nbr_msg_address(
             .collect::<Vec<IpAddr>>(),
     };
 
-    // Reevaluate all label mappings received from this neighbor.
-    for (prefix, mapping) in &nbr.rcvd_mappings {
-        let fec = instance.state.fecs.get_mut(prefix).unwrap();
-        let old_fec_status = fec.is_operational();
-
-        for nexthop in fec.nexthops.values_mut() {
-            for addr in &addr_list {
-                if nexthop.addr != *addr {
-                    continue;
-                }
-
-                match msg.msg_type {
-                    AddressMessageType::Address => {
-                        nexthop.set_label(Some(mapping.label));
-                        ibus::tx::label_install(
-                            &instance.tx.ibus,
-                            &fec.inner,
-                            nexthop,
-                        );
-                    }
-                    AddressMessageType::AddressWithdraw => {
-                        ibus::tx::label_uninstall(
-                            &instance.tx.ibus,
-                            &fec.inner,
-                            nexthop,
-                        );
-                        nexthop.set_label(None);
-                    }
-                }
-            }
-        }
-
-        if old_fec_status != fec.is_operational() {
-            notification::mpls_ldp_fec_event(
-                &instance.tx.nb,
-                instance.name,
-                fec,
-            );
-        }
-    }
-
-    match msg.msg_type {
-        AddressMessageType::Address => {
-            // Add new addresses.
-            nbr.addr_list.extend(addr_list);
-        }
-        AddressMessageType::AddressWithdraw => {
-            // Remove addresses.
-            nbr.addr_list.retain(|addr| !addr_list.contains(addr));
-        }
-    }
+    // Update the neighbor's address list.
+    Neighbor::addr_list_update(instance, nbr_idx, msg.msg_type, addr_list);
 
     Ok(())
 }
@@ -832,6 +966,17 @@ pub(crate) fn process_nbr_msg_label_mapping(
         o.remove_entry();
     }
 
+    // Release label mappings rejected by the label acceptance policy.
+    if !policy::label_accept_check(
+        &prefix,
+        nbr.lsr_id,
+        instance.config,
+        &instance.shared.policy_match_sets,
+    ) {
+        nbr.send_label_release(&instance.state.msg_id, fec_elem, Some(label));
+        return;
+    }
+
     // LMp.3 - LMp.8: loop detection - unnecessary for frame-mode MPLS networks.
 
     // LMp.9: does LSR have a previously received label mapping for FEC from
@@ -950,6 +1095,18 @@ fn process_nbr_msg_label_request(
         return;
     }
 
+    // Ignore requests for labels suppressed by the local label policy.
+    if fec.inner.local_label.is_none()
+        || !policy::label_advertise_check(
+            &prefix,
+            nbr.lsr_id,
+            instance.config,
+            &instance.shared.policy_match_sets,
+        )
+    {
+        return;
+    }
+
     // LRq.6: first check if we have a pending request running.
     match nbr.rcvd_requests.entry(prefix) {
         btree_map::Entry::Occupied(_) => {
@@ -994,6 +1151,18 @@ fn process_nbr_msg_label_request_wcard(
             continue;
         }
 
+        // Skip labels suppressed by the local label policy.
+        if fec.inner.local_label.is_none()
+            || !policy::label_advertise_check(
+                &fec.inner.prefix,
+                nbr.lsr_id,
+                instance.config,
+                &instance.shared.policy_match_sets,
+            )
+        {
+            continue;
+        }
+
         // LRq.6: first check if we have a pending request running.
         match nbr.rcvd_requests.entry(*fec.inner.prefix) {
             btree_map::Entry::Occupied(_) => {
@@ -1297,18 +1466,70 @@ pub(crate) fn process_nbr_backoff_timeout(
     lsr_id: Ipv4Addr,
 ) {
     // Lookup neighbor.
-    let (_, nbr) = match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+    let (nbr_idx, nbr) = match instance.state.neighbors.get_by_lsr_id(&lsr_id) {
         Some(value) => value,
         None => return,
     };
 
     Debug::NbrInitBackoffTimeout(&nbr.lsr_id).log();
 
+    let local_trans_addr =
+        instance.state.trans_addr(nbr.trans_addr.address_family());
+    let nbr = &mut instance.state.neighbors[nbr_idx];
     nbr.tasks.backoff_timeout = None;
+    let Some(local_trans_addr) = local_trans_addr else {
+        return;
+    };
     let password = instance.config.get_neighbor_password(nbr.lsr_id);
     nbr.connect(
-        instance.state.ipv4.trans_addr,
+        local_trans_addr,
         password,
         &instance.tx.protocol_input.tcp_connect,
     );
 }
+
+// ===== neighbor graceful restart timeout =====
+
+pub(crate) fn process_nbr_gr_timeout(
+    instance: &mut InstanceUpView<'_>,
+    lsr_id: Ipv4Addr,
+) {
+    // Lookup neighbor.
+    let Some((nbr_idx, _)) = instance.state.neighbors.get_by_lsr_id(&lsr_id)
+    else {
+        return;
+    };
+
+    // Purge the stale label bindings that weren't refreshed in time.
+    Neighbor::gr_purge_stale(instance, nbr_idx);
+
+    // Delete the neighbor if its last adjacency was deleted in the meantime.
+    Neighbors::delete_check(instance, &lsr_id, StatusCode::Shutdown);
+}
+
+// ===== neighbor session protection timeout =====
+
+pub(crate) fn process_nbr_protection_timeout(
+    instance: &mut InstanceUpView<'_>,
+    tneighbors: &mut TargetedNbrs,
+    lsr_id: Ipv4Addr,
+) {
+    // Lookup neighbor.
+    let Some((_, nbr)) = instance.state.neighbors.get_mut_by_lsr_id(&lsr_id)
+    else {
+        return;
+    };
+
+    Debug::NbrProtectionTimeout(&lsr_id).log();
+    nbr.protection_timeout = None;
+
+    // Stop the targeted neighbor protecting the session. The session is torn
+    // down along with its last adjacency.
+    if let Some((tnbr_idx, tnbr)) =
+        tneighbors.get_mut_by_addr(&IpAddr::from(lsr_id))
+        && tnbr.session_protection
+    {
+        tnbr.session_protection = false;
+        TargetedNbr::update(instance, tneighbors, tnbr_idx);
+    }
+}
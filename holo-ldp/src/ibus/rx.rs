@@ -7,6 +7,7 @@
 use std::net::Ipv4Addr;
 use std::sync::Mutex;
 
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::{Label, LabelManager};
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
@@ -21,11 +22,15 @@ use crate::fec::Fec;
 use crate::instance::{Instance, InstanceUpView};
 use crate::northbound::notification;
 use crate::packet::AddressMessageType;
-use crate::{events, ibus};
+use crate::packet::messages::address::TlvAddressList;
+use crate::{events, ibus, interface, policy};
 
 // ===== helper functions =====
 
-fn local_label_update(fec: &mut Fec, label_manager: &Mutex<LabelManager>) {
+pub(crate) fn local_label_update(
+    fec: &mut Fec,
+    label_manager: &Mutex<LabelManager>,
+) {
     if fec.inner.local_label.is_some() {
         return;
     }
@@ -47,14 +52,21 @@ fn process_new_fec(instance: &mut InstanceUpView<'_>, prefix: IpNetwork) {
     let fec = instance.state.fecs.get_mut(&prefix).unwrap();
 
     // FEC.1: perform lsr label distribution procedure.
-    let msg_id = &instance.state.msg_id;
-    for nbr in instance
-        .state
-        .neighbors
-        .iter_mut()
-        .filter(|nbr| nbr.is_operational())
-    {
-        nbr.send_label_mapping(msg_id, fec);
+    if fec.inner.local_label.is_some() {
+        let msg_id = &instance.state.msg_id;
+        let adjacencies = &instance.state.adjacencies;
+        for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+            nbr.is_operational()
+                && adjacencies.has_af(&nbr.lsr_id, prefix.address_family())
+                && policy::label_advertise_check(
+                    &prefix,
+                    nbr.lsr_id,
+                    instance.config,
+                    &instance.shared.policy_match_sets,
+                )
+        }) {
+            nbr.send_label_mapping(msg_id, fec);
+        }
     }
 
     for nexthop_addr in fec.nexthops.keys().copied().collect::<Vec<_>>() {
@@ -76,6 +88,25 @@ fn process_new_fec(instance: &mut InstanceUpView<'_>, prefix: IpNetwork) {
     }
 }
 
+// Sends an Address or Address Withdraw message to all operational neighbors
+// that have Hello adjacencies of the same address family.
+fn send_address(
+    instance: &mut InstanceUpView<'_>,
+    msg_type: AddressMessageType,
+    addr_list: TlvAddressList,
+) {
+    let af = match addr_list {
+        TlvAddressList::Ipv4(_) => AddressFamily::Ipv4,
+        TlvAddressList::Ipv6(_) => AddressFamily::Ipv6,
+    };
+    let adjacencies = &instance.state.adjacencies;
+    for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+        nbr.is_operational() && adjacencies.has_af(&nbr.lsr_id, af)
+    }) {
+        nbr.send_address(&instance.state.msg_id, msg_type, addr_list.clone());
+    }
+}
+
 // ===== global functions =====
 
 pub(crate) fn process_router_id_update(
@@ -86,6 +117,18 @@ pub(crate) fn process_router_id_update(
     instance.update();
 }
 
+pub(crate) fn process_ldp_igp_sync_req(instance: &mut Instance) {
+    instance.system.igp_sync_requested = true;
+
+    let Some((instance, interfaces, _)) = instance.as_up() else {
+        return;
+    };
+
+    for iface in interfaces.iter().filter(|iface| iface.is_active()) {
+        interface::igp_sync_update(&instance, &iface.name);
+    }
+}
+
 pub(crate) fn process_iface_update(
     instance: &mut Instance,
     msg: InterfaceUpdateMsg,
@@ -114,22 +157,24 @@ pub(crate) fn process_addr_add(instance: &mut Instance, msg: AddressMsg) {
                 && instance.system.ipv4_addr_list.insert(addr)
             {
                 // Inform neighbors about new address.
-                for nbr in instance
-                    .state
-                    .neighbors
-                    .iter_mut()
-                    .filter(|nbr| nbr.is_operational())
-                {
-                    nbr.send_address(
-                        &instance.state.msg_id,
-                        AddressMessageType::Address,
-                        btreeset![addr.ip()],
-                    )
-                }
+                send_address(
+                    &mut instance,
+                    AddressMessageType::Address,
+                    TlvAddressList::Ipv4(btreeset![addr.ip()]),
+                );
             }
         }
         IpNetwork::V6(addr) => {
-            instance.system.ipv6_addr_list.insert(addr);
+            if instance.system.ipv6_addr_list.insert(addr)
+                && !addr.ip().is_unicast_link_local()
+            {
+                // Inform neighbors about new address.
+                send_address(
+                    &mut instance,
+                    AddressMessageType::Address,
+                    TlvAddressList::Ipv6(btreeset![addr.ip()]),
+                );
+            }
         }
     }
 
@@ -142,7 +187,10 @@ pub(crate) fn process_addr_add(instance: &mut Instance, msg: AddressMsg) {
                 }
             }
             IpNetwork::V6(addr) => {
-                iface.system.ipv6_addr_list.insert(addr);
+                if iface.system.ipv6_addr_list.insert(addr) {
+                    // Check if LDP needs to be activated on this interface.
+                    iface.update(&mut instance);
+                }
             }
         }
     }
@@ -160,22 +208,24 @@ pub(crate) fn process_addr_del(instance: &mut Instance, msg: AddressMsg) {
                 && instance.system.ipv4_addr_list.remove(&addr)
             {
                 // Inform neighbors about deleted address.
-                for nbr in instance
-                    .state
-                    .neighbors
-                    .iter_mut()
-                    .filter(|nbr| nbr.is_operational())
-                {
-                    nbr.send_address(
-                        &instance.state.msg_id,
-                        AddressMessageType::AddressWithdraw,
-                        btreeset![addr.ip()],
-                    )
-                }
+                send_address(
+                    &mut instance,
+                    AddressMessageType::AddressWithdraw,
+                    TlvAddressList::Ipv4(btreeset![addr.ip()]),
+                );
             }
         }
         IpNetwork::V6(addr) => {
-            instance.system.ipv6_addr_list.remove(&addr);
+            if instance.system.ipv6_addr_list.remove(&addr)
+                && !addr.ip().is_unicast_link_local()
+            {
+                // Inform neighbors about deleted address.
+                send_address(
+                    &mut instance,
+                    AddressMessageType::AddressWithdraw,
+                    TlvAddressList::Ipv6(btreeset![addr.ip()]),
+                );
+            }
         }
     }
 
@@ -188,7 +238,10 @@ pub(crate) fn process_addr_del(instance: &mut Instance, msg: AddressMsg) {
                 }
             }
             IpNetwork::V6(addr) => {
-                iface.system.ipv6_addr_list.remove(&addr);
+                if iface.system.ipv6_addr_list.remove(&addr) {
+                    // Check if LDP needs to be disabled on this interface.
+                    iface.update(&mut instance);
+                }
             }
         }
     }
@@ -242,7 +295,13 @@ pub(crate) fn process_route_add(instance: &mut Instance, msg: RouteMsg) {
     }
 
     // Allocate new label if necessary.
-    local_label_update(fec, &instance.shared.label_manager);
+    if policy::label_assign_check(
+        &prefix,
+        instance.config,
+        &instance.shared.policy_match_sets,
+    ) {
+        local_label_update(fec, &instance.shared.label_manager);
+    }
     process_new_fec(&mut instance, prefix);
 }
 
@@ -256,14 +315,21 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
         let old_fec_status = fec.is_operational();
 
         // Withdraw previously allocated label.
-        let msg_id = &instance.state.msg_id;
-        for nbr in instance
-            .state
-            .neighbors
-            .iter_mut()
-            .filter(|nbr| nbr.is_operational())
-        {
-            nbr.send_label_withdraw(msg_id, fec);
+        if fec.inner.local_label.is_some() {
+            let msg_id = &instance.state.msg_id;
+            let adjacencies = &instance.state.adjacencies;
+            for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+                nbr.is_operational()
+                    && adjacencies.has_af(&nbr.lsr_id, prefix.address_family())
+                    && policy::label_advertise_check(
+                        &prefix,
+                        nbr.lsr_id,
+                        instance.config,
+                        &instance.shared.policy_match_sets,
+                    )
+            }) {
+                nbr.send_label_withdraw(msg_id, fec);
+            }
         }
 
         // Uninstall learned labels.
@@ -280,6 +346,7 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
 
         // Delete nexthops.
         fec.nexthops.clear();
+        fec.inner.protocol = None;
 
         if old_fec_status != fec.is_operational() {
             notification::mpls_ldp_fec_event(
@@ -290,3 +357,161 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
         }
     }
 }
+
+// Reevaluates the label assignment, advertisement and acceptance policies.
+pub(crate) fn process_label_policy_update(instance: &mut Instance) {
+    let Some((instance, _, _)) = instance.as_up() else {
+        return;
+    };
+    let config = instance.config;
+    let match_sets = &instance.shared.policy_match_sets;
+    let msg_id = &instance.state.msg_id;
+    let adjacencies = &instance.state.adjacencies;
+
+    // Reevaluate the local label assignments.
+    for fec in instance
+        .state
+        .fecs
+        .values_mut()
+        .filter(|fec| fec.inner.protocol.is_some())
+    {
+        let prefix = *fec.inner.prefix;
+        let assign = policy::label_assign_check(&prefix, config, match_sets);
+        match (assign, fec.inner.local_label) {
+            (true, None) => {
+                // Allocate new label and install the learned labels.
+                local_label_update(fec, &instance.shared.label_manager);
+                for nexthop in fec.nexthops.values() {
+                    ibus::tx::label_install(
+                        &instance.tx.ibus,
+                        &fec.inner,
+                        nexthop,
+                    );
+                }
+            }
+            (false, Some(local_label)) => {
+                // Withdraw previously advertised label.
+                for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+                    nbr.sent_mappings.contains_key(&prefix)
+                        && !nbr.sent_withdraws.contains_key(&prefix)
+                }) {
+                    nbr.send_label_withdraw(msg_id, fec);
+                }
+
+                // Uninstall learned labels.
+                for nexthop in fec.nexthops.values() {
+                    ibus::tx::label_uninstall(
+                        &instance.tx.ibus,
+                        &fec.inner,
+                        nexthop,
+                    );
+                }
+
+                // Release FEC's local label.
+                let mut label_manager =
+                    instance.shared.label_manager.lock().unwrap();
+                label_manager.label_release(local_label);
+                Debug::FecLabelUpdate(fec, &None).log();
+                fec.inner.local_label = None;
+            }
+            _ => {}
+        }
+    }
+
+    for nbr in instance
+        .state
+        .neighbors
+        .iter_mut()
+        .filter(|nbr| nbr.is_operational())
+    {
+        let lsr_id = nbr.lsr_id;
+
+        // Reevaluate the label advertisements.
+        for fec in instance.state.fecs.values_mut().filter(|fec| {
+            fec.inner.protocol.is_some()
+                && fec.inner.local_label.is_some()
+                && adjacencies
+                    .has_af(&lsr_id, fec.inner.prefix.address_family())
+        }) {
+            let prefix = *fec.inner.prefix;
+            let advertise = policy::label_advertise_check(
+                &prefix, lsr_id, config, match_sets,
+            );
+            let advertised = nbr.sent_mappings.contains_key(&prefix);
+            if advertise && !advertised {
+                nbr.send_label_mapping(msg_id, fec);
+            } else if !advertise
+                && advertised
+                && !nbr.sent_withdraws.contains_key(&prefix)
+            {
+                nbr.send_label_withdraw(msg_id, fec);
+            }
+        }
+
+        // Release the received label mappings that are no longer accepted.
+        let rejected = nbr
+            .rcvd_mappings
+            .iter()
+            .filter(|(prefix, _)| {
+                !policy::label_accept_check(
+                    prefix, nbr.lsr_id, config, match_sets,
+                )
+            })
+            .map(|(prefix, mapping)| (*prefix, mapping.label))
+            .collect::<Vec<_>>();
+        for (prefix, label) in rejected {
+            if let Some(fec) = instance.state.fecs.get_mut(&prefix) {
+                let old_fec_status = fec.is_operational();
+
+                // Uninstall learned labels.
+                for nexthop in fec.nexthops.values_mut() {
+                    if !nbr.addr_list.contains(&nexthop.addr) {
+                        continue;
+                    }
+
+                    ibus::tx::label_uninstall(
+                        &instance.tx.ibus,
+                        &fec.inner,
+                        nexthop,
+                    );
+                    nexthop.set_label(None);
+                }
+
+                if old_fec_status != fec.is_operational() {
+                    notification::mpls_ldp_fec_event(
+                        &instance.tx.nb,
+                        instance.name,
+                        fec,
+                    );
+                }
+
+                fec.inner.downstream.remove(&nbr.lsr_id);
+            }
+            nbr.rcvd_mappings.remove(&prefix);
+            nbr.send_label_release(msg_id, prefix.into(), Some(label));
+        }
+
+        // Request the missing labels from the downstream neighbor, as the
+        // corresponding mappings might have been released earlier.
+        let missing = instance
+            .state
+            .fecs
+            .values()
+            .filter(|fec| {
+                let prefix = &*fec.inner.prefix;
+                fec.inner.protocol.is_some()
+                    && fec.is_nbr_nexthop(nbr)
+                    && !nbr.rcvd_mappings.contains_key(prefix)
+                    && !nbr.sent_requests.contains_key(prefix)
+                    && adjacencies.has_af(&nbr.lsr_id, prefix.address_family())
+                    && policy::label_accept_check(
+                        prefix, nbr.lsr_id, config, match_sets,
+                    )
+            })
+            .map(|fec| *fec.inner.prefix)
+            .collect::<Vec<_>>();
+        for prefix in missing {
+            nbr.send_label_request(msg_id, prefix);
+        }
+    }
+}
@@ -6,6 +6,7 @@
 
 use holo_utils::ibus::IbusChannelsTx;
 use holo_utils::ip::AddressFamily;
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{self, LabelInstallMsg, LabelUninstallMsg};
 
@@ -17,14 +18,25 @@ pub(crate) fn router_id_sub(ibus_tx: &IbusChannelsTx) {
     ibus_tx.router_id_sub();
 }
 
-pub(crate) fn route_redistribute_sub(ibus_tx: &IbusChannelsTx) {
+pub(crate) fn route_redistribute_sub(
+    ibus_tx: &IbusChannelsTx,
+    af: AddressFamily,
+) {
     for protocol in
         Protocol::route_types().filter(|protocol| *protocol != Protocol::BGP)
     {
-        ibus_tx.route_redistribute_sub(protocol, Some(AddressFamily::Ipv4));
+        ibus_tx.route_redistribute_sub(protocol, Some(af));
     }
 }
 
+pub(crate) fn ldp_igp_sync_upd(
+    ibus_tx: &IbusChannelsTx,
+    ifname: &str,
+    state: LdpIgpSyncState,
+) {
+    ibus_tx.ldp_igp_sync_upd(ifname.to_owned(), state);
+}
+
 pub(crate) fn label_install(
     ibus_tx: &IbusChannelsTx,
     fec: &FecInner,
@@ -9,11 +9,11 @@ use std::net::{IpAddr, Ipv4Addr};
 use std::sync::Arc;
 use std::sync::atomic::{self, AtomicU32};
 
-use derive_new::new;
 use holo_protocol::{
     InstanceChannelsTx, InstanceShared, MessageReceiver, ProtocolInstance,
 };
 use holo_utils::ibus::IbusMsg;
+use holo_utils::ip::{AddressFamily, IpAddrExt, IpAddrKind};
 use holo_utils::protocol::Protocol;
 use holo_utils::socket::{TcpListener, UdpSocket};
 use holo_utils::task::Task;
@@ -26,11 +26,13 @@ use crate::debug::{Debug, InstanceInactiveReason, InterfaceInactiveReason};
 use crate::discovery::TargetedNbr;
 use crate::error::{Error, IoError};
 use crate::fec::Fec;
+use crate::neighbor::Neighbor;
 use crate::network::{tcp, udp};
 use crate::northbound::configuration::InstanceCfg;
 use crate::tasks::messages::input::{
-    AdjTimeoutMsg, NbrBackoffTimeoutMsg, NbrKaTimeoutMsg, NbrRxPduMsg,
-    TcpAcceptMsg, TcpConnectMsg, UdpRxPduMsg,
+    AdjTimeoutMsg, NbrBackoffTimeoutMsg, NbrGrTimeoutMsg, NbrKaTimeoutMsg,
+    NbrProtectionTimeoutMsg, NbrRxPduMsg, TcpAcceptMsg, TcpConnectMsg,
+    UdpRxPduMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::{events, ibus, tasks};
@@ -60,6 +62,8 @@ pub struct InstanceSys {
     pub router_id: Option<Ipv4Addr>,
     pub ipv4_addr_list: BTreeSet<Ipv4Network>,
     pub ipv6_addr_list: BTreeSet<Ipv6Network>,
+    // Whether LDP-IGP synchronization updates were requested by an IGP.
+    pub igp_sync_requested: bool,
 }
 
 #[derive(Debug)]
@@ -74,12 +78,18 @@ pub struct InstanceState {
     pub neighbors: Neighbors,
     // Known FECs and their associated label mappings.
     pub fecs: BTreeMap<IpNetwork, Fec>,
+    // Discovery adjacencies.
+    pub adjacencies: Adjacencies,
     // IPv4 instance state.
-    pub ipv4: InstanceIpv4State,
+    pub ipv4: Option<InstanceAfState>,
+    // IPv6 instance state.
+    pub ipv6: Option<InstanceAfState>,
+    // Preferred transport connection address family (dual-stack only).
+    pub trans_pref: Option<AddressFamily>,
 }
 
-#[derive(Debug, new)]
-pub struct InstanceIpv4State {
+#[derive(Debug)]
+pub struct InstanceAfState {
     // UDP discovery socket.
     pub disc_socket: Arc<UdpSocket>,
     // UDP extended discovery socket.
@@ -92,11 +102,8 @@ pub struct InstanceIpv4State {
     _edisc_rx_task: Task<()>,
     // TCP listener task.
     _tcp_listener_task: Task<()>,
-    // IPv4 transport address.
-    pub trans_addr: Ipv4Addr,
-    // Discovery adjacencies,
-    #[new(default)]
-    pub adjacencies: Adjacencies,
+    // Transport address.
+    pub trans_addr: IpAddr,
 }
 
 #[derive(Clone, Debug)]
@@ -115,6 +122,10 @@ pub struct ProtocolInputChannelsTx {
     pub nbr_ka_timeout: Sender<NbrKaTimeoutMsg>,
     // Neighbor backoff timeout event.
     pub nbr_backoff_timeout: Sender<NbrBackoffTimeoutMsg>,
+    // Neighbor graceful restart timeout event.
+    pub nbr_gr_timeout: Sender<NbrGrTimeoutMsg>,
+    // Neighbor session protection timeout event.
+    pub nbr_protection_timeout: Sender<NbrProtectionTimeoutMsg>,
 }
 
 #[derive(Debug)]
@@ -133,6 +144,10 @@ pub struct ProtocolInputChannelsRx {
     pub nbr_ka_timeout: Receiver<NbrKaTimeoutMsg>,
     // Neighbor backoff timeout event.
     pub nbr_backoff_timeout: Receiver<NbrBackoffTimeoutMsg>,
+    // Neighbor graceful restart timeout event.
+    pub nbr_gr_timeout: Receiver<NbrGrTimeoutMsg>,
+    // Neighbor session protection timeout event.
+    pub nbr_protection_timeout: Receiver<NbrProtectionTimeoutMsg>,
 }
 
 pub struct InstanceUpView<'a> {
@@ -158,6 +173,22 @@ impl Instance {
             Ok(()) if !self.is_active() => {
                 self.try_start(router_id.unwrap());
             }
+            Ok(()) if self.is_transport_outdated() => {
+                // Restart the instance using the new transport parameters.
+                self.stop(InstanceInactiveReason::TransportUpdate);
+                self.try_start(router_id.unwrap());
+
+                // Request the IPv4 routes flushed along with the previous
+                // instance state.
+                if let Some(state) = &self.state
+                    && state.ipv4.is_some()
+                {
+                    ibus::tx::route_redistribute_sub(
+                        &self.tx.ibus,
+                        AddressFamily::Ipv4,
+                    );
+                }
+            }
             Err(reason) if self.is_active() => {
                 self.stop(reason);
             }
@@ -166,13 +197,20 @@ impl Instance {
     }
 
     fn try_start(&mut self, router_id: Ipv4Addr) {
-        let trans_addr = router_id;
         let proto_input_tx = &self.tx.protocol_input;
 
-        match InstanceState::new(router_id, trans_addr, proto_input_tx) {
+        match InstanceState::new(router_id, &self.config, proto_input_tx) {
             Ok(state) => {
                 Debug::InstanceStart.log();
 
+                // Subscribe for the redistribution of IPv6 routes.
+                if state.ipv6.is_some() {
+                    ibus::tx::route_redistribute_sub(
+                        &self.tx.ibus,
+                        AddressFamily::Ipv6,
+                    );
+                }
+
                 // Store instance initial state.
                 self.state = Some(state);
 
@@ -213,6 +251,11 @@ impl Instance {
             }
         }
 
+        // Purge the stale label bindings of restarting neighbors.
+        for nbr_idx in instance.state.neighbors.indexes().collect::<Vec<_>>() {
+            Neighbor::gr_purge_stale(&mut instance, nbr_idx);
+        }
+
         // Clear instance state.
         self.state = None;
     }
@@ -226,8 +269,8 @@ impl Instance {
         &self,
         router_id: Option<Ipv4Addr>,
     ) -> Result<(), InstanceInactiveReason> {
-        if self.config.ipv4.is_none()
-            || !self.config.ipv4.as_ref().unwrap().enabled
+        if !self.config.is_af_enabled(AddressFamily::Ipv4)
+            && !self.config.is_af_enabled(AddressFamily::Ipv6)
         {
             return Err(InstanceInactiveReason::AdminDown);
         }
@@ -239,6 +282,20 @@ impl Instance {
         Ok(())
     }
 
+    // Returns whether the transport parameters in use differ from the
+    // configured ones.
+    fn is_transport_outdated(&self) -> bool {
+        let state = self.state.as_ref().unwrap();
+        let ipv4_trans_addr = state.ipv4.as_ref().map(|af| af.trans_addr);
+        let ipv6_trans_addr = state.ipv6.as_ref().map(|af| af.trans_addr);
+
+        ipv4_trans_addr
+            != self.config.trans_addr(AddressFamily::Ipv4, state.router_id)
+            || ipv6_trans_addr
+                != self.config.trans_addr(AddressFamily::Ipv6, state.router_id)
+            || state.trans_pref != self.config.trans_pref()
+    }
+
     fn get_router_id(&self) -> Option<Ipv4Addr> {
         self.config.router_id.or(self.system.router_id)
     }
@@ -294,7 +351,7 @@ impl ProtocolInstance for Instance {
         ibus::tx::router_id_sub(&self.tx.ibus);
 
         // Subscribe for the redistribution of all non-BGP routes.
-        ibus::tx::route_redistribute_sub(&self.tx.ibus);
+        ibus::tx::route_redistribute_sub(&self.tx.ibus, AddressFamily::Ipv4);
     }
 
     fn shutdown(mut self) {
@@ -328,6 +385,9 @@ impl ProtocolInstance for Instance {
         let (nbr_pdu_rxp, nbr_pdu_rxc) = mpsc::channel(4);
         let (nbr_ka_timeoutp, nbr_ka_timeoutc) = mpsc::channel(4);
         let (nbr_backoff_timeoutp, nbr_backoff_timeoutc) = mpsc::channel(4);
+        let (nbr_gr_timeoutp, nbr_gr_timeoutc) = mpsc::channel(4);
+        let (nbr_protection_timeoutp, nbr_protection_timeoutc) =
+            mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             udp_pdu_rx: udp_pdu_rxp,
@@ -337,6 +397,8 @@ impl ProtocolInstance for Instance {
             nbr_pdu_rx: nbr_pdu_rxp,
             nbr_ka_timeout: nbr_ka_timeoutp,
             nbr_backoff_timeout: nbr_backoff_timeoutp,
+            nbr_gr_timeout: nbr_gr_timeoutp,
+            nbr_protection_timeout: nbr_protection_timeoutp,
         };
         let rx = ProtocolInputChannelsRx {
             udp_pdu_rx: udp_pdu_rxc,
@@ -346,6 +408,8 @@ impl ProtocolInstance for Instance {
             nbr_pdu_rx: nbr_pdu_rxc,
             nbr_ka_timeout: nbr_ka_timeoutc,
             nbr_backoff_timeout: nbr_backoff_timeoutc,
+            nbr_gr_timeout: nbr_gr_timeoutc,
+            nbr_protection_timeout: nbr_protection_timeoutc,
         };
 
         (tx, rx)
@@ -375,6 +439,62 @@ impl InstanceCfg {
 
         self.password.as_deref()
     }
+
+    // Returns whether the given address family is enabled.
+    pub(crate) fn is_af_enabled(&self, af: AddressFamily) -> bool {
+        match af {
+            AddressFamily::Ipv4 => {
+                self.ipv4.as_ref().is_some_and(|ipv4| ipv4.enabled)
+            }
+            AddressFamily::Ipv6 => self
+                .ipv6
+                .as_ref()
+                .is_some_and(|ipv6| ipv6.enabled && ipv6.trans_addr.is_some()),
+        }
+    }
+
+    // Returns the transport address of the given address family, or `None` if
+    // the address family is disabled.
+    //
+    // The LSR-ID is used as the IPv4 transport address unless one is
+    // explicitly configured.
+    pub(crate) fn trans_addr(
+        &self,
+        af: AddressFamily,
+        router_id: Ipv4Addr,
+    ) -> Option<IpAddr> {
+        if !self.is_af_enabled(af) {
+            return None;
+        }
+
+        match af {
+            AddressFamily::Ipv4 => {
+                let ipv4 = self.ipv4.as_ref().unwrap();
+                Some(ipv4.trans_addr.unwrap_or(router_id).into())
+            }
+            AddressFamily::Ipv6 => {
+                let ipv6 = self.ipv6.as_ref().unwrap();
+                ipv6.trans_addr.map(IpAddr::V6)
+            }
+        }
+    }
+
+    // Returns the preferred address family for transport connection
+    // establishment, or `None` if the LSR isn't dual-stack.
+    pub(crate) fn trans_pref(&self) -> Option<AddressFamily> {
+        if !self.is_af_enabled(AddressFamily::Ipv4)
+            || !self.is_af_enabled(AddressFamily::Ipv6)
+        {
+            return None;
+        }
+
+        let trans_pref = if self.dual_stack.prefer_ipv4 {
+            AddressFamily::Ipv4
+        } else {
+            AddressFamily::Ipv6
+        };
+        Some(trans_pref)
+    }
 }
 
 // ===== impl InstanceState =====
@@ -382,17 +502,83 @@ impl InstanceCfg {
 impl InstanceState {
     fn new(
         router_id: Ipv4Addr,
-        trans_addr: Ipv4Addr,
+        config: &InstanceCfg,
         proto_input_tx: &ProtocolInputChannelsTx,
     ) -> Result<InstanceState, Error> {
+        let ipv4 = config
+            .trans_addr(AddressFamily::Ipv4, router_id)
+            .map(|trans_addr| InstanceAfState::new(trans_addr, proto_input_tx))
+            .transpose()?;
+        let ipv6 = config
+            .trans_addr(AddressFamily::Ipv6, router_id)
+            .map(|trans_addr| InstanceAfState::new(trans_addr, proto_input_tx))
+            .transpose()?;
+
+        Ok(InstanceState {
+            msg_id: Arc::new(AtomicU32::new(0)),
+            cfg_seqno: 0,
+            router_id,
+            neighbors: Default::default(),
+            fecs: Default::default(),
+            adjacencies: Default::default(),
+            ipv4,
+            ipv6,
+            trans_pref: config.trans_pref(),
+        })
+    }
+
+    // Returns the state of the given address family, if it's enabled.
+    pub(crate) fn af(&self, af: AddressFamily) -> Option<&InstanceAfState> {
+        match af {
+            AddressFamily::Ipv4 => self.ipv4.as_ref(),
+            AddressFamily::Ipv6 => self.ipv6.as_ref(),
+        }
+    }
+
+    // Returns the local transport address of the given address family, if
+    // it's enabled.
+    pub(crate) fn trans_addr(&self, af: AddressFamily) -> Option<IpAddr> {
+        self.af(af).map(|af_state| af_state.trans_addr)
+    }
+
+    // Updates the TCP MD5 password of the given neighbor in the listening
+    // socket of its address family.
+    pub(crate) fn listen_socket_md5sig_update(
+        &self,
+        nbr_addr: &IpAddr,
+        password: Option<&str>,
+    ) {
+        if let Some(af_state) = self.af(nbr_addr.address_family()) {
+            tcp::listen_socket_md5sig_update(
+                &af_state.session_socket,
+                nbr_addr,
+                password,
+            );
+        }
+    }
+
+    pub(crate) fn get_next_msg_id(msg_id: &Arc<AtomicU32>) -> u32 {
+        msg_id.fetch_add(1, atomic::Ordering::Relaxed)
+    }
+}
+
+// ===== impl InstanceAfState =====
+
+impl InstanceAfState {
+    fn new(
+        trans_addr: IpAddr,
+        proto_input_tx: &ProtocolInputChannelsTx,
+    ) -> Result<InstanceAfState, Error> {
+        let af = trans_addr.address_family();
+
         // Create UDP/TCP sockets.
-        let disc_socket = udp::discovery_socket(IpAddr::from([0, 0, 0, 0]))
+        let disc_socket = udp::discovery_socket(IpAddr::unspecified(af))
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
-        let edisc_socket = udp::discovery_socket(IpAddr::V4(trans_addr))
+        let edisc_socket = udp::discovery_socket(trans_addr)
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
-        let session_socket = tcp::listen_socket(IpAddr::V4(trans_addr))
+        let session_socket = tcp::listen_socket(trans_addr)
             .map(Arc::new)
             .map_err(IoError::TcpSocketError)?;
 
@@ -406,27 +592,16 @@ impl InstanceState {
         let tcp_listener_task =
             tasks::tcp_listener(&session_socket, &proto_input_tx.tcp_accept);
 
-        Ok(InstanceState {
-            msg_id: Arc::new(AtomicU32::new(0)),
-            cfg_seqno: 0,
-            router_id,
-            neighbors: Default::default(),
-            fecs: Default::default(),
-            ipv4: InstanceIpv4State::new(
-                disc_socket,
-                edisc_socket,
-                session_socket,
-                disc_rx_task,
-                edisc_rx_task,
-                tcp_listener_task,
-                trans_addr,
-            ),
+        Ok(InstanceAfState {
+            disc_socket,
+            edisc_socket,
+            session_socket,
+            _disc_rx_task: disc_rx_task,
+            _edisc_rx_task: edisc_rx_task,
+            _tcp_listener_task: tcp_listener_task,
+            trans_addr,
         })
     }
-
-    pub(crate) fn get_next_msg_id(msg_id: &Arc<AtomicU32>) -> u32 {
-        msg_id.fetch_add(1, atomic::Ordering::Relaxed)
-    }
 }
 
 // ===== impl ProtocolInputChannelsRx =====
@@ -455,6 +630,12 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.nbr_backoff_timeout.recv() => {
                 msg.map(ProtocolInputMsg::NbrBackoffTimeout)
             }
+            msg = self.nbr_gr_timeout.recv() => {
+                msg.map(ProtocolInputMsg::NbrGrTimeout)
+            }
+            msg = self.nbr_protection_timeout.recv() => {
+                msg.map(ProtocolInputMsg::NbrProtectionTimeout)
+            }
         }
     }
 }
@@ -478,6 +659,10 @@ fn process_ibus_msg(
         IbusMsg::InterfaceAddressDel(msg) => {
             ibus::rx::process_addr_del(instance, msg);
         }
+        // LDP-IGP synchronization state request.
+        IbusMsg::LdpIgpSyncReq => {
+            ibus::rx::process_ldp_igp_sync_req(instance);
+        }
         // Router ID update notification.
         IbusMsg::RouterIdUpdate(router_id) => {
             ibus::rx::process_router_id_update(instance, router_id);
@@ -490,6 +675,14 @@ fn process_ibus_msg(
         IbusMsg::RouteRedistributeDel(msg) => {
             ibus::rx::process_route_del(instance, msg);
         }
+        // Policy match sets update event.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Reevaluate the label policies.
+            ibus::rx::process_label_policy_update(instance);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -511,6 +704,7 @@ fn process_protocol_msg(
                 interfaces,
                 tneighbors,
                 msg.src_addr,
+                msg.ifindex,
                 msg.pdu,
                 msg.multicast,
             );
@@ -544,6 +738,16 @@ fn process_protocol_msg(
         ProtocolInputMsg::NbrBackoffTimeout(msg) => {
             events::process_nbr_backoff_timeout(instance, msg.lsr_id);
         }
+        // Neighbor's graceful restart timeout has expired.
+        ProtocolInputMsg::NbrGrTimeout(msg) => {
+            events::process_nbr_gr_timeout(instance, msg.lsr_id);
+        }
+        // Neighbor's session protection timeout has expired.
+        ProtocolInputMsg::NbrProtectionTimeout(msg) => {
+            events::process_nbr_protection_timeout(
+                instance, tneighbors, msg.lsr_id,
+            );
+        }
     }
 
     Ok(())
@@ -5,28 +5,30 @@
 //
 
 use std::collections::BTreeSet;
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
 use std::time::Duration;
 
+use holo_utils::ip::{AddressFamily, IpAddrKind};
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::socket::{SocketExt, UdpSocket};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::task::IntervalTask;
 use ipnetwork::{Ipv4Network, Ipv6Network};
 
-use crate::collections::{InterfaceId, InterfaceIndex};
+use crate::collections::{AdjacencyIndex, InterfaceId};
 use crate::debug::{Debug, InterfaceInactiveReason};
 use crate::error::{Error, IoError};
 use crate::instance::{InstanceState, InstanceUpView};
 use crate::northbound::configuration::InterfaceCfg;
 use crate::packet::Pdu;
 use crate::packet::messages::hello::{
-    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo,
-    TlvIpv4TransAddr,
+    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo, TlvDualStack,
+    TlvIpv4TransAddr, TlvIpv6TransAddr,
 };
 use crate::packet::messages::notification::StatusCode;
-use crate::{discovery, network, tasks};
+use crate::{discovery, ibus, network, tasks};
 
 #[derive(Debug)]
 pub struct Interface {
@@ -34,7 +36,7 @@ pub struct Interface {
     pub name: String,
     pub system: InterfaceSys,
     pub config: InterfaceCfg,
-    pub state: Option<InterfaceState>,
+    pub state: InterfaceState,
 }
 
 #[derive(Debug, Default)]
@@ -45,8 +47,16 @@ pub struct InterfaceSys {
     pub ipv6_addr_list: BTreeSet<Ipv6Network>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct InterfaceState {
+    // IPv4 discovery state.
+    pub ipv4: Option<InterfaceAfState>,
+    // IPv6 discovery state.
+    pub ipv6: Option<InterfaceAfState>,
+}
+
+#[derive(Debug)]
+pub struct InterfaceAfState {
     // UDP discovery socket bound to this interface.
     pub disc_socket: Arc<UdpSocket>,
     // Hello Tx interval task.
@@ -66,23 +76,27 @@ impl Interface {
             name,
             system: InterfaceSys::default(),
             config: InterfaceCfg::default(),
-            state: None,
+            state: InterfaceState::default(),
         }
     }
 
-    fn start(&mut self, instance_state: &InstanceState) -> Result<(), Error> {
-        Debug::InterfaceStart(&self.name).log();
+    fn start(
+        &mut self,
+        instance_state: &InstanceState,
+        af: AddressFamily,
+    ) -> Result<(), Error> {
+        Debug::InterfaceStart(&self.name, af).log();
 
-        let disc_socket = network::udp::interface_discovery_socket(self)
+        let disc_socket = network::udp::interface_discovery_socket(self, af)
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
 
-        self.system
-            .join_multicast_ipv4(&instance_state.ipv4.disc_socket);
+        let instance_af = instance_state.af(af).unwrap();
+        self.system.join_multicast(&instance_af.disc_socket, af);
         let hello_interval_task =
-            tasks::iface_hello_interval(self, &disc_socket, instance_state);
+            tasks::iface_hello_interval(self, af, &disc_socket, instance_state);
 
-        self.state = Some(InterfaceState {
+        *self.state.af_mut(af) = Some(InterfaceAfState {
             disc_socket,
             hello_interval_task,
         });
@@ -95,19 +109,35 @@ impl Interface {
         instance: &mut InstanceUpView<'_>,
         reason: InterfaceInactiveReason,
     ) {
-        Debug::InterfaceStop(&self.name, reason).log();
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            if self.state.af(af).is_some() {
+                self.stop_af(instance, af, reason);
+            }
+        }
+    }
+
+    fn stop_af(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        af: AddressFamily,
+        reason: InterfaceInactiveReason,
+    ) {
+        Debug::InterfaceStop(&self.name, af, reason).log();
 
-        self.system
-            .leave_multicast_ipv4(&instance.state.ipv4.disc_socket);
-        self.state = None;
+        if let Some(instance_af) = instance.state.af(af) {
+            self.system.leave_multicast(&instance_af.disc_socket, af);
+        }
+        *self.state.af_mut(af) = None;
 
         // Delete adjacencies.
-        let adjacencies = &mut instance.state.ipv4.adjacencies;
+        let adjacencies = &mut instance.state.adjacencies;
         for adj_idx in adjacencies
             .get_by_iface(&self.name)
             .iter()
-            .flat_map(|adjs| adjs.values().cloned())
-            .collect::<Vec<InterfaceIndex>>()
+            .flat_map(|adjs| adjs.iter())
+            .filter(|(source, _)| source.addr.address_family() == af)
+            .map(|(_, adj_idx)| *adj_idx)
+            .collect::<Vec<AdjacencyIndex>>()
         {
             discovery::adjacency_delete(
                 instance,
@@ -115,50 +145,83 @@ impl Interface {
                 StatusCode::Shutdown,
             );
         }
+
+        // LDP is no longer operational on this interface.
+        if !self.is_active() && instance.system.igp_sync_requested {
+            ibus::tx::ldp_igp_sync_upd(
+                &instance.tx.ibus,
+                &self.name,
+                LdpIgpSyncState::NotRequired,
+            );
+        }
     }
 
-    // Enables or disables the interface if necessary.
+    // Enables or disables the interface address families if necessary.
     pub(crate) fn update(&mut self, instance: &mut InstanceUpView<'_>) {
-        match self.is_ready() {
-            Ok(()) if !self.is_active() => {
-                // Attempt to activate interface.
-                if let Err(error) = self.start(instance.state) {
-                    Error::InterfaceStartError(
-                        self.name.clone(),
-                        Box::new(error),
-                    )
-                    .log();
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            let is_active = self.state.af(af).is_some();
+            match self.is_ready(instance.state, af) {
+                Ok(()) if !is_active => {
+                    // Attempt to activate address family.
+                    match self.start(instance.state, af) {
+                        Ok(()) => {
+                            igp_sync_update(instance, &self.name);
+                        }
+                        Err(error) => {
+                            Error::InterfaceStartError(
+                                self.name.clone(),
+                                Box::new(error),
+                            )
+                            .log();
+                        }
+                    }
                 }
+                Err(reason) if is_active => {
+                    // Deactivate address family.
+                    self.stop_af(instance, af, reason);
+                }
+                _ => (),
             }
-            Err(reason) if self.is_active() => {
-                // Deactivate interface.
-                self.stop(instance, reason);
-            }
-            _ => (),
         }
     }
 
     pub(crate) fn sync_hello_tx(&mut self, instance_state: &InstanceState) {
-        let state = self.state.as_ref().unwrap();
-        let hello_interval_task = tasks::iface_hello_interval(
-            self,
-            &state.disc_socket,
-            instance_state,
-        );
-
-        let state = self.state.as_mut().unwrap();
-        state.hello_interval_task = hello_interval_task;
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            let Some(af_state) = self.state.af(af) else {
+                continue;
+            };
+            let hello_interval_task = tasks::iface_hello_interval(
+                self,
+                af,
+                &af_state.disc_socket,
+                instance_state,
+            );
+
+            let af_state = self.state.af_mut(af).as_mut().unwrap();
+            af_state.hello_interval_task = hello_interval_task;
+        }
     }
 
     pub(crate) fn is_active(&self) -> bool {
-        self.state.is_some()
+        self.state.ipv4.is_some() || self.state.ipv6.is_some()
     }
 
-    // Returns whether the interface is ready for LDP operation.
-    fn is_ready(&self) -> Result<(), InterfaceInactiveReason> {
-        if self.config.ipv4.is_none()
-            || !self.config.ipv4.as_ref().unwrap().enabled
-        {
+    // Returns whether the interface is ready for LDP operation over the given
+    // address family.
+    fn is_ready(
+        &self,
+        instance_state: &InstanceState,
+        af: AddressFamily,
+    ) -> Result<(), InterfaceInactiveReason> {
+        let enabled = match af {
+            AddressFamily::Ipv4 => {
+                self.config.ipv4.as_ref().is_some_and(|ipv4| ipv4.enabled)
+            }
+            AddressFamily::Ipv6 => {
+                self.config.ipv6.as_ref().is_some_and(|ipv6| ipv6.enabled)
+            }
+        };
+        if !enabled || instance_state.af(af).is_none() {
             return Err(InterfaceInactiveReason::AdminDown);
         }
 
@@ -170,7 +233,7 @@ impl Interface {
             return Err(InterfaceInactiveReason::MissingIfindex);
         }
 
-        if self.system.ipv4_addr_list.is_empty() {
+        if self.system.local_addr(af).is_none() {
             return Err(InterfaceInactiveReason::MissingIpAddress);
         }
 
@@ -179,8 +242,11 @@ impl Interface {
 
     pub(crate) fn generate_hello(
         &self,
+        af: AddressFamily,
         instance_state: &InstanceState,
     ) -> HelloMsg {
+        let trans_addr = instance_state.trans_addr(af).unwrap();
+
         HelloMsg {
             // The message ID will be overwritten later.
             msg_id: 0,
@@ -188,15 +254,18 @@ impl Interface {
                 holdtime: self.config.hello_holdtime,
                 flags: HelloFlags::GTSM,
             },
-            ipv4_addr: Some(TlvIpv4TransAddr(instance_state.ipv4.trans_addr)),
-            ipv6_addr: None,
+            ipv4_addr: Ipv4Addr::get(trans_addr).map(TlvIpv4TransAddr),
+            ipv6_addr: Ipv6Addr::get(trans_addr).map(TlvIpv6TransAddr),
             cfg_seqno: Some(TlvConfigSeqNo(instance_state.cfg_seqno)),
-            dual_stack: None,
+            dual_stack: instance_state
+                .trans_pref
+                .map(|af| TlvDualStack(af.into())),
         }
     }
 
     pub(crate) async fn send_hello(
         disc_socket: Arc<UdpSocket>,
+        af: AddressFamily,
         router_id: Ipv4Addr,
         msg_id: Arc<AtomicU32>,
         mut hello: HelloMsg,
@@ -211,7 +280,7 @@ impl Interface {
 
         // Send multicast packet.
         if let Err(error) =
-            network::udp::send_packet_multicast(&disc_socket, pdu).await
+            network::udp::send_packet_multicast(&disc_socket, af, pdu).await
         {
             IoError::UdpSendError(error).log();
         }
@@ -228,10 +297,28 @@ impl Interface {
         std::cmp::min(self.config.hello_holdtime, hello_holdtime)
     }
 
-    pub(crate) fn next_hello(&self) -> Option<Duration> {
+    pub(crate) fn next_hello(&self, af: AddressFamily) -> Option<Duration> {
         self.state
-            .as_ref()
-            .map(|state| state.hello_interval_task.remaining())
+            .af(af)
+            .map(|af_state| af_state.hello_interval_task.remaining())
+    }
+}
+
+// ===== impl InterfaceState =====
+
+impl InterfaceState {
+    pub(crate) fn af(&self, af: AddressFamily) -> Option<&InterfaceAfState> {
+        match af {
+            AddressFamily::Ipv4 => self.ipv4.as_ref(),
+            AddressFamily::Ipv6 => self.ipv6.as_ref(),
+        }
+    }
+
+    fn af_mut(&mut self, af: AddressFamily) -> &mut Option<InterfaceAfState> {
+        match af {
+            AddressFamily::Ipv4 => &mut self.ipv4,
+            AddressFamily::Ipv6 => &mut self.ipv6,
+        }
     }
 }
 
@@ -266,32 +353,112 @@ impl InterfaceSys {
         false
     }
 
-    fn join_multicast_ipv4(&self, disc_socket: &UdpSocket) {
+    fn join_multicast(&self, disc_socket: &UdpSocket, af: AddressFamily) {
         #[cfg(not(feature = "testing"))]
         {
-            if let Err(error) = disc_socket.join_multicast_ifindex_v4(
-                &network::udp::LDP_MCAST_ADDR_V4,
-                self.ifindex.unwrap(),
-            ) {
+            let ifindex = self.ifindex.unwrap();
+            let result = match af {
+                AddressFamily::Ipv4 => disc_socket.join_multicast_ifindex_v4(
+                    &network::udp::LDP_MCAST_ADDR_V4,
+                    ifindex,
+                ),
+                AddressFamily::Ipv6 => disc_socket.join_multicast_v6(
+                    &network::udp::LDP_MCAST_ADDR_V6,
+                    ifindex,
+                ),
+            };
+            if let Err(error) = result {
                 IoError::UdpMulticastJoinError(error).log();
             }
         }
     }
 
-    fn leave_multicast_ipv4(&self, disc_socket: &UdpSocket) {
+    fn leave_multicast(&self, disc_socket: &UdpSocket, af: AddressFamily) {
         #[cfg(not(feature = "testing"))]
         {
-            if let Err(error) = disc_socket.leave_multicast_ifindex_v4(
-                &network::udp::LDP_MCAST_ADDR_V4,
-                self.ifindex.unwrap(),
-            ) {
-                IoError::UdpMulticastJoinError(error).log();
+            let ifindex = self.ifindex.unwrap();
+            let result = match af {
+                AddressFamily::Ipv4 => disc_socket.leave_multicast_ifindex_v4(
+                    &network::udp::LDP_MCAST_ADDR_V4,
+                    ifindex,
+                ),
+                AddressFamily::Ipv6 => disc_socket.leave_multicast_v6(
+                    &network::udp::LDP_MCAST_ADDR_V6,
+                    ifindex,
+                ),
+            };
+            if let Err(error) = result {
+                IoError::UdpMulticastLeaveError(error).log();
             }
         }
     }
 
-    pub(crate) fn local_ipv4_addr(&self) -> IpAddr {
-        let addr = self.ipv4_addr_list.iter().next().unwrap();
-        IpAddr::from(addr.ip())
+    // Returns the local address used for link discovery over the given address
+    // family.
+    //
+    // IPv6 link Hellos are always sourced from a link-local address.
+    pub(crate) fn local_addr(&self, af: AddressFamily) -> Option<IpAddr> {
+        match af {
+            AddressFamily::Ipv4 => self
+                .ipv4_addr_list
+                .iter()
+                .next()
+                .map(|addr| IpAddr::from(addr.ip())),
+            AddressFamily::Ipv6 => self
+                .ipv6_addr_list
+                .iter()
+                .map(|addr| addr.ip())
+                .find(|addr| addr.is_unicast_link_local())
+                .map(IpAddr::from),
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Notifies the IGPs about the LDP-IGP synchronization state of the given
+// interface (RFC 5443).
+//
+// The interface is considered synchronized once the LDP session of at least
+// one of its link adjacencies is operational.
+pub(crate) fn igp_sync_update(instance: &InstanceUpView<'_>, ifname: &str) {
+    // Do nothing if no IGP is interested in LDP-IGP synchronization.
+    if !instance.system.igp_sync_requested {
+        return;
+    }
+
+    let synced = instance
+        .state
+        .adjacencies
+        .iter_by_iface(ifname)
+        .into_iter()
+        .flatten()
+        .filter_map(|adj| instance.state.neighbors.get_by_lsr_id(&adj.lsr_id))
+        .any(|(_, nbr)| nbr.is_operational());
+    let state = if synced {
+        LdpIgpSyncState::Synced
+    } else {
+        LdpIgpSyncState::NotSynced
+    };
+
+    ibus::tx::ldp_igp_sync_upd(&instance.tx.ibus, ifname, state);
+}
+
+// Updates the LDP-IGP synchronization state of all interfaces where the given
+// neighbor has a link adjacency.
+pub(crate) fn igp_sync_update_nbr(
+    instance: &InstanceUpView<'_>,
+    lsr_id: &Ipv4Addr,
+) {
+    let ifnames = instance
+        .state
+        .adjacencies
+        .iter_by_lsr_id(lsr_id)
+        .into_iter()
+        .flatten()
+        .filter_map(|adj| adj.source.ifname.as_deref())
+        .collect::<BTreeSet<_>>();
+    for ifname in ifnames {
+        igp_sync_update(instance, ifname);
     }
 }
@@ -22,4 +22,5 @@ pub mod neighbor;
 pub mod network;
 pub mod northbound;
 pub mod packet;
+pub mod policy;
 pub mod tasks;
@@ -12,7 +12,7 @@ use std::time::{Duration, Instant};
 
 use bitflags::bitflags;
 use chrono::{DateTime, Utc};
-use holo_utils::ip::AddressFamily;
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::socket::{TcpConnInfo, TcpStream};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
@@ -32,7 +32,9 @@ use crate::packet::messages::address::TlvAddressList;
 use crate::packet::messages::capability::{
     TlvCapDynamic, TlvCapTwcardFec, TlvCapUnrecNotif,
 };
-use crate::packet::messages::initialization::{InitFlags, TlvCommonSessParams};
+use crate::packet::messages::initialization::{
+    FtFlags, InitFlags, TlvCommonSessParams, TlvFtSession,
+};
 use crate::packet::messages::label::{
     FecElem, FecElemWildcard, TlvFec, TlvLabel, TlvLabelRequestId,
     TypedWildcardFecElem,
@@ -46,10 +48,11 @@ use crate::packet::{AddressMessageType, LabelMessageType, Message};
 #[cfg(feature = "testing")]
 use crate::tasks::messages::ProtocolOutputMsg;
 use crate::tasks::messages::input::{
-    NbrBackoffTimeoutMsg, NbrKaTimeoutMsg, NbrRxPduMsg, TcpConnectMsg,
+    NbrBackoffTimeoutMsg, NbrGrTimeoutMsg, NbrKaTimeoutMsg,
+    NbrProtectionTimeoutMsg, NbrRxPduMsg, TcpConnectMsg,
 };
 use crate::tasks::messages::output::NbrTxPduMsg;
-use crate::{ibus, tasks};
+use crate::{discovery, ibus, interface, policy, tasks};
 
 #[derive(Debug)]
 pub struct Neighbor {
@@ -65,6 +68,7 @@ pub struct Neighbor {
     pub kalive_holdtime_negotiated: Option<u16>,
     pub kalive_interval: u16,
     pub rcvd_label_adv_mode: Option<LabelAdvMode>,
+    pub rcvd_ft_session: Option<TlvFtSession>,
     pub addr_list: BTreeSet<IpAddr>,
     pub rcvd_mappings: BTreeMap<IpNetwork, LabelMapping>,
     pub sent_mappings: BTreeMap<IpNetwork, LabelMapping>,
@@ -73,9 +77,14 @@ pub struct Neighbor {
     pub sent_withdraws: BTreeMap<IpNetwork, Label>,
     pub statistics: Statistics,
     pub uptime: Option<Instant>,
+    pub created: Instant,
     pub pdu_txp: Option<UnboundedSender<NbrTxPduMsg>>,
     pub tasks: NeighborTasks,
     pub flags: NeighborFlags,
+    pub gr: Option<NeighborGr>,
+    // Session protection timeout task (running while the session is held up
+    // only by targeted Hello adjacencies).
+    pub protection_timeout: Option<TimeoutTask>,
 }
 
 #[derive(Debug, Default)]
@@ -87,6 +96,22 @@ pub struct NeighborTasks {
     pub kalive_timeout: Option<TimeoutTask>,
 }
 
+// Graceful restart state of a neighbor whose session went down.
+#[derive(Debug)]
+pub struct NeighborGr {
+    pub phase: GrPhase,
+    // Addresses and label mappings learned during the previous session.
+    pub addr_list: BTreeSet<IpAddr>,
+    pub stale_mappings: BTreeMap<IpNetwork, LabelMapping>,
+    pub timeout: TimeoutTask,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GrPhase {
+    Reconnect,
+    Recovery,
+}
+
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
     pub struct NeighborFlags: u8 {
@@ -194,6 +219,7 @@ impl Neighbor {
             kalive_holdtime_negotiated: None,
             kalive_interval,
             rcvd_label_adv_mode: None,
+            rcvd_ft_session: None,
             addr_list: BTreeSet::default(),
             rcvd_mappings: Default::default(),
             sent_mappings: Default::default(),
@@ -202,9 +228,12 @@ impl Neighbor {
             sent_withdraws: Default::default(),
             statistics: Default::default(),
             uptime: None,
+            created: Instant::now(),
             pdu_txp: None,
             tasks: Default::default(),
             flags: NeighborFlags::empty(),
+            gr: None,
+            protection_timeout: None,
         }
     }
 
@@ -242,6 +271,11 @@ impl Neighbor {
                         instance.name,
                         nbr,
                     );
+
+                    // Update the LDP-IGP synchronization state of the
+                    // interfaces this neighbor is adjacent on.
+                    let lsr_id = nbr.lsr_id;
+                    interface::igp_sync_update_nbr(instance, &lsr_id);
                 }
 
                 if let Some(action) = action {
@@ -362,66 +396,85 @@ impl Neighbor {
                     &instance.tx.protocol_input.nbr_ka_timeout,
                 );
 
-                // Send address message;
-                let addr_list = instance
-                    .system
-                    .ipv4_addr_list
-                    .iter()
-                    .map(|addr| addr.ip())
-                    .collect();
-                nbr.send_address(
-                    &instance.state.msg_id,
-                    AddressMessageType::Address,
-                    addr_list,
+                // Use the source addresses of the IPv6 link adjacencies to map
+                // link-local next hops to this neighbor.
+                let lsr_id = nbr.lsr_id;
+                let adjacencies = &instance.state.adjacencies;
+                nbr.addr_list.extend(
+                    adjacencies
+                        .iter_by_lsr_id(&lsr_id)
+                        .into_iter()
+                        .flatten()
+                        .filter(|adj| {
+                            discovery::is_link_local_source(
+                                adj.source.ifname.as_deref(),
+                                &adj.source.addr,
+                            )
+                        })
+                        .map(|adj| adj.source.addr),
                 );
 
-                // Send label mappings
-                for fec in instance.state.fecs.values_mut() {
-                    if fec.inner.local_label.is_none() {
-                        continue;
+                // Advertise addresses and label bindings of the address
+                // families the neighbor has Hello adjacencies for.
+                for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+                    if instance.state.adjacencies.has_af(&lsr_id, af) {
+                        Neighbor::advertise_af(instance, nbr_idx, af);
                     }
-                    nbr.send_label_mapping(&instance.state.msg_id, fec);
-                }
-
-                // Signal completion of label advertisements.
-                if nbr.flags.contains(NeighborFlags::CAP_UNREC_NOTIF) {
-                    nbr.send_end_of_lib(
-                        &instance.state.msg_id,
-                        TypedWildcardFecElem::Prefix(AddressFamily::Ipv4),
-                    );
                 }
             }
             fsm::Action::CloseSession => {
+                // Check whether the label bindings learned from this neighbor
+                // should be retained while it restarts.
+                let reconnect_time = nbr.gr_reconnect_time(instance.config);
+                if reconnect_time.is_none() {
+                    Neighbor::gr_purge_stale(instance, nbr_idx);
+                }
+                let nbr = &mut instance.state.neighbors[nbr_idx];
+
                 // Iterate over all FECs.
                 for fec in instance.state.fecs.values_mut() {
-                    let old_fec_status = fec.is_operational();
-
-                    // Uninstall mapping received from this neighbor (if any).
-                    for nexthop in fec
-                        .nexthops
-                        .values_mut()
-                        .filter(|nexthop| nbr.addr_list.contains(&nexthop.addr))
-                    {
-                        ibus::tx::label_uninstall(
-                            &instance.tx.ibus,
-                            &fec.inner,
-                            nexthop,
-                        );
-                        nexthop.set_label(None);
-                    }
-                    if old_fec_status != fec.is_operational() {
-                        notification::mpls_ldp_fec_event(
-                            &instance.tx.nb,
-                            instance.name,
-                            fec,
-                        );
+                    if reconnect_time.is_none() {
+                        let old_fec_status = fec.is_operational();
+
+                        // Uninstall mapping received from this neighbor (if
+                        // any).
+                        for nexthop in
+                            fec.nexthops.values_mut().filter(|nexthop| {
+                                nbr.addr_list.contains(&nexthop.addr)
+                            })
+                        {
+                            ibus::tx::label_uninstall(
+                                &instance.tx.ibus,
+                                &fec.inner,
+                                nexthop,
+                            );
+                            nexthop.set_label(None);
+                        }
+                        if old_fec_status != fec.is_operational() {
+                            notification::mpls_ldp_fec_event(
+                                &instance.tx.nb,
+                                instance.name,
+                                fec,
+                            );
+                        }
+
+                        // Remove downstream label bindings (if any).
+                        fec.inner.downstream.remove(&nbr.lsr_id);
                     }
 
-                    // Remove downstream and upstream label bindings (if any).
-                    fec.inner.downstream.remove(&nbr.lsr_id);
+                    // Remove upstream label bindings (if any).
                     fec.inner.upstream.remove(&nbr.lsr_id);
                 }
 
+                // Mark the label bindings learned from this neighbor as stale
+                // and wait for it to reconnect.
+                if let Some(reconnect_time) = reconnect_time {
+                    nbr.gr_start(
+                        reconnect_time,
+                        &instance.tx.protocol_input.nbr_gr_timeout,
+                    );
+                }
+
                 // Close session.
                 nbr.close_session();
 
@@ -445,6 +498,270 @@ impl Neighbor {
         self.tasks.backoff_timeout = None;
     }
 
+    // Returns how long the label bindings learned from this neighbor should be
+    // retained after its session goes down, or `None` if graceful restart
+    // wasn't negotiated.
+    fn gr_reconnect_time(
+        &self,
+        instance_cfg: &InstanceCfg,
+    ) -> Option<Duration> {
+        // The neighbor must have advertised the FT Session TLV with the L flag
+        // set and a non-zero FT Reconnect Timeout.
+        if !instance_cfg.gr.enabled {
+            return None;
+        }
+        self.rcvd_ft_session
+            .as_ref()
+            .filter(|tlv| tlv.flags.contains(FtFlags::LEARN))
+            .filter(|tlv| tlv.reconnect_time != 0)
+            .map(|tlv| Duration::from_millis(tlv.reconnect_time.into()))
+    }
+
+    // Marks all label bindings learned from this neighbor as stale and starts
+    // the reconnect timer.
+    fn gr_start(
+        &mut self,
+        reconnect_time: Duration,
+        nbr_gr_timeoutp: &Sender<NbrGrTimeoutMsg>,
+    ) {
+        Debug::NbrGrStart(&self.lsr_id, &GrPhase::Reconnect).log();
+
+        // Bindings that weren't refreshed during an interrupted recovery phase
+        // remain stale.
+        let mut addr_list = std::mem::take(&mut self.addr_list);
+        let mut stale_mappings = std::mem::take(&mut self.rcvd_mappings);
+        if let Some(gr) = self.gr.take() {
+            addr_list.extend(gr.addr_list);
+            for (prefix, mapping) in gr.stale_mappings {
+                stale_mappings.entry(prefix).or_insert(mapping);
+            }
+        }
+
+        let timeout =
+            tasks::nbr_gr_timeout(self.lsr_id, reconnect_time, nbr_gr_timeoutp);
+        self.gr = Some(NeighborGr {
+            phase: GrPhase::Reconnect,
+            addr_list,
+            stale_mappings,
+            timeout,
+        });
+    }
+
+    // Starts the recovery phase after the neighbor has reconnected.
+    pub(crate) fn gr_recovery_start(
+        &mut self,
+        recovery_time: Duration,
+        nbr_gr_timeoutp: &Sender<NbrGrTimeoutMsg>,
+    ) {
+        Debug::NbrGrStart(&self.lsr_id, &GrPhase::Recovery).log();
+
+        let gr = self.gr.as_mut().unwrap();
+        gr.phase = GrPhase::Recovery;
+        gr.timeout =
+            tasks::nbr_gr_timeout(self.lsr_id, recovery_time, nbr_gr_timeoutp);
+    }
+
+    // Starts protecting the session after its last link Hello adjacency was
+    // lost.
+    //
+    // The session is retained over the remaining targeted Hello adjacencies
+    // until the configured duration expires, if any.
+    pub(crate) fn protection_start(
+        &mut self,
+        duration: Option<u32>,
+        nbr_protection_timeoutp: &Sender<NbrProtectionTimeoutMsg>,
+    ) {
+        Debug::NbrProtectionStart(&self.lsr_id).log();
+
+        self.protection_timeout = duration.map(|duration| {
+            tasks::nbr_protection_timeout(
+                self.lsr_id,
+                Duration::from_secs(duration.into()),
+                nbr_protection_timeoutp,
+            )
+        });
+    }
+
+    // Sends the local addresses and label bindings of the given address family
+    // to the neighbor.
+    pub(crate) fn advertise_af(
+        instance: &mut InstanceUpView<'_>,
+        nbr_idx: NeighborIndex,
+        af: AddressFamily,
+    ) {
+        let nbr = &mut instance.state.neighbors[nbr_idx];
+
+        // Send address message.
+        let addr_list = match af {
+            AddressFamily::Ipv4 => TlvAddressList::Ipv4(
+                instance
+                    .system
+                    .ipv4_addr_list
+                    .iter()
+                    .map(|addr| addr.ip())
+                    .collect(),
+            ),
+            AddressFamily::Ipv6 => TlvAddressList::Ipv6(
+                instance
+                    .system
+                    .ipv6_addr_list
+                    .iter()
+                    .map(|addr| addr.ip())
+                    .filter(|addr| !addr.is_unicast_link_local())
+                    .collect(),
+            ),
+        };
+        nbr.send_address(
+            &instance.state.msg_id,
+            AddressMessageType::Address,
+            addr_list,
+        );
+
+        // Send label mappings.
+        for fec in instance
+            .state
+            .fecs
+            .values_mut()
+            .filter(|fec| fec.inner.prefix.address_family() == af)
+        {
+            if fec.inner.local_label.is_none()
+                || !policy::label_advertise_check(
+                    &fec.inner.prefix,
+                    nbr.lsr_id,
+                    instance.config,
+                    &instance.shared.policy_match_sets,
+                )
+            {
+                continue;
+            }
+            nbr.send_label_mapping(&instance.state.msg_id, fec);
+        }
+
+        // Signal completion of label advertisements.
+        if nbr.flags.contains(NeighborFlags::CAP_UNREC_NOTIF) {
+            nbr.send_end_of_lib(
+                &instance.state.msg_id,
+                TypedWildcardFecElem::Prefix(af),
+            );
+        }
+    }
+
+    // Adds or removes addresses from the neighbor's address list, updating the
+    // label bindings of the FEC next hops that match those addresses.
+    pub(crate) fn addr_list_update(
+        instance: &mut InstanceUpView<'_>,
+        nbr_idx: NeighborIndex,
+        msg_type: AddressMessageType,
+        addr_list: Vec<IpAddr>,
+    ) {
+        let nbr = &mut instance.state.neighbors[nbr_idx];
+
+        // Reevaluate all label mappings received from this neighbor.
+        for (prefix, mapping) in &nbr.rcvd_mappings {
+            let fec = instance.state.fecs.get_mut(prefix).unwrap();
+            let old_fec_status = fec.is_operational();
+
+            for nexthop in fec.nexthops.values_mut() {
+                for addr in &addr_list {
+                    if nexthop.addr != *addr {
+                        continue;
+                    }
+
+                    match msg_type {
+                        AddressMessageType::Address => {
+                            nexthop.set_label(Some(mapping.label));
+                            ibus::tx::label_install(
+                                &instance.tx.ibus,
+                                &fec.inner,
+                                nexthop,
+                            );
+                        }
+                        AddressMessageType::AddressWithdraw => {
+                            ibus::tx::label_uninstall(
+                                &instance.tx.ibus,
+                                &fec.inner,
+                                nexthop,
+                            );
+                            nexthop.set_label(None);
+                        }
+                    }
+                }
+            }
+
+            if old_fec_status != fec.is_operational() {
+                notification::mpls_ldp_fec_event(
+                    &instance.tx.nb,
+                    instance.name,
+                    fec,
+                );
+            }
+        }
+
+        match msg_type {
+            AddressMessageType::Address => {
+                // Add new addresses.
+                nbr.addr_list.extend(addr_list);
+            }
+            AddressMessageType::AddressWithdraw => {
+                // Remove addresses.
+                nbr.addr_list.retain(|addr| !addr_list.contains(addr));
+            }
+        }
+    }
+
+    // Removes the stale label bindings that weren't refreshed by the
+    // neighbor, ending its graceful restart.
+    pub(crate) fn gr_purge_stale(
+        instance: &mut InstanceUpView<'_>,
+        nbr_idx: NeighborIndex,
+    ) {
+        let nbr = &mut instance.state.neighbors[nbr_idx];
+        let Some(gr) = nbr.gr.take() else {
+            return;
+        };
+
+        Debug::NbrGrPurge(&nbr.lsr_id, gr.stale_mappings.len()).log();
+
+        for prefix in gr.stale_mappings.keys() {
+            let Some(fec) = instance.state.fecs.get_mut(prefix) else {
+                continue;
+            };
+            let old_fec_status = fec.is_operational();
+            let refreshed = nbr.rcvd_mappings.contains_key(prefix);
+
+            // Uninstall stale mappings.
+            for nexthop in fec
+                .nexthops
+                .values_mut()
+                .filter(|nexthop| gr.addr_list.contains(&nexthop.addr))
+                .filter(|nexthop| nexthop.get_label().is_some())
+            {
+                if refreshed && nbr.addr_list.contains(&nexthop.addr) {
+                    continue;
+                }
+
+                ibus::tx::label_uninstall(
+                    &instance.tx.ibus,
+                    &fec.inner,
+                    nexthop,
+                );
+                nexthop.set_label(None);
+            }
+            if old_fec_status != fec.is_operational() {
+                notification::mpls_ldp_fec_event(
+                    &instance.tx.nb,
+                    instance.name,
+                    fec,
+                );
+            }
+
+            // Remove stale downstream label binding.
+            if !refreshed {
+                fec.inner.downstream.remove(&nbr.lsr_id);
+            }
+        }
+    }
+
     pub(crate) fn start_kalive_interval(&mut self, msg_id: &Arc<AtomicU32>) {
         let keepalive_counter = &self.statistics.msgs_sent.keepalive;
         let task = tasks::nbr_kalive_interval(self, msg_id, keepalive_counter);
@@ -510,6 +827,7 @@ impl Neighbor {
         self.kalive_holdtime_rcvd = None;
         self.kalive_holdtime_negotiated = None;
         self.rcvd_label_adv_mode = None;
+        self.rcvd_ft_session = None;
         self.addr_list.clear();
         self.rcvd_mappings.clear();
         self.sent_mappings.clear();
@@ -572,6 +890,15 @@ impl Neighbor {
             cap_dynamic: Some(TlvCapDynamic()),
             cap_twcard_fec: Some(TlvCapTwcardFec(true)),
             cap_unrec_notif: Some(TlvCapUnrecNotif(true)),
+            // Only the helper role is supported. The MPLS forwarding state
+            // isn't preserved across restarts, so the Recovery Time is always
+            // zero (RFC 3478 - Section 3.1).
+            ft_session: instance_cfg.gr.enabled.then(|| TlvFtSession {
+                flags: FtFlags::LEARN,
+                reconnect_time: u32::from(instance_cfg.gr.reconnect_time)
+                    * 1000,
+                recovery_time: 0,
+            }),
         };
         self.send_message(msg, true);
     }
@@ -653,12 +980,12 @@ impl Neighbor {
         &mut self,
         msg_id: &Arc<AtomicU32>,
         msg_type: AddressMessageType,
-        addr_list: BTreeSet<Ipv4Addr>,
+        addr_list: TlvAddressList,
     ) {
         let msg = AddressMsg {
             msg_id: InstanceState::get_next_msg_id(msg_id),
             msg_type,
-            addr_list: TlvAddressList::Ipv4(addr_list),
+            addr_list,
         };
         self.send_message(msg, false);
     }
@@ -744,6 +1071,26 @@ impl Neighbor {
         self.sent_withdraws.insert(prefix, label);
     }
 
+    pub(crate) fn send_label_request(
+        &mut self,
+        msg_id: &Arc<AtomicU32>,
+        prefix: IpNetwork,
+    ) {
+        let msg_id = InstanceState::get_next_msg_id(msg_id);
+        let msg = LabelMsg {
+            msg_id,
+            msg_type: LabelMessageType::LabelRequest,
+            fec: TlvFec(vec![FecElem::Prefix(prefix)]),
+            label: None,
+            request_id: None,
+        };
+        self.send_message(msg, false);
+
+        // Record sent label request.
+        let request = LabelRequest { id: msg_id };
+        self.sent_requests.insert(prefix, request);
+    }
+
     pub(crate) fn send_label_release(
         &mut self,
         msg_id: &Arc<AtomicU32>,
@@ -46,8 +46,16 @@ pub(crate) fn listen_socket(
         })?;
 
         // Set socket options.
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
-        socket.set_ipv4_ttl(TTL_MAX)?;
+        match addr {
+            IpAddr::V4(_) => {
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+                socket.set_ipv4_ttl(TTL_MAX)?;
+            }
+            IpAddr::V6(_) => {
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+                socket.set_ipv6_unicast_hops(TTL_MAX)?;
+            }
+        }
 
         Ok(socket)
     }
@@ -102,13 +110,29 @@ fn connect_socket(
     gtsm: bool,
 ) -> Result<TcpSocket, std::io::Error> {
     let sockaddr = SocketAddr::from((local_addr, 0));
-    let socket = TcpSocket::new_v4()?;
-    socket.set_reuseaddr(true)?;
-    socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
-    if gtsm {
-        socket.set_ipv4_ttl(TTL_MAX)?;
-        socket.set_ipv4_minttl(TTL_MAX)?;
-    }
+    let socket = match local_addr {
+        IpAddr::V4(_) => {
+            let socket = TcpSocket::new_v4()?;
+            socket.set_reuseaddr(true)?;
+            socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            if gtsm {
+                socket.set_ipv4_ttl(TTL_MAX)?;
+                socket.set_ipv4_minttl(TTL_MAX)?;
+            }
+            socket
+        }
+        IpAddr::V6(_) => {
+            let socket = TcpSocket::new_v6()?;
+            socket.set_ipv6_only(true)?;
+            socket.set_reuseaddr(true)?;
+            socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            if gtsm {
+                socket.set_ipv6_unicast_hops(TTL_MAX)?;
+                socket.set_ipv6_min_hopcount(TTL_MAX)?;
+            }
+            socket
+        }
+    };
 
     socket.bind(sockaddr)?;
     Ok(socket)
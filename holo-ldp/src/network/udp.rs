@@ -10,7 +10,8 @@ use std::sync::{Arc, LazyLock as Lazy};
 
 use holo_utils::capabilities;
 use holo_utils::ip::{AddressFamily, IpAddrExt};
-use holo_utils::socket::{SocketExt, UdpSocket, UdpSocketExt};
+use holo_utils::socket::{SocketExt, TTL_MAX, UdpSocket, UdpSocketExt};
+use socket2::{Domain, Type};
 use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::error::SendError;
 
@@ -42,11 +43,32 @@ pub(crate) fn discovery_socket(
     {
         // Create and bind socket.
         let sockaddr = SocketAddr::from((addr, network::LDP_PORT));
-        let socket =
-            capabilities::raise(|| UdpSocket::bind_reuseaddr(sockaddr))?;
+        let socket = match addr {
+            IpAddr::V4(_) => {
+                capabilities::raise(|| UdpSocket::bind_reuseaddr(sockaddr))?
+            }
+            IpAddr::V6(_) => {
+                // Restrict the socket to IPv6 so that it doesn't receive the
+                // IPv4 Hellos destined to the IPv4 discovery socket.
+                let socket =
+                    socket2::Socket::new(Domain::IPV6, Type::DGRAM, None)?;
+                socket.set_nonblocking(true)?;
+                socket.set_reuse_address(true)?;
+                socket.set_only_v6(true)?;
+                capabilities::raise(|| socket.bind(&sockaddr.into()))?;
+                UdpSocket::from_std(socket.into())?
+            }
+        };
 
         // Set socket options.
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+        match addr {
+            IpAddr::V4(_) => {
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+            IpAddr::V6(_) => {
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+        }
 
         Ok(socket)
     }
@@ -58,19 +80,32 @@ pub(crate) fn discovery_socket(
 
 pub(crate) fn interface_discovery_socket(
     iface: &Interface,
+    af: AddressFamily,
 ) -> Result<UdpSocket, std::io::Error> {
     #[cfg(not(feature = "testing"))]
     {
         // Create and bind socket.
-        let socket = UdpSocket::new(AddressFamily::Ipv4)?;
+        let socket = UdpSocket::new(af)?;
         capabilities::raise(|| {
             socket.bind_device(Some(iface.name.as_bytes()))
         })?;
 
         // Set socket options.
-        socket.set_multicast_loop_v4(false)?;
-        socket.set_multicast_ttl_v4(1)?;
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+        match af {
+            AddressFamily::Ipv4 => {
+                socket.set_multicast_loop_v4(false)?;
+                socket.set_multicast_ttl_v4(1)?;
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+            AddressFamily::Ipv6 => {
+                // IPv6 link Hellos are sent with a hop limit of 255 (GTSM).
+                socket.set_multicast_loop_v6(false)?;
+                socket.set_ipv6_multicast_hopcount(TTL_MAX)?;
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+                socket
+                    .set_multicast_ifindex_v6(iface.system.ifindex.unwrap())?;
+            }
+        }
 
         Ok(socket)
     }
@@ -82,6 +117,7 @@ pub(crate) fn interface_discovery_socket(
 
 pub(crate) async fn send_packet_multicast(
     socket: &UdpSocket,
+    af: AddressFamily,
     pdu: Pdu,
 ) -> Result<(), std::io::Error> {
     #[cfg(not(feature = "testing"))]
@@ -90,7 +126,11 @@ pub(crate) async fn send_packet_multicast(
         let buf = pdu.encode(Pdu::DFLT_MAX_LEN);
 
         // Send packet.
-        socket.send_to(&buf, &*LDP_MCAST_SOCKADDR_V4).await?;
+        let sockaddr = match af {
+            AddressFamily::Ipv4 => &*LDP_MCAST_SOCKADDR_V4,
+            AddressFamily::Ipv6 => &*LDP_MCAST_SOCKADDR_V6,
+        };
+        socket.send_to(&buf, sockaddr).await?;
     }
 
     Ok(())
@@ -164,8 +204,13 @@ pub(crate) async fn read_loop(
         cxt.pkt_info.src_addr = src_addr;
         let pdu = Pdu::get_pdu_size(&buf, &cxt)
             .and_then(|pdu_size| Pdu::decode(&buf[0..pdu_size], &cxt));
+        let ifindex = match src {
+            SocketAddr::V4(_) => None,
+            SocketAddr::V6(src) => Some(src.scope_id()),
+        };
         let msg = UdpRxPduMsg {
             src_addr,
+            ifindex,
             multicast,
             pdu,
         };
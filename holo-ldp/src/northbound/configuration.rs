@@ -5,7 +5,7 @@
 //
 
 use std::collections::HashMap;
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::LazyLock as Lazy;
 
 use enum_as_inner::EnumAsInner;
@@ -21,7 +21,7 @@ use crate::collections::{InterfaceIndex, TargetedNbrIndex};
 use crate::debug::InterfaceInactiveReason;
 use crate::discovery::TargetedNbr;
 use crate::instance::Instance;
-use crate::{neighbor, network};
+use crate::{ibus, neighbor};
 
 #[derive(Debug, Default, EnumAsInner)]
 pub enum ListEntry {
@@ -44,6 +44,8 @@ pub enum Event {
     TargetedNbrUpdate(TargetedNbrIndex),
     TargetedNbrRemoveCheck(TargetedNbrIndex),
     TargetedNbrRemoveDynamic,
+    SessionProtectionDisable,
+    LabelPolicyUpdate,
     StopInitBackoff,
     ResetNeighbors,
     ResetNeighbor(Ipv4Addr),
@@ -61,6 +63,8 @@ pub static CALLBACKS: Lazy<Callbacks<Instance>> = Lazy::new(load_callbacks);
 #[derive(Debug)]
 pub struct InstanceCfg {
     pub router_id: Option<Ipv4Addr>,
+    pub gr: InstanceGrCfg,
+    pub session_protection: InstanceSessionProtectionCfg,
     pub session_ka_holdtime: u16,
     pub session_ka_interval: u16,
     pub password: Option<String>,
@@ -70,12 +74,49 @@ pub struct InstanceCfg {
     pub targeted_hello_interval: u16,
     pub targeted_hello_accept: bool,
     pub ipv4: Option<InstanceIpv4Cfg>,
+    pub ipv6: Option<InstanceIpv6Cfg>,
+    pub dual_stack: InstanceDualStackCfg,
     pub neighbors: HashMap<Ipv4Addr, NeighborCfg>,
 }
 
+#[derive(Debug)]
+pub struct InstanceGrCfg {
+    pub enabled: bool,
+    pub reconnect_time: u16,
+}
+
+#[derive(Debug)]
+pub struct InstanceSessionProtectionCfg {
+    pub enabled: bool,
+    pub duration: Option<u32>,
+}
+
 #[derive(Debug)]
 pub struct InstanceIpv4Cfg {
     pub enabled: bool,
+    pub trans_addr: Option<Ipv4Addr>,
+    pub label_policy: LabelPolicyCfg,
+}
+
+#[derive(Debug)]
+pub struct InstanceIpv6Cfg {
+    pub enabled: bool,
+    pub trans_addr: Option<Ipv6Addr>,
+    pub label_policy: LabelPolicyCfg,
+}
+
+#[derive(Debug, Default)]
+pub struct LabelPolicyCfg {
+    pub advertise_prefix_list: Option<String>,
+    pub accept_prefix_list: Option<String>,
+    pub assign_prefix_list: Option<String>,
+    pub assign_host_routes_only: bool,
+}
+
+#[derive(Debug)]
+pub struct InstanceDualStackCfg {
+    pub max_wait: u16,
+    pub prefer_ipv4: bool,
 }
 
 #[derive(Debug)]
@@ -83,6 +124,7 @@ pub struct InterfaceCfg {
     pub hello_holdtime: u16,
     pub hello_interval: u16,
     pub ipv4: Option<InterfaceIpv4Cfg>,
+    pub ipv6: Option<InterfaceIpv6Cfg>,
 }
 
 #[derive(Debug)]
@@ -90,9 +132,22 @@ pub struct InterfaceIpv4Cfg {
     pub enabled: bool,
 }
 
+#[derive(Debug)]
+pub struct InterfaceIpv6Cfg {
+    pub enabled: bool,
+}
+
 #[derive(Debug, Default)]
 pub struct NeighborCfg {
     pub password: Option<String>,
+    pub ipv4_label_policy: NeighborLabelPolicyCfg,
+    pub ipv6_label_policy: NeighborLabelPolicyCfg,
+}
+
+#[derive(Debug, Default)]
+pub struct NeighborLabelPolicyCfg {
+    pub advertise_prefix_list: Option<String>,
+    pub accept_prefix_list: Option<String>,
 }
 
 #[derive(Debug)]
@@ -124,6 +179,34 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ResetNeighbors);
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::global::graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.gr.enabled = enabled;
+        })
+        .path(mpls_ldp::global::graceful_restart::reconnect_time::PATH)
+        .modify_apply(|instance, args| {
+            let reconnect_time = args.dnode.get_u16();
+            instance.config.gr.reconnect_time = reconnect_time;
+        })
+        .path(mpls_ldp::global::session_protection::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.session_protection.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            if !enabled {
+                event_queue.insert(Event::SessionProtectionDisable);
+            }
+        })
+        .path(mpls_ldp::global::session_protection::duration::PATH)
+        .modify_apply(|instance, args| {
+            let duration = args.dnode.get_u32();
+            instance.config.session_protection.duration = Some(duration);
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.session_protection.duration = None;
+        })
         .path(mpls_ldp::global::address_families::ipv4::PATH)
         .create_apply(|instance, args| {
             instance.config.ipv4 = Some(InstanceIpv4Cfg::default());
@@ -147,6 +230,157 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::InstanceUpdate);
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::global::address_families::ipv4::transport_address::PATH)
+        .modify_apply(|instance, args| {
+            let trans_addr = args.dnode.get_ipv4();
+            instance.config.ipv4.as_mut().unwrap().trans_addr = Some(trans_addr);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv4.as_mut().unwrap().trans_addr = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv4.as_mut().unwrap().label_policy.advertise_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv4.as_mut().unwrap().label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv4.as_mut().unwrap().label_policy.accept_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv4.as_mut().unwrap().label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::assign::independent_mode::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv4.as_mut().unwrap().label_policy.assign_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv4.as_mut().unwrap().label_policy.assign_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::assign::host_routes_only::PATH)
+        .modify_apply(|instance, args| {
+            let host_routes_only = args.dnode.get_bool();
+            instance.config.ipv4.as_mut().unwrap().label_policy.assign_host_routes_only = host_routes_only;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::PATH)
+        .create_apply(|instance, args| {
+            instance.config.ipv6 = Some(InstanceIpv6Cfg::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            for iface in instance.interfaces.iter() {
+                event_queue.insert(Event::InterfaceIbusSub(iface.name.clone()));
+            }
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv6 = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.ipv6.as_mut().unwrap().enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::transport_address::PATH)
+        .modify_apply(|instance, args| {
+            let trans_addr = args.dnode.get_ipv6();
+            instance.config.ipv6.as_mut().unwrap().trans_addr = Some(trans_addr);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv6.as_mut().unwrap().label_policy.advertise_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv6.as_mut().unwrap().label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv6.as_mut().unwrap().label_policy.accept_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv6.as_mut().unwrap().label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::assign::independent_mode::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_list = args.dnode.get_string();
+            instance.config.ipv6.as_mut().unwrap().label_policy.assign_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv6.as_mut().unwrap().label_policy.assign_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::assign::host_routes_only::PATH)
+        .modify_apply(|instance, args| {
+            let host_routes_only = args.dnode.get_bool();
+            instance.config.ipv6.as_mut().unwrap().label_policy.assign_host_routes_only = host_routes_only;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
         .path(mpls_ldp::discovery::interfaces::hello_holdtime::PATH)
         .modify_apply(|instance, args| {
             let hello_holdtime = args.dnode.get_u16();
@@ -229,6 +463,39 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::InterfaceUpdate(iface_idx));
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.ipv6 = Some(InterfaceIpv6Cfg::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.ipv6 = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ipv6.as_mut().unwrap().enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::discovery::targeted::hello_holdtime::PATH)
         .modify_apply(|instance, args| {
             let hello_holdtime = args.dnode.get_u16();
@@ -267,7 +534,7 @@ fn load_callbacks() -> Callbacks<Instance> {
             // Nothing to do.
         })
         .delete_apply(|instance, args| {
-            for tnbr in instance.tneighbors.iter_mut() {
+            for tnbr in instance.tneighbors.iter_mut().filter(|tnbr| tnbr.addr.is_ipv4()) {
                 tnbr.config.enabled = false;
             }
 
@@ -318,6 +585,62 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::TargetedNbrUpdate(tnbr_idx));
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::PATH)
+        .create_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .delete_apply(|instance, args| {
+            for tnbr in instance.tneighbors.iter_mut().filter(|tnbr| tnbr.addr.is_ipv6()) {
+                tnbr.config.enabled = false;
+            }
+
+            let event_queue = args.event_queue;
+            for tnbr_idx in instance.tneighbors.indexes() {
+                event_queue.insert(Event::TargetedNbrRemoveCheck(tnbr_idx));
+            }
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::target::PATH)
+        .create_apply(|instance, args| {
+            let addr = args.dnode.get_ip_relative("adjacent-address").unwrap();
+            let (tnbr_index, tnbr) =
+                instance.tneighbors.insert(addr);
+            tnbr.configured = true;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrUpdate(tnbr_index));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let tnbr_idx = args.list_entry.into_targeted_nbr().unwrap();
+            let tnbr = &mut instance.tneighbors[tnbr_idx];
+
+            tnbr.configured = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrRemoveCheck(tnbr_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .lookup(|instance, _list_entry, dnode| {
+            let addr = dnode.get_ip_relative("./adjacent-address").unwrap();
+            instance
+                .tneighbors
+                .get_mut_by_addr(&addr)
+                .map(|(tnbr_idx, _)| ListEntry::TargetedNbr(tnbr_idx))
+                .expect("could not find LDP targeted neighbor")
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::target::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let tnbr_idx = args.list_entry.into_targeted_nbr().unwrap();
+            let tnbr = &mut instance.tneighbors[tnbr_idx];
+
+            let enabled = args.dnode.get_bool();
+            tnbr.config.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrUpdate(tnbr_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::authentication::key::PATH)
         .modify_apply(|instance, args| {
             let password = args.dnode.get_string();
@@ -361,6 +684,26 @@ fn load_callbacks() -> Callbacks<Instance> {
             let event_queue = args.event_queue;
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::peers::dual_stack_transport_preference::max_wait::PATH)
+        .modify_apply(|instance, args| {
+            let max_wait = args.dnode.get_u16();
+            instance.config.dual_stack.max_wait = max_wait;
+        })
+        .path(mpls_ldp::peers::dual_stack_transport_preference::prefer_ipv4::PATH)
+        .create_apply(|instance, args| {
+            instance.config.dual_stack.prefer_ipv4 = true;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.dual_stack.prefer_ipv4 = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::peer::PATH)
         .create_apply(|instance, args| {
             let lsr_id = args.dnode.get_ipv4_relative("lsr-id").unwrap();
@@ -369,6 +712,9 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|instance, args| {
             let lsr_id = args.list_entry.into_neighbor().unwrap();
             instance.config.neighbors.remove(&lsr_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
         })
         .lookup(|_instance, _list_entry, dnode| {
             let lsr_id = dnode.get_ipv4_relative("lsr-id").unwrap();
@@ -409,9 +755,108 @@ fn load_callbacks() -> Callbacks<Instance> {
         .create_apply(|_instance, _args| {
             // Nothing to do.
         })
-        .delete_apply(|_instance, _args| {
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv4_label_policy = Default::default();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv4::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let prefix_list = args.dnode.get_string();
+            nbr_cfg.ipv4_label_policy.advertise_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv4_label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv4::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let prefix_list = args.dnode.get_string();
+            nbr_cfg.ipv4_label_policy.accept_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv4_label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::PATH)
+        .create_apply(|_instance, _args| {
             // Nothing to do.
         })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv6_label_policy = Default::default();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let prefix_list = args.dnode.get_string();
+            nbr_cfg.ipv6_label_policy.advertise_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv6_label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let prefix_list = args.dnode.get_string();
+            nbr_cfg.ipv6_label_policy.accept_prefix_list = Some(prefix_list);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv6_label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
         .build()
 }
 
@@ -472,10 +917,12 @@ impl Provider for Instance {
             }
             Event::InterfaceIbusSub(ifname) => {
                 if let Some((instance, _, _)) = self.as_up() {
-                    instance
-                        .tx
-                        .ibus
-                        .interface_sub(Some(ifname), Some(AddressFamily::Ipv4));
+                    let af = if instance.config.ipv6.is_some() {
+                        None
+                    } else {
+                        Some(AddressFamily::Ipv4)
+                    };
+                    instance.tx.ibus.interface_sub(Some(ifname), af);
                 }
             }
             Event::TargetedNbrUpdate(tnbr_idx) => {
@@ -512,6 +959,25 @@ impl Provider for Instance {
                     }
                 }
             }
+            Event::SessionProtectionDisable => {
+                if let Some((mut instance, _, tneighbors)) = self.as_up() {
+                    for nbr in instance.state.neighbors.iter_mut() {
+                        nbr.protection_timeout = None;
+                    }
+                    for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+                        let tnbr = &mut tneighbors[tnbr_idx];
+                        tnbr.session_protection = false;
+                        TargetedNbr::update(
+                            &mut instance,
+                            tneighbors,
+                            tnbr_idx,
+                        );
+                    }
+                }
+            }
+            Event::LabelPolicyUpdate => {
+                ibus::rx::process_label_policy_update(self);
+            }
             Event::StopInitBackoff => {
                 if let Some((instance, _, _)) = self.as_up() {
                     for nbr in instance.state.neighbors.iter_mut() {
@@ -548,14 +1014,12 @@ impl Provider for Instance {
             }
             Event::UpdateNeighborsAuth => {
                 if let Some((instance, _, _)) = self.as_up() {
-                    for nbr in instance.state.neighbors.iter_mut() {
+                    for nbr in instance.state.neighbors.iter() {
                         let password =
                             instance.config.get_neighbor_password(nbr.lsr_id);
-                        network::tcp::listen_socket_md5sig_update(
-                            &instance.state.ipv4.session_socket,
-                            &nbr.trans_addr,
-                            password,
-                        );
+                        instance
+                            .state
+                            .listen_socket_md5sig_update(&nbr.trans_addr, password);
                     }
                 }
             }
@@ -566,11 +1030,9 @@ impl Provider for Instance {
                 {
                     let password =
                         instance.config.get_neighbor_password(nbr.lsr_id);
-                    network::tcp::listen_socket_md5sig_update(
-                        &instance.state.ipv4.session_socket,
-                        &nbr.trans_addr,
-                        password,
-                    );
+                    instance
+                        .state
+                        .listen_socket_md5sig_update(&nbr.trans_addr, password);
                 }
             }
             Event::CfgSeqNumberUpdate => {
@@ -626,6 +1088,8 @@ impl Default for InstanceCfg {
 
         InstanceCfg {
             router_id: None,
+            gr: Default::default(),
+            session_protection: Default::default(),
             session_ka_holdtime,
             session_ka_interval,
             password: None,
@@ -635,17 +1099,71 @@ impl Default for InstanceCfg {
             targeted_hello_interval,
             targeted_hello_accept,
             ipv4: None,
+            ipv6: None,
+            dual_stack: Default::default(),
             neighbors: Default::default(),
         }
     }
 }
 
+impl Default for InstanceGrCfg {
+    fn default() -> InstanceGrCfg {
+        let enabled = mpls_ldp::global::graceful_restart::enabled::DFLT;
+        let reconnect_time =
+            mpls_ldp::global::graceful_restart::reconnect_time::DFLT;
+
+        InstanceGrCfg {
+            enabled,
+            reconnect_time,
+        }
+    }
+}
+
+impl Default for InstanceSessionProtectionCfg {
+    fn default() -> InstanceSessionProtectionCfg {
+        let enabled = mpls_ldp::global::session_protection::enabled::DFLT;
+
+        InstanceSessionProtectionCfg {
+            enabled,
+            duration: None,
+        }
+    }
+}
+
 impl Default for InstanceIpv4Cfg {
     fn default() -> InstanceIpv4Cfg {
         let enabled =
             mpls_ldp::discovery::targeted::address_families::ipv4::target::enabled::DFLT;
 
-        InstanceIpv4Cfg { enabled }
+        InstanceIpv4Cfg {
+            enabled,
+            trans_addr: None,
+            label_policy: Default::default(),
+        }
+    }
+}
+
+impl Default for InstanceIpv6Cfg {
+    fn default() -> InstanceIpv6Cfg {
+        let enabled = mpls_ldp::global::address_families::ipv6::enabled::DFLT;
+
+        InstanceIpv6Cfg {
+            enabled,
+            trans_addr: None,
+            label_policy: Default::default(),
+        }
+    }
+}
+
+impl Default for InstanceDualStackCfg {
+    fn default() -> InstanceDualStackCfg {
+        let max_wait =
+            mpls_ldp::peers::dual_stack_transport_preference::max_wait::DFLT;
+
+        InstanceDualStackCfg {
+            max_wait,
+            prefer_ipv4: false,
+        }
     }
 }
 
@@ -660,6 +1178,7 @@ impl Default for InterfaceCfg {
             hello_holdtime,
             hello_interval,
             ipv4: None,
+            ipv6: None,
         }
     }
 }
@@ -673,6 +1192,15 @@ impl Default for InterfaceIpv4Cfg {
     }
 }
 
+impl Default for InterfaceIpv6Cfg {
+    fn default() -> InterfaceIpv6Cfg {
+        let enabled =
+            mpls_ldp::discovery::interfaces::interface::address_families::ipv6::enabled::DFLT;
+
+        InterfaceIpv6Cfg { enabled }
+    }
+}
+
 impl Default for TargetedNbrCfg {
     fn default() -> TargetedNbrCfg {
         let enabled =
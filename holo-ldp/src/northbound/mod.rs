@@ -19,7 +19,7 @@ use crate::instance::Instance;
 
 impl ProviderBase for Instance {
     fn yang_modules() -> &'static [&'static str] {
-        &["ietf-mpls-ldp"]
+        &["ietf-mpls-ldp", "ietf-mpls-ldp-extended", "holo-mpls-ldp"]
     }
 
     fn top_level_node(&self) -> String {
@@ -5,7 +5,7 @@
 //
 
 use std::borrow::Cow;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::{LazyLock as Lazy, atomic};
 
 use derive_new::new;
@@ -14,18 +14,19 @@ use holo_northbound::state::{
     Callbacks, CallbacksBuilder, ListEntryKind, Provider,
 };
 use holo_northbound::yang::control_plane_protocol::mpls_ldp;
-use holo_utils::ip::{IpAddrKind, IpNetworkKind};
+use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::num::SaturatingInto;
 use holo_utils::option::OptionExt;
 use holo_yang::ToYang;
-use ipnetwork::Ipv4Network;
+use ipnetwork::{Ipv4Network, Ipv6Network};
 
 use crate::discovery::Adjacency;
 use crate::fec::Fec;
 use crate::instance::Instance;
 use crate::interface::Interface;
 use crate::neighbor::{LabelAdvMode, LabelDistMode, Neighbor, NeighborFlags};
+use crate::packet::messages::initialization::FtFlags;
 
 pub static CALLBACKS: Lazy<Callbacks<Instance>> = Lazy::new(load_callbacks);
 
@@ -45,7 +46,7 @@ pub enum ListEntry<'a> {
 
 #[derive(Debug, new)]
 pub struct AddrBinding {
-    addr: Ipv4Addr,
+    addr: IpAddr,
     adv_type: AdvertisementType,
     lsr_id: Option<Ipv4Addr>,
 }
@@ -86,17 +87,15 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             // Advertised addresses.
             let advertised = instance.system.ipv4_addr_list.iter().map(|addr| {
-                let binding = AddrBinding::new(addr.ip(), AdvertisementType::Advertised, None);
+                let binding = AddrBinding::new(addr.ip().into(), AdvertisementType::Advertised, None);
                 ListEntry::AddrBinding(binding)
             });
 
             // Received addresses.
             let received = instance_state.neighbors.iter().flat_map(|nbr| {
-                nbr.addr_list.iter().filter_map(move |addr| {
-                    Ipv4Addr::get(*addr).map(|addr| {
-                        let binding = AddrBinding::new(addr, AdvertisementType::Received, Some(nbr.lsr_id));
-                        ListEntry::AddrBinding(binding)
-                    })
+                nbr.addr_list.iter().filter(|addr| addr.is_ipv4()).map(move |addr| {
+                    let binding = AddrBinding::new(*addr, AdvertisementType::Received, Some(nbr.lsr_id));
+                    ListEntry::AddrBinding(binding)
                 })
             });
 
@@ -107,7 +106,7 @@ fn load_callbacks() -> Callbacks<Instance> {
             use mpls_ldp::global::address_families::ipv4::bindings::address::Address;
             let binding = args.list_entry.as_addr_binding().unwrap();
             Box::new(Address {
-                address: Cow::Owned(binding.addr),
+                address: Cow::Owned(Ipv4Addr::get(binding.addr).unwrap()),
                 advertisement_type: Some(binding.adv_type.to_yang()),
             })
         })
@@ -165,6 +164,103 @@ fn load_callbacks() -> Callbacks<Instance> {
                 used_in_forwarding: Some(binding.used_in_fwd),
             })
         })
+        .path(mpls_ldp::global::address_families::ipv6::PATH)
+        .get_object(|instance, _args| {
+            use mpls_ldp::global::address_families::ipv6::Ipv6;
+            Box::new(Ipv6 {
+                label_distribution_control_mode: instance.config.ipv6.is_some().then(|| LabelDistMode::Independent.to_yang()),
+            })
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+
+            // Skip if there's no neighbor in the operational state.
+            if !instance_state.neighbors.iter().any(|nbr| nbr.is_operational()) {
+                return None;
+            }
+
+            // Advertised addresses.
+            let advertised = instance.system.ipv6_addr_list.iter().filter(|addr| !addr.ip().is_unicast_link_local()).map(|addr| {
+                let binding = AddrBinding::new(addr.ip().into(), AdvertisementType::Advertised, None);
+                ListEntry::AddrBinding(binding)
+            });
+
+            // Received addresses.
+            let received = instance_state.neighbors.iter().flat_map(|nbr| {
+                nbr.addr_list.iter().filter_map(move |addr| {
+                    Ipv6Addr::get(*addr).filter(|addr| !addr.is_unicast_link_local()).map(|_| {
+                        let binding = AddrBinding::new(*addr, AdvertisementType::Received, Some(nbr.lsr_id));
+                        ListEntry::AddrBinding(binding)
+                    })
+                })
+            });
+
+            // Chain advertised and received addresses.
+            Some(Box::new(advertised.chain(received)))
+        })
+        .get_object(|_instance, args| {
+            use mpls_ldp::global::address_families::ipv6::bindings::address::Address;
+            let binding = args.list_entry.as_addr_binding().unwrap();
+            Box::new(Address {
+                address: Cow::Owned(Ipv6Addr::get(binding.addr).unwrap()),
+                advertisement_type: Some(binding.adv_type.to_yang()),
+            })
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::peer::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::global::address_families::ipv6::bindings::address::peer::Peer;
+            let binding = args.list_entry.as_addr_binding().unwrap();
+            Box::new(Peer {
+                lsr_id: binding.lsr_id.map(Cow::Owned),
+                label_space_id: binding.lsr_id.map(|_lsr_id| 0),
+            })
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+            let iter = instance_state.fecs.values().filter(|fec| fec.inner.prefix.is_ipv6()).filter(|fec| !fec.inner.upstream.is_empty() || !fec.inner.downstream.is_empty()).map(ListEntry::Fec);
+            Some(Box::new(iter))
+        })
+        .get_object(|_instance, args| {
+            use mpls_ldp::global::address_families::ipv6::bindings::fec_label::FecLabel;
+            let fec = args.list_entry.as_fec().unwrap();
+            Box::new(FecLabel {
+                fec: Cow::Owned(Ipv6Network::get(*fec.inner.prefix).unwrap()),
+            })
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::peer::PATH)
+        .get_iterate(|instance, args| {
+            let fec = args.parent_list_entry.as_fec().unwrap();
+
+            // Advertised label mappings.
+            let advertised = fec.inner.upstream.iter().map(|(lsr_id, mapping)| {
+                let binding = LabelBinding::new(*lsr_id, AdvertisementType::Advertised, mapping.label, true);
+                ListEntry::LabelBinding(binding)
+            });
+
+            // Received label mappings.
+            let received = fec.inner.downstream.iter().filter_map(|(lsr_id, mapping)| {
+                instance.state.as_ref().unwrap().neighbors.get_by_lsr_id(lsr_id).map(|(_, nbr)| {
+                    let binding = LabelBinding::new(*lsr_id, AdvertisementType::Received, mapping.label, fec.is_nbr_nexthop(nbr));
+                    ListEntry::LabelBinding(binding)
+                })
+            });
+
+            // Chain advertised and received label mappings.
+            Some(Box::new(advertised.chain(received)))
+        })
+        .get_object(|_instance, args| {
+            use mpls_ldp::global::address_families::ipv6::bindings::fec_label::peer::Peer;
+            let binding = args.list_entry.as_label_binding().unwrap();
+            Box::new(Peer {
+                lsr_id: Cow::Owned(binding.lsr_id),
+                label_space_id: 0,
+                advertisement_type: binding.adv_type.to_yang(),
+                label: Some(binding.label.to_yang()),
+                used_in_forwarding: Some(binding.used_in_fwd),
+            })
+        })
         .path(mpls_ldp::discovery::interfaces::interface::PATH)
         .get_iterate(|instance, _args| {
             if !instance.is_active() {
@@ -178,13 +274,13 @@ fn load_callbacks() -> Callbacks<Instance> {
             let iface = args.list_entry.as_interface().unwrap();
             Box::new(Interface {
                 name: iface.name.as_str().into(),
-                next_hello: iface.next_hello().map(|d| d.as_secs().saturating_into()).ignore_in_testing(),
+                next_hello: [AddressFamily::Ipv4, AddressFamily::Ipv6].into_iter().find_map(|af| iface.next_hello(af)).map(|d| d.as_secs().saturating_into()).ignore_in_testing(),
             })
         })
         .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv4::hello_adjacencies::hello_adjacency::PATH)
         .get_iterate(|instance, args| {
             let iface = args.parent_list_entry.as_interface().unwrap();
-            let iter = instance.state.as_ref().unwrap().ipv4.adjacencies.iter_by_iface(&iface.name).into_iter().flatten().map(ListEntry::InterfaceAdj);
+            let iter = instance.state.as_ref().unwrap().adjacencies.iter_by_iface(&iface.name).into_iter().flatten().filter(|adj| adj.source.addr.is_ipv4()).map(ListEntry::InterfaceAdj);
             Some(Box::new(iter))
         })
         .get_object(|instance, args| {
@@ -225,10 +321,54 @@ fn load_callbacks() -> Callbacks<Instance> {
                 label_space_id: Some(0),
             })
         })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, args| {
+            let iface = args.parent_list_entry.as_interface().unwrap();
+            let iter = instance.state.as_ref().unwrap().adjacencies.iter_by_iface(&iface.name).into_iter().flatten().filter(|adj| adj.source.addr.is_ipv6()).map(ListEntry::InterfaceAdj);
+            Some(Box::new(iter))
+        })
+        .get_object(|instance, args| {
+            use mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::HelloAdjacency;
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            let next_hello = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Box::new(HelloAdjacency {
+                adjacent_address: Cow::Owned(Ipv6Addr::get(adj.source.addr).unwrap()),
+                next_hello: Some(next_hello.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::HelloHoldtime;
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Box::new(HelloHoldtime {
+                adjacent: Some(adj.holdtime_adjacent),
+                negotiated: Some(adj.holdtime_negotiated),
+                remaining: adj.holdtime_remaining().map(|d| d.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::Statistics;
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Box::new(Statistics {
+                discontinuity_time: Some(Cow::Borrowed(&adj.discontinuity_time)).ignore_in_testing(),
+                hello_received: Some(adj.hello_rcvd).ignore_in_testing(),
+                hello_dropped: Some(adj.hello_dropped).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::Peer;
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Box::new(Peer {
+                lsr_id: Some(Cow::Owned(adj.lsr_id)),
+                label_space_id: Some(0),
+            })
+        })
         .path(mpls_ldp::discovery::targeted::address_families::ipv4::hello_adjacencies::hello_adjacency::PATH)
         .get_iterate(|instance, _args| {
             let Some(instance_state) = &instance.state else { return None };
-            let iter = instance_state.ipv4.adjacencies.iter().filter(|adj| adj.source.ifname.is_none()).map(ListEntry::TargetedNbrAdj);
+            let iter = instance_state.adjacencies.iter().filter(|adj| adj.source.ifname.is_none() && adj.source.addr.is_ipv4()).map(ListEntry::TargetedNbrAdj);
             Some(Box::new(iter))
         })
         .get_object(|instance, args| {
@@ -270,6 +410,51 @@ fn load_callbacks() -> Callbacks<Instance> {
                 label_space_id: Some(0),
             })
         })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, _args| {
+            let Some(instance_state) = &instance.state else { return None };
+            let iter = instance_state.adjacencies.iter().filter(|adj| adj.source.ifname.is_none() && adj.source.addr.is_ipv6()).map(ListEntry::TargetedNbrAdj);
+            Some(Box::new(iter))
+        })
+        .get_object(|instance, args| {
+            use mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::HelloAdjacency;
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            let next_hello = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Box::new(HelloAdjacency {
+                local_address: Cow::Owned(Ipv6Addr::get(adj.local_addr).unwrap()),
+                adjacent_address: Cow::Owned(Ipv6Addr::get(adj.source.addr).unwrap()),
+                next_hello: Some(next_hello.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::HelloHoldtime;
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Box::new(HelloHoldtime {
+                adjacent: Some(adj.holdtime_adjacent),
+                negotiated: Some(adj.holdtime_negotiated),
+                remaining: adj.holdtime_remaining().map(|d| d.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::Statistics;
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Box::new(Statistics {
+                discontinuity_time: Some(Cow::Borrowed(&adj.discontinuity_time)).ignore_in_testing(),
+                hello_received: Some(adj.hello_rcvd).ignore_in_testing(),
+                hello_dropped: Some(adj.hello_dropped).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::Peer;
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Box::new(Peer {
+                lsr_id: Some(Cow::Owned(adj.lsr_id)),
+                label_space_id: Some(0),
+            })
+        })
         .path(mpls_ldp::peers::peer::PATH)
         .get_iterate(|instance, _args| {
             let Some(instance_state) = &instance.state else { return None };
@@ -290,7 +475,7 @@ fn load_callbacks() -> Callbacks<Instance> {
         .path(mpls_ldp::peers::peer::address_families::ipv4::hello_adjacencies::hello_adjacency::PATH)
         .get_iterate(|instance, args| {
             let nbr = args.parent_list_entry.as_neighbor().unwrap();
-            let iter = instance.state.as_ref().unwrap().ipv4.adjacencies.iter_by_lsr_id(&nbr.lsr_id).into_iter().flatten().map(ListEntry::NeighborAdj);
+            let iter = instance.state.as_ref().unwrap().adjacencies.iter_by_lsr_id(&nbr.lsr_id).into_iter().flatten().filter(|adj| adj.source.addr.is_ipv4()).map(ListEntry::NeighborAdj);
             Some(Box::new(iter))
         })
         .get_object(|instance, args| {
@@ -323,6 +508,42 @@ fn load_callbacks() -> Callbacks<Instance> {
                 hello_dropped: Some(adj.hello_dropped).ignore_in_testing(),
             })
         })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, args| {
+            let nbr = args.parent_list_entry.as_neighbor().unwrap();
+            let iter = instance.state.as_ref().unwrap().adjacencies.iter_by_lsr_id(&nbr.lsr_id).into_iter().flatten().filter(|adj| adj.source.addr.is_ipv6()).map(ListEntry::NeighborAdj);
+            Some(Box::new(iter))
+        })
+        .get_object(|instance, args| {
+            use mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::HelloAdjacency;
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            let next_hello = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Box::new(HelloAdjacency {
+                local_address: Cow::Owned(Ipv6Addr::get(adj.local_addr).unwrap()),
+                adjacent_address: Cow::Owned(Ipv6Addr::get(adj.source.addr).unwrap()),
+                next_hello: Some(next_hello.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::HelloHoldtime;
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Box::new(HelloHoldtime {
+                adjacent: Some(adj.holdtime_adjacent),
+                negotiated: Some(adj.holdtime_negotiated),
+                remaining: adj.holdtime_remaining().map(|d| d.as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::Statistics;
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Box::new(Statistics {
+                discontinuity_time: Some(Cow::Borrowed(&adj.discontinuity_time)).ignore_in_testing(),
+                hello_received: Some(adj.hello_rcvd).ignore_in_testing(),
+                hello_dropped: Some(adj.hello_dropped).ignore_in_testing(),
+            })
+        })
         .path(mpls_ldp::peers::peer::label_advertisement_mode::PATH)
         .get_object(|_instance, args| {
             use mpls_ldp::peers::peer::label_advertisement_mode::LabelAdvertisementMode;
@@ -333,6 +554,36 @@ fn load_callbacks() -> Callbacks<Instance> {
                 negotiated: nbr.is_operational().then_some(LabelAdvMode::DownstreamUnsolicited.to_yang()),
             })
         })
+        .path(mpls_ldp::peers::peer::graceful_restart_state::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::peers::peer::graceful_restart_state::GracefulRestartState;
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            let gr = nbr.gr.as_ref();
+            Box::new(GracefulRestartState {
+                phase: gr.map(|gr| gr.phase.to_yang()),
+                remaining_time: gr.map(|gr| gr.timeout.remaining().as_secs().saturating_into()).ignore_in_testing(),
+                stale_bindings: gr.map(|gr| gr.stale_mappings.len().saturating_into()),
+            })
+        })
+        .path(mpls_ldp::peers::peer::session_protection_state::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::peers::peer::session_protection_state::SessionProtectionState;
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            Box::new(SessionProtectionState {
+                remaining_time: nbr.protection_timeout.as_ref().map(|timeout| timeout.remaining().as_secs().saturating_into()).ignore_in_testing(),
+            })
+        })
+        .path(mpls_ldp::peers::peer::received_peer_state::graceful_restart::PATH)
+        .get_object(|_instance, args| {
+            use mpls_ldp::peers::peer::received_peer_state::graceful_restart::GracefulRestart;
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            let ft_session = nbr.rcvd_ft_session.as_ref();
+            Box::new(GracefulRestart {
+                enabled: ft_session.map(|tlv| tlv.flags.contains(FtFlags::LEARN)),
+                reconnect_time: ft_session.map(|tlv| u64::from(tlv.reconnect_time / 1000).saturating_into()),
+                recovery_time: ft_session.map(|tlv| u64::from(tlv.recovery_time / 1000).saturating_into()),
+            })
+        })
         .path(mpls_ldp::peers::peer::received_peer_state::capability::end_of_lib::PATH)
         .get_object(|_instance, args| {
             use mpls_ldp::peers::peer::received_peer_state::capability::end_of_lib::EndOfLib;
@@ -8,7 +8,7 @@ use std::borrow::Cow;
 
 use holo_yang::ToYang;
 
-use crate::neighbor::{self, LabelAdvMode, LabelDistMode};
+use crate::neighbor::{self, GrPhase, LabelAdvMode, LabelDistMode};
 use crate::northbound::state::AdvertisementType;
 
 // ===== ToYang implementations =====
@@ -34,6 +34,15 @@ impl ToYang for neighbor::fsm::State {
     }
 }
 
+impl ToYang for GrPhase {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            GrPhase::Reconnect => "reconnect".into(),
+            GrPhase::Recovery => "recovery".into(),
+        }
+    }
+}
+
 impl ToYang for LabelDistMode {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
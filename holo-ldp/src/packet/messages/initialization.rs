@@ -49,6 +49,7 @@ pub struct InitMsg {
     pub cap_dynamic: Option<TlvCapDynamic>,
     pub cap_twcard_fec: Option<TlvCapTwcardFec>,
     pub cap_unrec_notif: Option<TlvCapUnrecNotif>,
+    pub ft_session: Option<TlvFtSession>,
 }
 
 //
@@ -91,6 +92,43 @@ bitflags! {
     }
 }
 
+//
+// Fault Tolerant Session TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |1|0| FT Session TLV (0x0503)   |      Length (= 12)            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |          FT Flags             |      Reserved                 |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |               FT Reconnect Timeout (in milliseconds)          |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                  Recovery Time (in milliseconds)              |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TlvFtSession {
+    pub flags: FtFlags,
+    pub reconnect_time: u32,
+    pub recovery_time: u32,
+}
+
+// Fault Tolerant Session TLV flags.
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct FtFlags: u16 {
+        const RECONNECT = 0x8000;
+        const SAVE_STATE = 0x0008;
+        const ACK = 0x0004;
+        const CHECKPOINT = 0x0002;
+        const LEARN = 0x0001;
+    }
+}
+
 // ===== impl InitMsg =====
 
 impl MessageKind for InitMsg {
@@ -118,6 +156,9 @@ impl MessageKind for InitMsg {
         if let Some(tlv) = &self.cap_unrec_notif {
             tlv.encode(self.msg_type(), buf);
         }
+        if let Some(tlv) = &self.ft_session {
+            tlv.encode(self.msg_type(), buf);
+        }
     }
 
     fn decode_body(
@@ -192,6 +233,13 @@ impl MessageKind for InitMsg {
                 self.cap_unrec_notif =
                     Some(TlvCapUnrecNotif::decode_value(buf, cxt, tlvi)?);
             }
+            TlvType::FtSession => {
+                if self.ft_session.is_some() {
+                    return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+                }
+                self.ft_session =
+                    Some(TlvFtSession::decode_value(buf, cxt, tlvi)?);
+            }
             _ => {
                 return Ok(true);
             }
@@ -260,6 +308,43 @@ impl TlvKind for TlvCommonSessParams {
     }
 }
 
+// ===== impl TlvFtSession =====
+
+impl TlvKind for TlvFtSession {
+    const TLV_TYPE: TlvType = TlvType::FtSession;
+    const U_BIT: bool = true;
+    const F_BIT: bool = false;
+
+    fn encode_value(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.flags.bits());
+        buf.put_u16(0);
+        buf.put_u32(self.reconnect_time);
+        buf.put_u32(self.recovery_time);
+    }
+
+    fn decode_value(
+        buf: &mut Bytes,
+        _cxt: &DecodeCxt,
+        tlvi: &TlvDecodeInfo,
+    ) -> DecodeResult<Self> {
+        if tlvi.tlv_len != 12 {
+            return Err(DecodeError::InvalidTlvLength(tlvi.tlv_len));
+        }
+
+        // Ignore unknown flags.
+        let flags = FtFlags::from_bits_truncate(buf.try_get_u16()?);
+        let _reserved = buf.try_get_u16()?;
+        let reconnect_time = buf.try_get_u32()?;
+        let recovery_time = buf.try_get_u32()?;
+
+        Ok(TlvFtSession {
+            flags,
+            reconnect_time,
+            recovery_time,
+        })
+    }
+}
+
 impl Default for TlvCommonSessParams {
     fn default() -> TlvCommonSessParams {
         TlvCommonSessParams {
@@ -62,6 +62,8 @@ pub enum TlvType {
     AtmSessParams = 0x0501,
     FrSessParams = 0x0502,
     LabelRequestId = 0x0600,
+    // RFC 3479
+    FtSession = 0x0503,
     // RFC 5561
     ReturnedTlvs = 0x0304,
     CapDynamic = 0x0506,
@@ -161,6 +163,7 @@ impl std::fmt::Display for TlvType {
                 write!(f, "Frame Relay Session Parameters")
             }
             TlvType::LabelRequestId => write!(f, "Label Request Message ID"),
+            TlvType::FtSession => write!(f, "Fault Tolerant Session"),
             TlvType::ReturnedTlvs => write!(f, "Returned TLVs"),
             TlvType::CapDynamic => write!(f, "Dynamic Capability Announcement"),
             TlvType::CapTwcardFec => write!(f, "Typed Wildcard FEC Capability"),
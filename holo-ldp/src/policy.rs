@@ -0,0 +1,144 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::Ipv4Addr;
+
+use holo_utils::ip::{
+    AddressFamily, IpNetworkKind, Ipv4NetworkExt, Ipv6NetworkExt,
+};
+use holo_utils::policy::MatchSets;
+use ipnetwork::IpNetwork;
+
+use crate::northbound::configuration::{
+    InstanceCfg, LabelPolicyCfg, NeighborCfg, NeighborLabelPolicyCfg,
+};
+
+// ===== global functions =====
+
+// Returns whether a local label should be assigned to the given prefix.
+pub(crate) fn label_assign_check(
+    prefix: &IpNetwork,
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+) -> bool {
+    let Some(label_policy) = af_label_policy(config, prefix.address_family())
+    else {
+        return true;
+    };
+
+    if label_policy.assign_host_routes_only && !is_host_prefix(prefix) {
+        return false;
+    }
+
+    prefix_list_check(
+        label_policy.assign_prefix_list.as_deref(),
+        prefix,
+        match_sets,
+    )
+}
+
+// Returns whether the local label of the given prefix can be advertised to
+// the specified neighbor.
+//
+// Per-peer prefix lists take precedence over the global ones.
+pub(crate) fn label_advertise_check(
+    prefix: &IpNetwork,
+    lsr_id: Ipv4Addr,
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+) -> bool {
+    let af = prefix.address_family();
+    let prefix_list = config
+        .neighbors
+        .get(&lsr_id)
+        .and_then(|nbr_cfg| {
+            nbr_label_policy(nbr_cfg, af)
+                .advertise_prefix_list
+                .as_deref()
+        })
+        .or_else(|| {
+            af_label_policy(config, af).and_then(|label_policy| {
+                label_policy.advertise_prefix_list.as_deref()
+            })
+        });
+
+    prefix_list_check(prefix_list, prefix, match_sets)
+}
+
+// Returns whether label mappings for the given prefix can be accepted from
+// the specified neighbor.
+//
+// Per-peer prefix lists take precedence over the global ones.
+pub(crate) fn label_accept_check(
+    prefix: &IpNetwork,
+    lsr_id: Ipv4Addr,
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+) -> bool {
+    let af = prefix.address_family();
+    let prefix_list = config
+        .neighbors
+        .get(&lsr_id)
+        .and_then(|nbr_cfg| {
+            nbr_label_policy(nbr_cfg, af).accept_prefix_list.as_deref()
+        })
+        .or_else(|| {
+            af_label_policy(config, af).and_then(|label_policy| {
+                label_policy.accept_prefix_list.as_deref()
+            })
+        });
+
+    prefix_list_check(prefix_list, prefix, match_sets)
+}
+
+// ===== helper functions =====
+
+fn af_label_policy(
+    config: &InstanceCfg,
+    af: AddressFamily,
+) -> Option<&LabelPolicyCfg> {
+    match af {
+        AddressFamily::Ipv4 => {
+            config.ipv4.as_ref().map(|ipv4| &ipv4.label_policy)
+        }
+        AddressFamily::Ipv6 => {
+            config.ipv6.as_ref().map(|ipv6| &ipv6.label_policy)
+        }
+    }
+}
+
+fn nbr_label_policy(
+    nbr_cfg: &NeighborCfg,
+    af: AddressFamily,
+) -> &NeighborLabelPolicyCfg {
+    match af {
+        AddressFamily::Ipv4 => &nbr_cfg.ipv4_label_policy,
+        AddressFamily::Ipv6 => &nbr_cfg.ipv6_label_policy,
+    }
+}
+
+// Checks the given prefix against an optional prefix list.
+//
+// No filtering takes place when the prefix list isn't specified. Prefix lists
+// that don't exist match nothing.
+fn prefix_list_check(
+    prefix_list: Option<&str>,
+    prefix: &IpNetwork,
+    match_sets: &MatchSets,
+) -> bool {
+    let Some(prefix_list) = prefix_list else {
+        return true;
+    };
+
+    match_sets.prefix_set_match(prefix_list, prefix)
+}
+
+fn is_host_prefix(prefix: &IpNetwork) -> bool {
+    match prefix {
+        IpNetwork::V4(prefix) => prefix.is_host_prefix(),
+        IpNetwork::V6(prefix) => prefix.is_host_prefix(),
+    }
+}
@@ -9,6 +9,7 @@ use std::sync::Arc;
 use std::sync::atomic::{self, AtomicU32, AtomicU64};
 use std::time::Duration;
 
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::socket::{
     OwnedReadHalf, OwnedWriteHalf, TcpListener, UdpSocket,
 };
@@ -44,6 +45,8 @@ use crate::network;
 //                 nbr_rx (Nx) -> |              | -> (Nx) nbr_tx
 //     nbr_kalive_timeout (Nx) -> |              | -> (Nx) nbr_kalive_interval
 //    nbr_backoff_timeout (Nx) -> |              |
+//         nbr_gr_timeout (Nx) -> |              |
+// nbr_protection_timeout (Nx) -> |              |
 //                                +--------------+
 //                         ibus_tx (1x) | ^ (1x) ibus_rx
 //                                      | |
@@ -81,11 +84,14 @@ pub mod messages {
             NbrRxPdu(NbrRxPduMsg),
             NbrKaTimeout(NbrKaTimeoutMsg),
             NbrBackoffTimeout(NbrBackoffTimeoutMsg),
+            NbrGrTimeout(NbrGrTimeoutMsg),
+            NbrProtectionTimeout(NbrProtectionTimeoutMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
         pub struct UdpRxPduMsg {
             pub src_addr: IpAddr,
+            pub ifindex: Option<u32>,
             pub multicast: bool,
             pub pdu: Result<Pdu, DecodeError>,
         }
@@ -126,6 +132,16 @@ pub mod messages {
             pub lsr_id: Ipv4Addr,
         }
 
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct NbrGrTimeoutMsg {
+            pub lsr_id: Ipv4Addr,
+        }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct NbrProtectionTimeoutMsg {
+            pub lsr_id: Ipv4Addr,
+        }
+
         impl TcpAcceptMsg {
             pub(crate) fn stream(&mut self) -> TcpStream {
                 #[cfg(not(feature = "testing"))]
@@ -239,12 +255,13 @@ pub(crate) fn extended_discovery_rx(
 // Send periodic LDP link hello messages.
 pub(crate) fn iface_hello_interval(
     interface: &Interface,
+    af: AddressFamily,
     disc_socket: &Arc<UdpSocket>,
     instance_state: &InstanceState,
 ) -> IntervalTask {
     #[cfg(not(feature = "testing"))]
     {
-        let span1 = debug_span!("interface", name = %interface.name);
+        let span1 = debug_span!("interface", name = %interface.name, %af);
         let _span1_guard = span1.enter();
         let span2 = debug_span!("discovery");
         let _span2_guard = span2.enter();
@@ -254,7 +271,7 @@ pub(crate) fn iface_hello_interval(
         let disc_socket = disc_socket.clone();
         let router_id = instance_state.router_id;
         let msg_id = instance_state.msg_id.clone();
-        let hello = interface.generate_hello(instance_state);
+        let hello = interface.generate_hello(af, instance_state);
 
         IntervalTask::new(
             Duration::from_secs(interface.config.hello_interval.into()),
@@ -264,7 +281,7 @@ pub(crate) fn iface_hello_interval(
                 let msg_id = msg_id.clone();
                 let hello = hello.clone();
 
-                Interface::send_hello(disc_socket, router_id, msg_id, hello)
+                Interface::send_hello(disc_socket, af, router_id, msg_id, hello)
             },
         )
     }
@@ -288,7 +305,11 @@ pub(crate) fn tnbr_hello_interval(
         let span3 = debug_span!("output");
         let _span3_guard = span3.enter();
 
-        let edisc_socket = instance_state.ipv4.edisc_socket.clone();
+        let edisc_socket = instance_state
+            .af(tnbr.addr.address_family())
+            .unwrap()
+            .edisc_socket
+            .clone();
         let addr = tnbr.addr;
         let router_id = instance_state.router_id;
         let msg_id = instance_state.msg_id.clone();
@@ -626,3 +647,45 @@ pub(crate) fn nbr_backoff_timeout(
         TimeoutTask {}
     }
 }
+
+// Neighbor graceful restart timeout task.
+pub(crate) fn nbr_gr_timeout(
+    lsr_id: Ipv4Addr,
+    timeout: Duration,
+    nbr_gr_timeoutp: &Sender<messages::input::NbrGrTimeoutMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let nbr_gr_timeoutp = nbr_gr_timeoutp.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::NbrGrTimeoutMsg { lsr_id };
+            let _ = nbr_gr_timeoutp.send(msg).await;
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Neighbor session protection timeout task.
+pub(crate) fn nbr_protection_timeout(
+    lsr_id: Ipv4Addr,
+    timeout: Duration,
+    nbr_protection_timeoutp: &Sender<messages::input::NbrProtectionTimeoutMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let nbr_protection_timeoutp = nbr_protection_timeoutp.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::NbrProtectionTimeoutMsg { lsr_id };
+            let _ = nbr_protection_timeoutp.send(msg).await;
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
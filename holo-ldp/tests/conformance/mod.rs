@@ -177,6 +177,97 @@ async fn message_hello3() {
     run_test::<Instance>("message-hello3", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Receipt of a LDP Hello message over IPv6 should cause the creation of a new
+// IPv6 adjacency, using the advertised IPv6 transport address.
+//
+// Input:
+//  * Northbound: enable IPv6 globally (transport address 2001:db8::2) and on
+//    eth-rt4-1
+// Output:
+//  * Protocol: LDP Notification message (Shutdown) to all peers (the change
+//    of the transport connection preference restarts the instance)
+//  * Northbound: all adjacencies and neighbors removed
+//  * Ibus: uninstall all labels learned from all peers
+// Input:
+//  * Ibus: add link-local address fe80::2/64 to eth-rt4-1
+// Output: no changes
+// Input:
+//  * Protocol: LDP Hello message from fe80::10 (IPv6 transport address
+//    2001:db8::10, dual-stack transport preference LDPoIPv6)
+// Output:
+//  * Northbound:
+//    - new IPv6 adjacency
+//    - new neighbor in the NON EXISTENT state
+#[tokio::test]
+async fn message_hello4() {
+    run_test::<Instance>("message-hello4", "topo2-1", "rt2").await;
+}
+
+// Test description:
+//
+// A dual-stack LSR should ignore LDP Hello messages whose dual-stack transport
+// connection preference differs from its own.
+//
+// Input:
+//  * Northbound: enable IPv6 globally (transport address 2001:db8::2) and on
+//    eth-rt4-1
+// Output:
+//  * Protocol: LDP Notification message (Shutdown) to all peers (the change
+//    of the transport connection preference restarts the instance)
+//  * Northbound: all adjacencies and neighbors removed
+//  * Ibus: uninstall all labels learned from all peers
+// Input:
+//  * Ibus: add link-local address fe80::2/64 to eth-rt4-1
+// Output: no changes
+// Input:
+//  * Protocol: LDP Hello message from fe80::10 (dual-stack transport
+//    preference LDPoIPv4)
+// Output: no changes
+#[tokio::test]
+async fn message_hello5() {
+    run_test::<Instance>("message-hello5", "topo2-1", "rt2").await;
+}
+
+// Test description:
+//
+// A dual-stack neighbor discovered over IPv4 first should switch to its IPv6
+// transport address once it's learned, since both LSRs prefer LDPoIPv6. The
+// TCP connection is then accepted from the IPv6 transport address.
+//
+// Input:
+//  * Northbound: enable IPv6 globally (transport address 2001:db8::2) and on
+//    eth-rt4-1
+// Output:
+//  * Protocol: LDP Notification message (Shutdown) to all peers (the change
+//    of the transport connection preference restarts the instance)
+//  * Northbound: all adjacencies and neighbors removed
+//  * Ibus: uninstall all labels learned from all peers
+// Input:
+//  * Ibus: add link-local address fe80::2/64 to eth-rt4-1
+// Output: no changes
+// Input:
+//  * Protocol: LDP Hello message from 10.0.2.10 (dual-stack transport
+//    preference LDPoIPv6)
+// Output:
+//  * Northbound:
+//    - new IPv4 adjacency
+//    - new neighbor in the NON EXISTENT state
+// Input:
+//  * Protocol: LDP Hello message from fe80::10 (IPv6 transport address
+//    2001:db8::10, dual-stack transport preference LDPoIPv6)
+// Output:
+//  * Northbound: new IPv6 adjacency
+// Input:
+//  * Protocol: Accepted TCP connection from 2001:db8::10
+// Output:
+//  * Northbound: neighbor transitioned to the INITIALIZED state
+#[tokio::test]
+async fn message_hello6() {
+    run_test::<Instance>("message-hello6", "topo2-1", "rt2").await;
+}
+
 // Test description:
 //
 // Receiving LDP Initialization messages while in the OPERATIONAL state should
@@ -747,6 +838,170 @@ async fn nb_config_ipv4_enabled2() {
     run_test::<Instance>("nb-config-ipv4-enabled2", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Restricting label assignment to host routes should prompt the withdrawal
+// of the local labels assigned to all other FECs.
+//
+// Input:
+//  * Northbound: assign labels to host routes only
+// Output:
+//  * Protocol: Label Withdraw message to all peers for all non-host FECs
+//  * Ibus: uninstall labels of all non-host FECs
+#[tokio::test]
+async fn nb_config_label_policy_assign1() {
+    run_test::<Instance>("nb-config-label-policy-assign1", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Configuring a label assignment prefix list should prompt the withdrawal
+// of the local labels assigned to the FECs rejected by the prefix list.
+//
+// Input:
+//  * Ibus: prefix set SUBNETS (10.0.0.0/8 le 24 ge 24)
+// Output: no changes
+//
+// Input:
+//  * Northbound: assign labels only to the prefixes matching SUBNETS
+// Output:
+//  * Protocol: Label Withdraw message to all peers for all host FECs
+//  * Ibus: uninstall labels of all host FECs
+#[tokio::test]
+async fn nb_config_label_policy_assign2() {
+    run_test::<Instance>("nb-config-label-policy-assign2", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Configuring a label advertisement prefix list should prompt the withdrawal
+// of the label mappings rejected by the prefix list.
+//
+// Input:
+//  * Ibus: prefix set LOOPBACKS (0.0.0.0/0 le 32 ge 32)
+// Output: no changes
+//
+// Input:
+//  * Northbound: advertise only the labels of prefixes matching LOOPBACKS
+// Output:
+//  * Protocol: Label Withdraw message to all peers for all non-host FECs
+#[tokio::test]
+async fn nb_config_label_policy_advertise1() {
+    run_test::<Instance>("nb-config-label-policy-advertise1", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Per-peer label advertisement prefix lists should apply only to the
+// corresponding peer.
+//
+// Input:
+//  * Ibus: prefix set LOOPBACKS (0.0.0.0/0 le 32 ge 32)
+// Output: no changes
+//
+// Input:
+//  * Northbound: advertise to rt4 only the labels of prefixes matching
+//    LOOPBACKS
+// Output:
+//  * Protocol: Label Withdraw message to rt4 for all non-host FECs
+//
+// Input:
+//  * Protocol: LDP Label Release message from rt4 for 10.0.1.0/24
+// Output:
+//  * Northbound: removed advertised label mapping to rt4 for 10.0.1.0/24
+//
+// Input:
+//  * Northbound: remove the rt4 peer configuration
+// Output:
+//  * Protocol: Label Mapping message to rt4 for 10.0.1.0/24
+//  * Northbound: new advertised label mapping to rt4 for 10.0.1.0/24
+#[tokio::test]
+async fn nb_config_label_policy_advertise2() {
+    run_test::<Instance>("nb-config-label-policy-advertise2", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Configuring a label acceptance prefix list should prompt the release of
+// the received label mappings rejected by the prefix list.
+//
+// Input:
+//  * Ibus: prefix set LOOPBACKS (0.0.0.0/0 le 32 ge 32)
+// Output: no changes
+//
+// Input:
+//  * Northbound: accept only the labels of prefixes matching LOOPBACKS
+// Output:
+//  * Protocol: Label Release message to all peers for all non-host FECs
+//  * Northbound:
+//    - removed all received label mappings for non-host FECs
+//    - FEC down notifications
+//  * Ibus: uninstall learned labels of all non-host FECs
+#[tokio::test]
+async fn nb_config_label_policy_accept1() {
+    run_test::<Instance>("nb-config-label-policy-accept1", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Received label mappings rejected by a per-peer label acceptance prefix
+// list should be released.
+//
+// Input:
+//  * Ibus: prefix set LOOPBACKS (0.0.0.0/0 le 32 ge 32)
+// Output: no changes
+//
+// Input:
+//  * Northbound: accept from rt4 only the labels of prefixes matching
+//    LOOPBACKS
+// Output:
+//  * Protocol: Label Release message to rt4 for all non-host FECs
+//  * Northbound:
+//    - removed all received label mappings from rt4 for non-host FECs
+//    - FEC down notifications
+//  * Ibus: uninstall labels learned from rt4 for all non-host FECs
+//
+// Input:
+//  * Protocol: LDP Label Mapping message from rt4 for 192.168.1.0/24
+// Output:
+//  * Protocol: Label Release message to rt4 for 192.168.1.0/24
+#[tokio::test]
+async fn nb_config_label_policy_accept2() {
+    run_test::<Instance>("nb-config-label-policy-accept2", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Once session protection is enabled, a targeted Hello adjacency should be
+// brought up with the LSR-ID of every peer discovered through a link Hello
+// adjacency. Disabling session protection should tear it down.
+//
+// Input:
+//  * Northbound: enable session protection
+// Output: no changes
+// Input:
+//  * Protocol: LDP Hello message from rt4 on eth-rt4-1
+// Output: no changes
+// Input:
+//  * Protocol: LDP Targeted Hello message from rt4's LSR-ID (4.4.4.4)
+// Output:
+//  * Northbound: new targeted adjacency to 4.4.4.4
+// Input:
+//  * Northbound: disable session protection
+// Output:
+//  * Northbound: removed targeted adjacency to 4.4.4.4
+#[tokio::test]
+async fn nb_config_session_protection1() {
+    run_test::<Instance>("nb-config-session-protection1", "topo2-1", "rt2")
+        .await;
+}
+
 // Test description:
 //
 // Targeted hellos should be accepted only for configured sources.
@@ -1033,6 +1288,38 @@ async fn ibus_iface_update3() {
     run_test::<Instance>("ibus-iface-update3", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// An interface is synchronized with the IGPs as long as the session of one of
+// its link adjacencies is operational.
+//
+// Input:
+//  * Ibus: LDP-IGP synchronization request
+// Output:
+//  * Ibus: eth-rt4-1, eth-rt4-2 and eth-sw1 are synchronized
+//
+// Input:
+//  * Protocol: eth-rt4-2 adjacency to rt4 timed out
+// Output:
+//  * Northbound: remove eth-rt4-2 adjacency
+//  * Ibus: eth-rt4-2 is no longer synchronized
+//
+// Input:
+//  * Protocol: eth-rt4-1 adjacency to rt4 timed out
+// Output:
+//  * Protocol: LDP Notification message (Hold Timer Expired) to rt4
+//  * Northbound:
+//    - remove eth-rt4-1 adjacency
+//    - remove rt4 neighbor
+//    - remove all address and label bindings learned from rt4
+//  * Ibus:
+//    - eth-rt4-1 is no longer synchronized
+//    - uninstall all labels learned from rt4
+#[tokio::test]
+async fn ibus_ldp_igp_sync1() {
+    run_test::<Instance>("ibus-ldp-igp-sync1", "topo2-1", "rt2").await;
+}
+
 // Test description:
 //
 // Route added without any available label binding.
@@ -1290,3 +1577,39 @@ async fn timeout_nbr1() {
 async fn timeout_nbr2() {
     run_test::<Instance>("timeout-nbr2", "topo1-1", "rt2").await;
 }
+
+// Test description:
+//
+// A protected session should be kept up by its targeted Hello adjacency after
+// all link Hello adjacencies are lost, until the session protection duration
+// expires.
+//
+// Input:
+//  * Northbound: enable session protection with a duration of 60 seconds
+// Output: no changes
+// Input:
+//  * Protocol: LDP Hello message from rt4 on eth-rt4-1
+// Output: no changes
+// Input:
+//  * Protocol: LDP Targeted Hello message from rt4's LSR-ID (4.4.4.4)
+// Output:
+//  * Northbound: new targeted adjacency to 4.4.4.4
+// Input:
+//  * Protocol: eth-rt4-2 adjacency to rt4 timed out
+// Output:
+//  * Northbound: removed eth-rt4-2 adjacency
+// Input:
+//  * Protocol: eth-rt4-1 adjacency to rt4 timed out
+// Output:
+//  * Northbound: removed eth-rt4-1 adjacency (the session with rt4 is kept up)
+// Input:
+//  * Protocol: session protection timeout for rt4
+// Output:
+//  * Northbound:
+//    - removed targeted adjacency to 4.4.4.4
+//    - session with rt4 torn down
+//  * Ibus: uninstall all labels learned from rt4
+#[tokio::test]
+async fn timeout_nbr_protection1() {
+    run_test::<Instance>("timeout-nbr-protection1", "topo2-1", "rt2").await;
+}
@@ -7,6 +7,7 @@
 mod address;
 mod capability;
 mod hello;
+mod initialization;
 mod keepalive;
 mod label;
 mod notification;
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::net::Ipv4Addr;
 
 use chrono::{DateTime, Utc};
@@ -271,25 +271,45 @@ fn update_net_summary_lsas<V>(
     // Save the old table of summary routes.
     let area = &mut areas[area_idx];
     let mut old_summaries = std::mem::take(&mut area.state.net_summaries);
+    let mut stale_lsa_ids = vec![];
 
     // (Re)originate the required Summary-LSAs.
+    let prefixes = new_summaries
+        .iter()
+        .map(|(prefix, _)| *prefix)
+        .collect::<BTreeSet<_>>();
     area.state.net_summaries = new_summaries
         .into_iter()
-        .map(|(prefix, new_summary)| {
+        .filter_map(|(prefix, new_summary)| {
+            // Skip prefixes that can't be assigned a unique LSA-ID. Their
+            // previously originated summary LSAs, if any, are flushed below.
+            let lsa_id = match V::lsa_id_inter_area_network(prefix, &prefixes) {
+                Ok(lsa_id) => lsa_id,
+                Err(error) => {
+                    error.log();
+                    return None;
+                }
+            };
             let lsa_id = match old_summaries.remove(&prefix) {
                 Some((old_lsa_id, old_summary)) => {
-                    // Reoriginate summary LSA if the route has changed, reusing
-                    // the previous LSA-ID.
-                    if new_summary != old_summary {
+                    // Reoriginate summary LSA if the route or its LSA-ID has
+                    // changed. The LSA-ID of a prefix might change when another
+                    // prefix with the same network address appears or
+                    // disappears.
+                    let lsa_id = lsa_id.unwrap_or(old_lsa_id);
+                    if new_summary != old_summary || lsa_id != old_lsa_id {
                         V::lsa_orig_inter_area_network(
                             area,
                             instance,
                             prefix,
-                            Some(old_lsa_id),
+                            Some(lsa_id),
                             &new_summary,
                         );
                     }
-                    old_lsa_id
+                    if lsa_id != old_lsa_id {
+                        stale_lsa_ids.push(old_lsa_id);
+                    }
+                    lsa_id
                 }
                 None => {
                     // Originate new summary LSA.
@@ -297,19 +317,30 @@ fn update_net_summary_lsas<V>(
                         area,
                         instance,
                         prefix,
-                        None,
+                        lsa_id,
                         &new_summary,
                     )
                 }
             };
 
-            (prefix, (lsa_id, new_summary))
+            Some((prefix, (lsa_id, new_summary)))
         })
         .collect();
 
-    // Flush old summaries that are no longer valid.
+    // Flush old summaries that are no longer valid, except for those whose
+    // LSA-ID was taken over by another prefix.
     let lsa_type = V::type3_summary(instance.config.extended_lsa);
-    let lsa_ids = old_summaries.into_values().map(|(lsa_id, _)| lsa_id);
+    let lsa_ids = old_summaries
+        .into_values()
+        .map(|(lsa_id, _)| lsa_id)
+        .chain(stale_lsa_ids)
+        .filter(|lsa_id| {
+            !area
+                .state
+                .net_summaries
+                .values()
+                .any(|(new_lsa_id, _)| new_lsa_id == lsa_id)
+        });
     flush_summary_lsas(lsa_type, lsa_ids, area, instance, lsa_entries);
 }
 
@@ -88,6 +88,9 @@ pub enum Debug<'a, V: Version> {
     GrHelperReject(Ipv4Addr, GrRejectReason),
     GrHelperEnter(Ipv4Addr, GrReason, u32),
     GrHelperExit(Ipv4Addr, GrExitReason),
+    // Database overflow
+    ExtOverflowEnter(u32),
+    ExtOverflowExit,
     // Internal bus
     IbusRx(&'a IbusMsg),
 }
@@ -341,6 +344,14 @@ where
                     debug!(%reason, "{}", self);
                 })
             }
+            Debug::ExtOverflowEnter(ext_lsdb_limit) => {
+                // Parent span(s): ospf-instance
+                debug!(%ext_lsdb_limit, "{}", self);
+            }
+            Debug::ExtOverflowExit => {
+                // Parent span(s): ospf-instance
+                debug!("{}", self);
+            }
             Debug::IbusRx(msg) => {
                 // Parent span(s): ospf-instance
                 debug_span!("internal-bus").in_scope(|| {
@@ -490,6 +501,12 @@ where
             Debug::GrHelperExit(..) => {
                 write!(f, "exiting from helper mode")
             }
+            Debug::ExtOverflowEnter(..) => {
+                write!(f, "entering database overflow state")
+            }
+            Debug::ExtOverflowExit => {
+                write!(f, "exiting database overflow state")
+            }
             Debug::IbusRx(..) => {
                 write!(f, "message")
             }
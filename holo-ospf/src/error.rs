@@ -42,6 +42,8 @@ pub enum Error<V: Version> {
     // SPF
     SpfRootNotFound(Ipv4Addr),
     SpfNexthopCalcError(V::VertexId),
+    // LSA origination
+    LsaIdUnavailable(V::IpNetwork),
     // Segment Routing
     SrgbNotFound(Ipv4Addr, Ipv4Addr),
     InvalidSidIndex(u32),
@@ -140,6 +142,9 @@ where
             Error::SpfNexthopCalcError(vertex_id) => {
                 warn!(?vertex_id, "{}", self);
             }
+            Error::LsaIdUnavailable(prefix) => {
+                warn!(%prefix, "{}", self);
+            }
             Error::SrgbNotFound(area_id, router_id) => {
                 warn!(%area_id, %router_id, "{}", self);
             }
@@ -226,6 +231,9 @@ where
             Error::SpfNexthopCalcError(..) => {
                 write!(f, "failed to calculate nexthop address")
             }
+            Error::LsaIdUnavailable(..) => {
+                write!(f, "failed to assign Link State ID to prefix")
+            }
             Error::SrgbNotFound(..) => {
                 write!(f, "failed to find nexthop's neighbor SRGB")
             }
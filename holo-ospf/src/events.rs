@@ -22,7 +22,7 @@ use crate::error::{Error, InterfaceCfgError};
 use crate::flood::flood;
 use crate::gr::GrExitReason;
 use crate::instance::{InstanceArenas, InstanceUpView};
-use crate::interface::{Interface, ism};
+use crate::interface::{Interface, InterfaceType, ism};
 use crate::lsdb::{
     self, LsaEntry, LsaEntryFlags, LsaOriginateEvent, lsa_compare,
 };
@@ -100,7 +100,7 @@ where
         iface.state.neighbors.delete(&mut arenas.neighbors, nbr_idx);
 
         // Synchronize interface's Hello Tx task (updated list of neighbors).
-        iface.sync_hello_tx(area, instance);
+        iface.sync_hello_tx(area, instance, &arenas.neighbors);
     }
 
     Ok(())
@@ -289,11 +289,14 @@ where
                     nbr.bdr = hello.bdr();
                 }
 
+                // Stop polling the neighbor if it was previously inactive.
+                iface.nbma_poll_interval_stop(src);
+
                 // Synchronize interface's Hello Tx task (updated list of
                 // neighbors).
-                iface.sync_hello_tx(area, instance);
+                iface.sync_hello_tx(area, instance, neighbors);
 
-                (nbr_idx, nbr)
+                (nbr_idx, &mut neighbors[nbr_idx])
             }
         };
 
@@ -311,6 +314,21 @@ where
     // Trigger the HelloReceived event.
     nbr.fsm(iface, area, instance, lsa_entries, nsm::Event::HelloRcvd);
 
+    // On NBMA networks, routers that aren't eligible to become DR must reply
+    // to Hello packets received from eligible neighbors other than the current
+    // DR and BDR (RFC 2328 - Section 9.5.1).
+    if iface.config.if_type == InterfaceType::NonBroadcast
+        && iface.config.priority == 0
+        && hello.priority() != 0
+    {
+        let nbr_net_id = nbr.network_id();
+        if iface.state.dr != Some(nbr_net_id)
+            && iface.state.bdr != Some(nbr_net_id)
+        {
+            output::send_hello_direct(iface, area, instance, src);
+        }
+    }
+
     // Trigger the 1-WayReceived or the 2-WayReceived event.
     if hello
         .neighbors()
@@ -939,6 +957,11 @@ where
             );
         }
 
+        // Check whether the AS-external-LSA limit has been reached.
+        if lsdb_idx == LsdbIndex::As {
+            lsdb::ext_overflow_check(instance, arenas);
+        }
+
         // Examine the next LSA.
         return false;
     }
@@ -1401,6 +1424,55 @@ where
     spf::fsm(event, instance, arenas)
 }
 
+// ===== LDP-IGP synchronization hold-down timeout =====
+
+pub(crate) fn process_ldp_sync_holddown_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    area_key: AreaKey,
+    iface_key: InterfaceKey,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    // Lookup area and interface.
+    let (_, area) = arenas.areas.get_mut_by_key(&area_key)?;
+    let (_iface_idx, iface) = area
+        .interfaces
+        .get_mut_by_key(&mut arenas.interfaces, &iface_key)?;
+
+    // Stop advertising the maximum link metric.
+    iface.state.ldp_sync_holddown_expired = true;
+    iface.state.tasks.ldp_sync_holddown = None;
+    instance.tx.protocol_input.lsa_orig_event(
+        LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+    );
+
+    Ok(())
+}
+
+// ===== Database overflow exit timeout =====
+
+pub(crate) fn process_exit_overflow_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    // Leave the overflow state only if the number of non-default
+    // AS-external-LSAs is below the limit. Otherwise, restart the timer.
+    if let Some(limit) = instance.config.ext_lsdb_limit
+        && instance.state.ext_lsa_count >= limit
+    {
+        lsdb::ext_overflow_timer_update(instance);
+        return Ok(());
+    }
+
+    lsdb::ext_overflow_exit(instance);
+
+    Ok(())
+}
+
 // ===== Grace period timeout =====
 
 pub(crate) fn process_grace_period_timeout<V>(
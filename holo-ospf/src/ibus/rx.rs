@@ -9,6 +9,7 @@ use std::net::Ipv4Addr;
 use holo_utils::bfd;
 use holo_utils::bier::BierCfgEvent;
 use holo_utils::ip::IpNetworkKind;
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::southbound::{AddressFlags, AddressMsg, InterfaceUpdateMsg};
 use holo_utils::sr::SrCfgEvent;
 
@@ -281,13 +282,58 @@ where
             }
 
             // Update interface authentication keys.
-            iface.auth_update(area, &instance);
+            iface.auth_update(area, &instance, &arenas.neighbors);
         }
     }
 
     Ok(())
 }
 
+pub(crate) fn process_ldp_igp_sync_update<V>(
+    instance: &mut Instance<V>,
+    ifname: String,
+    state: LdpIgpSyncState,
+) where
+    V: Version,
+{
+    // Lookup interface.
+    let arenas = &mut instance.arenas;
+    let Some((area_idx, iface_idx)) =
+        arenas.areas.indexes().find_map(|area_idx| {
+            let area = &arenas.areas[area_idx];
+            area.interfaces
+                .get_by_name(&arenas.interfaces, &ifname)
+                .map(|(iface_idx, _iface)| (area_idx, iface_idx))
+        })
+    else {
+        return;
+    };
+    let iface = &mut arenas.interfaces[iface_idx];
+
+    // Update LDP-IGP synchronization state.
+    if iface.system.ldp_sync == state {
+        return;
+    }
+    iface.system.ldp_sync = state;
+
+    let Some((instance, arenas)) = instance.as_up() else {
+        return;
+    };
+    if !instance.config.ldp_igp_sync {
+        return;
+    }
+    let area = &arenas.areas[area_idx];
+    let iface = &mut arenas.interfaces[iface_idx];
+
+    // Start or stop the hold-down timer.
+    iface.ldp_sync_holddown_update(area, &instance);
+
+    // Reoriginate the area's Router-LSA to update the interface metric.
+    instance.tx.protocol_input.lsa_orig_event(
+        LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+    );
+}
+
 pub(crate) fn process_hostname_update<V>(
     instance: &mut Instance<V>,
     hostname: Option<String>,
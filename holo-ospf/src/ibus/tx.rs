@@ -76,6 +76,7 @@ pub(crate) fn route_install<V>(
             route_type: route.path_type,
         },
         nexthops: nexthops.clone(),
+        backup_nexthops: Default::default(),
     };
     ibus_tx.route_ip_add(msg);
 
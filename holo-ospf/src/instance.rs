@@ -36,10 +36,10 @@ use crate::northbound::notification;
 use crate::route::{RouteNet, RouteNetFlags};
 use crate::spf::{SpfLogEntry, SpfTriggerLsa};
 use crate::tasks::messages::input::{
-    DbDescFreeMsg, DelayedAckMsg, GracePeriodMsg, IsmEventMsg, LsaFlushMsg,
-    LsaOrigCheckMsg, LsaOrigDelayedMsg, LsaOrigEventMsg, LsaRefreshMsg,
-    LsdbMaxAgeSweepMsg, NetRxPacketMsg, NsmEventMsg, RxmtIntervalMsg,
-    SendLsUpdateMsg, SpfDelayEventMsg,
+    DbDescFreeMsg, DelayedAckMsg, ExitOverflowMsg, GracePeriodMsg, IsmEventMsg,
+    LdpSyncHolddownMsg, LsaFlushMsg, LsaOrigCheckMsg, LsaOrigDelayedMsg,
+    LsaOrigEventMsg, LsaRefreshMsg, LsdbMaxAgeSweepMsg, NetRxPacketMsg,
+    NsmEventMsg, RxmtIntervalMsg, SendLsUpdateMsg, SpfDelayEventMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::version::Version;
@@ -104,6 +104,11 @@ pub struct InstanceState<V: Version> {
     pub auth_seqno: Arc<AtomicU64>,
     // Hostname cache.
     pub hostnames: BTreeMap<Ipv4Addr, String>,
+    // Database overflow state (RFC 1765).
+    pub ext_lsa_count: u32,
+    pub ext_overflow: bool,
+    pub ext_approaching_overflow: bool,
+    pub exit_overflow_timer: Option<TimeoutTask>,
 }
 
 #[derive(Debug, Default)]
@@ -146,6 +151,10 @@ pub struct ProtocolInputChannelsTx<V: Version> {
     pub spf_delay_event: UnboundedSender<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Sender<GracePeriodMsg>,
+    // LDP-IGP synchronization hold-down timeout.
+    pub ldp_sync_holddown: Sender<LdpSyncHolddownMsg>,
+    // Database overflow exit timeout.
+    pub exit_overflow: Sender<ExitOverflowMsg>,
 }
 
 #[derive(Debug)]
@@ -180,6 +189,10 @@ pub struct ProtocolInputChannelsRx<V: Version> {
     pub spf_delay_event: UnboundedReceiver<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Receiver<GracePeriodMsg>,
+    // LDP-IGP synchronization hold-down timeout.
+    pub ldp_sync_holddown: Receiver<LdpSyncHolddownMsg>,
+    // Database overflow exit timeout.
+    pub exit_overflow: Receiver<ExitOverflowMsg>,
 }
 
 pub struct InstanceUpView<'a, V: Version> {
@@ -482,6 +495,8 @@ where
             mpsc::channel(4);
         let (spf_delay_eventp, spf_delay_eventc) = mpsc::unbounded_channel();
         let (grace_periodp, grace_periodc) = mpsc::channel(4);
+        let (ldp_sync_holddownp, ldp_sync_holddownc) = mpsc::channel(4);
+        let (exit_overflowp, exit_overflowc) = mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             ism_event: ism_eventp,
@@ -499,6 +514,8 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalp,
             spf_delay_event: spf_delay_eventp,
             grace_period: grace_periodp,
+            ldp_sync_holddown: ldp_sync_holddownp,
+            exit_overflow: exit_overflowp,
         };
         let rx = ProtocolInputChannelsRx {
             ism_event: ism_eventc,
@@ -516,6 +533,8 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalc,
             spf_delay_event: spf_delay_eventc,
             grace_period: grace_periodc,
+            ldp_sync_holddown: ldp_sync_holddownc,
+            exit_overflow: exit_overflowc,
         };
 
         (tx, rx)
@@ -583,6 +602,10 @@ where
             gr_helper_count: 0,
             auth_seqno: Arc::new(V::initial_auth_seqno(boot_count).into()),
             hostnames: Default::default(),
+            ext_lsa_count: 0,
+            ext_overflow: false,
+            ext_approaching_overflow: false,
+            exit_overflow_timer: None,
         }
     }
 }
@@ -725,6 +748,12 @@ where
             msg = self.grace_period.recv() => {
                 msg.map(ProtocolInputMsg::GracePeriod)
             }
+            msg = self.ldp_sync_holddown.recv() => {
+                msg.map(ProtocolInputMsg::LdpSyncHolddown)
+            }
+            msg = self.exit_overflow.recv() => {
+                msg.map(ProtocolInputMsg::ExitOverflow)
+            }
         }
     }
 }
@@ -778,6 +807,10 @@ where
             // Update all interfaces using this keychain.
             ibus::rx::process_keychain_update(instance, &keychain_name)?
         }
+        // LDP-IGP synchronization state update.
+        IbusMsg::LdpIgpSyncUpd { ifname, state } => {
+            ibus::rx::process_ldp_igp_sync_update(instance, ifname, state);
+        }
         // Router ID update notification.
         IbusMsg::RouterIdUpdate(router_id) => {
             ibus::rx::process_router_id_update(instance, router_id);
@@ -938,6 +971,19 @@ where
                 msg.nbr_key,
             )?
         }
+        // LDP-IGP synchronization hold-down timeout.
+        ProtocolInputMsg::LdpSyncHolddown(msg) => {
+            events::process_ldp_sync_holddown_timeout(
+                instance,
+                arenas,
+                msg.area_key,
+                msg.iface_key,
+            )?
+        }
+        // Database overflow exit timeout.
+        ProtocolInputMsg::ExitOverflow(_msg) => {
+            events::process_exit_overflow_timeout(instance)?
+        }
     }
 
     Ok(())
@@ -13,12 +13,13 @@ use chrono::{DateTime, Utc};
 use holo_protocol::InstanceChannelsTx;
 use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::keychain::{Key, Keychains};
+use holo_utils::mpls::LdpIgpSyncState;
 use holo_utils::socket::{AsyncFd, Socket};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
 use ipnetwork::{Ipv4Network, Ipv6Network};
 use ism::{Event, State};
-use smallvec::smallvec;
+use smallvec::{SmallVec, smallvec};
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::UnboundedSender;
 
@@ -30,7 +31,7 @@ use crate::instance::{Instance, InstanceUpView};
 use crate::lsdb::{LsaEntry, LsaOriginateEvent};
 use crate::neighbor::{Neighbor, NeighborNetId, nsm};
 use crate::network::MulticastAddr;
-use crate::northbound::configuration::InterfaceCfg;
+use crate::northbound::configuration::{InstanceCfg, InterfaceCfg};
 use crate::northbound::notification;
 use crate::packet::Packet;
 use crate::packet::auth::AuthMethod;
@@ -63,6 +64,8 @@ pub struct InterfaceSys<V: Version> {
     pub unnumbered: bool,
     // OSPFv3: link-local address.
     pub linklocal_addr: Option<Ipv6Network>,
+    // LDP-IGP synchronization state.
+    pub ldp_sync: LdpIgpSyncState,
 }
 
 #[derive(Debug)]
@@ -92,6 +95,8 @@ pub struct InterfaceState<V: Version> {
     pub network_lsa_self: Option<LsaKey<V::LsaType>>,
     // Authentication data.
     pub auth: Option<AuthMethod>,
+    // Whether the LDP-IGP synchronization hold-down timer has expired.
+    pub ldp_sync_holddown_expired: bool,
     // Tasks.
     pub tasks: InterfaceTasks<V>,
 }
@@ -119,6 +124,8 @@ pub struct InterfaceTasks<V: Version> {
     pub ls_update_timer: Option<TimeoutTask>,
     // Delayed Ack task.
     pub ls_delayed_ack: Option<TimeoutTask>,
+    // LDP-IGP synchronization hold-down timer task.
+    pub ldp_sync_holddown: Option<TimeoutTask>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -308,9 +315,12 @@ where
             }
 
             // Start Hello Tx task.
-            self.hello_interval_start(area, instance);
+            self.hello_interval_start(area, instance, neighbors);
         }
 
+        // Start LDP-IGP synchronization hold-down timer if necessary.
+        self.ldp_sync_holddown_update(area, instance);
+
         // Get new ISM state.
         let new_ism_state = match self.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
@@ -334,24 +344,15 @@ where
                 // Examine the configured list of neighbors for this interface
                 // and generate the neighbor event Start for each neighbor that
                 // is also eligible to become Designated Router.
-                for nbr in self
+                let addrs = self
                     .config
                     .static_nbrs
                     .iter()
                     .filter(|(_, snbr)| snbr.priority != 0)
-                    .filter_map(|(addr, _)| {
-                        self.state
-                            .neighbors
-                            .iter(neighbors)
-                            .find(|nbr| nbr.src == *addr)
-                    })
-                {
-                    instance.tx.protocol_input.nsm_event(
-                        area.id,
-                        self.id,
-                        nbr.id,
-                        nsm::Event::Start,
-                    );
+                    .map(|(addr, _)| *addr)
+                    .collect::<Vec<_>>();
+                for addr in addrs {
+                    self.nbma_nbr_start(area, instance, neighbors, addr);
                 }
             }
         }
@@ -410,9 +411,10 @@ where
         &mut self,
         area: &Area<V>,
         instance: &InstanceUpView<'_, V>,
+        neighbors: &Arena<Neighbor<V>>,
     ) {
         if !self.is_passive() && self.state.ism_state >= ism::State::Waiting {
-            self.hello_interval_start(area, instance);
+            self.hello_interval_start(area, instance, neighbors);
         }
     }
 
@@ -475,6 +477,7 @@ where
         &mut self,
         area: &Area<V>,
         instance: &InstanceUpView<'_, V>,
+        neighbors: &Arena<Neighbor<V>>,
     ) {
         // Update authentication data.
         self.state.auth = self.auth(&instance.shared.keychains);
@@ -497,7 +500,7 @@ where
                 instance.tx,
             );
             self.state.net = Some(net);
-            self.sync_hello_tx(area, instance);
+            self.sync_hello_tx(area, instance, neighbors);
         }
     }
 
@@ -609,12 +612,16 @@ where
         &mut self,
         area: &Area<V>,
         instance: &InstanceUpView<'_, V>,
+        neighbors: &Arena<Neighbor<V>>,
     ) {
         let dst = match self.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::Broadcast => {
                 smallvec![*V::multicast_addr(MulticastAddr::AllSpfRtrs)]
             }
-            InterfaceType::NonBroadcast | InterfaceType::PointToMultipoint => {
+            InterfaceType::NonBroadcast => {
+                self.nbma_hello_dst(instance, neighbors)
+            }
+            InterfaceType::PointToMultipoint => {
                 self.config.static_nbrs.keys().copied().collect()
             }
         };
@@ -640,6 +647,167 @@ where
         self.state.tasks.nbma_poll_interval.remove(&addr);
     }
 
+    // Restarts the NBMA poll interval tasks using the configured intervals.
+    //
+    // Neighbors that are no longer configured stop being polled.
+    pub(crate) fn nbma_poll_interval_reset(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        let addrs = self
+            .state
+            .tasks
+            .nbma_poll_interval
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        for addr in addrs {
+            match self.config.static_nbrs.get(&addr) {
+                Some(snbr) => {
+                    let poll_interval = snbr.poll_interval;
+                    self.nbma_poll_interval_start(
+                        area,
+                        instance,
+                        addr,
+                        poll_interval,
+                    );
+                }
+                None => self.nbma_poll_interval_stop(addr),
+            }
+        }
+    }
+
+    // Generates the neighbor event Start for the given NBMA neighbor.
+    //
+    // Neighbors are only instantiated once a Hello packet is received from
+    // them. Until then, the Attempt state is represented by sending Hello
+    // packets to the neighbor at the HelloInterval rate instead of polling it.
+    fn nbma_nbr_start(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        neighbors: &Arena<Neighbor<V>>,
+        addr: V::NetIpAddr,
+    ) {
+        let nbr = self
+            .state
+            .neighbors
+            .iter(neighbors)
+            .find(|nbr| nbr.src == addr)
+            .map(|nbr| (nbr.id, nbr.state));
+        match nbr {
+            Some((nbr_id, nsm::State::Down)) => {
+                instance.tx.protocol_input.nsm_event(
+                    area.id,
+                    self.id,
+                    nbr_id,
+                    nsm::Event::Start,
+                );
+            }
+            Some(_) => (),
+            None => self.nbma_poll_interval_stop(addr),
+        }
+    }
+
+    // Returns the addresses of the configured NBMA neighbors that should
+    // periodically receive Hello packets at the HelloInterval rate (RFC 2328 -
+    // Section 9.5.1).
+    //
+    // Inactive neighbors are excluded since they're polled separately at the
+    // PollInterval rate.
+    fn nbma_hello_dst(
+        &self,
+        instance: &InstanceUpView<'_, V>,
+        neighbors: &Arena<Neighbor<V>>,
+    ) -> SmallVec<[V::NetIpAddr; 4]> {
+        let Some(src_addr) = &self.state.src_addr else {
+            return Default::default();
+        };
+        let router_id = instance.state.router_id;
+        let net_id = V::network_id(src_addr, router_id);
+        let is_dr_or_bdr = |net_id| {
+            self.state.dr == Some(net_id) || self.state.bdr == Some(net_id)
+        };
+
+        self.config
+            .static_nbrs
+            .iter()
+            .filter(|(addr, _)| {
+                !self.state.tasks.nbma_poll_interval.contains_key(addr)
+            })
+            .filter(|(addr, snbr)| {
+                // The DR and BDR send Hello packets to all neighbors.
+                if is_dr_or_bdr(net_id) {
+                    return true;
+                }
+
+                // Routers eligible to become DR send Hello packets to all
+                // neighbors that are also eligible.
+                if self.config.priority != 0 {
+                    return snbr.priority != 0;
+                }
+
+                // Otherwise, Hello packets are sent only to the DR and BDR.
+                self.state
+                    .neighbors
+                    .iter(neighbors)
+                    .find(|nbr| nbr.src == **addr)
+                    .is_some_and(|nbr| is_dr_or_bdr(nbr.network_id()))
+            })
+            .map(|(addr, _)| *addr)
+            .collect()
+    }
+
+    // Starts or stops the LDP-IGP synchronization hold-down timer according to
+    // the current LDP state of the interface.
+    pub(crate) fn ldp_sync_holddown_update(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        self.state.ldp_sync_holddown_expired = false;
+        self.state.tasks.ldp_sync_holddown = None;
+
+        if instance.config.ldp_igp_sync
+            && self.system.ldp_sync == LdpIgpSyncState::NotSynced
+            && let Some(holddown) = instance.config.ldp_igp_sync_holddown
+        {
+            let task =
+                tasks::ldp_sync_holddown_timer(self, area, instance, holddown);
+            self.state.tasks.ldp_sync_holddown = Some(task);
+        }
+    }
+
+    // Returns whether the interface should advertise the maximum link metric
+    // while waiting for LDP to converge (RFC 5443).
+    pub(crate) fn ldp_sync_max_metric(
+        &self,
+        instance_cfg: &InstanceCfg,
+    ) -> bool {
+        instance_cfg.ldp_igp_sync
+            && self.system.ldp_sync == LdpIgpSyncState::NotSynced
+            && !self.state.ldp_sync_holddown_expired
+    }
+
+    // Returns the cost of the link to the given neighbor.
+    //
+    // On point-to-multipoint interfaces, the cost can be configured on a
+    // per-neighbor basis.
+    pub(crate) fn nbr_cost(&self, nbr: &Neighbor<V>) -> u16 {
+        if self.config.if_type == InterfaceType::PointToMultipoint
+            && let Some(cost) = self
+                .config
+                .static_nbrs
+                .get(&nbr.src)
+                .and_then(|snbr| snbr.cost)
+        {
+            return cost;
+        }
+
+        self.config.cost
+    }
+
     fn update_mcast_groups(&mut self) {
         let socket = match &self.state.net {
             Some(net) => net.socket.get_ref(),
@@ -754,24 +922,15 @@ where
         if self.config.if_type == InterfaceType::NonBroadcast
             && matches!(next_state, ism::State::Dr | ism::State::Backup)
         {
-            for nbr in self
+            let addrs = self
                 .config
                 .static_nbrs
                 .iter()
                 .filter(|(_, snbr)| snbr.priority == 0)
-                .filter_map(|(addr, _)| {
-                    self.state
-                        .neighbors
-                        .iter(neighbors)
-                        .find(|nbr| nbr.src == *addr)
-                })
-            {
-                instance.tx.protocol_input.nsm_event(
-                    area.id,
-                    self.id,
-                    nbr.id,
-                    nsm::Event::Start,
-                );
+                .map(|(addr, _)| *addr)
+                .collect::<Vec<_>>();
+            for addr in addrs {
+                self.nbma_nbr_start(area, instance, neighbors, addr);
             }
         }
 
@@ -793,7 +952,7 @@ where
             }
 
             // Synchronize interface's Hello Tx task (updated DR and/or BDR).
-            self.sync_hello_tx(area, instance);
+            self.sync_hello_tx(area, instance, neighbors);
         }
 
         // If the DR changed, reoriginate LSAs that might have been affected.
@@ -947,6 +1106,7 @@ where
             primary_addr: None,
             unnumbered: false,
             linklocal_addr: None,
+            ldp_sync: LdpIgpSyncState::NotRequired,
         }
     }
 }
@@ -973,6 +1133,7 @@ where
             lsdb: Default::default(),
             network_lsa_self: None,
             auth: None,
+            ldp_sync_holddown_expired: false,
             tasks: Default::default(),
         }
     }
@@ -1079,6 +1240,7 @@ where
             wait_timer: Default::default(),
             ls_update_timer: Default::default(),
             ls_delayed_ack: Default::default(),
+            ldp_sync_holddown: Default::default(),
         }
     }
 }
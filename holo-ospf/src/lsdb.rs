@@ -5,7 +5,7 @@
 //
 
 use std::cmp::Ordering;
-use std::collections::{btree_map, hash_map};
+use std::collections::{BTreeSet, btree_map, hash_map};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Instant;
@@ -29,6 +29,7 @@ use crate::error::Error;
 use crate::flood::flood;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::Interface;
+use crate::northbound::notification;
 use crate::packet::lsa::{
     Lsa, LsaBodyVersion, LsaHdrVersion, LsaKey, LsaTypeVersion,
 };
@@ -131,6 +132,7 @@ pub enum LsaOriginateEvent {
     },
     HostnameChange,
     NodeTagsChange,
+    ExtOverflowExit,
 }
 
 #[derive(Debug)]
@@ -187,6 +189,16 @@ pub trait LsdbVersion<V: Version> {
         event: LsaOriginateEvent,
     ) -> Result<(), Error<V>>;
 
+    // Return the Link State ID used to advertise the given prefix, taking into
+    // account all other prefixes advertised in the same LSDB.
+    //
+    // Returns `None` when the Link State ID isn't derived from the prefix
+    // (OSPFv3), or an error if no Link State ID is available for the prefix.
+    fn lsa_id_inter_area_network(
+        prefix: V::IpNetwork,
+        prefixes: &BTreeSet<V::IpNetwork>,
+    ) -> Result<Option<u32>, Error<V>>;
+
     // Originate Type-3 Summary LSA (OSPFv2) or Inter-Area-Network-LSA (OSPFv3).
     fn lsa_orig_inter_area_network(
         area: &mut Area<V>,
@@ -462,6 +474,19 @@ where
     let route_recalc =
         content_change && !lsa.body.is_unknown() && !self_orig_summary;
 
+    // Update the number of non-default AS-external-LSAs (RFC 1765).
+    if lsdb_idx == LsdbIndex::As {
+        let is_counted = |lsa: &Lsa<V>| {
+            !lsa.hdr.is_maxage() && lsa.body.is_non_default_as_external()
+        };
+        if old_lsa.as_deref().is_some_and(is_counted) {
+            instance.state.ext_lsa_count -= 1;
+        }
+        if is_counted(&lsa) {
+            instance.state.ext_lsa_count += 1;
+        }
+    }
+
     // A network topology change forces the termination of a graceful restart.
     if content_change
         && lsa.hdr.lsa_type().is_gr_topology_info()
@@ -577,6 +602,11 @@ pub(crate) fn originate<V>(
         &instance.tx.protocol_input.lsa_refresh,
     );
     lse.refresh_timer = Some(refresh_timer);
+
+    // Self-originated AS-external-LSAs also count towards the limit.
+    if lsdb_idx == LsdbIndex::As {
+        ext_overflow_check(instance, arenas);
+    }
 }
 
 // Attempts to originate the provided LSA, but only if it passes a few checks.
@@ -590,6 +620,12 @@ pub(crate) fn originate_check<V>(
 ) where
     V: Version,
 {
+    // Non-default AS-external-LSAs can't be originated while in the database
+    // overflow state (RFC 1765).
+    if instance.state.ext_overflow && lsa_body.is_non_default_as_external() {
+        return;
+    }
+
     let (lsdb_id, lsdb) = lsdb_index_mut(
         &mut instance.state.lsdb,
         &mut arenas.areas,
@@ -776,6 +812,93 @@ pub(crate) fn flush_all_self_originated<V>(
     }
 }
 
+// Checks whether the number of non-default AS-external-LSAs has reached the
+// configured limit, entering the database overflow state if necessary
+// (RFC 1765).
+pub(crate) fn ext_overflow_check<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    let Some(limit) = instance.config.ext_lsdb_limit else {
+        return;
+    };
+    let count = instance.state.ext_lsa_count;
+
+    // Send a notification once the number of non-default AS-external-LSAs
+    // exceeds ninety percent of the limit.
+    let approaching = u64::from(count) * 10 > u64::from(limit) * 9;
+    if approaching != instance.state.ext_approaching_overflow {
+        instance.state.ext_approaching_overflow = approaching;
+        if approaching && !instance.state.ext_overflow {
+            notification::lsdb_approaching_overflow(instance, limit);
+        }
+    }
+
+    if instance.state.ext_overflow || count < limit {
+        return;
+    }
+
+    // Enter the database overflow state.
+    Debug::<V>::ExtOverflowEnter(limit).log();
+    instance.state.ext_overflow = true;
+    notification::lsdb_overflow(instance, limit);
+
+    // Flush all self-originated non-default AS-external-LSAs.
+    let lsdb_idx = LsdbIndex::As;
+    let reason = LsaFlushReason::PrematureAging;
+    let idxs = instance
+        .state
+        .lsdb
+        .iter(&arenas.lsa_entries)
+        .filter(|(_, lse)| lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED))
+        .filter(|(_, lse)| !lse.data.hdr.is_maxage())
+        .filter(|(_, lse)| lse.data.body.is_non_default_as_external())
+        .map(|(lse_idx, _)| lse_idx)
+        .collect::<Vec<_>>();
+    for lse_idx in idxs {
+        flush(instance, arenas, lsdb_idx, lse_idx, reason);
+    }
+
+    // Start the exit overflow timer.
+    ext_overflow_timer_update(instance);
+}
+
+// Leaves the database overflow state.
+pub(crate) fn ext_overflow_exit<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    if !instance.state.ext_overflow {
+        return;
+    }
+
+    Debug::<V>::ExtOverflowExit.log();
+    instance.state.ext_overflow = false;
+    instance.state.exit_overflow_timer = None;
+
+    // Reoriginate the non-default AS-external-LSAs from the current routing
+    // state.
+    instance
+        .tx
+        .protocol_input
+        .lsa_orig_event(LsaOriginateEvent::ExtOverflowExit);
+}
+
+// Starts or stops the exit overflow timer according to the configured
+// interval. An interval of zero means the router stays in the database
+// overflow state indefinitely.
+pub(crate) fn ext_overflow_timer_update<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let interval = instance.config.exit_overflow_interval;
+    instance.state.exit_overflow_timer = (instance.state.ext_overflow
+        && interval != 0)
+        .then(|| tasks::exit_overflow_timer(instance, interval));
+}
+
 // Removes old instance of the given LSA from all neighbors' Link state
 // retransmission lists.
 fn rxmt_lists_remove<V>(
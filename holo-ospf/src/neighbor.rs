@@ -217,7 +217,7 @@ where
         let new_state = match (self.state, &event) {
             // NSM (state, event) -> (Action, new state)
             (State::Down, Event::Start) => {
-                // TODO: Send an Hello Packet to the NBMA neighbor.
+                output::send_hello_direct(iface, area, instance, self.src);
                 self.inactivity_timer_reset(iface, area, instance);
                 Some(State::Attempt)
             }
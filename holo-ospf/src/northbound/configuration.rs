@@ -28,7 +28,7 @@ use crate::collections::{AreaIndex, InterfaceIndex};
 use crate::debug::InterfaceInactiveReason;
 use crate::instance::Instance;
 use crate::interface::{InterfaceType, ism};
-use crate::lsdb::LsaOriginateEvent;
+use crate::lsdb::{self, LsaOriginateEvent};
 use crate::neighbor::nsm;
 use crate::packet::PacketType;
 use crate::route::RouteNetFlags;
@@ -43,7 +43,7 @@ pub enum ListEntry<V: Version> {
     Area(AreaIndex),
     AreaRange(AreaIndex, V::IpNetwork),
     Interface(AreaIndex, InterfaceIndex),
-    StaticNbr(InterfaceIndex, V::NetIpAddr),
+    StaticNbr(AreaIndex, InterfaceIndex, V::NetIpAddr),
     InterfaceTraceOption(InterfaceIndex, InterfaceTraceOption),
 }
 
@@ -72,6 +72,8 @@ pub enum Event {
     InterfaceUpdateTraceOptions(InterfaceIndex),
     InterfaceIbusSub(String),
     StubRouterChange,
+    LdpIgpSyncChange,
+    ExtLsdbLimitChange,
     GrHelperChange,
     SrEnableChange(bool),
     RerunSpf,
@@ -107,6 +109,10 @@ pub struct InstanceCfg {
     pub spf_hold_down: u32,
     pub spf_time_to_learn: u32,
     pub stub_router: bool,
+    pub ldp_igp_sync: bool,
+    pub ldp_igp_sync_holddown: Option<u16>,
+    pub ext_lsdb_limit: Option<u32>,
+    pub exit_overflow_interval: u32,
     pub node_tags: BTreeSet<u32>,
     pub extended_lsa: bool,
     pub sr_enabled: bool,
@@ -427,6 +433,28 @@ where
             let time_to_learn = args.dnode.get_u32();
             instance.config.spf_time_to_learn = time_to_learn;
         })
+        .path(ospf::database_control::ext_lsdb_limit::PATH)
+        .modify_apply(|instance, args| {
+            let ext_lsdb_limit = args.dnode.get_u32();
+            instance.config.ext_lsdb_limit = Some(ext_lsdb_limit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExtLsdbLimitChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ext_lsdb_limit = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExtLsdbLimitChange);
+        })
+        .path(ospf::database_control::exit_overflow_interval::PATH)
+        .modify_apply(|instance, args| {
+            let exit_overflow_interval = args.dnode.get_u32();
+            instance.config.exit_overflow_interval = exit_overflow_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExtLsdbLimitChange);
+        })
         .path(ospf::stub_router::always::PATH)
         .create_apply(|instance, args| {
             instance.config.stub_router = true;
@@ -440,6 +468,34 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::StubRouterChange);
         })
+        .path(ospf::mpls::ldp::igp_sync::PATH)
+        .modify_apply(|instance, args| {
+            let ldp_igp_sync = args.dnode.get_bool();
+            instance.config.ldp_igp_sync = ldp_igp_sync;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ldp_igp_sync = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
+        })
+        .path(ospf::mpls::ldp::igp_sync_holddown::PATH)
+        .modify_apply(|instance, args| {
+            let holddown = args.dnode.get_u16();
+            instance.config.ldp_igp_sync_holddown = Some(holddown);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ldp_igp_sync_holddown = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
+        })
         .path(ospf::node_tags::node_tag::PATH)
         .create_apply(|instance, args| {
             let node_tag = args.dnode.get_u32_relative("tag").unwrap();
@@ -685,7 +741,7 @@ where
         })
         .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::PATH)
         .create_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
             let identifier = args.dnode.get_ip_relative("identifier").unwrap();
@@ -694,53 +750,97 @@ where
                 .config
                 .static_nbrs
                 .insert(identifier, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
         })
         .delete_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (area_idx, iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let area = &instance.arenas.areas[area_idx];
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
             iface.config.static_nbrs.remove(&addr);
+
+            // Stop polling the neighbor and kill it, if it exists.
+            iface.nbma_poll_interval_stop(addr);
+            if let Some(nbr) = iface
+                .state
+                .neighbors
+                .iter(&instance.arenas.neighbors)
+                .find(|nbr| nbr.src == addr)
+            {
+                instance.tx.protocol_input.nsm_event(
+                    area.id,
+                    iface.id,
+                    nbr.id,
+                    nsm::Event::Kill,
+                );
+            }
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetHelloInterval(
+                area_idx, iface_idx,
+            ));
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceCostChange(area_idx));
         })
         .lookup(|_instance, list_entry, dnode| {
-            let (_, iface_idx) = list_entry.into_interface().unwrap();
+            let (area_idx, iface_idx) = list_entry.into_interface().unwrap();
 
             let identifier = dnode.get_ip_relative("./identifier").unwrap();
             let identifier = V::NetIpAddr::get(identifier).unwrap();
-            ListEntry::StaticNbr(iface_idx, identifier)
+            ListEntry::StaticNbr(area_idx, iface_idx, identifier)
         })
         .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::cost::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (area_idx, iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
             let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
             let cost = args.dnode.get_u16();
             snbr.cost = Some(cost);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceCostChange(area_idx));
         })
         .delete_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (area_idx, iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
             let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
             snbr.cost = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceCostChange(area_idx));
         })
         .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::poll_interval::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (area_idx, iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
             let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
             let poll_interval = args.dnode.get_u16();
             snbr.poll_interval = poll_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetHelloInterval(
+                area_idx, iface_idx,
+            ));
         })
         .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::priority::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (area_idx, iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
             let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
             let priority = args.dnode.get_u8();
             snbr.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
         })
         .path(ospf::areas::area::interfaces::interface::bfd::enabled::PATH)
         .modify_apply(|instance, args| {
@@ -1608,7 +1708,11 @@ where
                     for iface_idx in area.interfaces.indexes() {
                         let iface = &mut arenas.interfaces[iface_idx];
 
-                        iface.sync_hello_tx(area, &instance);
+                        iface.sync_hello_tx(
+                            area,
+                            &instance,
+                            &arenas.neighbors,
+                        );
                     }
                 }
             }
@@ -1677,7 +1781,12 @@ where
                     let iface = &mut arenas.interfaces[iface_idx];
 
                     if iface.state.tasks.hello_interval.is_some() {
-                        iface.hello_interval_start(area, &instance);
+                        iface.hello_interval_start(
+                            area,
+                            &instance,
+                            &arenas.neighbors,
+                        );
+                        iface.nbma_poll_interval_reset(area, &instance);
                     }
                 }
             }
@@ -1726,7 +1835,11 @@ where
                     let area = &arenas.areas[area_idx];
                     let iface = &mut arenas.interfaces[iface_idx];
 
-                    iface.sync_hello_tx(area, &instance);
+                    iface.sync_hello_tx(
+                        area,
+                        &instance,
+                        &arenas.neighbors,
+                    );
                 }
             }
             Event::InterfaceUpdateAuth(area_idx, iface_idx) => {
@@ -1735,7 +1848,7 @@ where
                     let iface = &mut arenas.interfaces[iface_idx];
 
                     // Update interface authentication keys.
-                    iface.auth_update(area, &instance);
+                    iface.auth_update(area, &instance, &arenas.neighbors);
                 }
             }
             Event::InterfaceBfdChange(iface_idx) => {
@@ -1783,6 +1896,44 @@ where
                         .lsa_orig_event(LsaOriginateEvent::StubRouterChange);
                 }
             }
+            Event::LdpIgpSyncChange => {
+                // Request LDP-IGP synchronization updates from LDP.
+                if self.config.ldp_igp_sync {
+                    self.tx.ibus.ldp_igp_sync_req();
+                }
+
+                if let Some((instance, arenas)) = self.as_up() {
+                    // Restart or stop the hold-down timers.
+                    for area in arenas.areas.iter() {
+                        for iface_idx in area.interfaces.indexes() {
+                            let iface = &mut arenas.interfaces[iface_idx];
+                            iface.ldp_sync_holddown_update(area, &instance);
+                        }
+
+                        // (Re)originate Router-LSA.
+                        instance.tx.protocol_input.lsa_orig_event(
+                            LsaOriginateEvent::InterfaceCostChange {
+                                area_id: area.id,
+                            },
+                        );
+                    }
+                }
+            }
+            Event::ExtLsdbLimitChange => {
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    if instance.config.ext_lsdb_limit.is_some() {
+                        // Check whether the new limit has been reached.
+                        lsdb::ext_overflow_check(&mut instance, arenas);
+                    } else {
+                        // Leave the database overflow state.
+                        lsdb::ext_overflow_exit(&mut instance);
+                        instance.state.ext_approaching_overflow = false;
+                    }
+
+                    // Restart or stop the exit overflow timer.
+                    lsdb::ext_overflow_timer_update(&mut instance);
+                }
+            }
             Event::GrHelperChange => {
                 if let Some((mut instance, arenas)) = self.as_up() {
                     // Exit from the helper mode for all neighbors.
@@ -2019,6 +2170,8 @@ impl Default for InstanceCfg {
         let extended_lsa = ospf::extended_lsa_support::DFLT;
         let sr_enabled = ospf::segment_routing::enabled::DFLT;
         let instance_id = ospf::instance_id::DFLT;
+        let exit_overflow_interval =
+            ospf::database_control::exit_overflow_interval::DFLT;
 
         InstanceCfg {
             af: None,
@@ -2033,6 +2186,10 @@ impl Default for InstanceCfg {
             spf_hold_down,
             spf_time_to_learn,
             stub_router: false,
+            ldp_igp_sync: false,
+            ldp_igp_sync_holddown: None,
+            ext_lsdb_limit: None,
+            exit_overflow_interval,
             node_tags: Default::default(),
             extended_lsa,
             sr_enabled,
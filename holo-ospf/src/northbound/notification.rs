@@ -194,6 +194,38 @@ pub(crate) fn if_rx_bad_lsa<V>(
     notification::send(&instance.tx.nb, if_rx_bad_lsa::PATH, data);
 }
 
+pub(crate) fn lsdb_approaching_overflow<V>(
+    instance: &InstanceUpView<'_, V>,
+    ext_lsdb_limit: u32,
+) where
+    V: Version,
+{
+    use yang::lsdb_approaching_overflow::{self, LsdbApproachingOverflow};
+
+    let data = LsdbApproachingOverflow {
+        routing_protocol_name: Some(instance.name.into()),
+        address_family: Some(instance.state.af.to_yang()),
+        ext_lsdb_limit: Some(ext_lsdb_limit),
+    };
+    notification::send(&instance.tx.nb, lsdb_approaching_overflow::PATH, data);
+}
+
+pub(crate) fn lsdb_overflow<V>(
+    instance: &InstanceUpView<'_, V>,
+    ext_lsdb_limit: u32,
+) where
+    V: Version,
+{
+    use yang::lsdb_overflow::{self, LsdbOverflow};
+
+    let data = LsdbOverflow {
+        routing_protocol_name: Some(instance.name.into()),
+        address_family: Some(instance.state.af.to_yang()),
+        ext_lsdb_limit: Some(ext_lsdb_limit),
+    };
+    notification::send(&instance.tx.nb, lsdb_overflow::PATH, data);
+}
+
 pub(crate) fn sr_index_out_of_range<V>(
     instance: &InstanceUpView<'_, V>,
     nbr_router_id: Ipv4Addr,
@@ -26,7 +26,7 @@ use num_traits::FromPrimitive;
 use crate::area::Area;
 use crate::collections::LsdbSingleType;
 use crate::instance::Instance;
-use crate::interface::{Interface, ism};
+use crate::interface::{Interface, InterfaceType, ism};
 use crate::lsdb::{LsaEntry, LsaLogEntry, LsaLogId};
 use crate::neighbor::Neighbor;
 use crate::packet::lsa::{LsaBodyVersion, LsaHdrVersion};
@@ -133,6 +133,22 @@ where
                 last_spf_time: last_spf_time.map(Cow::Borrowed).ignore_in_testing(),
             })
         })
+        .path(ospf::database_control::PATH)
+        .get_object(|instance, _args| {
+            use ospf::database_control::DatabaseControl;
+            let mut ext_lsa_count = None;
+            let mut overflow_state = None;
+            if let Some(state) = &instance.state
+                && instance.config.ext_lsdb_limit.is_some()
+            {
+                ext_lsa_count = Some(state.ext_lsa_count);
+                overflow_state = Some(state.ext_overflow);
+            }
+            Box::new(DatabaseControl {
+                ext_lsa_count,
+                overflow_state,
+            })
+        })
         .path(ospf::local_rib::route::PATH)
         .get_iterate(|instance, _args| {
             let Some(instance_state) = &instance.state else { return None };
@@ -485,6 +501,7 @@ where
                 bdr_router_id: bdr_router_id.map(Cow::Owned),
                 bdr_ip_addr: bdr_ip_addr.map(Cow::Owned),
                 state: Some(nbr.state.to_yang()),
+                cost: (iface.config.if_type == InterfaceType::PointToMultipoint).then(|| iface.nbr_cost(nbr)),
                 dead_timer: nbr.tasks.inactivity_timer.as_ref().map(|task| task.remaining()).map(Cow::Owned).ignore_in_testing(),
             })
         })
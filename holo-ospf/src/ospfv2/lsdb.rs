@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::Ipv4Addr;
 
 use holo_utils::ip::{AddressFamily, Ipv4NetworkExt};
@@ -246,17 +246,30 @@ impl LsdbVersion<Self> for Ospfv2 {
                     lsa_orig_router_info(area, instance);
                 }
             }
+            LsaOriginateEvent::ExtOverflowExit => {
+                // Reoriginate non-default AS-external-LSAs (redistribution of
+                // local routes isn't supported at the moment).
+            }
             _ => (),
         };
 
         Ok(())
     }
 
+    fn lsa_id_inter_area_network(
+        prefix: Ipv4Network,
+        prefixes: &BTreeSet<Ipv4Network>,
+    ) -> Result<Option<u32>, Error<Self>> {
+        lsa_id_appendix_e(prefix, prefixes)
+            .map(|lsa_id| Some(lsa_id.into()))
+            .ok_or(Error::LsaIdUnavailable(prefix))
+    }
+
     fn lsa_orig_inter_area_network(
         area: &mut Area<Self>,
         instance: &InstanceUpView<'_, Self>,
         prefix: Ipv4Network,
-        _lsa_id: Option<u32>,
+        lsa_id: Option<u32>,
         summary: &SummaryNet<Self>,
     ) -> u32 {
         let lsdb_id = LsdbId::Area(area.id);
@@ -264,8 +277,8 @@ impl LsdbVersion<Self> for Ospfv2 {
         // LSA's header options.
         let options = Self::area_options(area, OptionsLocation::Lsa);
 
-        // TODO: implement Appendix's E algorithm for assigning Link State IDs.
-        let lsa_id = prefix.ip();
+        // LSA ID.
+        let lsa_id = lsa_id.map(Ipv4Addr::from).unwrap_or(prefix.ip());
 
         // (Re)originate Type-3 Summary-LSA.
         let lsa_body = LsaBody::SummaryNetwork(LsaSummary {
@@ -373,6 +386,45 @@ impl LsdbVersion<Self> for Ospfv2 {
 
 // ===== helper functions =====
 
+// Returns the Link State ID used to advertise the given prefix, as specified
+// in RFC 2328 - Appendix E.
+//
+// The network address is used as the Link State ID, unless another prefix with
+// the same network address and a longer mask is also advertised. In that case,
+// the Link State ID is formed by setting all host bits of the prefix, which
+// keeps the LSAs of both prefixes distinct.
+//
+// Returns `None` if the host bits Link State ID is already taken, either by the
+// host route of that same address or by a longer prefix also using its host
+// bits Link State ID.
+fn lsa_id_appendix_e(
+    prefix: Ipv4Network,
+    prefixes: &BTreeSet<Ipv4Network>,
+) -> Option<Ipv4Addr> {
+    let uses_host_bits = |prefix: &Ipv4Network| {
+        prefixes.iter().any(|other| {
+            other.ip() == prefix.ip() && other.prefix() > prefix.prefix()
+        })
+    };
+
+    if !uses_host_bits(&prefix) {
+        return Some(prefix.ip());
+    }
+
+    let lsa_id = prefix.broadcast();
+    if prefixes.iter().any(|other| {
+        if uses_host_bits(other) {
+            other.broadcast() == lsa_id && other.prefix() > prefix.prefix()
+        } else {
+            other.ip() == lsa_id
+        }
+    }) {
+        return None;
+    }
+
+    Some(lsa_id)
+}
+
 fn lsa_orig_router(
     area: &Area<Ospfv2>,
     instance: &InstanceUpView<'_, Ospfv2>,
@@ -413,8 +465,11 @@ fn lsa_orig_router(
         }
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // non-stub links to MaxLinkMetric.
-        let non_stub_cost = if instance.config.stub_router {
+        // non-stub links to MaxLinkMetric. The same applies to links where LDP
+        // hasn't converged yet (RFC 5443).
+        let max_metric = instance.config.stub_router
+            || iface.ldp_sync_max_metric(instance.config);
+        let non_stub_cost = if max_metric {
             MAX_LINK_METRIC
         } else {
             iface.config.cost
@@ -435,11 +490,16 @@ fn lsa_orig_router(
                     } else {
                         primary_addr.ip()
                     };
+                    let cost = if max_metric {
+                        MAX_LINK_METRIC
+                    } else {
+                        iface.nbr_cost(nbr)
+                    };
                     let link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         nbr.router_id,
                         link_data,
-                        non_stub_cost,
+                        cost,
                     );
                     links.push(link);
                 }
@@ -979,3 +1039,70 @@ fn lsa_flush(
         LsaFlushReason::PrematureAging,
     );
 }
+
+#[cfg(test)]
+mod test_lsa_id_appendix_e {
+    use super::*;
+
+    fn lsa_ids(prefixes: &[&str]) -> Vec<Option<Ipv4Addr>> {
+        let prefixes = prefixes
+            .iter()
+            .map(|prefix| prefix.parse().unwrap())
+            .collect::<Vec<Ipv4Network>>();
+        let prefix_set = prefixes.iter().copied().collect::<BTreeSet<_>>();
+        prefixes
+            .into_iter()
+            .map(|prefix| lsa_id_appendix_e(prefix, &prefix_set))
+            .collect()
+    }
+
+    fn addr(addr: &str) -> Option<Ipv4Addr> {
+        Some(addr.parse().unwrap())
+    }
+
+    #[test]
+    fn test_no_overlap() {
+        assert_eq!(
+            lsa_ids(&["10.0.0.0/8", "172.16.0.0/16"]),
+            vec![addr("10.0.0.0"), addr("172.16.0.0")]
+        );
+    }
+
+    #[test]
+    fn test_same_network_address() {
+        assert_eq!(
+            lsa_ids(&["10.0.0.0/8", "10.0.0.0/16", "10.0.0.0/24"]),
+            vec![
+                addr("10.255.255.255"),
+                addr("10.0.255.255"),
+                addr("10.0.0.0")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_host_route_collision() {
+        assert_eq!(
+            lsa_ids(&["10.0.0.0/8", "10.0.0.0/16", "10.255.255.255/32"]),
+            vec![None, addr("10.0.0.0"), addr("10.255.255.255")]
+        );
+    }
+
+    #[test]
+    fn test_host_bits_collision() {
+        assert_eq!(
+            lsa_ids(&[
+                "10.0.0.0/8",
+                "10.0.0.0/16",
+                "10.128.0.0/9",
+                "10.128.0.0/16",
+            ]),
+            vec![
+                None,
+                addr("10.0.0.0"),
+                addr("10.255.255.255"),
+                addr("10.128.0.0")
+            ]
+        );
+    }
+}
@@ -546,6 +546,11 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
         }
     }
 
+    fn is_non_default_as_external(&self) -> bool {
+        self.as_as_external()
+            .is_some_and(|lsa_body| !lsa_body.mask.is_unspecified())
+    }
+
     fn as_grace(&self) -> Option<(u32, GrReason, Option<Ipv4Addr>)> {
         let grace = self.as_opaque_link()?.as_grace()?;
         let grace_period = grace.grace_period?.get();
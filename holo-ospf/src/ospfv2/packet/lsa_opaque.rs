@@ -825,7 +825,7 @@ impl ExtPrefixTlv {
             buf.put_u8(prefix_sid.flags.bits());
             buf.put_u8(0);
             buf.put_u8(0);
-            buf.put_u8(*algo as u8);
+            buf.put_u8(u8::from(*algo));
             match prefix_sid.sid {
                 Sid::Index(index) => buf.put_u32(index),
                 Sid::Label(label) => buf.put_u24(label.get()),
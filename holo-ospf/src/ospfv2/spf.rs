@@ -142,6 +142,7 @@ impl SpfVersion<Self> for Ospfv2 {
             .map(|(lsa_hdr, lsa_body)| {
                 Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask)
                     .unwrap()
+                    .apply_mask()
             })
             .collect();
 
@@ -165,6 +166,7 @@ impl SpfVersion<Self> for Ospfv2 {
             .map(|(lsa_hdr, lsa_body)| {
                 Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask)
                     .unwrap()
+                    .apply_mask()
             })
             .collect();
 
@@ -551,7 +553,8 @@ impl SpfVersion<Self> for Ospfv2 {
                 let lsa_body = lsa.body.as_summary_network().unwrap();
                 let prefix =
                     Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
-                        .unwrap();
+                        .unwrap()
+                        .apply_mask();
                 let prefix_sids = route_prefix_sids(
                     area,
                     lsa.hdr.adv_rtr,
@@ -601,7 +604,8 @@ impl SpfVersion<Self> for Ospfv2 {
                 let lsa_body = lsa.body.as_as_external().unwrap();
                 let prefix =
                     Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
-                        .unwrap();
+                        .unwrap()
+                        .apply_mask();
 
                 SpfExternalNetwork {
                     adv_rtr: lsa.hdr.adv_rtr,
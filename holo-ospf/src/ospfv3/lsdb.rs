@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::{BTreeMap, HashMap, hash_map};
+use std::collections::{BTreeMap, BTreeSet, HashMap, hash_map};
 use std::net::{IpAddr, Ipv4Addr};
 
 use holo_utils::bier::{
@@ -305,11 +305,22 @@ impl LsdbVersion<Self> for Ospfv3 {
                     }
                 }
             },
+            LsaOriginateEvent::ExtOverflowExit => {
+                // Reoriginate non-default AS-external-LSAs (redistribution of
+                // local routes isn't supported at the moment).
+            }
         };
 
         Ok(())
     }
 
+    fn lsa_id_inter_area_network(
+        _prefix: IpNetwork,
+        _prefixes: &BTreeSet<IpNetwork>,
+    ) -> Result<Option<u32>, Error<Self>> {
+        Ok(None)
+    }
+
     fn lsa_orig_inter_area_network(
         area: &mut Area<Self>,
         instance: &InstanceUpView<'_, Self>,
@@ -501,8 +512,11 @@ fn lsa_orig_router(
         let ifindex = iface.system.ifindex.unwrap();
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // links to MaxLinkMetric.
-        let cost = if instance.config.stub_router {
+        // links to MaxLinkMetric. The same applies to links where LDP
+        // hasn't converged yet (RFC 5443).
+        let max_metric = instance.config.stub_router
+            || iface.ldp_sync_max_metric(instance.config);
+        let cost = if max_metric {
             MAX_LINK_METRIC
         } else {
             iface.config.cost
@@ -517,6 +531,11 @@ fn lsa_orig_router(
                     .iter(&arenas.neighbors)
                     .filter(|nbr| nbr.state == nsm::State::Full)
                 {
+                    let cost = if max_metric {
+                        MAX_LINK_METRIC
+                    } else {
+                        iface.nbr_cost(nbr)
+                    };
                     let link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         cost,
@@ -1352,6 +1352,11 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
         Ok(())
     }
 
+    fn is_non_default_as_external(&self) -> bool {
+        self.as_as_external()
+            .is_some_and(|lsa_body| lsa_body.prefix.prefix() != 0)
+    }
+
     fn as_grace(&self) -> Option<(u32, GrReason, Option<Ipv6Addr>)> {
         let grace = self.as_grace()?;
         let grace_period = grace.grace_period?.get();
@@ -2797,7 +2802,7 @@ impl ExtLsaStlvs {
         for (algo, prefix_sid) in &self.prefix_sids {
             let start_pos = tlv_encode_start(buf, ExtLsaStlv::PrefixSid);
             buf.put_u8(prefix_sid.flags.bits());
-            buf.put_u8(*algo as u8);
+            buf.put_u8(u8::from(*algo));
             buf.put_u16(0);
             match prefix_sid.sid {
                 Sid::Index(index) => buf.put_u32(index),
@@ -21,6 +21,29 @@ use crate::packet::{
 use crate::tasks::messages::output::NetTxPacketMsg;
 use crate::version::Version;
 
+// ===== Hello Packets =====
+
+pub(crate) fn send_hello_direct<V>(
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    addr: V::NetIpAddr,
+) where
+    V: Version,
+{
+    // Generate Hello packet.
+    let packet = V::generate_hello(iface, area, instance);
+
+    // Enqueue packet for network transmission.
+    let msg = NetTxPacketMsg {
+        packet,
+        #[cfg(feature = "testing")]
+        ifname: iface.name.clone(),
+        dst: smallvec![addr],
+    };
+    iface.send_packet(msg);
+}
+
 // ===== Database Description Packets =====
 
 pub(crate) fn send_dbdesc<V>(
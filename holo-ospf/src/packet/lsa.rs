@@ -183,6 +183,7 @@ where
     fn encode(&self, buf: &mut BytesMut);
     fn lsa_type(&self) -> V::LsaType;
     fn is_unknown(&self) -> bool;
+    fn is_non_default_as_external(&self) -> bool;
     fn validate(&self, hdr: &V::LsaHdr) -> Result<(), LsaValidationError>;
     fn as_grace(&self) -> Option<(u32, GrReason, Option<V::NetIpAddr>)>;
 }
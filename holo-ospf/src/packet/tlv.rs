@@ -652,7 +652,7 @@ impl SrAlgoTlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, RouterInfoTlvType::SrAlgo);
         for algo in &self.0 {
-            buf.put_u8(*algo as u8);
+            buf.put_u8(u8::from(*algo));
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -50,6 +50,8 @@ use crate::{lsdb, network, spf};
 // lsdb_maxage_sweep_interval (Nx) -> |              |
 //                                    |              |
 //            spf_delay_timer (Nx) -> |              |
+//    ldp_sync_holddown_timer (Nx) -> |              |
+//        exit_overflow_timer (1x) -> |              |
 //                                    +--------------+
 //                             ibus_tx (1x) | ^ (1x) ibus_rx
 //                                          | |
@@ -105,6 +107,8 @@ pub mod messages {
             LsdbMaxAgeSweep(LsdbMaxAgeSweepMsg),
             SpfDelayEvent(SpfDelayEventMsg),
             GracePeriod(GracePeriodMsg),
+            LdpSyncHolddown(LdpSyncHolddownMsg),
+            ExitOverflow(ExitOverflowMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -211,6 +215,15 @@ pub mod messages {
             pub iface_key: InterfaceKey,
             pub nbr_key: NeighborKey,
         }
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct LdpSyncHolddownMsg {
+            pub area_key: AreaKey,
+            pub iface_key: InterfaceKey,
+        }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct ExitOverflowMsg {}
     }
 
     // Output messages (main task -> child task).
@@ -773,3 +786,64 @@ where
         TimeoutTask {}
     }
 }
+
+// LDP-IGP synchronization hold-down timer.
+pub(crate) fn ldp_sync_holddown_timer<V>(
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    holddown: u16,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let area_id = area.id;
+        let iface_id = iface.id;
+        let ldp_sync_holddownp =
+            instance.tx.protocol_input.ldp_sync_holddown.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(holddown.into()),
+            move || async move {
+                let _ = ldp_sync_holddownp
+                    .send(messages::input::LdpSyncHolddownMsg {
+                        area_key: area_id.into(),
+                        iface_key: iface_id.into(),
+                    })
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Database overflow exit timer task.
+pub(crate) fn exit_overflow_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+    interval: u32,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let exit_overflowp = instance.tx.protocol_input.exit_overflow.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(interval.into()),
+            move || async move {
+                let msg = messages::input::ExitOverflowMsg {};
+                let _ = exit_overflowp.send(msg).await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
@@ -445,6 +445,65 @@ async fn nb_config_iface1() {
     run_test::<Instance<Ospfv2>>("nb-config-iface1", "topo1-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable LDP-IGP synchronization
+// Output:
+//  * Ibus: request LDP-IGP synchronization updates from LDP
+//
+// Input:
+//  * Ibus: LDP reports that eth-rt1 isn't synchronized
+// Output:
+//  * Northbound:
+//    - the self-originated Router-LSA advertises the maximum metric on the
+//      point-to-point link to 1.1.1.1
+//    - the retransmission queue length of the 1.1.1.1 adjacency increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//  * Protocol: send an LS Update to 1.1.1.1 containing the updated Router-LSA
+//
+// Input:
+//  * Ibus: LDP reports that eth-rt1 is synchronized
+// Output:
+//  * Northbound: the self-originated Router-LSA advertises the regular metric
+//    on the point-to-point link to 1.1.1.1
+//  * Protocol: send an LS Update to 1.1.1.1 containing the updated Router-LSA
+#[tokio::test]
+async fn nb_config_ldp_igp_sync1() {
+    run_test::<Instance<Ospfv2>>("nb-config-ldp-igp-sync1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LDP-IGP synchronization with a hold-down time of 10
+//    seconds
+// Output:
+//  * Ibus: request LDP-IGP synchronization updates from LDP
+//
+// Input:
+//  * Ibus: LDP reports that eth-rt1 isn't synchronized
+// Output:
+//  * Northbound:
+//    - the self-originated Router-LSA advertises the maximum metric on the
+//      point-to-point link to 1.1.1.1
+//    - the retransmission queue length of the 1.1.1.1 adjacency increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//  * Protocol: send an LS Update to 1.1.1.1 containing the updated Router-LSA
+//
+// Input:
+//  * Protocol: LDP-IGP synchronization hold-down timer expiration on eth-rt1
+// Output:
+//  * Northbound: the self-originated Router-LSA advertises the regular metric
+//    on the point-to-point link to 1.1.1.1, even though LDP isn't synchronized
+//  * Protocol: send an LS Update to 1.1.1.1 containing the updated Router-LSA
+#[tokio::test]
+async fn nb_config_ldp_igp_sync2() {
+    run_test::<Instance<Ospfv2>>("nb-config-ldp-igp-sync2", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -579,6 +638,67 @@ async fn nb_config_router_id2() {
         .await;
 }
 
+// Test description:
+//
+// On point-to-multipoint interfaces, the cost of the link to each neighbor
+// can be configured individually.
+//
+// Input:
+//  * Northbound: change eth-sw1 to the point-to-multipoint mode with two
+//    static neighbors (10.0.1.1 with cost 50 and 10.0.1.3 with the default
+//    cost)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA
+//  * Northbound:
+//    - the eth-sw1 interface was reset and transitioned to the
+//      "point-to-point" state
+//    - the 1.1.1.1 and 3.3.3.3 neighbors were killed
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1 and 10.0.1.3
+// Output:
+//  * Protocol: send a Database Description packet to 10.0.1.1 and 10.0.1.3
+//  * Northbound: new 1.1.1.1 (cost 50) and 3.3.3.3 (cost 10) neighbors in the
+//    "exstart" state
+#[tokio::test]
+async fn nb_config_static_nbr1() {
+    run_test::<Instance<Ospfv2>>("nb-config-static-nbr1", "topo2-1", "rt2")
+        .await;
+}
+
+// Test description:
+//
+// Deleting a static neighbor should tear down the corresponding neighbor.
+//
+// Input:
+//  * Northbound: change eth-sw1 to the non-broadcast mode with priority 0 and
+//    two static neighbors (10.0.1.1 with priority 1 and 10.0.1.3 with
+//    priority 3)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to the 10.0.1.0/24 transit network became a stub
+//    network)
+//  * Northbound:
+//    - the eth-sw1 interface was reset and transitioned to the "dr-other"
+//      state
+//    - the 1.1.1.1 and 3.3.3.3 neighbors were killed
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1 with priority 0
+// Output:
+//  * Northbound: new 1.1.1.1 neighbor
+//
+// Input:
+//  * Northbound: delete the 10.0.1.1 static neighbor
+// Output:
+//  * Northbound: the 1.1.1.1 neighbor was killed
+#[tokio::test]
+async fn nb_config_static_nbr2() {
+    run_test::<Instance<Ospfv2>>("nb-config-static-nbr2", "topo2-1", "rt2")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -778,6 +898,84 @@ async fn packet_hello_validation5() {
         .await;
 }
 
+// Test description:
+//
+// A router that isn't eligible to become DR shouldn't reply to Hello packets
+// received on NBMA networks from neighbors that aren't eligible either.
+//
+// Input:
+//  * Northbound: change eth-sw1 to the non-broadcast mode with priority 0 and
+//    two static neighbors (10.0.1.1 with priority 1 and 10.0.1.3 with
+//    priority 3)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to the 10.0.1.0/24 transit network became a stub
+//    network)
+//  * Northbound:
+//    - the eth-sw1 interface was reset and transitioned to the "dr-other"
+//      state
+//    - the 1.1.1.1 and 3.3.3.3 neighbors were killed
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1 with priority 0
+// Output:
+//  * Northbound: new 1.1.1.1 neighbor
+#[tokio::test]
+async fn packet_hello_nbma1() {
+    run_test::<Instance<Ospfv2>>("packet-hello-nbma1", "topo2-1", "rt2").await;
+}
+
+// Test description:
+//
+// A router that isn't eligible to become DR should reply to Hello packets
+// received on NBMA networks from eligible neighbors, unless they're the DR or
+// the BDR (RFC 2328 - Section 9.5.1).
+//
+// Input:
+//  * Northbound: change eth-sw1 to the non-broadcast mode with priority 0 and
+//    two static neighbors (10.0.1.1 with priority 1 and 10.0.1.3 with
+//    priority 3)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to the 10.0.1.0/24 transit network became a stub
+//    network)
+//  * Northbound:
+//    - the eth-sw1 interface was reset and transitioned to the "dr-other"
+//      state
+//    - the 1.1.1.1 and 3.3.3.3 neighbors were killed
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.3 (priority 3)
+// Output:
+//  * Protocol:
+//    - send a Hello packet directly to 10.0.1.3
+//    - send a Database Description packet to 10.0.1.3
+//  * Northbound:
+//    - new 3.3.3.3 neighbor in the "exstart" state
+//    - 3.3.3.3 elected as DR
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1 (priority 1)
+// Output:
+//  * Protocol:
+//    - send a Hello packet directly to 10.0.1.1
+//    - send a Database Description packet to 10.0.1.1
+//  * Northbound:
+//    - new 1.1.1.1 neighbor in the "exstart" state
+//    - 1.1.1.1 elected as BDR
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.3 (DR)
+// Output: no changes
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1 (BDR)
+// Output: no changes
+#[tokio::test]
+async fn packet_hello_nbma2() {
+    run_test::<Instance<Ospfv2>>("packet-hello-nbma2", "topo2-1", "rt2").await;
+}
+
 // Test description:
 //
 // Input:
@@ -1587,3 +1785,113 @@ async fn timeout_nbr1() {
 async fn timeout_nbr2() {
     run_test::<Instance<Ospfv2>>("timeout-nbr2", "topo1-2", "rt3").await;
 }
+
+// Test description:
+//
+// An NBMA neighbor that becomes inactive should be deleted, after which it's
+// polled at the PollInterval rate until a Hello packet is received from it
+// again.
+//
+// Input:
+//  * Northbound: change eth-sw1 to the non-broadcast mode with two static
+//    neighbors (10.0.1.1 with priority 1 and 10.0.1.3 with priority 3)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA
+//  * Northbound:
+//    - the eth-sw1 interface was reset and transitioned to the "waiting"
+//      state
+//    - the 1.1.1.1 and 3.3.3.3 neighbors were killed
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1
+// Output:
+//  * Protocol: send a Database Description packet to 10.0.1.1
+//  * Northbound: new 1.1.1.1 neighbor in the "exstart" state
+//
+// Input:
+//  * Protocol: InactivityTimer event for the 1.1.1.1 neighbor
+// Output:
+//  * Northbound:
+//    - the 1.1.1.1 neighbor was killed
+//    - the eth-sw1 interface transitioned to the "dr" state
+//
+// Input:
+//  * Protocol: received a Hello packet from 10.0.1.1
+// Output:
+//  * Protocol: send a Database Description packet to 10.0.1.1
+//  * Northbound: new 1.1.1.1 neighbor in the "exstart" state
+#[tokio::test]
+async fn timeout_nbr_nbma1() {
+    run_test::<Instance<Ospfv2>>("timeout-nbr-nbma1", "topo2-1", "rt2").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: set the AS-external-LSA limit to 2 and the exit overflow
+//    interval to 60 seconds
+// Output:
+//  * Northbound: the number of non-default AS-external-LSAs (zero) and the
+//    overflow state are now reported
+//
+// Input:
+//  * Protocol: received two AS-External LSAs in eth-rt3 from 3.3.3.3
+// Output:
+//  * Protocol: send an LS Ack out the same interface, destined to 224.0.0.5
+//  * Northbound:
+//    - the received LSAs are present in the LSDB
+//    - the number of non-default AS-external-LSAs is now 2
+//    - the router entered the database overflow state
+//  * Notification: send the LSDB approaching overflow and LSDB overflow
+//    notifications
+//
+// Input:
+//  * Protocol: exit overflow timer expiration
+// Output: no changes (the limit is still reached)
+//
+// Input:
+//  * Northbound: raise the AS-external-LSA limit to 10
+// Output: no changes (the router stays in the overflow state)
+//
+// Input:
+//  * Protocol: exit overflow timer expiration
+// Output:
+//  * Northbound: the router left the database overflow state
+#[tokio::test]
+async fn ext_overflow1() {
+    run_test::<Instance<Ospfv2>>("ext-overflow1", "topo1-1", "rt6").await;
+}
+
+// Test description:
+//
+// Self-originated AS-External LSAs count towards the limit.
+//
+// Input:
+//  * Northbound: set the AS-external-LSA limit to 1
+// Output:
+//  * Northbound: the number of non-default AS-external-LSAs (zero) and the
+//    overflow state are now reported
+//
+// Input:
+//  * Protocol: received a self-originated AS-External LSA in eth-rt3 from
+//    3.3.3.3
+// Output:
+//  * Protocol:
+//    - send an LS Update out eth-rt3 containing the flushed AS-External LSA
+//    - send an LS Ack out the same interface, destined to 224.0.0.5
+//  * Northbound:
+//    - the router entered the database overflow state
+//    - the AS-External LSA was flushed (MaxAge)
+//  * Notification: send the LSDB approaching overflow and LSDB overflow
+//    notifications
+//
+// Input:
+//  * Northbound: remove the AS-external-LSA limit
+// Output:
+//  * Northbound: the number of non-default AS-external-LSAs and the overflow
+//    state are no longer reported
+#[tokio::test]
+async fn ext_overflow2() {
+    run_test::<Instance<Ospfv2>>("ext-overflow2", "topo1-1", "rt6").await;
+}
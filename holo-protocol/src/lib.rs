@@ -8,6 +8,7 @@ pub mod event_recorder;
 #[cfg(feature = "testing")]
 pub mod test;
 
+use std::collections::BTreeSet;
 use std::sync::{Arc, Mutex};
 
 use derive_new::new;
@@ -98,6 +99,9 @@ pub struct InstanceShared {
     pub bier_config: Arc<BierCfg>,
     // Event recorder configuration.
     pub event_recorder_config: Option<event_recorder::Config>,
+    // Protocols whose kernel routes were preserved from a previous Holo
+    // instance and haven't been swept yet.
+    pub preserved_routes: Arc<BTreeSet<Protocol>>,
 }
 
 /// Instance input message.
@@ -155,6 +159,7 @@ impl std::fmt::Debug for InstanceShared {
             .field("policies", &self.policies)
             .field("sr_config", &self.sr_config)
             .field("bier_config", &self.bier_config)
+            .field("preserved_routes", &self.preserved_routes)
             .finish()
     }
 }
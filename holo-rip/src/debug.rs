@@ -37,6 +37,7 @@ pub enum Debug<'a, V: Version> {
     RouteUpdate(&'a V::IpNetwork, &'a Option<V::IpAddr>, &'a Metric),
     RouteTimeout(&'a V::IpNetwork),
     RouteGcTimeout(&'a V::IpNetwork),
+    RouteHolddownTimeout(&'a V::IpNetwork),
     RouteInvalidate(&'a V::IpNetwork),
     IbusRx(&'a IbusMsg),
 }
@@ -133,6 +134,7 @@ where
             }
             Debug::RouteTimeout(prefix)
             | Debug::RouteGcTimeout(prefix)
+            | Debug::RouteHolddownTimeout(prefix)
             | Debug::RouteInvalidate(prefix) => {
                 // Parent span(s): rip-instance
                 debug!(%prefix, "{}", self);
@@ -210,6 +212,9 @@ where
             Debug::RouteGcTimeout(..) => {
                 write!(f, "route deleted")
             }
+            Debug::RouteHolddownTimeout(..) => {
+                write!(f, "route holddown expired")
+            }
             Debug::RouteInvalidate(..) => {
                 write!(f, "route invalidated")
             }
@@ -15,6 +15,7 @@ pub enum Error<V: Version> {
     UdpInvalidSourceAddr(V::IpAddr),
     UdpPduDecodeError(V::PduDecodeError),
     UdpPduAuthInvalidSeqno(V::SocketAddr, u32),
+    UdpPduAuthMissingKey,
     InterfaceStartError(String, IoError),
 }
 
@@ -54,6 +55,9 @@ where
             Error::UdpPduAuthInvalidSeqno(source, seqno) => {
                 warn!(%source, %seqno, "{}", self);
             }
+            Error::UdpPduAuthMissingKey => {
+                warn!("{}", self);
+            }
             Error::InterfaceStartError(name, error) => {
                 error!(%name, error = %with_source(error), "{}", self);
             }
@@ -77,6 +81,9 @@ where
             Error::UdpPduAuthInvalidSeqno(..) => {
                 write!(f, "authentication failed: decreasing sequence number")
             }
+            Error::UdpPduAuthMissingKey => {
+                write!(f, "failed to send PDU: no valid authentication key")
+            }
             Error::InterfaceStartError(..) => {
                 write!(f, "failed to start interface")
             }
@@ -13,11 +13,12 @@ use crate::error::Error;
 use crate::instance::InstanceUpView;
 use crate::interface::{Interface, Interfaces};
 use crate::network::SendDestination;
+use crate::northbound::configuration::DistributeListDirection;
 use crate::output::{self, ResponseType};
 use crate::packet::{Command, PduVersion, RteRouteVersion, RteVersion};
 use crate::route::{Metric, Route, RouteFlags, RouteType};
 use crate::version::Version;
-use crate::{ibus, neighbor};
+use crate::{ibus, neighbor, policy};
 
 // ===== UDP packet receipt =====
 
@@ -179,8 +180,10 @@ fn process_pdu_response<V>(
     V: Version,
 {
     let invalid_interval = iface.config.invalid_interval;
+    let holddown_interval = iface.holddown_interval(instance.config);
     let flush_interval = iface.config.flush_interval;
     let distance = instance.config.distance;
+    let maximum_paths = instance.config.maximum_paths;
 
     // The Response must be ignored if it is not from the RIP port.
     if src.port() != V::UDP_PORT {
@@ -207,6 +210,17 @@ fn process_pdu_response<V>(
             None => continue,
         };
 
+        // Apply the inbound distribute lists.
+        if !policy::distribute_list_check(
+            (*rte.prefix()).into(),
+            &iface.name,
+            DistributeListDirection::In,
+            instance.config,
+            &instance.shared.policy_match_sets,
+        ) {
+            continue;
+        }
+
         // Update the metric by adding the cost of the network on which the
         // message arrived.
         let mut metric = rte.metric();
@@ -226,20 +240,83 @@ fn process_pdu_response<V>(
         if let Some(rte_nexthop) = ripng_nexthop {
             nexthop = *rte_nexthop;
         }
-        let nexthop = Some(nexthop);
 
         // Check if the route already exists in the routing table.
         match instance.state.routes.entry(*rte.prefix()) {
             btree_map::Entry::Occupied(mut o) => {
                 let route = o.get_mut();
 
+                // Locally originated routes take precedence over routes
+                // learned from RIP neighbors.
+                if route.route_type == RouteType::External
+                    && !route.metric.is_infinite()
+                {
+                    continue;
+                }
+
+                // Routes in holddown only accept updates that are better than
+                // the route was before being withdrawn.
+                if let Some(holddown) = &route.holddown {
+                    if metric.get() >= holddown.metric.get() {
+                        continue;
+                    }
+                    route.holddown_stop();
+                }
+
+                if route.route_type == RouteType::Rip
+                    && !route.metric.is_infinite()
+                {
+                    // Add or refresh equal-cost paths learned from other
+                    // neighbors.
+                    if source != route.source && metric == route.metric {
+                        if (route.ecmp_paths.contains_key(src.ip())
+                            || route.ecmp_paths.len() + 1
+                                < maximum_paths as usize)
+                            && route.ecmp_path_update(
+                                *src.ip(),
+                                iface.system.ifindex.unwrap(),
+                                nexthop,
+                                rte.metric(),
+                                invalid_interval,
+                                &instance.tx.protocol_input.route_timeout,
+                            )
+                        {
+                            // Reinstall route with the new or updated nexthop.
+                            ibus::tx::route_install(
+                                &instance.tx.ibus,
+                                route,
+                                distance,
+                            );
+                        }
+                        continue;
+                    }
+
+                    // Remove equal-cost paths that got worse. If it's the
+                    // primary path, replace it with another equal-cost path.
+                    if metric.get() > route.metric.get()
+                        && ((source == route.source
+                            && route.ecmp_path_promote(
+                                &instance.tx.protocol_input.route_timeout,
+                            ))
+                            || route.ecmp_paths.remove(src.ip()).is_some())
+                    {
+                        // Reinstall route without the removed nexthop.
+                        ibus::tx::route_install(
+                            &instance.tx.ibus,
+                            route,
+                            distance,
+                        );
+                        continue;
+                    }
+                }
+
                 // Update route in the following cases:
                 // * New metric is lower
                 // * Same neighbor, but different metric, nexthop or tag
                 if metric.get() < route.metric.get()
                     || (source == route.source
                         && (metric != route.metric
-                            || nexthop != route.nexthop
+                            || Some(nexthop) != route.nexthop
                             || rte.tag() != route.tag))
                 {
                     if instance.config.trace_opts.route {
@@ -253,13 +330,20 @@ fn process_pdu_response<V>(
 
                     let old_metric = route.metric;
 
+                    // Equal-cost paths are no longer valid when the metric
+                    // changes.
+                    if metric != old_metric {
+                        route.ecmp_paths.clear();
+                    }
+
                     // Update route.
                     route.ifindex = iface.system.ifindex.unwrap();
                     route.source = source;
-                    route.nexthop = nexthop;
+                    route.nexthop = Some(nexthop);
                     route.metric = metric;
                     route.rcvd_metric = Some(rte.metric());
                     route.tag = rte.tag();
+                    route.route_type = RouteType::Rip;
                     route.flags.insert(RouteFlags::CHANGED);
 
                     // Signal the output process to trigger an update.
@@ -280,6 +364,18 @@ fn process_pdu_response<V>(
                             flush_interval,
                             &instance.tx.protocol_input.route_gc_timeout,
                         );
+
+                        // Start holddown.
+                        if let Some(holddown_interval) = holddown_interval {
+                            route.holddown_start(
+                                old_metric,
+                                holddown_interval,
+                                &instance
+                                    .tx
+                                    .protocol_input
+                                    .route_holddown_timeout,
+                            );
+                        }
                     }
                 }
 
@@ -307,7 +403,7 @@ fn process_pdu_response<V>(
                     RouteType::Rip,
                     &instance.config.trace_opts,
                 );
-                route.nexthop = nexthop;
+                route.nexthop = Some(nexthop);
                 route.rcvd_metric = Some(rte.metric());
 
                 // Set route timeout.
@@ -349,13 +445,38 @@ pub(crate) fn process_initial_update<V>(
 pub(crate) fn process_update_interval<V>(
     instance: &mut InstanceUpView<'_, V>,
     interfaces: &mut Interfaces<V>,
+    ifname: Option<String>,
 ) where
     V: Version,
 {
     if instance.config.trace_opts.events {
         Debug::<V>::UpdateInterval.log();
     }
-    output::send_response_all(instance, interfaces, ResponseType::Normal);
+
+    match ifname {
+        // Interface-specific update interval.
+        Some(ifname) => {
+            let Some((_, iface)) = interfaces.get_mut_by_name(&ifname) else {
+                return;
+            };
+            if !iface.state.active {
+                return;
+            }
+
+            iface.with_destinations(|iface, dst| {
+                output::send_response(
+                    instance,
+                    iface,
+                    dst,
+                    ResponseType::Normal,
+                );
+            });
+        }
+        // Instance update interval.
+        None => {
+            output::regular_update(instance, interfaces);
+        }
+    }
 }
 
 // ===== instance triggered update =====
@@ -415,6 +536,7 @@ pub(crate) fn process_route_timeout<V>(
     instance: &mut InstanceUpView<'_, V>,
     interfaces: &mut Interfaces<V>,
     prefix: V::IpNetwork,
+    source: Option<V::IpAddr>,
 ) where
     V: Version,
 {
@@ -422,12 +544,39 @@ pub(crate) fn process_route_timeout<V>(
         Some(route) => route,
         None => return,
     };
+    let distance = instance.config.distance;
+
+    // Remove the expired equal-cost path.
+    if let Some(source) = source {
+        if route.ecmp_paths.remove(&source).is_some() {
+            ibus::tx::route_install(&instance.tx.ibus, route, distance);
+        }
+        return;
+    }
+
+    // Replace the expired primary path with an equal-cost path, if any.
+    if route.ecmp_path_promote(&instance.tx.protocol_input.route_timeout) {
+        ibus::tx::route_install(&instance.tx.ibus, route, distance);
+        return;
+    }
 
     if let Some((_, iface)) = interfaces.get_by_ifindex(route.ifindex) {
         if instance.config.trace_opts.route {
             Debug::<V>::RouteTimeout(&prefix).log();
         }
 
+        // Start holddown.
+        if let Some(holddown_interval) =
+            iface.holddown_interval(instance.config)
+            && !route.metric.is_infinite()
+        {
+            route.holddown_start(
+                route.metric,
+                holddown_interval,
+                &instance.tx.protocol_input.route_holddown_timeout,
+            );
+        }
+
         route.invalidate(
             iface.config.flush_interval,
             instance.tx,
@@ -455,5 +604,41 @@ pub(crate) fn process_route_gc_timeout<V>(
     if instance.config.trace_opts.route {
         Debug::<V>::RouteGcTimeout(&prefix).log();
     }
+
+    // Keep the route until its holddown expires.
+    if route.holddown.is_some() {
+        route.garbage_collection_stop();
+        route.flags.insert(RouteFlags::FLUSH_EXPIRED);
+        return;
+    }
+
     instance.state.routes.remove(&prefix);
 }
+
+// ===== route holddown timeout =====
+
+pub(crate) fn process_route_holddown_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    prefix: V::IpNetwork,
+) where
+    V: Version,
+{
+    let route = match instance.state.routes.get_mut(&prefix) {
+        Some(route) => route,
+        None => return,
+    };
+    if route.holddown.is_none() {
+        return;
+    }
+
+    if instance.config.trace_opts.route {
+        Debug::<V>::RouteHolddownTimeout(&prefix).log();
+    }
+
+    // Delete the route if it was flushed while in holddown.
+    let flush_expired = route.flags.contains(RouteFlags::FLUSH_EXPIRED);
+    route.holddown_stop();
+    if flush_expired {
+        instance.state.routes.remove(&prefix);
+    }
+}
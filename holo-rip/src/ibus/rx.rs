@@ -5,12 +5,14 @@
 //
 
 use holo_utils::ip::IpNetworkKind;
-use holo_utils::southbound::{AddressFlags, AddressMsg, InterfaceUpdateMsg};
+use holo_utils::southbound::{
+    AddressFlags, AddressMsg, InterfaceUpdateMsg, RouteKeyMsg, RouteMsg,
+};
 
 use crate::ibus;
 use crate::instance::{Instance, InstanceUpView};
 use crate::interface::Interface;
-use crate::route::{Route, RouteType};
+use crate::route::{Route, RouteSys, RouteType};
 use crate::version::Version;
 
 // ===== global functions =====
@@ -120,6 +122,84 @@ where
     }
 }
 
+pub(crate) fn process_route_add<V>(instance: &mut Instance<V>, msg: RouteMsg)
+where
+    V: Version,
+{
+    let Some(prefix) = V::IpNetwork::get(msg.prefix) else {
+        return;
+    };
+
+    // Ignore routes from protocols that aren't being redistributed.
+    if !instance.config.redistribution.contains_key(&msg.protocol) {
+        return;
+    }
+
+    // Store redistributed route.
+    let route = RouteSys {
+        protocol: msg.protocol,
+        metric: msg.metric,
+        tag: msg.tag,
+    };
+    instance.system.routes.insert(msg.prefix, route);
+
+    // Originate RIP route.
+    if let Some((mut instance, _)) = instance.as_up() {
+        instance.external_route_update(prefix);
+    }
+}
+
+pub(crate) fn process_route_del<V>(instance: &mut Instance<V>, msg: RouteKeyMsg)
+where
+    V: Version,
+{
+    let Some(prefix) = V::IpNetwork::get(msg.prefix) else {
+        return;
+    };
+
+    // Remove redistributed route.
+    if instance.system.routes.remove(&msg.prefix).is_none() {
+        return;
+    }
+
+    // Withdraw RIP route.
+    if let Some((mut instance, _)) = instance.as_up() {
+        instance.external_route_update(prefix);
+    }
+}
+
+pub(crate) fn process_policy_update<V>(instance: &mut Instance<V>)
+where
+    V: Version,
+{
+    let Some((mut instance, _)) = instance.as_up() else {
+        return;
+    };
+
+    // Reevaluate all locally originated routes.
+    instance.external_routes_update();
+}
+
+pub(crate) fn process_keychain_update<V>(
+    instance: &mut Instance<V>,
+    keychain_name: &str,
+) where
+    V: Version,
+{
+    let Some((instance, interfaces)) = instance.as_up() else {
+        return;
+    };
+
+    for iface in interfaces.iter_mut() {
+        if iface.config.auth_keychain.as_deref() != Some(keychain_name) {
+            continue;
+        }
+
+        // Update interface authentication keys.
+        iface.auth_update(&instance);
+    }
+}
+
 // ===== helper functions =====
 
 fn connected_route_add<V>(
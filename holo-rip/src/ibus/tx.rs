@@ -26,7 +26,20 @@ pub(crate) fn route_install<V>(
         return;
     }
 
-    // Fill-in message.
+    // Fill-in message. Equal-cost paths are installed as ECMP nexthops.
+    let nexthops = std::iter::once((route.ifindex, route.nexthop.unwrap()))
+        .chain(
+            route
+                .ecmp_paths
+                .values()
+                .map(|path| (path.ifindex, path.nexthop)),
+        )
+        .map(|(ifindex, addr)| Nexthop::Address {
+            ifindex,
+            addr: addr.into(),
+            labels: Vec::new(),
+        })
+        .collect();
     let msg = RouteMsg {
         protocol: V::PROTOCOL,
         kind: RouteKind::Unicast,
@@ -35,12 +48,8 @@ pub(crate) fn route_install<V>(
         metric: route.metric.get() as u32,
         tag: Some(route.tag.into()),
         opaque_attrs: RouteOpaqueAttrs::None,
-        nexthops: [Nexthop::Address {
-            ifindex: route.ifindex,
-            addr: route.nexthop.unwrap().into(),
-            labels: Vec::new(),
-        }]
-        .into(),
+        nexthops,
+        backup_nexthops: Default::default(),
     };
 
     // Send message.
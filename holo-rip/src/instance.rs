@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
@@ -15,8 +15,11 @@ use holo_protocol::{
     InstanceChannelsTx, InstanceShared, MessageReceiver, ProtocolInstance,
 };
 use holo_utils::ibus::IbusMsg;
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::policy::PolicyResult;
 use holo_utils::protocol::Protocol;
 use holo_utils::task::{IntervalTask, TimeoutTask};
+use ipnetwork::IpNetwork;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
 
@@ -26,14 +29,15 @@ use crate::interface::Interfaces;
 use crate::neighbor::Neighbor;
 use crate::northbound::configuration::InstanceCfg;
 use crate::packet::Command;
-use crate::route::Route;
+use crate::route::{Metric, Route, RouteSys, RouteType};
 use crate::tasks::messages::input::{
-    InitialUpdateMsg, NbrTimeoutMsg, RouteGcTimeoutMsg, RouteTimeoutMsg,
-    TriggeredUpdMsg, TriggeredUpdTimeoutMsg, UdpRxPduMsg, UpdateIntervalMsg,
+    InitialUpdateMsg, NbrTimeoutMsg, RouteGcTimeoutMsg,
+    RouteHolddownTimeoutMsg, RouteTimeoutMsg, TriggeredUpdMsg,
+    TriggeredUpdTimeoutMsg, UdpRxPduMsg, UpdateIntervalMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::version::Version;
-use crate::{events, ibus, tasks};
+use crate::{events, ibus, policy, tasks};
 
 #[derive(Debug)]
 pub struct Instance<V: Version> {
@@ -49,11 +53,15 @@ pub struct Instance<V: Version> {
     pub interfaces: Interfaces<V>,
     // Instance Tx channels.
     pub tx: InstanceChannelsTx<Instance<V>>,
+    // Shared data.
+    pub shared: InstanceShared,
 }
 
 #[derive(Debug, Default)]
 pub struct InstanceSys {
     pub router_id: Option<Ipv4Addr>,
+    // Routes redistributed from the global RIB.
+    pub routes: BTreeMap<IpNetwork, RouteSys>,
 }
 
 #[derive(Debug)]
@@ -90,6 +98,7 @@ pub struct InstanceUpView<'a, V: Version> {
     pub config: &'a InstanceCfg,
     pub state: &'a mut InstanceState<V>,
     pub tx: &'a InstanceChannelsTx<Instance<V>>,
+    pub shared: &'a InstanceShared,
 }
 
 #[derive(Clone, Debug)]
@@ -110,6 +119,8 @@ pub struct ProtocolInputChannelsTx<V: Version> {
     pub route_timeout: Sender<RouteTimeoutMsg<V>>,
     // Route garbage-collection event.
     pub route_gc_timeout: Sender<RouteGcTimeoutMsg<V>>,
+    // Route holddown timeout event.
+    pub route_holddown_timeout: Sender<RouteHolddownTimeoutMsg<V>>,
 }
 
 #[derive(Debug)]
@@ -130,6 +141,8 @@ pub struct ProtocolInputChannelsRx<V: Version> {
     pub route_timeout: Receiver<RouteTimeoutMsg<V>>,
     // Route garbage-collection event.
     pub route_gc_timeout: Receiver<RouteGcTimeoutMsg<V>>,
+    // Route holddown timeout event.
+    pub route_holddown_timeout: Receiver<RouteHolddownTimeoutMsg<V>>,
 }
 
 // ===== impl Instance =====
@@ -164,6 +177,9 @@ where
         for iface in interfaces.iter_mut() {
             iface.update(&mut instance);
         }
+
+        // Originate the default route and the redistributed routes.
+        instance.external_routes_update();
     }
 
     fn stop(&mut self, reason: InstanceInactiveReason) {
@@ -204,6 +220,7 @@ where
                 config: &self.config,
                 state,
                 tx: &self.tx,
+                shared: &self.shared,
             };
             Some((instance, &mut self.interfaces))
         } else {
@@ -225,7 +242,7 @@ where
 
     fn new(
         name: String,
-        _shared: InstanceShared,
+        shared: InstanceShared,
         tx: InstanceChannelsTx<Instance<V>>,
     ) -> Instance<V> {
         Debug::<V>::InstanceCreate.log();
@@ -237,6 +254,7 @@ where
             interfaces: Default::default(),
             state: None,
             tx,
+            shared,
         }
     }
 
@@ -276,6 +294,8 @@ where
         let (nbr_timeoutp, nbr_timeoutc) = mpsc::channel(4);
         let (route_timeoutp, route_timeoutc) = mpsc::channel(4);
         let (route_gc_timeoutp, route_gc_timeoutc) = mpsc::channel(4);
+        let (route_holddown_timeoutp, route_holddown_timeoutc) =
+            mpsc::channel(4);
         let (triggered_updp, triggered_updc) = mpsc::unbounded_channel();
         let (triggered_upd_timeoutp, triggered_upd_timeoutc) = mpsc::channel(4);
 
@@ -286,6 +306,7 @@ where
             nbr_timeout: nbr_timeoutp,
             route_timeout: route_timeoutp,
             route_gc_timeout: route_gc_timeoutp,
+            route_holddown_timeout: route_holddown_timeoutp,
             triggered_upd: triggered_updp,
             triggered_upd_timeout: triggered_upd_timeoutp,
         };
@@ -296,6 +317,7 @@ where
             nbr_timeout: nbr_timeoutc,
             route_timeout: route_timeoutc,
             route_gc_timeout: route_gc_timeoutc,
+            route_holddown_timeout: route_holddown_timeoutc,
             triggered_upd: triggered_updc,
             triggered_upd_timeout: triggered_upd_timeoutc,
         };
@@ -330,6 +352,7 @@ where
         // Start update interval task.
         let interval = Duration::from_secs(update_interval.into());
         let update_interval_task = tasks::update_interval(
+            None,
             interval,
             &tx.protocol_input.update_interval,
         );
@@ -367,6 +390,159 @@ where
     }
 }
 
+// ===== impl InstanceUpView =====
+
+impl<V> InstanceUpView<'_, V>
+where
+    V: Version,
+{
+    // Updates the locally originated RIP route for the given prefix, which can
+    // be either the default route or a route redistributed from the global
+    // RIB.
+    pub(crate) fn external_route_update(&mut self, prefix: V::IpNetwork) {
+        match self.external_route(prefix) {
+            Some((metric, tag)) => {
+                if let Some(route) = self.state.routes.get(&prefix) {
+                    match route.route_type {
+                        // Connected routes take precedence.
+                        RouteType::Connected => return,
+                        RouteType::External => {
+                            // Nothing has changed.
+                            if route.metric == metric && route.tag == tag {
+                                return;
+                            }
+                        }
+                        RouteType::Rip => {
+                            // Uninstall previously learned route.
+                            ibus::tx::route_uninstall(&self.tx.ibus, route);
+                        }
+                    }
+                }
+
+                // Add new external route.
+                let route = Route::new(
+                    prefix,
+                    0,
+                    None,
+                    metric,
+                    tag,
+                    RouteType::External,
+                    &self.config.trace_opts,
+                );
+                self.state.routes.insert(prefix, route);
+
+                // Signal the output process to trigger an update.
+                self.tx.protocol_input.trigger_update();
+            }
+            None => {
+                // Invalidate previously originated route.
+                if let Some(route) = self.state.routes.get_mut(&prefix)
+                    && route.route_type == RouteType::External
+                    && !route.metric.is_infinite()
+                {
+                    route.invalidate(
+                        self.config.flush_interval,
+                        self.tx,
+                        &self.config.trace_opts,
+                    );
+                }
+            }
+        }
+    }
+
+    // Updates all locally originated RIP routes.
+    pub(crate) fn external_routes_update(&mut self) {
+        let mut prefixes = self
+            .system
+            .routes
+            .keys()
+            .filter_map(|prefix| V::IpNetwork::get(*prefix))
+            .collect::<BTreeSet<_>>();
+        prefixes.insert(V::IpNetwork::default(V::ADDRESS_FAMILY));
+        prefixes.extend(
+            self.state
+                .routes
+                .values()
+                .filter(|route| route.route_type == RouteType::External)
+                .map(|route| route.prefix),
+        );
+        for prefix in prefixes {
+            self.external_route_update(prefix);
+        }
+    }
+
+    // Returns the metric and tag of the default route, or `None` if the
+    // default route was rejected by the given route policy.
+    pub(crate) fn default_route(
+        &self,
+        route_policy: Option<&str>,
+    ) -> Option<(Metric, u16)> {
+        let prefix = V::IpNetwork::default(V::ADDRESS_FAMILY);
+        let route = RouteSys {
+            protocol: V::PROTOCOL,
+            metric: self.config.default_metric.get().into(),
+            tag: None,
+        };
+        self.route_policy_apply(prefix, &route, route_policy)
+    }
+
+    // Returns the metric and tag of the locally originated route for the given
+    // prefix, or `None` if no route should be originated.
+    fn external_route(&self, prefix: V::IpNetwork) -> Option<(Metric, u16)> {
+        let config = self.config;
+
+        // Check for default route origination.
+        if prefix == V::IpNetwork::default(V::ADDRESS_FAMILY)
+            && config.originate_default_route.enabled
+        {
+            let route_policy =
+                config.originate_default_route.route_policy.as_deref();
+            return self.default_route(route_policy);
+        }
+
+        // Check for redistributed route.
+        let sys_prefix: IpNetwork = prefix.into();
+        let route = self.system.routes.get(&sys_prefix)?;
+        let redistribution = config.redistribution.get(&route.protocol)?;
+        let metric =
+            redistribution.metric.unwrap_or(config.default_metric.get());
+        let route = RouteSys {
+            metric: metric.into(),
+            ..route.clone()
+        };
+        let route_policy = redistribution.route_policy.as_deref();
+        self.route_policy_apply(prefix, &route, route_policy)
+    }
+
+    fn route_policy_apply(
+        &self,
+        prefix: V::IpNetwork,
+        route: &RouteSys,
+        route_policy: Option<&str>,
+    ) -> Option<(Metric, u16)> {
+        match policy::route_policy_apply(
+            prefix.into(),
+            route,
+            route_policy,
+            &self.shared.policies,
+            &self.shared.policy_match_sets,
+        ) {
+            PolicyResult::Accept(route) => {
+                // Routes with an infinite metric aren't originated.
+                let metric = Metric::new(route.metric.max(1))
+                    .ok()
+                    .filter(|metric| !metric.is_infinite())?;
+                let tag = route
+                    .tag
+                    .and_then(|tag| u16::try_from(tag).ok())
+                    .unwrap_or(0);
+                Some((metric, tag))
+            }
+            PolicyResult::Reject => None,
+        }
+    }
+}
+
 // ===== impl MessageStatistics =====
 
 impl MessageStatistics {
@@ -424,6 +600,9 @@ where
             msg = self.route_gc_timeout.recv() => {
                 msg.map(ProtocolInputMsg::RouteGcTimeout)
             }
+            msg = self.route_holddown_timeout.recv() => {
+                msg.map(ProtocolInputMsg::RouteHolddownTimeout)
+            }
         }
     }
 }
@@ -454,6 +633,60 @@ where
         IbusMsg::InterfaceAddressDel(msg) => {
             ibus::rx::process_addr_del(instance, msg);
         }
+        // Route redistribute update notification.
+        IbusMsg::RouteRedistributeAdd(msg) => {
+            ibus::rx::process_route_add(instance, msg);
+        }
+        // Route redistribute delete notification.
+        IbusMsg::RouteRedistributeDel(msg) => {
+            ibus::rx::process_route_del(instance, msg);
+        }
+        // Policy match sets update event.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition update event.
+        IbusMsg::PolicyUpd(policy) => {
+            // Update the local copy of the policy definition.
+            instance
+                .shared
+                .policies
+                .insert(policy.name.clone(), policy.clone());
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition delete event.
+        IbusMsg::PolicyDel(policy_name) => {
+            // Remove the local copy of the policy definition.
+            instance.shared.policies.remove(&policy_name);
+
+            // Reevaluate the redistribution policies.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Keychain update event.
+        IbusMsg::KeychainUpd(keychain) => {
+            // Update the local copy of the keychain.
+            instance
+                .shared
+                .keychains
+                .insert(keychain.name.clone(), keychain.clone());
+
+            // Update all interfaces using this keychain.
+            ibus::rx::process_keychain_update(instance, &keychain.name);
+        }
+        // Keychain delete event.
+        IbusMsg::KeychainDel(keychain_name) => {
+            // Remove the local copy of the keychain.
+            instance.shared.keychains.remove(&keychain_name);
+
+            // Update all interfaces using this keychain.
+            ibus::rx::process_keychain_update(instance, &keychain_name);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -479,8 +712,8 @@ where
             events::process_initial_update(instance, interfaces);
         }
         // Route update interval.
-        ProtocolInputMsg::UpdateInterval(_msg) => {
-            events::process_update_interval(instance, interfaces);
+        ProtocolInputMsg::UpdateInterval(msg) => {
+            events::process_update_interval(instance, interfaces, msg.ifname);
         }
         // Signal to send triggered update.
         ProtocolInputMsg::TriggeredUpd(_msg) => {
@@ -496,12 +729,18 @@ where
         }
         // Route's timeout has expired.
         ProtocolInputMsg::RouteTimeout(msg) => {
-            events::process_route_timeout(instance, interfaces, msg.prefix);
+            events::process_route_timeout(
+                instance, interfaces, msg.prefix, msg.source,
+            );
         }
         // Route's garbage-collection timeout has expired.
         ProtocolInputMsg::RouteGcTimeout(msg) => {
             events::process_route_gc_timeout(instance, msg.prefix);
         }
+        // Route's holddown timeout has expired.
+        ProtocolInputMsg::RouteHolddownTimeout(msg) => {
+            events::process_route_holddown_timeout(instance, msg.prefix);
+        }
     }
 
     Ok(())
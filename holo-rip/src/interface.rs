@@ -7,15 +7,17 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
 use std::sync::atomic::AtomicU32;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use generational_arena::{Arena, Index};
 use holo_protocol::InstanceChannelsTx;
 use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ip::{IpNetworkKind, SocketAddrKind};
+use holo_utils::keychain::{Key, Keychains};
 use holo_utils::socket::UdpSocket;
 use holo_utils::southbound::InterfaceFlags;
-use holo_utils::task::Task;
+use holo_utils::task::{IntervalTask, Task};
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::UnboundedSender;
 
@@ -23,11 +25,12 @@ use crate::debug::{Debug, InterfaceInactiveReason};
 use crate::error::{Error, IoError};
 use crate::instance::{Instance, InstanceUpView};
 use crate::network::SendDestination;
-use crate::northbound::configuration::InterfaceCfg;
-use crate::packet::AuthCtx;
+use crate::northbound::configuration::{InstanceCfg, InterfaceCfg};
+use crate::packet::{AuthCtx, AuthMethod};
+use crate::route::RouteType;
 use crate::tasks::messages::output::UdpTxPduMsg;
 use crate::version::Version;
-use crate::{output, tasks};
+use crate::{ibus, output, tasks};
 
 pub type InterfaceIndex = Index;
 
@@ -53,6 +56,8 @@ pub struct InterfaceState<V: Version> {
     pub active: bool,
     // UDP socket and Tx/Rx tasks.
     pub net: Option<InterfaceNet<V>>,
+    // Interface-specific update interval task.
+    pub update_interval_task: Option<IntervalTask>,
     // Message statistics.
     pub statistics: MessageStatistics,
 }
@@ -144,7 +149,10 @@ where
         if !self.system.flags.contains(InterfaceFlags::LOOPBACK) {
             let net = InterfaceNet::new(
                 &self.name,
-                self.auth(&instance.state.auth_seqno),
+                self.auth(
+                    &instance.shared.keychains,
+                    &instance.state.auth_seqno,
+                ),
                 instance.tx,
             )?;
             if !self.config.no_listen {
@@ -160,6 +168,9 @@ where
             });
         }
 
+        // Start the interface-specific update interval, if any.
+        self.update_interval_reset(instance.tx);
+
         // Mark interface as active.
         self.state.active = true;
 
@@ -179,12 +190,40 @@ where
         Debug::<V>::InterfaceStop(&self.name, reason).log();
 
         // Invalidate all routes that go through this interface.
+        let ifindex = self.system.ifindex.unwrap();
+        let holddown_interval = self.holddown_interval(instance.config);
+        let distance = instance.config.distance;
         for route in instance
             .state
             .routes
             .values_mut()
-            .filter(|route| route.ifindex == self.system.ifindex.unwrap())
+            .filter(|route| route.is_via_interface(ifindex))
         {
+            // Remove the equal-cost paths that go through this interface.
+            route.ecmp_paths.retain(|_, path| path.ifindex != ifindex);
+            if route.ifindex != ifindex {
+                ibus::tx::route_install(&instance.tx.ibus, route, distance);
+                continue;
+            }
+
+            // Replace the primary path with an equal-cost path, if any.
+            if route
+                .ecmp_path_promote(&instance.tx.protocol_input.route_timeout)
+            {
+                ibus::tx::route_install(&instance.tx.ibus, route, distance);
+                continue;
+            }
+
+            if let Some(holddown_interval) = holddown_interval
+                && route.route_type == RouteType::Rip
+                && !route.metric.is_infinite()
+            {
+                route.holddown_start(
+                    route.metric,
+                    holddown_interval,
+                    &instance.tx.protocol_input.route_holddown_timeout,
+                );
+            }
             route.invalidate(
                 self.config.flush_interval,
                 instance.tx,
@@ -195,6 +234,7 @@ where
         // Reset interface state.
         self.state.active = false;
         self.state.net = None;
+        self.state.update_interval_task = None;
         self.state.statistics = Default::default();
     }
 
@@ -215,19 +255,80 @@ where
         Ok(())
     }
 
+    // Starts, restarts or stops the interface-specific update interval task,
+    // depending on whether the update interval is configured explicitly for
+    // this interface.
+    pub(crate) fn update_interval_reset(
+        &mut self,
+        instance_tx: &InstanceChannelsTx<Instance<V>>,
+    ) {
+        self.state.update_interval_task =
+            self.config.update_interval.map(|interval| {
+                tasks::update_interval(
+                    Some(self.name.clone()),
+                    Duration::from_secs(interval.into()),
+                    &instance_tx.protocol_input.update_interval,
+                )
+            });
+    }
+
+    // Returns the route holddown interval in effect for this interface, if
+    // holddown is enabled.
+    pub(crate) fn holddown_interval(
+        &self,
+        instance_cfg: &InstanceCfg,
+    ) -> Option<u16> {
+        self.config
+            .holddown_interval
+            .or(instance_cfg.holddown_interval)
+    }
+
     pub(crate) fn is_passive(&self) -> bool {
         self.system.flags.contains(InterfaceFlags::LOOPBACK)
             || self.config.passive
     }
 
-    pub(crate) fn auth(&self, seqno: &Arc<AtomicU32>) -> Option<AuthCtx> {
-        self.config.auth_key.as_ref().map(|auth_key| {
-            AuthCtx::new(
-                auth_key.clone(),
-                self.config.auth_algo.unwrap_or(CryptoAlgo::Md5),
-                seqno.clone(),
-            )
-        })
+    pub(crate) fn auth_method(
+        &self,
+        keychains: &Keychains,
+    ) -> Option<AuthMethod> {
+        if let Some(auth_key) = &self.config.auth_key {
+            // The manually configured key doesn't have a Key ID, so a static
+            // one is used.
+            let algo = self.config.auth_algo.unwrap_or(CryptoAlgo::Md5);
+            let key = Key::new(1, algo, auth_key.as_bytes().to_vec());
+            return Some(AuthMethod::ManualKey(key));
+        }
+
+        if let Some(keychain) = &self.config.auth_keychain
+            && let Some(keychain) = keychains.get(keychain)
+        {
+            return Some(AuthMethod::Keychain(keychain.clone()));
+        }
+
+        None
+    }
+
+    pub(crate) fn auth(
+        &self,
+        keychains: &Keychains,
+        seqno: &Arc<AtomicU32>,
+    ) -> Option<AuthCtx> {
+        self.auth_method(keychains)
+            .map(|method| AuthCtx::new(method, seqno.clone()))
+    }
+
+    // Restarts the network Tx/Rx tasks using up-to-date authentication data.
+    pub(crate) fn auth_update(&mut self, instance: &InstanceUpView<'_, V>) {
+        if !self.state.active {
+            return;
+        }
+
+        let auth =
+            self.auth(&instance.shared.keychains, &instance.state.auth_seqno);
+        if let Some(net) = &mut self.state.net {
+            net.restart_tasks(auth, instance.tx);
+        }
     }
 
     // Runs the passed closure once for each one of the valid interface
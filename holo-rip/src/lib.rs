@@ -20,6 +20,7 @@ pub mod network;
 pub mod northbound;
 pub mod output;
 pub mod packet;
+pub mod policy;
 pub mod ripng;
 pub mod ripv2;
 pub mod route;
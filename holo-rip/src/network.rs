@@ -14,7 +14,7 @@ use tokio::sync::mpsc::error::SendError;
 use tokio::sync::mpsc::{Sender, UnboundedReceiver};
 
 use crate::error::{Error, IoError};
-use crate::packet::{AuthCtx, PduVersion};
+use crate::packet::{AuthCtx, AuthEncodeCtx, PduVersion};
 use crate::tasks::messages::input::UdpRxPduMsg;
 use crate::tasks::messages::output::UdpTxPduMsg;
 use crate::version::Version;
@@ -61,7 +61,7 @@ pub(crate) async fn send_packet<V>(
     socket: &UdpSocket,
     pdu: V::Pdu,
     dst: SendDestination<V::SocketAddr>,
-    auth: Option<&AuthCtx>,
+    auth: Option<&AuthEncodeCtx<'_>>,
 ) -> Result<(), std::io::Error>
 where
     V: Version,
@@ -93,9 +93,26 @@ pub(crate) async fn write_loop<V>(
 ) where
     V: Version,
 {
-    while let Some(UdpTxPduMsg { dst, pdu }) = udp_tx_pduc.recv().await {
+    while let Some(UdpTxPduMsg { dst, pdu, delay }) = udp_tx_pduc.recv().await {
+        // Pace multipacket updates to avoid overrunning slow receivers.
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        // Prepare authentication context.
+        let auth_ctx = match &auth {
+            Some(auth) => match auth.encode_ctx() {
+                Some(auth_ctx) => Some(auth_ctx),
+                None => {
+                    Error::<V>::UdpPduAuthMissingKey.log();
+                    continue;
+                }
+            },
+            None => None,
+        };
+
         if let Err(error) =
-            send_packet::<V>(&socket, pdu, dst, auth.as_ref()).await
+            send_packet::<V>(&socket, pdu, dst, auth_ctx.as_ref()).await
         {
             IoError::UdpSendError(error).log();
         }
@@ -132,7 +149,8 @@ where
         }
 
         // Decode packet.
-        let pdu = V::Pdu::decode(&buf[0..num_bytes], auth.as_ref());
+        let auth_method = auth.as_ref().map(|auth| &auth.method);
+        let pdu = V::Pdu::decode(&buf[0..num_bytes], auth_method);
         let msg = UdpRxPduMsg { src, pdu };
         udp_pdu_rxp.send(msg).await?;
     }
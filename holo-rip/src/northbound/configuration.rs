@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::sync::LazyLock as Lazy;
 use std::time::Duration;
 
@@ -15,7 +15,8 @@ use holo_northbound::configuration::{
 };
 use holo_northbound::yang::control_plane_protocol::rip;
 use holo_utils::crypto::CryptoAlgo;
-use holo_utils::ip::IpAddrKind;
+use holo_utils::ip::{IpAddrKind, IpNetworkKind};
+use holo_utils::protocol::Protocol;
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::{ToYang, TryFromYang};
 
@@ -31,6 +32,8 @@ pub enum ListEntry<V: Version> {
     None,
     Interface(InterfaceIndex),
     StaticNbr(InterfaceIndex, V::IpAddr),
+    DistributeList(String, DistributeListDirection),
+    Redistribution(Protocol),
     TraceOption(TraceOption),
 }
 
@@ -43,11 +46,16 @@ pub enum Event {
     InterfaceDelete(InterfaceIndex),
     InterfaceCostUpdate(InterfaceIndex),
     InterfaceRestartNetTasks(InterfaceIndex),
+    InterfaceResetUpdateInterval(InterfaceIndex),
     InterfaceIbusSub(String),
     JoinMulticast(InterfaceIndex),
     LeaveMulticast(InterfaceIndex),
     ReinstallRoutes,
+    MaximumPathsUpdate,
     ResetUpdateInterval,
+    RedistributeAdd(Protocol),
+    RedistributeDelete(Protocol),
+    ExternalRoutesUpdate,
 }
 
 pub static VALIDATION_CALLBACKS_RIPV2: Lazy<ValidationCallbacks> =
@@ -63,15 +71,51 @@ pub static CALLBACKS_RIPNG: Lazy<Callbacks<Instance<Ripng>>> =
 
 #[derive(Debug)]
 pub struct InstanceCfg {
+    pub originate_default_route: OriginateDefaultRouteCfg,
     pub default_metric: Metric,
     pub distance: u8,
     pub triggered_update_threshold: u8,
+    pub distribute_lists:
+        BTreeMap<(String, DistributeListDirection), DistributeListCfg>,
+    pub redistribution: BTreeMap<Protocol, RedistributionCfg>,
+    pub maximum_paths: u8,
+    pub output_delay: Option<u8>,
     pub update_interval: u16,
     pub invalid_interval: u16,
+    pub holddown_interval: Option<u16>,
     pub flush_interval: u16,
     pub trace_opts: TraceOptions,
 }
 
+#[derive(Debug, Default)]
+pub struct OriginateDefaultRouteCfg {
+    pub enabled: bool,
+    pub route_policy: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DistributeListDirection {
+    In,
+    Out,
+}
+
+#[derive(Debug, Default)]
+pub struct DistributeListCfg {
+    pub ifname: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct RedistributionCfg {
+    pub metric: Option<u8>,
+    pub route_policy: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SummaryAddressCfg<V: Version> {
+    pub prefix: Option<V::IpNetwork>,
+    pub metric: Option<u8>,
+}
+
 #[derive(Debug)]
 pub struct InterfaceCfg<V: Version> {
     pub cost: Metric,
@@ -79,10 +123,15 @@ pub struct InterfaceCfg<V: Version> {
     pub no_listen: bool,
     pub passive: bool,
     pub split_horizon: SplitHorizon,
+    pub originate_default_route: OriginateDefaultRouteCfg,
+    pub summary_address: SummaryAddressCfg<V>,
+    pub update_interval: Option<u16>,
     pub invalid_interval: u16,
+    pub holddown_interval: Option<u16>,
     pub flush_interval: u16,
     pub auth_key: Option<String>,
     pub auth_algo: Option<CryptoAlgo>,
+    pub auth_keychain: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -109,11 +158,37 @@ where
     V: Version,
 {
     CallbacksBuilder::<Instance<V>>::default()
+        .path(rip::originate_default_route::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.originate_default_route.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExternalRoutesUpdate);
+        })
+        .path(rip::originate_default_route::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            instance.config.originate_default_route.route_policy =
+                Some(route_policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExternalRoutesUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.originate_default_route.route_policy = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExternalRoutesUpdate);
+        })
         .path(rip::default_metric::PATH)
         .modify_apply(|instance, args| {
             let default_metric = args.dnode.get_u8();
             let default_metric = Metric::from(default_metric);
             instance.config.default_metric = default_metric;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ExternalRoutesUpdate);
         })
         .path(rip::distance::PATH)
         .modify_apply(|instance, args| {
@@ -128,6 +203,345 @@ where
             let threshold = args.dnode.get_u8();
             instance.config.triggered_update_threshold = threshold;
         })
+        .path(rip::maximum_paths::PATH)
+        .modify_apply(|instance, args| {
+            let maximum_paths = args.dnode.get_u8();
+            instance.config.maximum_paths = maximum_paths;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MaximumPathsUpdate);
+        })
+        .path(rip::output_delay::PATH)
+        .modify_apply(|instance, args| {
+            let output_delay = args.dnode.get_u8();
+            instance.config.output_delay = Some(output_delay);
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.output_delay = None;
+        })
+        .path(rip::distribute_list::PATH)
+        .create_apply(|instance, args| {
+            let prefix_set =
+                args.dnode.get_string_relative("prefix-set-name").unwrap();
+            let direction =
+                args.dnode.get_string_relative("direction").unwrap();
+            let direction =
+                DistributeListDirection::try_from_yang(&direction).unwrap();
+            instance
+                .config
+                .distribute_lists
+                .insert((prefix_set, direction), Default::default());
+        })
+        .delete_apply(|instance, args| {
+            let (prefix_set, direction) =
+                args.list_entry.into_distribute_list().unwrap();
+            instance
+                .config
+                .distribute_lists
+                .remove(&(prefix_set, direction));
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let prefix_set =
+                dnode.get_string_relative("prefix-set-name").unwrap();
+            let direction = dnode.get_string_relative("direction").unwrap();
+            let direction =
+                DistributeListDirection::try_from_yang(&direction).unwrap();
+            ListEntry::DistributeList(prefix_set, direction)
+        })
+        .path(rip::distribute_list::if_name::PATH)
+        .modify_apply(|instance, args| {
+            let (prefix_set, direction) =
+                args.list_entry.into_distribute_list().unwrap();
+            let distribute_list = instance
+                .config
+                .distribute_lists
+                .get_mut(&(prefix_set, direction))
+                .unwrap();
+
+            let ifname = args.dnode.get_string();
+            distribute_list.ifname = Some(ifname);
+        })
+        .delete_apply(|instance, args| {
+            let (prefix_set, direction) =
+                args.list_entry.into_distribute_list().unwrap();
+            let distribute_list = instance
+                .config
+                .distribute_lists
+                .get_mut(&(prefix_set, direction))
+                .unwrap();
+
+            distribute_list.ifname = None;
+        })
+        .path(rip::redistribute::bgp::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::BGP);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::BGP);
+        })
+        .lookup(|_instance, _list_entry, _dnode| {
+            ListEntry::Redistribution(Protocol::BGP)
+        })
+        .path(rip::redistribute::bgp::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::BGP,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::BGP,
+                None,
+            );
+        })
+        .path(rip::redistribute::bgp::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::BGP,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::BGP,
+                None,
+            );
+        })
+        .path(rip::redistribute::connected::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::DIRECT);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::DIRECT);
+        })
+        .path(rip::redistribute::connected::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::DIRECT,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::DIRECT,
+                None,
+            );
+        })
+        .path(rip::redistribute::connected::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::DIRECT,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::DIRECT,
+                None,
+            );
+        })
+        .path(rip::redistribute::isis::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::ISIS);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::ISIS);
+        })
+        .lookup(|_instance, _list_entry, _dnode| {
+            ListEntry::Redistribution(Protocol::ISIS)
+        })
+        .path(rip::redistribute::isis::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::ISIS,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::ISIS,
+                None,
+            );
+        })
+        .path(rip::redistribute::isis::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::ISIS,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::ISIS,
+                None,
+            );
+        })
+        .path(rip::redistribute::ospfv2::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::OSPFV2);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::OSPFV2);
+        })
+        .lookup(|_instance, _list_entry, _dnode| {
+            ListEntry::Redistribution(Protocol::OSPFV2)
+        })
+        .path(rip::redistribute::ospfv2::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV2,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV2,
+                None,
+            );
+        })
+        .path(rip::redistribute::ospfv2::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV2,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV2,
+                None,
+            );
+        })
+        .path(rip::redistribute::ospfv3::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::OSPFV3);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::OSPFV3);
+        })
+        .lookup(|_instance, _list_entry, _dnode| {
+            ListEntry::Redistribution(Protocol::OSPFV3)
+        })
+        .path(rip::redistribute::ospfv3::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV3,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV3,
+                None,
+            );
+        })
+        .path(rip::redistribute::ospfv3::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV3,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::OSPFV3,
+                None,
+            );
+        })
+        .path(rip::redistribute::r#static::PATH)
+        .create_apply(|instance, args| {
+            redistribution_create(instance, args.event_queue, Protocol::STATIC);
+        })
+        .delete_apply(|instance, args| {
+            redistribution_delete(instance, args.event_queue, Protocol::STATIC);
+        })
+        .path(rip::redistribute::r#static::metric::PATH)
+        .modify_apply(|instance, args| {
+            let metric = args.dnode.get_u8();
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::STATIC,
+                Some(metric),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_metric(
+                instance,
+                args.event_queue,
+                Protocol::STATIC,
+                None,
+            );
+        })
+        .path(rip::redistribute::r#static::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let route_policy = args.dnode.get_string();
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::STATIC,
+                Some(route_policy),
+            );
+        })
+        .delete_apply(|instance, args| {
+            redistribution_route_policy(
+                instance,
+                args.event_queue,
+                Protocol::STATIC,
+                None,
+            );
+        })
         .path(rip::timers::update_interval::PATH)
         .modify_apply(|instance, args| {
             let update_interval = args.dnode.get_u16();
@@ -141,6 +555,14 @@ where
             let invalid_interval = args.dnode.get_u16();
             instance.config.invalid_interval = invalid_interval;
         })
+        .path(rip::timers::holddown_interval::PATH)
+        .modify_apply(|instance, args| {
+            let holddown_interval = args.dnode.get_u16();
+            instance.config.holddown_interval = Some(holddown_interval);
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.holddown_interval = None;
+        })
         .path(rip::timers::flush_interval::PATH)
         .modify_apply(|instance, args| {
             let flush_interval = args.dnode.get_u16();
@@ -273,6 +695,33 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::JoinMulticast(iface_idx));
         })
+        .path(
+            rip::interfaces::interface::originate_default_route::enabled::PATH,
+        )
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.originate_default_route.enabled = enabled;
+        })
+        .path(
+            rip::interfaces::interface::originate_default_route::route_policy::PATH,
+        )
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let route_policy = args.dnode.get_string();
+            iface.config.originate_default_route.route_policy =
+                Some(route_policy);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.originate_default_route.route_policy = None;
+        })
         .path(rip::interfaces::interface::passive::PATH)
         .create_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -296,6 +745,55 @@ where
                 SplitHorizon::try_from_yang(&split_horizon).unwrap();
             iface.config.split_horizon = split_horizon;
         })
+        .path(rip::interfaces::interface::summary_address::address::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let prefix = args.dnode.get_prefix();
+            let prefix = V::IpNetwork::get(prefix).unwrap().apply_mask();
+            iface.config.summary_address.prefix = Some(prefix);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.summary_address.prefix = None;
+        })
+        .path(rip::interfaces::interface::summary_address::metric::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let metric = args.dnode.get_u8();
+            iface.config.summary_address.metric = Some(metric);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.summary_address.metric = None;
+        })
+        .path(rip::interfaces::interface::timers::update_interval::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let update_interval = args.dnode.get_u16();
+            iface.config.update_interval = Some(update_interval);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetUpdateInterval(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.update_interval = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetUpdateInterval(iface_idx));
+        })
         .path(rip::interfaces::interface::timers::invalid_interval::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -304,6 +802,20 @@ where
             let invalid_interval = args.dnode.get_u16();
             iface.config.invalid_interval = invalid_interval;
         })
+        .path(rip::interfaces::interface::timers::holddown_interval::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let holddown_interval = args.dnode.get_u16();
+            iface.config.holddown_interval = Some(holddown_interval);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.holddown_interval = None;
+        })
         .path(rip::interfaces::interface::timers::flush_interval::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -315,9 +827,92 @@ where
         .build()
 }
 
+fn redistribution_create<V>(
+    instance: &mut Instance<V>,
+    event_queue: &mut BTreeSet<Event>,
+    protocol: Protocol,
+) where
+    V: Version,
+{
+    instance
+        .config
+        .redistribution
+        .insert(protocol, Default::default());
+
+    event_queue.insert(Event::RedistributeAdd(protocol));
+}
+
+fn redistribution_delete<V>(
+    instance: &mut Instance<V>,
+    event_queue: &mut BTreeSet<Event>,
+    protocol: Protocol,
+) where
+    V: Version,
+{
+    instance.config.redistribution.remove(&protocol);
+
+    event_queue.insert(Event::RedistributeDelete(protocol));
+}
+
+fn redistribution_metric<V>(
+    instance: &mut Instance<V>,
+    event_queue: &mut BTreeSet<Event>,
+    protocol: Protocol,
+    metric: Option<u8>,
+) where
+    V: Version,
+{
+    let Some(redistribution) =
+        instance.config.redistribution.get_mut(&protocol)
+    else {
+        return;
+    };
+    redistribution.metric = metric;
+
+    event_queue.insert(Event::ExternalRoutesUpdate);
+}
+
+fn redistribution_route_policy<V>(
+    instance: &mut Instance<V>,
+    event_queue: &mut BTreeSet<Event>,
+    protocol: Protocol,
+    route_policy: Option<String>,
+) where
+    V: Version,
+{
+    let Some(redistribution) =
+        instance.config.redistribution.get_mut(&protocol)
+    else {
+        return;
+    };
+    redistribution.route_policy = route_policy;
+
+    event_queue.insert(Event::ExternalRoutesUpdate);
+}
+
 fn load_callbacks_ripv2() -> Callbacks<Instance<Ripv2>> {
     let core_cbs = load_callbacks();
     CallbacksBuilder::<Instance<Ripv2>>::new(core_cbs)
+        .path(rip::interfaces::interface::authentication::key_chain::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let auth_keychain = args.dnode.get_string();
+            iface.config.auth_keychain = Some(auth_keychain);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.auth_keychain = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
         .path(rip::interfaces::interface::authentication::key::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -366,7 +961,73 @@ fn load_callbacks_ripv2() -> Callbacks<Instance<Ripv2>> {
 
 fn load_callbacks_ripng() -> Callbacks<Instance<Ripng>> {
     let core_cbs = load_callbacks();
-    CallbacksBuilder::<Instance<Ripng>>::new(core_cbs).build()
+    CallbacksBuilder::<Instance<Ripng>>::new(core_cbs)
+        .path(
+            rip::interfaces::interface::ripng_authentication::key_chain::PATH,
+        )
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let auth_keychain = args.dnode.get_string();
+            iface.config.auth_keychain = Some(auth_keychain);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.auth_keychain = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .path(rip::interfaces::interface::ripng_authentication::key::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let auth_key = args.dnode.get_string();
+            iface.config.auth_key = Some(auth_key);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.auth_key = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .path(
+            rip::interfaces::interface::ripng_authentication::crypto_algorithm::PATH,
+        )
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let auth_algo = args.dnode.get_string();
+            let auth_algo = CryptoAlgo::try_from_yang(&auth_algo).unwrap();
+            iface.config.auth_algo = Some(auth_algo);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.auth_algo = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceRestartNetTasks(iface_idx));
+        })
+        .build()
 }
 
 fn load_validation_callbacks_ripv2() -> ValidationCallbacks {
@@ -378,18 +1039,19 @@ fn load_validation_callbacks_ripv2() -> ValidationCallbacks {
             }
             Ok(())
         })
+        .path(rip::interfaces::interface::summary_address::address::PATH)
+        .validate(|args| {
+            if args.dnode.get_prefix().is_ipv6() {
+                return Err("unexpected IPv6 prefix".to_owned());
+            }
+            Ok(())
+        })
         .path(
             rip::interfaces::interface::authentication::crypto_algorithm::PATH,
         )
         .validate(|args| {
             let algo = args.dnode.get_string();
-            if algo != CryptoAlgo::Md5.to_yang() {
-                return Err(format!(
-                    "unsupported cryptographic algorithm (valid options: \"{}\")",
-                    CryptoAlgo::Md5.to_yang()
-                ));
-            }
-            Ok(())
+            validate_crypto_algo(&algo)
         })
         .build()
 }
@@ -403,6 +1065,20 @@ fn load_validation_callbacks_ripng() -> ValidationCallbacks {
             }
             Ok(())
         })
+        .path(rip::interfaces::interface::summary_address::address::PATH)
+        .validate(|args| {
+            if args.dnode.get_prefix().is_ipv4() {
+                return Err("unexpected IPv4 prefix".to_owned());
+            }
+            Ok(())
+        })
+        .path(
+            rip::interfaces::interface::ripng_authentication::crypto_algorithm::PATH,
+        )
+        .validate(|args| {
+            let algo = args.dnode.get_string();
+            validate_crypto_algo(&algo)
+        })
         .build()
 }
 
@@ -481,8 +1157,10 @@ where
                         .log();
                     }
 
-                    // Update route.
+                    // Update route. Equal-cost paths are relearned from
+                    // subsequent updates.
                     route.metric = metric;
+                    route.ecmp_paths.clear();
                     route.flags.insert(RouteFlags::CHANGED);
 
                     // Signal the output process to trigger an update.
@@ -510,16 +1188,22 @@ where
                     return;
                 };
 
+                // Restart network Tx/Rx tasks.
+                let iface = &mut interfaces[iface_idx];
+                iface.auth_update(&instance);
+            }
+            Event::InterfaceResetUpdateInterval(iface_idx) => {
+                let Some((instance, interfaces)) = self.as_up() else {
+                    return;
+                };
+
                 let iface = &mut interfaces[iface_idx];
                 if !iface.state.active {
                     return;
                 }
 
-                // Restart network Tx/Rx tasks.
-                let auth = iface.auth(&instance.state.auth_seqno);
-                if let Some(net) = &mut iface.state.net {
-                    net.restart_tasks(auth, instance.tx);
-                }
+                // Start, restart or stop the interface's own update interval.
+                iface.update_interval_reset(instance.tx);
             }
             Event::InterfaceIbusSub(ifname) => {
                 self.tx
@@ -548,6 +1232,54 @@ where
                     ibus::tx::route_install(&instance.tx.ibus, route, distance);
                 }
             }
+            Event::MaximumPathsUpdate => {
+                let Some((instance, _)) = self.as_up() else {
+                    return;
+                };
+
+                // Remove the equal-cost paths that exceed the new limit (the
+                // primary path counts towards it).
+                let maximum_paths = instance.config.maximum_paths as usize;
+                let distance = instance.config.distance;
+                for route in instance.state.routes.values_mut() {
+                    if route.ecmp_paths.len() < maximum_paths {
+                        continue;
+                    }
+                    while route.ecmp_paths.len() >= maximum_paths {
+                        route.ecmp_paths.pop_last();
+                    }
+                    ibus::tx::route_install(&instance.tx.ibus, route, distance);
+                }
+            }
+            Event::RedistributeAdd(protocol) => {
+                // Subscribe to route redistribution for the given protocol.
+                self.tx
+                    .ibus
+                    .route_redistribute_sub(protocol, Some(V::ADDRESS_FAMILY));
+            }
+            Event::RedistributeDelete(protocol) => {
+                // Unsubscribe from route redistribution for the given
+                // protocol.
+                self.tx.ibus.route_redistribute_unsub(
+                    protocol,
+                    Some(V::ADDRESS_FAMILY),
+                );
+
+                // Remove redistributed routes.
+                self.system
+                    .routes
+                    .retain(|_, route| route.protocol != protocol);
+
+                // Withdraw the corresponding RIP routes.
+                if let Some((mut instance, _)) = self.as_up() {
+                    instance.external_routes_update();
+                }
+            }
+            Event::ExternalRoutesUpdate => {
+                if let Some((mut instance, _)) = self.as_up() {
+                    instance.external_routes_update();
+                }
+            }
             Event::ResetUpdateInterval => {
                 let Some((instance, _)) = self.as_up() else {
                     return;
@@ -573,6 +1305,31 @@ where
     }
 }
 
+// ===== helper functions =====
+
+fn validate_crypto_algo(algo: &str) -> Result<(), String> {
+    const VALID_OPTIONS: [CryptoAlgo; 5] = [
+        CryptoAlgo::Md5,
+        CryptoAlgo::HmacSha1,
+        CryptoAlgo::HmacSha256,
+        CryptoAlgo::HmacSha384,
+        CryptoAlgo::HmacSha512,
+    ];
+
+    if !VALID_OPTIONS.iter().any(|option| algo == option.to_yang()) {
+        let valid_options = VALID_OPTIONS
+            .iter()
+            .map(|option| format!("\"{}\"", option.to_yang()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "unsupported cryptographic algorithm (valid options: {valid_options})"
+        ));
+    }
+
+    Ok(())
+}
+
 // ===== configuration defaults =====
 
 impl Default for InstanceCfg {
@@ -580,16 +1337,23 @@ impl Default for InstanceCfg {
         let default_metric = Metric::from(rip::default_metric::DFLT);
         let distance = rip::distance::DFLT;
         let triggered_update_threshold = rip::triggered_update_threshold::DFLT;
+        let maximum_paths = rip::maximum_paths::DFLT;
         let update_interval = rip::timers::update_interval::DFLT;
         let invalid_interval = rip::timers::invalid_interval::DFLT;
         let flush_interval = rip::timers::flush_interval::DFLT;
 
         InstanceCfg {
+            originate_default_route: Default::default(),
             default_metric,
             distance,
             triggered_update_threshold,
+            distribute_lists: Default::default(),
+            redistribution: Default::default(),
+            maximum_paths,
+            output_delay: None,
             update_interval,
             invalid_interval,
+            holddown_interval: None,
             flush_interval,
             trace_opts: Default::default(),
         }
@@ -615,10 +1379,18 @@ where
             no_listen: false,
             passive: false,
             split_horizon,
+            originate_default_route: Default::default(),
+            summary_address: SummaryAddressCfg {
+                prefix: None,
+                metric: None,
+            },
+            update_interval: None,
             invalid_interval,
+            holddown_interval: None,
             flush_interval,
             auth_key: None,
             auth_algo: None,
+            auth_keychain: None,
         }
     }
 }
@@ -19,7 +19,7 @@ use holo_yang::ToYang;
 use crate::instance::Instance;
 use crate::interface::Interface;
 use crate::neighbor::Neighbor;
-use crate::route::{Route, RouteFlags};
+use crate::route::{Route, RouteFlags, RouteType};
 use crate::version::{Ripng, Ripv2, Version};
 
 pub static CALLBACKS_RIPV2: Lazy<Callbacks<Instance<Ripv2>>> =
@@ -78,10 +78,14 @@ where
             if let Some(instance_state) = &instance.state
                 && iface.state.active
             {
-                // The same update interval is shared by all interfaces.
-                next_full_update = Some(
-                    instance_state.next_update().as_secs().saturating_into(),
-                );
+                // Use the instance update interval unless the interface has
+                // its own.
+                let next_update = match &iface.state.update_interval_task {
+                    Some(task) => task.remaining(),
+                    None => instance_state.next_update(),
+                };
+                next_full_update =
+                    Some(next_update.as_secs().saturating_into());
             }
             Box::new(Interface {
                 interface: iface.name.as_str().into(),
@@ -184,7 +188,7 @@ fn load_callbacks_ripv2() -> Callbacks<Instance<Ripv2>> {
                     .interfaces
                     .get_by_ifindex(route.ifindex)
                     .map(|(_, iface)| iface.name.as_str().into()),
-                redistributed: Some(false),
+                redistributed: Some(route.route_type == RouteType::External),
                 route_type: Some(route.route_type.to_yang()),
                 metric: Some(route.metric.get()),
                 expire_time: route
@@ -192,10 +196,17 @@ fn load_callbacks_ripv2() -> Callbacks<Instance<Ripv2>> {
                     .map(|d| d.as_secs().saturating_into())
                     .ignore_in_testing(),
                 deleted: Some(false),
+                holddown: Some(route.holddown.is_some()),
                 need_triggered_update: Some(
                     route.flags.contains(RouteFlags::CHANGED),
                 ),
-                inactive: Some(route.garbage_collect_task.is_some()),
+                inactive: Some(
+                    route.garbage_collect_task.is_some()
+                        || route.flags.contains(RouteFlags::FLUSH_EXPIRED),
+                ),
+                flush_expire_before_holddown: Some(
+                    route.flags.contains(RouteFlags::FLUSH_EXPIRED),
+                ),
             })
         })
         .build()
@@ -245,7 +256,7 @@ fn load_callbacks_ripng() -> Callbacks<Instance<Ripng>> {
                     .interfaces
                     .get_by_ifindex(route.ifindex)
                     .map(|(_, iface)| iface.name.as_str().into()),
-                redistributed: Some(false),
+                redistributed: Some(route.route_type == RouteType::External),
                 route_type: Some(route.route_type.to_yang()),
                 metric: Some(route.metric.get()),
                 expire_time: route
@@ -253,10 +264,17 @@ fn load_callbacks_ripng() -> Callbacks<Instance<Ripng>> {
                     .map(|d| d.as_secs().saturating_into())
                     .ignore_in_testing(),
                 deleted: Some(false),
+                holddown: Some(route.holddown.is_some()),
                 need_triggered_update: Some(
                     route.flags.contains(RouteFlags::CHANGED),
                 ),
-                inactive: Some(route.garbage_collect_task.is_some()),
+                inactive: Some(
+                    route.garbage_collect_task.is_some()
+                        || route.flags.contains(RouteFlags::FLUSH_EXPIRED),
+                ),
+                flush_expire_before_holddown: Some(
+                    route.flags.contains(RouteFlags::FLUSH_EXPIRED),
+                ),
             })
         })
         .build()
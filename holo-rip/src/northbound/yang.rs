@@ -9,7 +9,7 @@ use std::borrow::Cow;
 use holo_yang::{ToYang, TryFromYang};
 
 use crate::interface::SplitHorizon;
-use crate::northbound::configuration::TraceOption;
+use crate::northbound::configuration::{DistributeListDirection, TraceOption};
 use crate::route::RouteType;
 
 // ===== ToYang implementations =====
@@ -18,6 +18,7 @@ impl ToYang for RouteType {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
             RouteType::Connected => "connected".into(),
+            RouteType::External => "external".into(),
             RouteType::Rip => "rip".into(),
         }
     }
@@ -25,6 +26,16 @@ impl ToYang for RouteType {
 
 // ===== TryFromYang implementations =====
 
+impl TryFromYang for DistributeListDirection {
+    fn try_from_yang(value: &str) -> Option<DistributeListDirection> {
+        match value {
+            "in" => Some(DistributeListDirection::In),
+            "out" => Some(DistributeListDirection::Out),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for SplitHorizon {
     fn try_from_yang(value: &str) -> Option<SplitHorizon> {
         match value {
@@ -6,6 +6,7 @@
 
 use std::time::Duration;
 
+use holo_utils::ip::IpNetworkKind;
 use itertools::Itertools;
 use rand::Rng;
 
@@ -13,11 +14,12 @@ use crate::debug::Debug;
 use crate::instance::InstanceUpView;
 use crate::interface::{Interface, Interfaces, SplitHorizon};
 use crate::network::SendDestination;
+use crate::northbound::configuration::DistributeListDirection;
 use crate::packet::{Command, PduVersion, RteVersion};
-use crate::route::{RouteFlags, RouteType};
-use crate::tasks;
+use crate::route::{Metric, RouteFlags, RouteType};
 use crate::tasks::messages::output::UdpTxPduMsg;
 use crate::version::Version;
+use crate::{policy, tasks};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ResponseType {
@@ -34,6 +36,18 @@ pub(crate) fn send_pdu<V>(
     pdu: V::Pdu,
 ) where
     V: Version,
+{
+    send_pdu_delayed(instance, iface, dst, pdu, None);
+}
+
+fn send_pdu_delayed<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    iface: &mut Interface<V>,
+    dst: SendDestination<V::SocketAddr>,
+    pdu: V::Pdu,
+    delay: Option<Duration>,
+) where
+    V: Version,
 {
     // Log PDU being sent.
     if instance.config.trace_opts.packets_tx {
@@ -51,7 +65,7 @@ pub(crate) fn send_pdu<V>(
 
     // Send packet.
     if let Some(net) = &iface.state.net {
-        let _ = net.udp_tx_pdup.send(UdpTxPduMsg { dst, pdu });
+        let _ = net.udp_tx_pdup.send(UdpTxPduMsg { dst, pdu, delay });
     }
 }
 
@@ -85,21 +99,54 @@ pub(crate) fn send_response<V>(
         return;
     }
 
+    // Check for default route origination on this interface.
+    let default_prefix = V::IpNetwork::default(V::ADDRESS_FAMILY);
+    let default_route = iface
+        .config
+        .originate_default_route
+        .enabled
+        .then(|| {
+            let route_policy =
+                iface.config.originate_default_route.route_policy.as_deref();
+            instance.default_route(route_policy)
+        })
+        .flatten();
+
+    // Check for route summarization on this interface.
+    let summary_prefix = iface.config.summary_address.prefix;
+    let mut summary_active = false;
+    let mut summary_changed = false;
+
     // Build Response PDU.
     let mut rtes = vec![];
     for route in instance.state.routes.values() {
         let mut metric = route.metric;
 
-        // Skip unchanged routes for triggered updates.
-        if response_type == ResponseType::Triggered
-            && !route.flags.contains(RouteFlags::CHANGED)
-        {
+        // Skip routes that were flushed while in holddown.
+        if route.flags.contains(RouteFlags::FLUSH_EXPIRED) {
+            continue;
+        }
+
+        // The default route originated on this interface takes precedence.
+        if default_route.is_some() && route.prefix == default_prefix {
+            continue;
+        }
+
+        // Apply the outbound distribute lists.
+        if !policy::distribute_list_check(
+            route.prefix.into(),
+            &iface.name,
+            DistributeListDirection::Out,
+            instance.config,
+            &instance.shared.policy_match_sets,
+        ) {
             continue;
         }
 
         // Split-horizon processing.
         if route.route_type == RouteType::Rip {
-            let suppress = route.ifindex == iface.system.ifindex.unwrap();
+            let suppress =
+                route.is_via_interface(iface.system.ifindex.unwrap());
 
             match iface.config.split_horizon {
                 SplitHorizon::Disabled => (),
@@ -116,6 +163,22 @@ pub(crate) fn send_response<V>(
             }
         }
 
+        // Suppress the more-specific routes covered by the summary address.
+        if let Some(summary_prefix) = summary_prefix
+            && summary_prefix.is_supernet_of(route.prefix)
+        {
+            summary_active |= !metric.is_infinite();
+            summary_changed |= route.flags.contains(RouteFlags::CHANGED);
+            continue;
+        }
+
+        // Skip unchanged routes for triggered updates.
+        if response_type == ResponseType::Triggered
+            && !route.flags.contains(RouteFlags::CHANGED)
+        {
+            continue;
+        }
+
         // Append RTE.
         let rte = <V::Pdu as PduVersion<_, _, _>>::Rte::new_route(
             route.prefix,
@@ -126,22 +189,69 @@ pub(crate) fn send_response<V>(
         rtes.push(rte);
     }
 
+    // Append the summary route. Triggered updates advertise it with an infinite
+    // metric once all of its more-specific routes are gone.
+    if let Some(summary_prefix) = summary_prefix
+        && match response_type {
+            ResponseType::Normal => summary_active,
+            ResponseType::Triggered => summary_changed,
+        }
+    {
+        let mut metric = iface
+            .config
+            .summary_address
+            .metric
+            .and_then(|metric| Metric::new(metric).ok())
+            .unwrap_or(instance.config.default_metric);
+        if !summary_active {
+            metric.set_infinite();
+        }
+        let rte = <V::Pdu as PduVersion<_, _, _>>::Rte::new_route(
+            summary_prefix,
+            None,
+            metric,
+            0,
+        );
+        rtes.push(rte);
+    }
+
+    // Append the default route originated on this interface.
+    if let Some((metric, tag)) = default_route
+        && response_type == ResponseType::Normal
+    {
+        let rte = <V::Pdu as PduVersion<_, _, _>>::Rte::new_route(
+            default_prefix,
+            None,
+            metric,
+            tag,
+        );
+        rtes.push(rte);
+    }
+
     // Nothing to send.
     if rtes.is_empty() {
         return;
     }
 
-    // Send as many PDUs as necessary.
+    // Send as many PDUs as necessary, inserting the configured delay between
+    // consecutive packets.
+    let auth = iface.auth_method(&instance.shared.keychains);
     let max_entries =
-        V::Pdu::max_entries(iface.system.mtu.unwrap(), iface.config.auth_algo);
-    for rtes in rtes
+        V::Pdu::max_entries(iface.system.mtu.unwrap(), auth.as_ref());
+    let output_delay = instance
+        .config
+        .output_delay
+        .map(|delay| Duration::from_millis(delay.into()));
+    for (i, rtes) in rtes
         .into_iter()
         .chunks(max_entries)
         .into_iter()
         .map(|c| c.collect())
+        .enumerate()
     {
         let pdu = V::Pdu::new(Command::Response, rtes);
-        send_pdu(instance, iface, dst, pdu);
+        let delay = if i > 0 { output_delay } else { None };
+        send_pdu_delayed(instance, iface, dst, pdu, delay);
     }
 }
 
@@ -152,7 +262,31 @@ pub(crate) fn send_response_all<V>(
 ) where
     V: Version,
 {
-    for iface in interfaces.iter_mut().filter(|iface| iface.state.active) {
+    send_response_ifaces(instance, interfaces.iter_mut(), response_type);
+}
+
+// Sends a regular update on all interfaces that don't have their own update
+// interval.
+pub(crate) fn regular_update<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    interfaces: &mut Interfaces<V>,
+) where
+    V: Version,
+{
+    let ifaces = interfaces
+        .iter_mut()
+        .filter(|iface| iface.config.update_interval.is_none());
+    send_response_ifaces(instance, ifaces, ResponseType::Normal);
+}
+
+fn send_response_ifaces<'a, V>(
+    instance: &mut InstanceUpView<'_, V>,
+    ifaces: impl Iterator<Item = &'a mut Interface<V>>,
+    response_type: ResponseType,
+) where
+    V: Version,
+{
+    for iface in ifaces.filter(|iface| iface.state.active) {
         iface.with_destinations(|iface, dst| {
             send_response(instance, iface, dst, response_type);
         })
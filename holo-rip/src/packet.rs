@@ -9,11 +9,22 @@ use std::sync::atomic::AtomicU32;
 
 use bytes::BytesMut;
 use derive_new::new;
-use holo_utils::crypto::CryptoAlgo;
+use hmac::Hmac;
+use hmac::digest::block_buffer::Eager;
+use hmac::digest::core_api::{
+    BlockSizeUser, BufferKindUser, CoreProxy, FixedOutputCore, UpdateCore,
+};
+use hmac::digest::typenum::{IsLess, Le, NonZero, U256};
+use hmac::digest::{HashMarker, Mac, OutputSizeUser};
+use holo_utils::crypto::{CryptoAlgo, HMAC_APAD};
 use holo_utils::ip::{IpAddrKind, IpNetworkKind};
+use holo_utils::keychain::{Key, Keychain};
+use md5::{Digest, Md5};
 use num_derive::FromPrimitive;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Sha256, Sha384, Sha512};
 
 use crate::route::Metric;
 
@@ -24,16 +35,28 @@ pub enum Command {
     Response = 2,
 }
 
+#[derive(Clone, Debug)]
+pub enum AuthMethod {
+    ManualKey(Key),
+    Keychain(Arc<Keychain>),
+}
+
 #[derive(Clone, Debug, new)]
 pub struct AuthCtx {
-    // Authentication key.
-    pub key: String,
-    // Authentication cryptographic algorithm.
-    pub algo: CryptoAlgo,
+    // Authentication method.
+    pub method: AuthMethod,
     // Non-decreasing sequence number (only used for encoding packets).
     pub seqno: Arc<AtomicU32>,
 }
 
+#[derive(Clone, Copy, Debug, new)]
+pub struct AuthEncodeCtx<'a> {
+    // Authentication key.
+    pub key: &'a Key,
+    // Authentication sequence number.
+    pub seqno: &'a Arc<AtomicU32>,
+}
+
 // RIP version-specific code.
 pub trait PduVersion<
     IpAddr: IpAddrKind,
@@ -48,11 +71,13 @@ pub trait PduVersion<
     fn new(command: Command, rtes: Vec<Self::Rte>) -> Self;
 
     // Encode PDU into a bytes buffer.
-    fn encode(&self, auth: Option<&AuthCtx>) -> BytesMut;
+    fn encode(&self, auth: Option<&AuthEncodeCtx<'_>>) -> BytesMut;
 
     // Decode PDU from a bytes buffer.
-    fn decode(data: &[u8], auth: Option<&AuthCtx>)
-    -> Result<Self, DecodeError>;
+    fn decode(
+        data: &[u8],
+        auth: Option<&AuthMethod>,
+    ) -> Result<Self, DecodeError>;
 
     // Return the PDU command.
     fn command(&self) -> Command;
@@ -61,7 +86,7 @@ pub trait PduVersion<
     fn set_command(&mut self, command: Command);
 
     // Return maximum number of RTEs that can fit in the specified MTU size.
-    fn max_entries(mtu: u32, auth_algo: Option<CryptoAlgo>) -> usize;
+    fn max_entries(mtu: u32, auth: Option<&AuthMethod>) -> usize;
 
     // Return a reference to the PDU's RTEs.
     fn rtes(&self) -> &Vec<Self::Rte>;
@@ -128,3 +153,121 @@ pub trait DecodeErrorVersion:
     std::error::Error + Send + Serialize + DeserializeOwned
 {
 }
+
+// ===== impl AuthMethod =====
+
+impl AuthMethod {
+    // Looks up the key used to send a packet.
+    //
+    // Keys whose ID doesn't fit in the one-octet Key ID field of the
+    // authentication header are skipped.
+    pub fn key_send(&self) -> Option<&Key> {
+        let key = match self {
+            AuthMethod::ManualKey(key) => Some(key),
+            AuthMethod::Keychain(keychain) => keychain
+                .keys
+                .values()
+                .filter(|key| key.send_lifetime.is_active())
+                .map(|key| &key.data)
+                .find(|key| u8::try_from(key.id).is_ok()),
+        };
+        key.filter(|key| algo_supported(key.algo))
+    }
+
+    // Looks up the key used to accept a packet with the provided Key ID.
+    //
+    // The manually configured key has no associated Key ID, so it's used
+    // regardless of the Key ID present in the received packet.
+    pub fn key_accept(&self, key_id: u8) -> Option<&Key> {
+        let key = match self {
+            AuthMethod::ManualKey(key) => Some(key),
+            AuthMethod::Keychain(keychain) => {
+                keychain.key_lookup_accept(key_id.into())
+            }
+        };
+        key.filter(|key| algo_supported(key.algo))
+    }
+
+    // Returns the size of the largest message digest that might be used.
+    pub fn max_digest_size(&self) -> u8 {
+        match self {
+            AuthMethod::ManualKey(key) => key.algo.digest_size(),
+            AuthMethod::Keychain(keychain) => keychain.max_digest_size,
+        }
+    }
+}
+
+// ===== impl AuthCtx =====
+
+impl AuthCtx {
+    // Returns the context used to authenticate outgoing packets, or `None` if
+    // no valid send key is available.
+    pub fn encode_ctx(&self) -> Option<AuthEncodeCtx<'_>> {
+        self.method
+            .key_send()
+            .map(|key| AuthEncodeCtx::new(key, &self.seqno))
+    }
+}
+
+// ===== helper functions =====
+
+// Returns whether the given algorithm can be used to authenticate RIP packets.
+fn algo_supported(algo: CryptoAlgo) -> bool {
+    matches!(
+        algo,
+        CryptoAlgo::Md5
+            | CryptoAlgo::HmacSha1
+            | CryptoAlgo::HmacSha256
+            | CryptoAlgo::HmacSha384
+            | CryptoAlgo::HmacSha512
+    )
+}
+
+fn keyed_md5_digest(data: &[u8], key: &[u8]) -> [u8; 16] {
+    // The Authentication Key is always 16 octets when "Keyed-MD5" is in use.
+    let mut key = key.to_vec();
+    key.resize(16, 0);
+
+    let mut ctx = Md5::new();
+    ctx.update(data);
+    ctx.update(&key);
+    ctx.finalize().into()
+}
+
+fn hmac_sha_digest<H>(data: &[u8], key: &[u8]) -> Vec<u8>
+where
+    H: CoreProxy,
+    H::Core: HashMarker
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + Default
+        + Clone,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    // The digest is computed with the Authentication Data field filled with
+    // Apad, which is not part of the provided data.
+    let mut mac = Hmac::<H>::new_from_slice(key).unwrap();
+    mac.update(data);
+    mac.update(&HMAC_APAD[..H::Core::output_size()]);
+    let digest = mac.finalize();
+    digest.into_bytes().to_vec()
+}
+
+// ===== global functions =====
+
+// Computes the message digest of a RIP packet using the given key.
+pub(crate) fn message_digest(data: &[u8], key: &Key) -> Vec<u8> {
+    match key.algo {
+        CryptoAlgo::Md5 => keyed_md5_digest(data, &key.string).to_vec(),
+        CryptoAlgo::HmacSha1 => hmac_sha_digest::<Sha1>(data, &key.string),
+        CryptoAlgo::HmacSha256 => hmac_sha_digest::<Sha256>(data, &key.string),
+        CryptoAlgo::HmacSha384 => hmac_sha_digest::<Sha384>(data, &key.string),
+        CryptoAlgo::HmacSha512 => hmac_sha_digest::<Sha512>(data, &key.string),
+        _ => {
+            // Keys using other algorithms are filtered out during lookup.
+            unreachable!()
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use holo_utils::policy::{
+    DefaultPolicyType, MatchSets, Policies, PolicyResult, policy_apply,
+    route_action_apply, route_condition_match,
+};
+use ipnetwork::IpNetwork;
+
+use crate::northbound::configuration::{DistributeListDirection, InstanceCfg};
+use crate::route::RouteSys;
+
+// ===== global functions =====
+
+// Applies the specified route policy to the provided route and returns the
+// policy result.
+//
+// Routes that don't match any policy statement are rejected.
+pub(crate) fn route_policy_apply(
+    prefix: IpNetwork,
+    route: &RouteSys,
+    route_policy: Option<&str>,
+    policies: &Policies,
+    match_sets: &MatchSets,
+) -> PolicyResult<RouteSys> {
+    // Accept all routes when no route policy is configured.
+    let Some(route_policy) = route_policy else {
+        return PolicyResult::Accept(route.clone());
+    };
+    let Some(policy) = policies.get(route_policy) else {
+        return PolicyResult::Reject;
+    };
+
+    policy_apply(
+        policy.stmts.values(),
+        route.clone(),
+        DefaultPolicyType::RejectRoute,
+        |route, stmt, condition| {
+            route_condition_match(&prefix, route, stmt, condition, match_sets)
+        },
+        route_action_apply,
+    )
+}
+
+// Checks whether the given prefix is permitted by the distribute lists
+// applicable to the specified interface and direction.
+//
+// Distribute lists referencing nonexistent prefix sets deny all prefixes.
+pub(crate) fn distribute_list_check(
+    prefix: IpNetwork,
+    ifname: &str,
+    direction: DistributeListDirection,
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+) -> bool {
+    config
+        .distribute_lists
+        .iter()
+        .filter(|((_, list_direction), list_cfg)| {
+            *list_direction == direction
+                && list_cfg
+                    .ifname
+                    .as_ref()
+                    .is_none_or(|list_ifname| list_ifname == ifname)
+        })
+        .all(|((prefix_set, _), _)| {
+            match_sets.prefix_set_match(prefix_set, &prefix)
+        })
+}
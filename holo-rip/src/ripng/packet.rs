@@ -5,19 +5,19 @@
 //
 
 use std::net::Ipv6Addr;
+use std::sync::atomic;
 
 use bytes::{Buf, BufMut, Bytes, BytesMut, TryGetError};
 use derive_new::new;
 use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
-use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ip::Ipv6NetworkExt;
 use ipnetwork::Ipv6Network;
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::packet::{
-    AuthCtx, Command, DecodeErrorVersion, PduVersion, RteRouteVersion,
-    RteVersion,
+    self, AuthEncodeCtx, AuthMethod, Command, DecodeErrorVersion, PduVersion,
+    RteRouteVersion, RteVersion,
 };
 use crate::route::Metric;
 
@@ -44,6 +44,10 @@ pub struct Pdu {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub rte_errors: Vec<DecodeError>,
+    #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_seqno: Option<u32>,
 }
 
 //
@@ -64,6 +68,7 @@ pub struct Pdu {
 pub enum Rte {
     Ipv6(RteIpv6),
     Nexthop(RteNexthop),
+    Auth(RteAuth),
 }
 
 #[derive(Debug, Deserialize, Eq, new, PartialEq, Serialize)]
@@ -78,6 +83,39 @@ pub struct RteNexthop {
     pub addr: Option<Ipv6Addr>,
 }
 
+//
+// RIPng doesn't define any authentication mechanism of its own, relying on
+// IPsec instead. As an alternative, Holo supports a cryptographic
+// authentication scheme modeled after the RIPv2 one (RFC 4822).
+//
+// The authentication RTE must be the first RTE in the packet, and is
+// identified by a value of 0xFE in the metric field:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +---------------+---------------+---------------+---------------+
+// |      RIPng Packet Length      |    Key ID     | Auth Data Len |
+// +---------------+---------------+---------------+---------------+
+// |               Sequence Number (non-decreasing)                |
+// +---------------+---------------+---------------+---------------+
+// |                      reserved must be zero                    |
+// +---------------+---------------+---------------+---------------+
+// |                      reserved must be zero                    |
+// +---------------+---------------+---------------+---------------+
+// |        must be zero (2)       | must be zero  |     0xFE      |
+// +---------------+---------------+---------------+---------------+
+//
+// The message digest is appended right after the last RTE, at the offset given
+// by the "RIPng Packet Length" field.
+//
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RteAuth {
+    pub pkt_len: u16,
+    pub key_id: u8,
+    pub auth_data_len: u8,
+    pub seqno: u32,
+}
+
 // RIP decode errors.
 #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum DecodeError {
@@ -89,6 +127,9 @@ pub enum DecodeError {
     InvalidRtePrefixLength(u8),
     InvalidRteNexthop(Ipv6Addr),
     InvalidRteMetric(u8),
+    AuthTypeMismatch,
+    AuthKeyIdNotFound(u8),
+    AuthError,
 }
 
 // Type aliases.
@@ -100,6 +141,82 @@ impl Pdu {
     pub const VERSION: u8 = 1;
     pub const HDR_LENGTH: usize = 4;
     pub const MIN_SIZE: usize = (Self::HDR_LENGTH + Rte::LENGTH);
+
+    fn encode_auth_header(
+        buf: &mut BytesMut,
+        auth: Option<&AuthEncodeCtx<'_>>,
+    ) {
+        if let Some(auth) = &auth {
+            let auth_hdr = RteAuth {
+                // The packet length field will be rewritten later.
+                pkt_len: 0,
+                key_id: auth.key.id as u8,
+                auth_data_len: auth.key.algo.digest_size(),
+                seqno: auth.seqno.fetch_add(1, atomic::Ordering::Relaxed),
+            };
+            auth_hdr.encode(buf);
+        }
+    }
+
+    fn encode_auth_trailer(
+        buf: &mut BytesMut,
+        auth: Option<&AuthEncodeCtx<'_>>,
+    ) {
+        if let Some(auth) = auth {
+            // Update the RIPng Packet Length field.
+            let pkt_len = buf.len() as u16;
+            buf[4..6].copy_from_slice(&pkt_len.to_be_bytes());
+
+            // Append message digest.
+            let digest = packet::message_digest(buf, auth.key);
+            buf.put_slice(&digest);
+        }
+    }
+
+    fn decode_auth_validate(
+        buf: &Bytes,
+        auth: Option<&AuthMethod>,
+    ) -> Result<Option<RteAuth>, DecodeError> {
+        // Decode the first RTE in advance for authentication purposes.
+        let rte = match Rte::decode(
+            &mut buf.slice(Pdu::HDR_LENGTH..Pdu::HDR_LENGTH + Rte::LENGTH),
+        ) {
+            Ok(Rte::Auth(rte)) => Some(rte),
+            _ => None,
+        };
+
+        // Discard the packet if its authentication type doesn't match the
+        // interface's configured authentication type.
+        if auth.is_some() != rte.is_some() {
+            return Err(DecodeError::AuthTypeMismatch);
+        }
+        let (Some(auth), Some(rte)) = (auth, rte) else {
+            return Ok(None);
+        };
+
+        // Get the authentication key associated to the received Key ID.
+        let key = auth
+            .key_accept(rte.key_id)
+            .ok_or(DecodeError::AuthKeyIdNotFound(rte.key_id))?;
+
+        // Validate the "RIPng Packet Length" field.
+        let pkt_len = rte.pkt_len as usize;
+        let digest_size = key.algo.digest_size() as usize;
+        if pkt_len < Pdu::MIN_SIZE || pkt_len + digest_size > buf.len() {
+            return Err(DecodeError::AuthError);
+        }
+
+        // Compute message digest.
+        let digest = packet::message_digest(&buf[..pkt_len], key);
+
+        // Check if the received message digest is valid.
+        if buf[pkt_len..pkt_len + digest_size] != *digest {
+            return Err(DecodeError::AuthError);
+        }
+
+        // Authentication succeeded.
+        Ok(Some(rte))
+    }
 }
 
 impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
@@ -109,8 +226,7 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
         Pdu::new(command, rtes)
     }
 
-    // NOTE: RIPng supports only IPSec authentication.
-    fn encode(&self, _auth: Option<&AuthCtx>) -> BytesMut {
+    fn encode(&self, auth: Option<&AuthEncodeCtx<'_>>) -> BytesMut {
         TLS_BUF.with(|buf| {
             let mut buf = buf.borrow_mut();
             buf.clear();
@@ -120,19 +236,24 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
             buf.put_u8(self.version);
             buf.put_u16(0);
 
+            // Encode the authentication header if necessary.
+            Self::encode_auth_header(&mut buf, auth);
+
             // Encode RTEs.
             for rte in &self.rtes {
                 rte.encode(&mut buf);
             }
 
+            // Encode the authentication trailer if necessary.
+            Self::encode_auth_trailer(&mut buf, auth);
+
             buf.clone()
         })
     }
 
-    // NOTE: RIPng supports only IPSec authentication.
     fn decode(
         data: &[u8],
-        _auth: Option<&AuthCtx>,
+        auth: Option<&AuthMethod>,
     ) -> Result<Self, DecodeError> {
         let mut buf = Bytes::copy_from_slice(data);
 
@@ -142,6 +263,15 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
             return Err(DecodeError::InvalidLength(buf_size));
         }
 
+        // Validate the packet before anything.
+        let auth_hdr = Self::decode_auth_validate(&buf, auth)?;
+        let auth_seqno = auth_hdr.as_ref().map(|auth_hdr| auth_hdr.seqno);
+
+        // Leave the message digest out of the RTE parsing.
+        if let Some(auth_hdr) = &auth_hdr {
+            buf.truncate(auth_hdr.pkt_len as usize);
+        }
+
         // Parse and validate RIP command.
         let command = buf.try_get_u8()?;
         let command = Command::from_u8(command)
@@ -164,7 +294,13 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
         let mut rte_errors = vec![];
         while buf.remaining() >= Rte::LENGTH {
             match Rte::decode(&mut buf) {
-                Ok(rte) => rtes.push(rte),
+                Ok(rte) => {
+                    // Ignore authentication RTEs (already processed).
+                    if matches!(rte, Rte::Auth(..)) {
+                        continue;
+                    }
+                    rtes.push(rte);
+                }
                 Err(error) => rte_errors.push(error),
             }
         }
@@ -174,6 +310,7 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
             version,
             rtes,
             rte_errors,
+            auth_seqno,
         };
 
         Ok(pdu)
@@ -198,12 +335,17 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
     // #RTEs = INT | --------------------------------------------------- |
     //             |                      RTE_size                       |
     //             +-                                                   -+"
-    fn max_entries(mtu: u32, _auth_algo: Option<CryptoAlgo>) -> usize {
+    fn max_entries(mtu: u32, auth: Option<&AuthMethod>) -> usize {
         const IPV6_HDR_LENGTH: usize = 40;
         const UDP_HDR_LENGTH: usize = 8;
 
-        (mtu as usize - IPV6_HDR_LENGTH - UDP_HDR_LENGTH - Pdu::HDR_LENGTH)
-            / Rte::LENGTH
+        let mut len =
+            mtu as usize - IPV6_HDR_LENGTH - UDP_HDR_LENGTH - Pdu::HDR_LENGTH;
+        if let Some(auth) = auth {
+            // Reserve space for the authentication header and trailer.
+            len -= Rte::LENGTH + auth.max_digest_size() as usize;
+        }
+        len / Rte::LENGTH
     }
 
     fn rtes(&self) -> &Vec<Self::Rte> {
@@ -219,7 +361,7 @@ impl PduVersion<Ipv6Addr, Ipv6Network, DecodeError> for Pdu {
     }
 
     fn auth_seqno(&self) -> Option<u32> {
-        None
+        self.auth_seqno
     }
 
     fn new_dump_request() -> Self {
@@ -259,10 +401,17 @@ impl Rte {
         match self {
             Rte::Ipv6(rte) => rte.encode(buf),
             Rte::Nexthop(rte) => rte.encode(buf),
+            Rte::Auth(rte) => rte.encode(buf),
         }
     }
 
     pub(crate) fn decode(buf: &mut Bytes) -> DecodeResult<Self> {
+        // An authentication RTE is identified by a value of 0xFE in the
+        // metric field.
+        if buf.len() >= Rte::LENGTH && buf[Rte::LENGTH - 1] == RteAuth::METRIC {
+            return Ok(Rte::Auth(RteAuth::decode(buf)?));
+        }
+
         let addr = buf.try_get_ipv6()?;
         let tag = buf.try_get_u16()?;
         let plen = buf.try_get_u8()?;
@@ -391,6 +540,46 @@ impl RteNexthop {
     }
 }
 
+// ===== impl RteAuth =====
+
+impl RteAuth {
+    const METRIC: u8 = 0xFE;
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.pkt_len);
+        buf.put_u8(self.key_id);
+        buf.put_u8(self.auth_data_len);
+        buf.put_u32(self.seqno);
+        // Reserved bytes.
+        buf.put_u32(0);
+        buf.put_u32(0);
+        buf.put_u16(0);
+        buf.put_u8(0);
+        buf.put_u8(Self::METRIC);
+    }
+
+    pub(crate) fn decode(buf: &mut Bytes) -> DecodeResult<Self> {
+        let pkt_len = buf.try_get_u16()?;
+        let key_id = buf.try_get_u8()?;
+        let auth_data_len = buf.try_get_u8()?;
+        let seqno = buf.try_get_u32()?;
+        // Reserved bytes.
+        let _ = buf.try_get_u32()?;
+        let _ = buf.try_get_u32()?;
+        let _ = buf.try_get_u16()?;
+        let _ = buf.try_get_u8()?;
+        // Metric.
+        let _ = buf.try_get_u8()?;
+
+        Ok(RteAuth {
+            pkt_len,
+            key_id,
+            auth_data_len,
+            seqno,
+        })
+    }
+}
+
 // ===== impl DecodeError =====
 
 impl DecodeErrorVersion for DecodeError {}
@@ -422,6 +611,15 @@ impl std::fmt::Display for DecodeError {
             DecodeError::InvalidRteMetric(metric) => {
                 write!(f, "Invalid RIP metric: {metric}")
             }
+            DecodeError::AuthTypeMismatch => {
+                write!(f, "Authentication type mismatch")
+            }
+            DecodeError::AuthKeyIdNotFound(key_id) => {
+                write!(f, "Authentication Key ID not found: {key_id}")
+            }
+            DecodeError::AuthError => {
+                write!(f, "Authentication failed")
+            }
         }
     }
 }
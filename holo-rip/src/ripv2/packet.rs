@@ -11,15 +11,13 @@ use bytes::{Buf, BufMut, Bytes, BytesMut, TryGetError};
 use derive_new::new;
 use enum_as_inner::EnumAsInner;
 use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
-use holo_utils::crypto::CryptoAlgo;
 use ipnetwork::Ipv4Network;
-use md5::{Digest, Md5};
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::packet::{
-    AuthCtx, Command, DecodeErrorVersion, PduVersion, RteRouteVersion,
-    RteVersion,
+    self, AuthEncodeCtx, AuthMethod, Command, DecodeErrorVersion, PduVersion,
+    RteRouteVersion, RteVersion,
 };
 use crate::route::Metric;
 
@@ -141,6 +139,7 @@ pub enum DecodeError {
     InvalidRteMetric(u32),
     InvalidRteAuthType(u16),
     AuthTypeMismatch,
+    AuthKeyIdNotFound(u8),
     AuthError,
 }
 
@@ -157,21 +156,26 @@ impl Pdu {
     pub const MAX_SIZE: usize =
         (Self::HDR_LENGTH + Self::MAX_ENTRIES * Rte::LENGTH);
 
-    fn encode_auth_header(buf: &mut BytesMut, auth: Option<&AuthCtx>) {
+    fn encode_auth_header(
+        buf: &mut BytesMut,
+        auth: Option<&AuthEncodeCtx<'_>>,
+    ) {
         if let Some(auth) = &auth {
             let auth_hdr = RteAuthCrypto {
                 // The packet length field will be rewritten later.
                 pkt_len: 0,
-                // Use a static key for now.
-                key_id: 1,
-                auth_data_len: auth.algo.digest_size(),
+                key_id: auth.key.id as u8,
+                auth_data_len: auth.key.algo.digest_size(),
                 seqno: auth.seqno.fetch_add(1, atomic::Ordering::Relaxed),
             };
             auth_hdr.encode(buf);
         }
     }
 
-    fn encode_auth_trailer(buf: &mut BytesMut, auth: Option<&AuthCtx>) {
+    fn encode_auth_trailer(
+        buf: &mut BytesMut,
+        auth: Option<&AuthEncodeCtx<'_>>,
+    ) {
         if let Some(auth) = auth {
             // Update the RIPv2 Packet Length field.
             let pkt_len = buf.len() as u16;
@@ -182,84 +186,71 @@ impl Pdu {
             buf.put_u16(RteAuth::AUTH_TYPE_TRAILER);
 
             // Append message digest.
-            match auth.algo {
-                CryptoAlgo::Md5 => {
-                    let digest = md5_digest(buf, &auth.key);
-                    buf.put_slice(&digest);
-                }
-                _ => {
-                    // Other algorithms can't be configured yet.
-                    unreachable!()
-                }
-            }
+            let digest = packet::message_digest(buf, auth.key);
+            buf.put_slice(&digest);
         }
     }
 
     fn decode_auth_validate(
         buf: &Bytes,
-        auth: Option<&AuthCtx>,
-    ) -> Result<Option<u32>, DecodeError> {
-        let mut auth_seqno = None;
-
+        auth: Option<&AuthMethod>,
+    ) -> Result<Option<RteAuthCrypto>, DecodeError> {
         // Decode the first RTE in advance for authentication purposes.
-        if let Ok(rte) = Rte::decode(
+        let Ok(rte) = Rte::decode(
             &mut buf.slice(Pdu::HDR_LENGTH..Pdu::HDR_LENGTH + Rte::LENGTH),
-        ) {
-            // Discard the packet if its authentication type doesn't match the
-            // interface's configured authentication type.
-            if auth.is_some() != matches!(rte, Rte::Auth(RteAuth::Crypto(..))) {
-                return Err(DecodeError::AuthTypeMismatch);
-            }
+        ) else {
+            return Ok(None);
+        };
 
-            // Handle cryptographic authentication (RFC 4822).
-            if let Rte::Auth(RteAuth::Crypto(rte)) = rte {
-                let auth = auth.as_ref().unwrap();
-
-                // Validate the "RIPv2 Packet Length" field.
-                //
-                // Note: to ensure compatibility with legacy RIP
-                // implementations, the "Auth Data Len" field is not validated
-                // (that field is completely ignored anyway).
-                if rte.pkt_len as usize
-                    > (buf.len()
-                        - RteAuthCrypto::HDR_LENGTH
-                        - auth.algo.digest_size() as usize)
-                {
-                    return Err(DecodeError::AuthError);
-                }
+        // Discard the packet if its authentication type doesn't match the
+        // interface's configured authentication type.
+        if auth.is_some() != matches!(rte, Rte::Auth(RteAuth::Crypto(..))) {
+            return Err(DecodeError::AuthTypeMismatch);
+        }
 
-                // Get the authentication trailer.
-                let auth_trailer =
-                    match Rte::decode(&mut buf.slice(rte.pkt_len as usize..)) {
-                        Ok(Rte::Auth(RteAuth::Trailer(trailer))) => trailer,
-                        _ => return Err(DecodeError::AuthError),
-                    };
-
-                // Compute message digest.
-                let digest = match auth.algo {
-                    CryptoAlgo::Md5 => {
-                        let data = buf.slice(
-                            ..rte.pkt_len as usize + RteAuthCrypto::HDR_LENGTH,
-                        );
-                        md5_digest(&data, &auth.key)
-                    }
-                    _ => {
-                        // Other algorithms can't be configured yet.
-                        unreachable!()
-                    }
-                };
+        // Handle cryptographic authentication (RFC 4822).
+        let Rte::Auth(RteAuth::Crypto(rte)) = rte else {
+            return Ok(None);
+        };
+        let auth = auth.unwrap();
 
-                // Check if the received message digest is valid.
-                if *auth_trailer.0 != digest {
-                    return Err(DecodeError::AuthError);
-                }
+        // Get the authentication key associated to the received Key ID.
+        let key = auth
+            .key_accept(rte.key_id)
+            .ok_or(DecodeError::AuthKeyIdNotFound(rte.key_id))?;
 
-                // Authentication succeeded.
-                auth_seqno = Some(rte.seqno);
-            }
+        // Validate the "RIPv2 Packet Length" field.
+        //
+        // Note: to ensure compatibility with legacy RIP implementations, the
+        // "Auth Data Len" field is not validated (that field is completely
+        // ignored anyway).
+        if rte.pkt_len as usize
+            + RteAuthCrypto::HDR_LENGTH
+            + key.algo.digest_size() as usize
+            > buf.len()
+        {
+            return Err(DecodeError::AuthError);
         }
 
-        Ok(auth_seqno)
+        // Get the authentication trailer.
+        let auth_trailer =
+            match Rte::decode(&mut buf.slice(rte.pkt_len as usize..)) {
+                Ok(Rte::Auth(RteAuth::Trailer(trailer))) => trailer,
+                _ => return Err(DecodeError::AuthError),
+            };
+
+        // Compute message digest.
+        let data =
+            buf.slice(..rte.pkt_len as usize + RteAuthCrypto::HDR_LENGTH);
+        let digest = packet::message_digest(&data, key);
+
+        // Check if the received message digest is valid.
+        if *auth_trailer.0 != *digest {
+            return Err(DecodeError::AuthError);
+        }
+
+        // Authentication succeeded.
+        Ok(Some(rte))
     }
 }
 
@@ -270,7 +261,7 @@ impl PduVersion<Ipv4Addr, Ipv4Network, DecodeError> for Pdu {
         Pdu::new(command, rtes)
     }
 
-    fn encode(&self, auth: Option<&AuthCtx>) -> BytesMut {
+    fn encode(&self, auth: Option<&AuthEncodeCtx<'_>>) -> BytesMut {
         TLS_BUF.with(|buf| {
             let mut buf = buf.borrow_mut();
             buf.clear();
@@ -297,7 +288,7 @@ impl PduVersion<Ipv4Addr, Ipv4Network, DecodeError> for Pdu {
 
     fn decode(
         data: &[u8],
-        auth: Option<&AuthCtx>,
+        auth: Option<&AuthMethod>,
     ) -> Result<Self, DecodeError> {
         let mut buf = Bytes::copy_from_slice(data);
 
@@ -308,7 +299,13 @@ impl PduVersion<Ipv4Addr, Ipv4Network, DecodeError> for Pdu {
         }
 
         // Validate the packet before anything.
-        let auth_seqno = Self::decode_auth_validate(&buf, auth)?;
+        let auth_hdr = Self::decode_auth_validate(&buf, auth)?;
+        let auth_seqno = auth_hdr.as_ref().map(|auth_hdr| auth_hdr.seqno);
+
+        // Leave the authentication trailer out of the RTE parsing.
+        if let Some(auth_hdr) = &auth_hdr {
+            buf.truncate(auth_hdr.pkt_len as usize);
+        }
 
         // Parse and validate RIP command.
         let command = buf.try_get_u8()?;
@@ -362,11 +359,13 @@ impl PduVersion<Ipv4Addr, Ipv4Network, DecodeError> for Pdu {
         self.command = command;
     }
 
-    fn max_entries(_mtu: u32, auth_algo: Option<CryptoAlgo>) -> usize {
+    fn max_entries(_mtu: u32, auth: Option<&AuthMethod>) -> usize {
         let mut max_entries = Self::MAX_ENTRIES;
-        if auth_algo.is_some() {
+        if let Some(auth) = auth {
             // Reserve space for the authentication header and trailer.
-            max_entries -= 2;
+            let trailer_len =
+                RteAuthCrypto::HDR_LENGTH + auth.max_digest_size() as usize;
+            max_entries -= 1 + trailer_len.div_ceil(Rte::LENGTH);
         }
         max_entries
     }
@@ -682,6 +681,9 @@ impl std::fmt::Display for DecodeError {
             DecodeError::AuthTypeMismatch => {
                 write!(f, "Authentication type mismatch")
             }
+            DecodeError::AuthKeyIdNotFound(key_id) => {
+                write!(f, "Authentication Key ID not found: {key_id}")
+            }
             DecodeError::AuthError => {
                 write!(f, "Authentication failed")
             }
@@ -696,17 +698,3 @@ impl From<TryGetError> for DecodeError {
 }
 
 impl std::error::Error for DecodeError {}
-
-// ===== helper functions =====
-
-fn md5_digest(data: &[u8], auth_key: &str) -> [u8; 16] {
-    // The RIPv2 Authentication Key is always 16 octets when
-    // "Keyed-MD5" is in use.
-    let mut auth_key = auth_key.as_bytes().to_vec();
-    auth_key.resize(16, 0);
-
-    let mut ctx = Md5::new();
-    ctx.update(data);
-    ctx.update(&auth_key);
-    ctx.finalize().into()
-}
@@ -4,10 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use bitflags::bitflags;
 use holo_protocol::InstanceChannelsTx;
+use holo_utils::policy::PolicyRoute;
+use holo_utils::protocol::Protocol;
 use holo_utils::task::TimeoutTask;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::Sender;
@@ -16,7 +19,9 @@ use crate::debug::Debug;
 use crate::error::MetricError;
 use crate::instance::Instance;
 use crate::northbound::configuration::TraceOptions;
-use crate::tasks::messages::input::{RouteGcTimeoutMsg, RouteTimeoutMsg};
+use crate::tasks::messages::input::{
+    RouteGcTimeoutMsg, RouteHolddownTimeoutMsg, RouteTimeoutMsg,
+};
 use crate::version::Version;
 use crate::{ibus, tasks};
 
@@ -33,6 +38,33 @@ pub struct Route<V: Version> {
     pub flags: RouteFlags,
     pub timeout_task: Option<TimeoutTask>,
     pub garbage_collect_task: Option<TimeoutTask>,
+    pub holddown: Option<RouteHolddown>,
+    pub ecmp_paths: BTreeMap<V::IpAddr, RouteEcmpPath<V>>,
+}
+
+// Additional equal-cost path of a RIP route, indexed by its source.
+#[derive(Debug)]
+pub struct RouteEcmpPath<V: Version> {
+    pub ifindex: u32,
+    pub nexthop: V::IpAddr,
+    pub rcvd_metric: Metric,
+    pub timeout_task: TimeoutTask,
+}
+
+// Holddown state of a withdrawn route.
+#[derive(Debug)]
+pub struct RouteHolddown {
+    // Route metric before it was withdrawn.
+    pub metric: Metric,
+    pub timeout_task: TimeoutTask,
+}
+
+// Route redistributed from the global RIB.
+#[derive(Clone, Debug)]
+pub struct RouteSys {
+    pub protocol: Protocol,
+    pub metric: u32,
+    pub tag: Option<u32>,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -41,6 +73,7 @@ pub struct Metric(u8);
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RouteType {
     Connected,
+    External,
     Rip,
 }
 
@@ -48,6 +81,7 @@ bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
     pub struct RouteFlags: u8 {
         const CHANGED = 0x01;
+        const FLUSH_EXPIRED = 0x02;
     }
 }
 
@@ -82,6 +116,8 @@ where
             flags: RouteFlags::CHANGED,
             timeout_task: None,
             garbage_collect_task: None,
+            holddown: None,
+            ecmp_paths: Default::default(),
         }
     }
 
@@ -100,6 +136,7 @@ where
 
         // Set metric to infinite and start GC timeout.
         self.metric.set_infinite();
+        self.ecmp_paths.clear();
         self.flags.insert(RouteFlags::CHANGED);
         self.timeout_stop();
         self.garbage_collection_start(
@@ -117,14 +154,25 @@ where
         route_timeoutp: &Sender<RouteTimeoutMsg<V>>,
     ) {
         let timeout = Duration::from_secs(timeout.into());
+        self.timeout_reset_duration(timeout, route_timeoutp);
+    }
 
+    fn timeout_reset_duration(
+        &mut self,
+        timeout: Duration,
+        route_timeoutp: &Sender<RouteTimeoutMsg<V>>,
+    ) {
         if let Some(timeout_task) = &mut self.timeout_task {
             // Reset existing timeout task.
             timeout_task.reset(Some(timeout));
         } else {
             // Create new timeout task.
-            let timeout_task =
-                tasks::route_timeout(self.prefix, timeout, route_timeoutp);
+            let timeout_task = tasks::route_timeout(
+                self.prefix,
+                None,
+                timeout,
+                route_timeoutp,
+            );
             self.timeout_task = Some(timeout_task);
         }
     }
@@ -137,6 +185,78 @@ where
         self.timeout_task.as_ref().map(TimeoutTask::remaining)
     }
 
+    // Adds or refreshes an equal-cost path learned from the given source.
+    //
+    // Returns whether a path was added or any of its attributes changed.
+    pub(crate) fn ecmp_path_update(
+        &mut self,
+        source: V::IpAddr,
+        ifindex: u32,
+        nexthop: V::IpAddr,
+        rcvd_metric: Metric,
+        timeout: u16,
+        route_timeoutp: &Sender<RouteTimeoutMsg<V>>,
+    ) -> bool {
+        let timeout = Duration::from_secs(timeout.into());
+        match self.ecmp_paths.get_mut(&source) {
+            Some(path) => {
+                let changed = path.ifindex != ifindex
+                    || path.nexthop != nexthop
+                    || path.rcvd_metric != rcvd_metric;
+                path.ifindex = ifindex;
+                path.nexthop = nexthop;
+                path.rcvd_metric = rcvd_metric;
+                path.timeout_task.reset(Some(timeout));
+                changed
+            }
+            None => {
+                let timeout_task = tasks::route_timeout(
+                    self.prefix,
+                    Some(source),
+                    timeout,
+                    route_timeoutp,
+                );
+                let path = RouteEcmpPath {
+                    ifindex,
+                    nexthop,
+                    rcvd_metric,
+                    timeout_task,
+                };
+                self.ecmp_paths.insert(source, path);
+                true
+            }
+        }
+    }
+
+    // Replaces the primary path with one of the equal-cost paths, if any.
+    //
+    // Returns whether a path was promoted.
+    pub(crate) fn ecmp_path_promote(
+        &mut self,
+        route_timeoutp: &Sender<RouteTimeoutMsg<V>>,
+    ) -> bool {
+        let Some((source, path)) = self.ecmp_paths.pop_first() else {
+            return false;
+        };
+
+        self.ifindex = path.ifindex;
+        self.source = Some(source);
+        self.nexthop = Some(path.nexthop);
+        self.rcvd_metric = Some(path.rcvd_metric);
+        self.timeout_stop();
+        self.timeout_reset_duration(
+            path.timeout_task.remaining(),
+            route_timeoutp,
+        );
+        true
+    }
+
+    // Returns whether any of the route paths goes through the given interface.
+    pub(crate) fn is_via_interface(&self, ifindex: u32) -> bool {
+        self.ifindex == ifindex
+            || self.ecmp_paths.values().any(|path| path.ifindex == ifindex)
+    }
+
     pub(crate) fn garbage_collection_start(
         &mut self,
         timeout: u16,
@@ -151,6 +271,51 @@ where
     pub(crate) fn garbage_collection_stop(&mut self) {
         self.garbage_collect_task = None;
     }
+
+    // Puts the withdrawn route in holddown. Until the holddown timer expires,
+    // only updates that are better than the given metric are accepted.
+    pub(crate) fn holddown_start(
+        &mut self,
+        metric: Metric,
+        timeout: u16,
+        route_holddown_timeoutp: &Sender<RouteHolddownTimeoutMsg<V>>,
+    ) {
+        let timeout = Duration::from_secs(timeout.into());
+        let timeout_task = tasks::route_holddown_timeout(
+            self.prefix,
+            timeout,
+            route_holddown_timeoutp,
+        );
+        self.holddown = Some(RouteHolddown {
+            metric,
+            timeout_task,
+        });
+    }
+
+    pub(crate) fn holddown_stop(&mut self) {
+        self.holddown = None;
+        self.flags.remove(RouteFlags::FLUSH_EXPIRED);
+    }
+}
+
+// ===== impl RouteSys =====
+
+impl PolicyRoute for RouteSys {
+    fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    fn metric_mut(&mut self) -> &mut u32 {
+        &mut self.metric
+    }
+
+    fn tag(&self) -> Option<u32> {
+        self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Option<u32> {
+        &mut self.tag
+    }
 }
 
 // ===== impl Metric =====
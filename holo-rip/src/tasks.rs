@@ -34,6 +34,7 @@ use crate::version::Version;
 //             nbr_timeout (Nx) -> |              |
 //           route_timeout (Nx) -> |              |
 //        route_gc_timeout (Nx) -> |              |
+//  route_holddown_timeout (Nx) -> |              |
 //                                 +--------------+
 //                          ibus_tx (1x) | ^ (1x) ibus_rx
 //                                       | |
@@ -45,6 +46,8 @@ use crate::version::Version;
 
 // RIP inter-task message types.
 pub mod messages {
+    use std::time::Duration;
+
     use serde::{Deserialize, Serialize};
 
     use crate::network::SendDestination;
@@ -69,6 +72,7 @@ pub mod messages {
             NbrTimeout(NbrTimeoutMsg<V>),
             RouteTimeout(RouteTimeoutMsg<V>),
             RouteGcTimeout(RouteGcTimeoutMsg<V>),
+            RouteHolddownTimeout(RouteHolddownTimeoutMsg<V>),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -82,7 +86,10 @@ pub mod messages {
         pub struct InitialUpdateMsg {}
 
         #[derive(Debug, Deserialize, Serialize)]
-        pub struct UpdateIntervalMsg {}
+        pub struct UpdateIntervalMsg {
+            // Set for interfaces that have their own update interval.
+            pub ifname: Option<String>,
+        }
 
         #[derive(Debug, Deserialize, Serialize)]
         pub struct TriggeredUpdMsg {}
@@ -100,6 +107,8 @@ pub mod messages {
         #[serde(bound = "V: Version")]
         pub struct RouteTimeoutMsg<V: Version> {
             pub prefix: V::IpNetwork,
+            // Set for the timeout of an equal-cost path.
+            pub source: Option<V::IpAddr>,
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -107,6 +116,12 @@ pub mod messages {
         pub struct RouteGcTimeoutMsg<V: Version> {
             pub prefix: V::IpNetwork,
         }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        #[serde(bound = "V: Version")]
+        pub struct RouteHolddownTimeoutMsg<V: Version> {
+            pub prefix: V::IpNetwork,
+        }
     }
 
     // Output messages (main task -> child task).
@@ -124,6 +139,9 @@ pub mod messages {
         pub struct UdpTxPduMsg<V: Version> {
             pub dst: SendDestination<V::SocketAddr>,
             pub pdu: V::Pdu,
+            // Delay before sending the packet (multipacket updates).
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub delay: Option<Duration>,
         }
     }
 }
@@ -230,6 +248,7 @@ pub(crate) fn initial_update(
 
 // RIP route update interval.
 pub(crate) fn update_interval(
+    ifname: Option<String>,
     interval: Duration,
     update_intervalp: &Sender<messages::input::UpdateIntervalMsg>,
 ) -> IntervalTask {
@@ -237,9 +256,10 @@ pub(crate) fn update_interval(
     {
         let update_intervalp = update_intervalp.clone();
         IntervalTask::new(interval, false, move || {
+            let ifname = ifname.clone();
             let update_intervalp = update_intervalp.clone();
             async move {
-                let msg = messages::input::UpdateIntervalMsg {};
+                let msg = messages::input::UpdateIntervalMsg { ifname };
                 let _ = update_intervalp.send(msg).await;
             }
         })
@@ -295,6 +315,7 @@ where
 // Route timeout task.
 pub(crate) fn route_timeout<V>(
     prefix: V::IpNetwork,
+    source: Option<V::IpAddr>,
     timeout: Duration,
     route_timeoutp: &Sender<messages::input::RouteTimeoutMsg<V>>,
 ) -> TimeoutTask
@@ -305,7 +326,7 @@ where
     {
         let route_timeoutp = route_timeoutp.clone();
         TimeoutTask::new(timeout, move || async move {
-            let msg = messages::input::RouteTimeoutMsg { prefix };
+            let msg = messages::input::RouteTimeoutMsg { prefix, source };
             let _ = route_timeoutp.send(msg).await;
         })
     }
@@ -337,3 +358,28 @@ where
         TimeoutTask {}
     }
 }
+
+// Route holddown timeout task.
+pub(crate) fn route_holddown_timeout<V>(
+    prefix: V::IpNetwork,
+    timeout: Duration,
+    route_holddown_timeoutp: &Sender<
+        messages::input::RouteHolddownTimeoutMsg<V>,
+    >,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let route_holddown_timeoutp = route_holddown_timeoutp.clone();
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::RouteHolddownTimeoutMsg { prefix };
+            let _ = route_holddown_timeoutp.send(msg).await;
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
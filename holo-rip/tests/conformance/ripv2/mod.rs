@@ -82,12 +82,51 @@ async fn message_response9() {
     run_test::<Instance<Ripv2>>("message-response9", "topo2-1", "rt1").await;
 }
 
+// Test description:
+#[tokio::test]
+async fn message_response10() {
+    run_test::<Instance<Ripv2>>("message-response10", "topo1-1", "rt2").await;
+}
+
+// Test description:
+#[tokio::test]
+async fn message_response11() {
+    run_test::<Instance<Ripv2>>("message-response11", "topo1-1", "rt2").await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_config_distance1() {
     run_test::<Instance<Ripv2>>("nb-config-distance1", "topo1-1", "rt2").await;
 }
 
+// Test description:
+#[tokio::test]
+async fn nb_config_default_route1() {
+    run_test::<Instance<Ripv2>>("nb-config-default-route1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_distribute_list1() {
+    run_test::<Instance<Ripv2>>("nb-config-distribute-list1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_distribute_list2() {
+    run_test::<Instance<Ripv2>>("nb-config-distribute-list2", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_holddown1() {
+    run_test::<Instance<Ripv2>>("nb-config-holddown1", "topo1-1", "rt2").await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_config_iface1() {
@@ -107,6 +146,31 @@ async fn nb_config_iface_cost1() {
         .await;
 }
 
+// Test description:
+#[tokio::test]
+async fn nb_config_iface_update_interval1() {
+    run_test::<Instance<Ripv2>>(
+        "nb-config-iface-update-interval1",
+        "topo1-1",
+        "rt2",
+    )
+    .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_maximum_paths1() {
+    run_test::<Instance<Ripv2>>("nb-config-maximum-paths1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_maximum_paths2() {
+    run_test::<Instance<Ripv2>>("nb-config-maximum-paths2", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_config_neighbor1() {
@@ -119,6 +183,13 @@ async fn nb_config_neighbor2() {
     run_test::<Instance<Ripv2>>("nb-config-neighbor2", "topo2-1", "rt1").await;
 }
 
+// Test description:
+#[tokio::test]
+async fn nb_config_output_delay1() {
+    run_test::<Instance<Ripv2>>("nb-config-output-delay1", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_config_passive1() {
@@ -131,6 +202,20 @@ async fn nb_config_passive2() {
     run_test::<Instance<Ripv2>>("nb-config-passive2", "topo1-1", "rt2").await;
 }
 
+// Test description:
+#[tokio::test]
+async fn nb_config_redistribute1() {
+    run_test::<Instance<Ripv2>>("nb-config-redistribute1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_redistribute2() {
+    run_test::<Instance<Ripv2>>("nb-config-redistribute2", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_config_split_horizon1() {
@@ -152,6 +237,20 @@ async fn nb_config_split_horizon3() {
         .await;
 }
 
+// Test description:
+#[tokio::test]
+async fn nb_config_summary_address1() {
+    run_test::<Instance<Ripv2>>("nb-config-summary-address1", "topo1-1", "rt2")
+        .await;
+}
+
+// Test description:
+#[tokio::test]
+async fn nb_config_summary_address2() {
+    run_test::<Instance<Ripv2>>("nb-config-summary-address2", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 #[tokio::test]
 async fn nb_rpc_clear_route1() {
@@ -219,6 +318,13 @@ async fn timeout_route_gc1() {
     run_test::<Instance<Ripv2>>("timeout-route-gc1", "topo1-1", "rt2").await;
 }
 
+// Test description:
+#[tokio::test]
+async fn timeout_route_holddown1() {
+    run_test::<Instance<Ripv2>>("timeout-route-holddown1", "topo1-1", "rt2")
+        .await;
+}
+
 // Test description:
 #[tokio::test]
 async fn timeout_triggered_update1() {
@@ -4,27 +4,40 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::sync::LazyLock as Lazy;
+use std::sync::atomic::AtomicU32;
+use std::sync::{Arc, LazyLock as Lazy};
 
 use const_addrs::{ip6, net6};
 use holo_protocol::assert_eq_hex;
-use holo_rip::packet::{Command, PduVersion};
+use holo_rip::packet::{AuthCtx, AuthMethod, Command, PduVersion};
 use holo_rip::ripng::packet::{
     DecodeError, DecodeResult, Pdu, Rte, RteIpv6, RteNexthop,
 };
 use holo_rip::route::Metric;
+use holo_utils::crypto::CryptoAlgo;
+use holo_utils::keychain::Key;
 
 //
 // Helper functions.
 //
 
-fn test_encode_pdu(bytes_expected: &[u8], pdu: &DecodeResult<Pdu>) {
-    let bytes_actual = pdu.as_ref().unwrap().encode(None);
+fn test_encode_pdu(
+    bytes_expected: &[u8],
+    pdu: &DecodeResult<Pdu>,
+    auth: &Option<AuthCtx>,
+) {
+    let auth = auth.as_ref().map(|auth| auth.encode_ctx().unwrap());
+    let bytes_actual = pdu.as_ref().unwrap().encode(auth.as_ref());
     assert_eq_hex!(bytes_expected, bytes_actual);
 }
 
-fn test_decode_pdu(bytes: &[u8], pdu_expected: &DecodeResult<Pdu>) {
-    let pdu_actual = Pdu::decode(bytes, None);
+fn test_decode_pdu(
+    bytes: &[u8],
+    pdu_expected: &DecodeResult<Pdu>,
+    auth: &Option<AuthCtx>,
+) {
+    let auth = auth.as_ref().map(|auth| &auth.method);
+    let pdu_actual = Pdu::decode(bytes, auth);
     assert_eq!(*pdu_expected, pdu_actual);
 }
 
@@ -32,131 +45,204 @@ fn test_decode_pdu(bytes: &[u8], pdu_expected: &DecodeResult<Pdu>) {
 // Test PDUs.
 //
 
-static REQUEST1: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x10,
-        ],
-        Ok(Pdu {
-            command: Command::Request,
-            version: 1,
-            rtes: vec![Rte::Ipv6(RteIpv6 {
-                prefix: net6!("::/0"),
-                tag: 0,
-                metric: Metric::from(Metric::INFINITE),
-            })],
-            rte_errors: vec![],
-        }),
-    )
-});
-
-static RESPONSE1: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x02, 0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
-            0x80, 0x01, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x80, 0x02,
-            0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x80, 0x03,
-        ],
-        Ok(Pdu {
-            command: Command::Response,
-            version: 1,
-            rtes: vec![
-                Rte::Ipv6(RteIpv6 {
-                    prefix: net6!("2001:db8:1000::1/128"),
-                    tag: 0,
-                    metric: Metric::from(1),
-                }),
-                Rte::Ipv6(RteIpv6 {
-                    prefix: net6!("2001:db8:1000::3/128"),
-                    tag: 0,
-                    metric: Metric::from(2),
-                }),
-                Rte::Ipv6(RteIpv6 {
-                    prefix: net6!("2001:db8:1000::4/128"),
+static REQUEST1: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x10,
+            ],
+            None,
+            Ok(Pdu {
+                command: Command::Request,
+                version: 1,
+                rtes: vec![Rte::Ipv6(RteIpv6 {
+                    prefix: net6!("::/0"),
                     tag: 0,
-                    metric: Metric::from(3),
-                }),
+                    metric: Metric::from(Metric::INFINITE),
+                })],
+                rte_errors: vec![],
+                auth_seqno: None,
+            }),
+        )
+    });
+
+static RESPONSE1: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                0x00, 0x00, 0x80, 0x01, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+                0x00, 0x00, 0x80, 0x02, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+                0x00, 0x00, 0x80, 0x03,
+            ],
+            None,
+            Ok(Pdu {
+                command: Command::Response,
+                version: 1,
+                rtes: vec![
+                    Rte::Ipv6(RteIpv6 {
+                        prefix: net6!("2001:db8:1000::1/128"),
+                        tag: 0,
+                        metric: Metric::from(1),
+                    }),
+                    Rte::Ipv6(RteIpv6 {
+                        prefix: net6!("2001:db8:1000::3/128"),
+                        tag: 0,
+                        metric: Metric::from(2),
+                    }),
+                    Rte::Ipv6(RteIpv6 {
+                        prefix: net6!("2001:db8:1000::4/128"),
+                        tag: 0,
+                        metric: Metric::from(3),
+                    }),
+                ],
+                rte_errors: vec![],
+                auth_seqno: None,
+            }),
+        )
+    });
+
+static RESPONSE2: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x50, 0xbf, 0xcf, 0xff, 0xfe, 0xbe, 0x99, 0x99,
+                0x00, 0x00, 0x00, 0xff, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x40, 0x01,
+            ],
+            None,
+            Ok(Pdu {
+                command: Command::Response,
+                version: 1,
+                rtes: vec![
+                    Rte::Nexthop(RteNexthop {
+                        addr: Some(ip6!("fe80::50bf:cfff:febe:9999")),
+                    }),
+                    Rte::Ipv6(RteIpv6 {
+                        prefix: net6!("2001:db8:1000::/64"),
+                        tag: 0,
+                        metric: Metric::from(1),
+                    }),
+                ],
+                rte_errors: vec![],
+                auth_seqno: None,
+            }),
+        )
+    });
+
+static RESPONSE3: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x02, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                0x00, 0x00, 0x80, 0x01,
+            ],
+            None,
+            Err(DecodeError::InvalidVersion(2)),
+        )
+    });
+
+static RESPONSE4: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x03, 0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                0x00, 0x00, 0x80, 0x01,
+            ],
+            None,
+            Err(DecodeError::InvalidCommand(3)),
+        )
+    });
+
+static RESPONSE5: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                0x00, 0x01, 0x80, 0x01, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+                0x00, 0x00, 0x81, 0x02, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+                0x00, 0x00, 0x80, 0x14,
             ],
-            rte_errors: vec![],
-        }),
-    )
-});
-
-static RESPONSE2: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x02, 0x01, 0x00, 0x00, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x50, 0xbf, 0xcf, 0xff, 0xfe, 0xbe, 0x99, 0x99, 0x00, 0x00,
-            0x00, 0xff, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x01,
-        ],
-        Ok(Pdu {
-            command: Command::Response,
-            version: 1,
-            rtes: vec![
-                Rte::Nexthop(RteNexthop {
-                    addr: Some(ip6!("fe80::50bf:cfff:febe:9999")),
-                }),
-                Rte::Ipv6(RteIpv6 {
+            None,
+            Ok(Pdu {
+                command: Command::Response,
+                version: 1,
+                rtes: vec![],
+                rte_errors: vec![
+                    DecodeError::InvalidRtePrefix(ip6!("::1")),
+                    DecodeError::InvalidRtePrefixLength(129),
+                    DecodeError::InvalidRteMetric(20),
+                ],
+                auth_seqno: None,
+            }),
+        )
+    });
+
+static RESPONSE6: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0x00, 0x2c, 0x01, 0x14, 0x00, 0x00,
+                0x00, 0x46, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0xfe, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x40, 0x01, 0xb1, 0xa0, 0x5d, 0xe3, 0x72, 0x21,
+                0x78, 0x9f, 0xa0, 0xff, 0xd6, 0x18, 0x88, 0x2c, 0x77, 0xb7,
+                0x99, 0xc3, 0x6a, 0x87,
+            ],
+            Some(AuthCtx::new(
+                AuthMethod::ManualKey(Key::new(
+                    1,
+                    CryptoAlgo::HmacSha1,
+                    "HOLO".as_bytes().to_vec(),
+                )),
+                Arc::new(AtomicU32::new(70)),
+            )),
+            Ok(Pdu {
+                command: Command::Response,
+                version: 1,
+                rtes: vec![Rte::Ipv6(RteIpv6 {
                     prefix: net6!("2001:db8:1000::/64"),
                     tag: 0,
                     metric: Metric::from(1),
-                }),
-            ],
-            rte_errors: vec![],
-        }),
-    )
-});
-
-static RESPONSE3: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x02, 0x02, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
-            0x80, 0x01,
-        ],
-        Err(DecodeError::InvalidVersion(2)),
-    )
-});
-
-static RESPONSE4: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x03, 0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
-            0x80, 0x01,
-        ],
-        Err(DecodeError::InvalidCommand(3)),
-    )
-});
-
-static RESPONSE5: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
-    (
-        vec![
-            0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01,
-            0x80, 0x01, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x81, 0x02,
-            0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x80, 0x14,
-        ],
-        Ok(Pdu {
-            command: Command::Response,
-            version: 1,
-            rtes: vec![],
-            rte_errors: vec![
-                DecodeError::InvalidRtePrefix(ip6!("::1")),
-                DecodeError::InvalidRtePrefixLength(129),
-                DecodeError::InvalidRteMetric(20),
+                })],
+                rte_errors: vec![],
+                auth_seqno: Some(70),
+            }),
+        )
+    });
+
+static RESPONSE7: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x10, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x40, 0x01,
             ],
-        }),
-    )
-});
+            Some(AuthCtx::new(
+                AuthMethod::ManualKey(Key::new(
+                    1,
+                    CryptoAlgo::HmacSha1,
+                    "HOLO".as_bytes().to_vec(),
+                )),
+                Arc::new(AtomicU32::new(70)),
+            )),
+            Err(DecodeError::AuthTypeMismatch),
+        )
+    });
 
 //
 // Tests.
@@ -164,54 +250,72 @@ static RESPONSE5: Lazy<(Vec<u8>, DecodeResult<Pdu>)> = Lazy::new(|| {
 
 #[test]
 fn test_encode_request1() {
-    let (ref bytes, ref pdu) = *REQUEST1;
-    test_encode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *REQUEST1;
+    test_encode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_request1() {
-    let (ref bytes, ref pdu) = *REQUEST1;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *REQUEST1;
+    test_decode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_encode_response1() {
-    let (ref bytes, ref pdu) = *RESPONSE1;
-    test_encode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE1;
+    test_encode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_response1() {
-    let (ref bytes, ref pdu) = *RESPONSE1;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE1;
+    test_decode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_encode_response2() {
-    let (ref bytes, ref pdu) = *RESPONSE2;
-    test_encode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE2;
+    test_encode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_response2() {
-    let (ref bytes, ref pdu) = *RESPONSE2;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE2;
+    test_decode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_response3() {
-    let (ref bytes, ref pdu) = *RESPONSE3;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE3;
+    test_decode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_response4() {
-    let (ref bytes, ref pdu) = *RESPONSE4;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE4;
+    test_decode_pdu(bytes, pdu, auth);
 }
 
 #[test]
 fn test_decode_response5() {
-    let (ref bytes, ref pdu) = *RESPONSE5;
-    test_decode_pdu(bytes, pdu);
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE5;
+    test_decode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_encode_response6() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE6;
+    test_encode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_decode_response6() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE6;
+    test_decode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_decode_response7() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE7;
+    test_decode_pdu(bytes, pdu, auth);
 }
@@ -9,12 +9,13 @@ use std::sync::{Arc, LazyLock as Lazy};
 
 use const_addrs::{ip4, net4};
 use holo_protocol::assert_eq_hex;
-use holo_rip::packet::{AuthCtx, Command, PduVersion};
+use holo_rip::packet::{AuthCtx, AuthMethod, Command, PduVersion};
 use holo_rip::ripv2::packet::{
     DecodeError, DecodeResult, Pdu, Rte, RteIpv4, RteZero,
 };
 use holo_rip::route::Metric;
 use holo_utils::crypto::CryptoAlgo;
+use holo_utils::keychain::{Key, Keychain, KeychainKey};
 
 //
 // Helper functions.
@@ -25,6 +26,7 @@ fn test_encode_pdu(
     pdu: &DecodeResult<Pdu>,
     auth: &Option<AuthCtx>,
 ) {
+    let auth = auth.as_ref().map(|auth| auth.encode_ctx().unwrap());
     let bytes_actual = pdu.as_ref().unwrap().encode(auth.as_ref());
     assert_eq_hex!(bytes_expected, bytes_actual);
 }
@@ -34,7 +36,8 @@ fn test_decode_pdu(
     pdu_expected: &DecodeResult<Pdu>,
     auth: &Option<AuthCtx>,
 ) {
-    let pdu_actual = Pdu::decode(bytes, auth.as_ref());
+    let auth = auth.as_ref().map(|auth| &auth.method);
+    let pdu_actual = Pdu::decode(bytes, auth);
     assert_eq!(*pdu_expected, pdu_actual);
 }
 
@@ -185,8 +188,11 @@ static RESPONSE5: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
                 0xd1, 0x6d, 0x1d, 0x5d,
             ],
             Some(AuthCtx::new(
-                "HOLO".to_owned(),
-                CryptoAlgo::Md5,
+                AuthMethod::ManualKey(Key::new(
+                    1,
+                    CryptoAlgo::Md5,
+                    "HOLO".as_bytes().to_vec(),
+                )),
                 Arc::new(AtomicU32::new(69)),
             )),
             Ok(Pdu {
@@ -230,6 +236,67 @@ static RESPONSE5: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
         )
     });
 
+static RESPONSE6: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x02, 0x00, 0x00, 0xff, 0xff, 0x00, 0x03, 0x00, 0x2c,
+                0x02, 0x20, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x00,
+                0x01, 0x00, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0x00, 0x01, 0xb0, 0x48,
+                0x24, 0x9f, 0x2a, 0x17, 0x69, 0xc0, 0x47, 0x8b, 0xf2, 0xbf,
+                0x42, 0x3c, 0xbd, 0x74, 0x03, 0x8b, 0x0b, 0x57, 0x75, 0xff,
+                0xed, 0xfb, 0xcf, 0x70, 0xfb, 0xcc, 0xb3, 0xc1, 0xff, 0xe6,
+            ],
+            Some(AuthCtx::new(
+                AuthMethod::Keychain(Arc::new(test_keychain())),
+                Arc::new(AtomicU32::new(70)),
+            )),
+            Ok(Pdu {
+                command: Command::Response,
+                version: 2,
+                rtes: vec![Rte::Ipv4(RteIpv4 {
+                    tag: 0,
+                    prefix: net4!("10.0.1.0/24"),
+                    nexthop: None,
+                    metric: Metric::from(1),
+                })],
+                rte_errors: vec![],
+                auth_seqno: Some(70),
+            }),
+        )
+    });
+
+static RESPONSE7: Lazy<(Vec<u8>, Option<AuthCtx>, DecodeResult<Pdu>)> =
+    Lazy::new(|| {
+        (
+            vec![
+                0x02, 0x02, 0x00, 0x00, 0xff, 0xff, 0x00, 0x03, 0x00, 0x2c,
+                0x03, 0x20, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x00,
+                0x01, 0x00, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0x00, 0x01, 0xb0, 0x48,
+                0x24, 0x9f, 0x2a, 0x17, 0x69, 0xc0, 0x47, 0x8b, 0xf2, 0xbf,
+                0x42, 0x3c, 0xbd, 0x74, 0x03, 0x8b, 0x0b, 0x57, 0x75, 0xff,
+                0xed, 0xfb, 0xcf, 0x70, 0xfb, 0xcc, 0xb3, 0xc1, 0xff, 0xe6,
+            ],
+            Some(AuthCtx::new(
+                AuthMethod::Keychain(Arc::new(test_keychain())),
+                Arc::new(AtomicU32::new(70)),
+            )),
+            Err(DecodeError::AuthKeyIdNotFound(3)),
+        )
+    });
+
+fn test_keychain() -> Keychain {
+    let key = Key::new(2, CryptoAlgo::HmacSha256, b"HOLO-SHA256".to_vec());
+    let mut keychain = Keychain::new("test".to_owned());
+    keychain.max_digest_size = key.algo.digest_size();
+    keychain.keys.insert(key.id, KeychainKey::new(key));
+    keychain
+}
+
 //
 // Tests.
 //
@@ -287,3 +354,34 @@ fn test_decode_response5() {
     let (ref bytes, ref auth, ref pdu) = *RESPONSE5;
     test_decode_pdu(bytes, pdu, auth);
 }
+
+#[test]
+fn test_encode_response6() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE6;
+    test_encode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_decode_response6() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE6;
+    test_decode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_decode_response7() {
+    let (ref bytes, ref auth, ref pdu) = *RESPONSE7;
+    test_decode_pdu(bytes, pdu, auth);
+}
+
+#[test]
+fn test_encode_auth_key_id_out_of_range() {
+    let key = Key::new(258, CryptoAlgo::HmacSha256, b"HOLO-SHA256".to_vec());
+    let mut keychain = Keychain::new("test".to_owned());
+    keychain.max_digest_size = key.algo.digest_size();
+    keychain.keys.insert(key.id, KeychainKey::new(key));
+    let auth = AuthCtx::new(
+        AuthMethod::Keychain(Arc::new(keychain)),
+        Arc::new(AtomicU32::new(70)),
+    );
+    assert!(auth.encode_ctx().is_none());
+}
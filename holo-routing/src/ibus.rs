@@ -6,6 +6,7 @@
 
 use std::collections::{BTreeMap, hash_map};
 use std::net::IpAddr;
+use std::sync::Arc;
 
 use holo_utils::ibus::{IbusChannelsTx, IbusMsg, IbusSender};
 use holo_utils::ip::{AddressFamily, IpNetworkKind, JointPrefixMapExt};
@@ -30,8 +31,23 @@ pub(crate) fn process_msg(master: &mut Master, msg: IbusMsg) {
                 send(&instance.ibus_tx, msg.clone());
             }
         }
+        IbusMsg::LdpIgpSyncReq => {
+            // Relay to the LDP instances, including the ones created later.
+            master.ldp_igp_sync_requested = true;
+            for instance in master
+                .instances
+                .iter()
+                .filter(|(instance_id, _)| {
+                    instance_id.protocol == Protocol::LDP
+                })
+                .map(|(_, instance)| instance)
+            {
+                send(&instance.ibus_tx, msg.clone());
+            }
+        }
         IbusMsg::KeychainUpd(..)
         | IbusMsg::KeychainDel(..)
+        | IbusMsg::LdpIgpSyncUpd { .. }
         | IbusMsg::PolicyMatchSetsUpd(..)
         | IbusMsg::PolicyUpd(..)
         | IbusMsg::PolicyDel(..) => {
@@ -130,6 +146,25 @@ pub(crate) fn process_msg(master: &mut Master, msg: IbusMsg) {
             // Remove MPLS route from the LIB.
             master.rib.mpls_route_del(msg);
         }
+        IbusMsg::RouteSrv6LocalSidAdd(msg) => {
+            // Add SRv6 local SID to the RIB.
+            master.rib.srv6_local_sid_add(msg);
+        }
+        IbusMsg::RouteSrv6LocalSidDel(msg) => {
+            // Remove SRv6 local SID from the RIB.
+            master.rib.srv6_local_sid_del(msg);
+        }
+        IbusMsg::RouteRetain { protocol, retain } => {
+            // Update the set of protocols whose routes are preserved on exit.
+            master.rib.route_retain(protocol, retain);
+            master.retained_protocols_store();
+        }
+        IbusMsg::RouteStaleSweep(protocol) => {
+            // Remove preserved routes that weren't reinstalled.
+            master.rib.stale_routes_sweep(protocol, &master.netlink_tx);
+            master.shared.preserved_routes =
+                Arc::new(master.rib.stale.values().copied().collect());
+        }
         IbusMsg::RouteBierAdd(msg) => {
             master.birt.bier_nbr_add(msg);
         }
@@ -243,6 +278,7 @@ pub(crate) fn notify_redistribute_add(
         tag: route.tag,
         opaque_attrs: route.opaque_attrs,
         nexthops: route.nexthops.clone(),
+        backup_nexthops: route.backup_nexthops.clone(),
     };
     let msg = IbusMsg::RouteRedistributeAdd(msg);
     send(&sub.tx, msg.clone());
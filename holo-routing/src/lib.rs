@@ -12,7 +12,9 @@ mod northbound;
 mod rib;
 mod sysctl;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::Duration;
 
 use derive_new::new;
 use holo_northbound::{
@@ -36,6 +38,12 @@ use crate::netlink::NetlinkRequest;
 use crate::northbound::configuration::StaticRoute;
 use crate::rib::Rib;
 
+// Key used to store the protocols whose routes are preserved on exit.
+const RETAINED_PROTOCOLS_KEY: &str = "routing-retained-protocols";
+
+// Maximum time preserved routes are kept before being removed.
+const STALE_ROUTES_TIMEOUT: Duration = Duration::from_secs(300);
+
 pub struct Master {
     // Northbound Tx channel.
     pub nb_tx: NbProviderSender,
@@ -57,8 +65,12 @@ pub struct Master {
     pub bier_config: BierCfg,
     // Protocol instances.
     pub instances: BTreeMap<InstanceId, InstanceHandle>,
+    // Whether LDP-IGP synchronization updates were requested by an IGP.
+    pub ldp_igp_sync_requested: bool,
     // BIER Routing Table (BIRT)
     pub birt: Birt,
+    // Timeout for removing preserved routes that weren't reclaimed.
+    pub stale_routes_timeout: Option<Task<()>>,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, new, Ord)]
@@ -132,6 +144,36 @@ impl Master {
             }
         }
     }
+
+    // Loads the protocols that requested their routes to be preserved before
+    // the previous Holo instance exited, and clears the stored set so that it
+    // only reflects the requests received from now on.
+    fn retained_protocols_load(&self) -> BTreeSet<Protocol> {
+        let Some(db) = &self.shared.db else {
+            return Default::default();
+        };
+        let mut db = db.lock().unwrap();
+        let retained = db
+            .get::<BTreeSet<Protocol>>(RETAINED_PROTOCOLS_KEY)
+            .unwrap_or_default();
+        if let Err(error) =
+            db.set(RETAINED_PROTOCOLS_KEY, &BTreeSet::<Protocol>::new())
+        {
+            warn!(%error, "failed to update retained protocols");
+        }
+        retained
+    }
+
+    // Stores the protocols whose routes should be preserved when Holo exits.
+    fn retained_protocols_store(&self) {
+        let Some(db) = &self.shared.db else {
+            return;
+        };
+        let mut db = db.lock().unwrap();
+        if let Err(error) = db.set(RETAINED_PROTOCOLS_KEY, &self.rib.retained) {
+            warn!(%error, "failed to update retained protocols");
+        }
+    }
 }
 
 // ===== helper functions =====
@@ -190,7 +232,9 @@ pub fn start(
             sr_config: Default::default(),
             bier_config: Default::default(),
             instances: Default::default(),
+            ldp_igp_sync_requested: false,
             birt: Birt::new(birt_update_queue_tx),
+            stale_routes_timeout: None,
         };
 
         // Request information about all interfaces addresses.
@@ -213,8 +257,24 @@ pub fn start(
         let netlink_handle = netlink::init();
 
         // Purge stale routes potentially left behind by a previous Holo
-        // instance.
-        netlink::purge_stale_routes(&netlink_handle).await;
+        // instance. Routes preserved for graceful restart are removed once
+        // the owning protocol finishes restarting, or after a timeout.
+        let retained = master.retained_protocols_load();
+        master.rib.stale =
+            netlink::purge_stale_routes(&netlink_handle, &retained).await;
+        if !master.rib.stale.is_empty() {
+            let protocols =
+                master.rib.stale.values().copied().collect::<BTreeSet<_>>();
+            master.shared.preserved_routes = Arc::new(protocols.clone());
+            let ibus_tx = master.ibus_tx.clone();
+            let task = Task::spawn(async move {
+                tokio::time::sleep(STALE_ROUTES_TIMEOUT).await;
+                for protocol in protocols {
+                    ibus_tx.route_stale_sweep(protocol);
+                }
+            });
+            master.stale_routes_timeout = Some(task);
+        }
 
         // Start netlink Tx task.
         let netlink_tx_task = tokio::task::spawn(async move {
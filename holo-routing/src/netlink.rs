@@ -4,20 +4,24 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::IpAddr;
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::num::NonZeroI32;
 
 use capctl::caps::CapState;
 use futures::TryStreamExt;
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
-use holo_utils::southbound::{Nexthop, RouteKind};
+use holo_utils::southbound::{InterfaceFlags, Nexthop, RouteKind};
+use holo_utils::sr::Srv6EndpointBehavior;
 use ipnetwork::IpNetwork;
 use netlink_packet_core::ErrorMessage;
 use netlink_packet_route::AddressFamily;
 use netlink_packet_route::route::{
-    MplsLabel, RouteMessage, RouteNextHop, RouteProtocol, RouteType,
+    MplsLabel, RouteAddress, RouteAttribute, RouteLwEnCapType,
+    RouteLwTunnelEncap, RouteMessage, RouteNextHop, RouteProtocol, RouteType,
 };
+use netlink_packet_utils::nla::DefaultNla;
 use rtnetlink::{
     Error, Handle, RouteMessageBuilder, RouteNextHopBuilder, new_connection,
 };
@@ -25,7 +29,20 @@ use tokio::sync::mpsc::UnboundedSender;
 use tracing::{error, warn};
 
 use crate::interface::Interfaces;
-use crate::rib::Route;
+use crate::rib::{Route, Srv6LocalSid};
+
+// Attributes and actions of the SRv6 local SID lightweight tunnel
+// encapsulation (include/uapi/linux/seg6_local.h).
+const SEG6_LOCAL_ACTION: u16 = 1;
+const SEG6_LOCAL_NH6: u16 = 5;
+const SEG6_LOCAL_ACTION_END: u32 = 1;
+const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+
+// Priority of the kernel routes holding the backup nexthops of a route. These
+// routes are only used once all primary nexthops become unusable (e.g. when
+// their outgoing interfaces go down). The value is higher than the default
+// priority the kernel assigns to IPv6 routes (IP6_RT_PRIO_USER).
+const BACKUP_ROUTE_PRIORITY: u32 = 65535;
 
 pub enum NetlinkRequest {
     RouteAdd(RouteMessage),
@@ -93,6 +110,21 @@ pub(crate) fn ip_route_install(
 
     // Enqueue netlink request.
     netlink_tx.send(NetlinkRequest::RouteAdd(msg)).unwrap();
+
+    // Install the backup nexthops, if any, as a separate lower-priority route.
+    if !route.backup_nexthops.is_empty() {
+        let nexthops =
+            netlink_nexthops(af, route.backup_nexthops.iter(), interfaces);
+        let msg = RouteMessageBuilder::<IpAddr>::new()
+            .destination_prefix(prefix.ip(), prefix.prefix())
+            .unwrap()
+            .protocol(protocol)
+            .kind(RouteType::Unicast)
+            .priority(BACKUP_ROUTE_PRIORITY)
+            .multipath(nexthops)
+            .build();
+        netlink_tx.send(NetlinkRequest::RouteAdd(msg)).unwrap();
+    }
 }
 
 pub(crate) fn ip_route_uninstall(
@@ -113,6 +145,25 @@ pub(crate) fn ip_route_uninstall(
     netlink_tx.send(NetlinkRequest::RouteDel(msg)).unwrap();
 }
 
+pub(crate) fn ip_backup_route_uninstall(
+    netlink_tx: &UnboundedSender<NetlinkRequest>,
+    prefix: &IpNetwork,
+    protocol: Protocol,
+) {
+    // Create netlink message.
+    let protocol = netlink_protocol(protocol);
+    let msg = RouteMessageBuilder::<IpAddr>::new()
+        .destination_prefix(prefix.ip(), prefix.prefix())
+        .unwrap()
+        .protocol(protocol)
+        .kind(RouteType::Unspec)
+        .priority(BACKUP_ROUTE_PRIORITY)
+        .build();
+
+    // Enqueue netlink request.
+    netlink_tx.send(NetlinkRequest::RouteDel(msg)).unwrap();
+}
+
 pub(crate) fn mpls_route_install(
     netlink_tx: &UnboundedSender<NetlinkRequest>,
     local_label: Label,
@@ -164,6 +215,77 @@ pub(crate) fn mpls_route_uninstall(
     netlink_tx.send(NetlinkRequest::RouteDel(msg)).unwrap();
 }
 
+pub(crate) fn srv6_local_sid_install(
+    netlink_tx: &UnboundedSender<NetlinkRequest>,
+    sid: Ipv6Addr,
+    local_sid: &Srv6LocalSid,
+    interfaces: &Interfaces,
+) {
+    // Build the seg6local encapsulation attributes.
+    let (action, ifindex) = match (local_sid.behavior, local_sid.nexthop) {
+        (Srv6EndpointBehavior::End, _) => {
+            // The End behavior isn't tied to any particular interface, but the
+            // kernel requires one. Use the loopback interface.
+            let Some(iface) = interfaces
+                .iter()
+                .find(|iface| iface.flags.contains(InterfaceFlags::LOOPBACK))
+            else {
+                warn!(%sid, "failed to find loopback interface");
+                return;
+            };
+            (SEG6_LOCAL_ACTION_END, iface.ifindex)
+        }
+        (Srv6EndpointBehavior::EndX, Some((ifindex, _))) => {
+            (SEG6_LOCAL_ACTION_END_X, ifindex)
+        }
+        (Srv6EndpointBehavior::EndX, None) => {
+            warn!(%sid, "missing End.X nexthop");
+            return;
+        }
+    };
+    let mut encap = vec![RouteLwTunnelEncap::Other(DefaultNla::new(
+        SEG6_LOCAL_ACTION,
+        action.to_ne_bytes().to_vec(),
+    ))];
+    if let Some((_, nbr_addr)) = local_sid.nexthop {
+        encap.push(RouteLwTunnelEncap::Other(DefaultNla::new(
+            SEG6_LOCAL_NH6,
+            nbr_addr.octets().to_vec(),
+        )));
+    }
+
+    // Create netlink message.
+    let protocol = netlink_protocol(local_sid.protocol);
+    let mut msg = RouteMessageBuilder::<Ipv6Addr>::new()
+        .destination_prefix(sid, Ipv6Addr::BITS as u8)
+        .protocol(protocol)
+        .output_interface(ifindex)
+        .build();
+    msg.attributes
+        .push(RouteAttribute::EncapType(RouteLwEnCapType::Seg6Local));
+    msg.attributes.push(RouteAttribute::Encap(encap));
+
+    // Enqueue netlink request.
+    netlink_tx.send(NetlinkRequest::RouteAdd(msg)).unwrap();
+}
+
+pub(crate) fn srv6_local_sid_uninstall(
+    netlink_tx: &UnboundedSender<NetlinkRequest>,
+    sid: Ipv6Addr,
+    protocol: Protocol,
+) {
+    // Create netlink message.
+    let protocol = netlink_protocol(protocol);
+    let msg = RouteMessageBuilder::<Ipv6Addr>::new()
+        .destination_prefix(sid, Ipv6Addr::BITS as u8)
+        .protocol(protocol)
+        .kind(RouteType::Unspec)
+        .build();
+
+    // Enqueue netlink request.
+    netlink_tx.send(NetlinkRequest::RouteDel(msg)).unwrap();
+}
+
 // Purge stale routes that may have been left behind by a previous Holo
 // instance.
 //
@@ -174,7 +296,17 @@ pub(crate) fn mpls_route_uninstall(
 // This function should be called during startup to clean up any such stale
 // routes. It filters routes by protocol type (e.g., BGP, OSPF), assuming that
 // only Holo installs routes using those protocols.
-pub(crate) async fn purge_stale_routes(handle: &Handle) {
+//
+// Routes from protocols that requested their forwarding state to be preserved
+// before the previous Holo instance exited (currently only IS-IS, when graceful
+// restart is enabled) are left in place and returned to the caller, so that
+// forwarding can continue while the protocol resynchronizes. These routes must
+// later be removed by a stale route sweep.
+pub(crate) async fn purge_stale_routes(
+    handle: &Handle,
+    retained: &BTreeSet<Protocol>,
+) -> BTreeMap<IpNetwork, Protocol> {
+    let mut preserved = BTreeMap::new();
     let msg = RouteMessageBuilder::<IpAddr>::new().build();
     let mut routes = handle.route().get(msg).execute();
     while let Ok(Some(route)) = routes.try_next().await {
@@ -191,11 +323,21 @@ pub(crate) async fn purge_stale_routes(handle: &Handle) {
             continue;
         }
 
+        // Preserve routes from protocols that requested it.
+        if let Some((prefix, protocol)) =
+            stale_route_preserved(&route, retained)
+        {
+            preserved.insert(prefix, protocol);
+            continue;
+        }
+
         // Attempt to uninstall the stale route.
         if let Err(error) = handle.route().del(route).execute().await {
             warn!(?protocol, ?error, "failed to purge stale route");
         }
     }
+
+    preserved
 }
 
 pub(crate) fn init() -> Handle {
@@ -233,6 +375,47 @@ fn netlink_protocol(protocol: Protocol) -> RouteProtocol {
     }
 }
 
+fn route_prefix(route: &RouteMessage) -> Option<IpNetwork> {
+    let addr = route
+        .attributes
+        .iter()
+        .find_map(|attr| match attr {
+            RouteAttribute::Destination(RouteAddress::Inet(addr)) => {
+                Some(IpAddr::from(*addr))
+            }
+            RouteAttribute::Destination(RouteAddress::Inet6(addr)) => {
+                Some(IpAddr::from(*addr))
+            }
+            _ => None,
+        })
+        .or(match route.header.address_family {
+            AddressFamily::Inet => Some(Ipv4Addr::UNSPECIFIED.into()),
+            AddressFamily::Inet6 => Some(Ipv6Addr::UNSPECIFIED.into()),
+            _ => None,
+        })?;
+    IpNetwork::new(addr, route.header.destination_prefix_length).ok()
+}
+
+// Returns the prefix and owning protocol of a stale kernel route if it should
+// be preserved, given the protocols that requested the preservation of their
+// routes before the previous Holo instance exited.
+fn stale_route_preserved(
+    route: &RouteMessage,
+    retained: &BTreeSet<Protocol>,
+) -> Option<(IpNetwork, Protocol)> {
+    // Only IS-IS supports graceful restart for now.
+    let protocol = match route.header.protocol {
+        RouteProtocol::Isis => Protocol::ISIS,
+        _ => return None,
+    };
+    if !retained.contains(&protocol) {
+        return None;
+    }
+
+    let prefix = route_prefix(route)?;
+    Some((prefix, protocol))
+}
+
 fn netlink_nexthops<'a>(
     af: AddressFamily,
     nexthops: impl Iterator<Item = &'a Nexthop>,
@@ -296,3 +479,287 @@ fn netlink_label_stack(labels: &[Label]) -> Vec<MplsLabel> {
     }
     labels
 }
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use holo_utils::southbound::RouteOpaqueAttrs;
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    fn kernel_route(prefix: &str, protocol: RouteProtocol) -> RouteMessage {
+        let prefix: IpNetwork = prefix.parse().unwrap();
+        RouteMessageBuilder::<IpAddr>::new()
+            .destination_prefix(prefix.ip(), prefix.prefix())
+            .unwrap()
+            .protocol(protocol)
+            .build()
+    }
+
+    fn srv6_local_sid(
+        behavior: Srv6EndpointBehavior,
+        nexthop: Option<(u32, Ipv6Addr)>,
+    ) -> Srv6LocalSid {
+        Srv6LocalSid {
+            protocol: Protocol::ISIS,
+            behavior,
+            nexthop,
+            last_updated: Default::default(),
+            flags: Default::default(),
+        }
+    }
+
+    fn ip_route(backup_nexthops: BTreeSet<Nexthop>) -> Route {
+        let nexthop = Nexthop::Address {
+            ifindex: 2,
+            addr: "10.0.1.2".parse().unwrap(),
+            labels: vec![],
+        };
+        let mut route = Route::new(
+            Protocol::ISIS,
+            RouteKind::Unicast,
+            115,
+            20,
+            None,
+            RouteOpaqueAttrs::None,
+            [nexthop].into(),
+            Default::default(),
+            Default::default(),
+        );
+        route.backup_nexthops = backup_nexthops;
+        route
+    }
+
+    fn ip_route_install_msgs(
+        prefix: &IpNetwork,
+        route: &Route,
+    ) -> Vec<RouteMessage> {
+        let (netlink_tx, mut netlink_rx) = mpsc::unbounded_channel();
+        ip_route_install(&netlink_tx, prefix, route, &interfaces());
+        let mut msgs = vec![];
+        while let Ok(request) = netlink_rx.try_recv() {
+            match request {
+                NetlinkRequest::RouteAdd(msg) => msgs.push(msg),
+                NetlinkRequest::RouteDel(_) => {
+                    panic!("unexpected route removal")
+                }
+            }
+        }
+        msgs
+    }
+
+    fn interfaces() -> Interfaces {
+        let mut interfaces = Interfaces::default();
+        interfaces.update("lo".to_owned(), 1, InterfaceFlags::LOOPBACK);
+        interfaces.update("eth-rt2".to_owned(), 2, InterfaceFlags::empty());
+        interfaces
+    }
+
+    fn srv6_local_sid_install_msg(
+        sid: Ipv6Addr,
+        local_sid: &Srv6LocalSid,
+        interfaces: &Interfaces,
+    ) -> Option<RouteMessage> {
+        let (netlink_tx, mut netlink_rx) = mpsc::unbounded_channel();
+        srv6_local_sid_install(&netlink_tx, sid, local_sid, interfaces);
+        match netlink_rx.try_recv().ok()? {
+            NetlinkRequest::RouteAdd(msg) => Some(msg),
+            NetlinkRequest::RouteDel(_) => panic!("unexpected route removal"),
+        }
+    }
+
+    fn seg6_local_encap(
+        action: u32,
+        nbr_addr: Option<Ipv6Addr>,
+    ) -> RouteAttribute {
+        let mut encap = vec![RouteLwTunnelEncap::Other(DefaultNla::new(
+            SEG6_LOCAL_ACTION,
+            action.to_ne_bytes().to_vec(),
+        ))];
+        if let Some(nbr_addr) = nbr_addr {
+            encap.push(RouteLwTunnelEncap::Other(DefaultNla::new(
+                SEG6_LOCAL_NH6,
+                nbr_addr.octets().to_vec(),
+            )));
+        }
+        RouteAttribute::Encap(encap)
+    }
+
+    #[test]
+    fn srv6_local_sid_install_end() {
+        let sid: Ipv6Addr = "fc00:0:1::".parse().unwrap();
+        let local_sid = srv6_local_sid(Srv6EndpointBehavior::End, None);
+        let msg =
+            srv6_local_sid_install_msg(sid, &local_sid, &interfaces()).unwrap();
+        assert_eq!(msg.header.destination_prefix_length, 128);
+        assert_eq!(msg.header.protocol, RouteProtocol::Isis);
+        let attrs = &msg.attributes;
+        assert!(
+            attrs.contains(&RouteAttribute::Destination(RouteAddress::Inet6(
+                sid
+            )))
+        );
+        assert!(attrs.contains(&RouteAttribute::Oif(1)));
+        assert!(
+            attrs.contains(&RouteAttribute::EncapType(
+                RouteLwEnCapType::Seg6Local
+            ))
+        );
+        assert!(attrs.contains(&seg6_local_encap(SEG6_LOCAL_ACTION_END, None)));
+    }
+
+    #[test]
+    fn srv6_local_sid_install_end_no_loopback() {
+        let sid: Ipv6Addr = "fc00:0:1::".parse().unwrap();
+        let local_sid = srv6_local_sid(Srv6EndpointBehavior::End, None);
+        let mut interfaces = Interfaces::default();
+        interfaces.update("eth-rt2".to_owned(), 2, InterfaceFlags::empty());
+        assert!(
+            srv6_local_sid_install_msg(sid, &local_sid, &interfaces).is_none()
+        );
+    }
+
+    #[test]
+    fn srv6_local_sid_install_end_x() {
+        let sid: Ipv6Addr = "fc00:0:1:e000::".parse().unwrap();
+        let nbr_addr: Ipv6Addr = "fe80::2".parse().unwrap();
+        let local_sid =
+            srv6_local_sid(Srv6EndpointBehavior::EndX, Some((2, nbr_addr)));
+        let msg =
+            srv6_local_sid_install_msg(sid, &local_sid, &interfaces()).unwrap();
+        let attrs = &msg.attributes;
+        assert!(
+            attrs.contains(&RouteAttribute::Destination(RouteAddress::Inet6(
+                sid
+            )))
+        );
+        assert!(attrs.contains(&RouteAttribute::Oif(2)));
+        assert!(attrs.contains(&seg6_local_encap(
+            SEG6_LOCAL_ACTION_END_X,
+            Some(nbr_addr)
+        )));
+    }
+
+    #[test]
+    fn srv6_local_sid_install_end_x_no_nexthop() {
+        let sid: Ipv6Addr = "fc00:0:1:e000::".parse().unwrap();
+        let local_sid = srv6_local_sid(Srv6EndpointBehavior::EndX, None);
+        assert!(
+            srv6_local_sid_install_msg(sid, &local_sid, &interfaces())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn srv6_local_sid_uninstall_route_del() {
+        let sid: Ipv6Addr = "fc00:0:1::".parse().unwrap();
+        let (netlink_tx, mut netlink_rx) = mpsc::unbounded_channel();
+        srv6_local_sid_uninstall(&netlink_tx, sid, Protocol::ISIS);
+        let Ok(NetlinkRequest::RouteDel(msg)) = netlink_rx.try_recv() else {
+            panic!("expected route removal");
+        };
+        assert_eq!(msg.header.destination_prefix_length, 128);
+        assert!(
+            msg.attributes.contains(&RouteAttribute::Destination(
+                RouteAddress::Inet6(sid)
+            ))
+        );
+    }
+
+    #[test]
+    fn ip_route_install_no_backup_nexthops() {
+        let prefix: IpNetwork = "10.0.2.0/24".parse().unwrap();
+        let msgs = ip_route_install_msgs(&prefix, &ip_route([].into()));
+        assert_eq!(msgs.len(), 1);
+        assert!(
+            !msgs[0]
+                .attributes
+                .iter()
+                .any(|attr| matches!(attr, RouteAttribute::Priority(_)))
+        );
+    }
+
+    #[test]
+    fn ip_route_install_backup_nexthops() {
+        let prefix: IpNetwork = "10.0.2.0/24".parse().unwrap();
+        let backup = Nexthop::Address {
+            ifindex: 2,
+            addr: "10.0.1.3".parse().unwrap(),
+            labels: vec![Label::new(16003)],
+        };
+        let msgs = ip_route_install_msgs(&prefix, &ip_route([backup].into()));
+        assert_eq!(msgs.len(), 2);
+        let msg = &msgs[1];
+        assert_eq!(msg.header.destination_prefix_length, 24);
+        assert_eq!(msg.header.protocol, RouteProtocol::Isis);
+        assert!(
+            msg.attributes
+                .contains(&RouteAttribute::Priority(BACKUP_ROUTE_PRIORITY))
+        );
+        let nexthops = msg
+            .attributes
+            .iter()
+            .find_map(|attr| match attr {
+                RouteAttribute::MultiPath(nexthops) => Some(nexthops),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(nexthops.len(), 1);
+        assert!(nexthops[0].attributes.contains(&RouteAttribute::Gateway(
+            RouteAddress::Inet(Ipv4Addr::new(10, 0, 1, 3))
+        )));
+    }
+
+    #[test]
+    fn ip_backup_route_uninstall_priority() {
+        let prefix: IpNetwork = "10.0.2.0/24".parse().unwrap();
+        let (netlink_tx, mut netlink_rx) = mpsc::unbounded_channel();
+        ip_backup_route_uninstall(&netlink_tx, &prefix, Protocol::ISIS);
+        let Ok(NetlinkRequest::RouteDel(msg)) = netlink_rx.try_recv() else {
+            panic!("expected route removal");
+        };
+        assert!(
+            msg.attributes
+                .contains(&RouteAttribute::Priority(BACKUP_ROUTE_PRIORITY))
+        );
+    }
+
+    #[test]
+    fn stale_isis_route_retained() {
+        let retained = BTreeSet::from([Protocol::ISIS]);
+        let route = kernel_route("10.0.1.0/24", RouteProtocol::Isis);
+        assert_eq!(
+            stale_route_preserved(&route, &retained),
+            Some(("10.0.1.0/24".parse().unwrap(), Protocol::ISIS))
+        );
+        let route = kernel_route("2001:db8::/64", RouteProtocol::Isis);
+        assert_eq!(
+            stale_route_preserved(&route, &retained),
+            Some(("2001:db8::/64".parse().unwrap(), Protocol::ISIS))
+        );
+    }
+
+    #[test]
+    fn stale_isis_route_not_retained() {
+        // Graceful restart wasn't enabled when the previous instance exited.
+        let retained = BTreeSet::new();
+        let route = kernel_route("10.0.1.0/24", RouteProtocol::Isis);
+        assert_eq!(stale_route_preserved(&route, &retained), None);
+    }
+
+    #[test]
+    fn stale_route_other_protocol() {
+        let retained = BTreeSet::from([Protocol::ISIS]);
+        for protocol in [
+            RouteProtocol::Bgp,
+            RouteProtocol::Ospf,
+            RouteProtocol::Rip,
+            RouteProtocol::Static,
+        ] {
+            let route = kernel_route("10.0.1.0/24", protocol);
+            assert_eq!(stale_route_preserved(&route, &retained), None);
+        }
+    }
+}
@@ -14,7 +14,7 @@ use holo_northbound::configuration::{
     ValidationCallbacks, ValidationCallbacksBuilder,
 };
 use holo_northbound::yang::control_plane_protocol;
-use holo_northbound::yang::routing::segment_routing::sr_mpls;
+use holo_northbound::yang::routing::segment_routing::{sr_mpls, srv6};
 use holo_northbound::yang::routing::{bier, ribs};
 use holo_northbound::{CallbackKey, NbDaemonSender};
 use holo_utils::bier::{
@@ -30,7 +30,7 @@ use holo_utils::southbound::{
     Nexthop, RouteKeyMsg, RouteKind, RouteMsg, RouteOpaqueAttrs,
 };
 use holo_utils::sr::{
-    IgpAlgoType, SidLastHopBehavior, SrCfgEvent, SrCfgPrefixSid,
+    IgpAlgoType, SidLastHopBehavior, SrCfgEvent, SrCfgPrefixSid, Srv6LocatorCfg,
 };
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::TryFromYang;
@@ -54,6 +54,7 @@ pub enum ListEntry {
     StaticRoute(IpNetwork),
     StaticRouteNexthop(IpNetwork, String),
     SrCfgPrefixSid(IpNetwork, IgpAlgoType),
+    Srv6Locator(String),
     BierCfgSubDomain(SubDomainId, AddressFamily),
     BierCfgEncapsulation(
         SubDomainId,
@@ -633,6 +634,87 @@ fn load_callbacks() -> Callbacks<Master> {
         .lookup(|_master, _list_entry, _dnode| {
             ListEntry::None
         })
+        .path(srv6::locators::locator::PATH)
+        .create_apply(|master, args| {
+            let name = args.dnode.get_string_relative("./name").unwrap();
+            let prefix = args.dnode.get_prefix6_relative("./prefix").unwrap();
+            let block_len = args.dnode.get_u8_relative("./block-bits-length").unwrap();
+            let node_len = args.dnode.get_u8_relative("./node-bits-length").unwrap();
+            let func_len = args.dnode.get_u8_relative("./function-bits-length").unwrap();
+            let arg_len = args.dnode.get_u8_relative("./argument-bits-length").unwrap();
+            let locator = Srv6LocatorCfg {
+                prefix,
+                block_len,
+                node_len,
+                func_len,
+                arg_len,
+            };
+            master.sr_config.srv6_locators.insert(name, locator);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .delete_apply(|master, args| {
+            let name = args.dnode.get_string_relative("./name").unwrap();
+            master.sr_config.srv6_locators.remove(&name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .lookup(|_master, _list_entry, dnode| {
+            let name = dnode.get_string_relative("./name").unwrap();
+            ListEntry::Srv6Locator(name)
+        })
+        .path(srv6::locators::locator::prefix::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.prefix = args.dnode.get_prefix6();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .path(srv6::locators::locator::block_bits_length::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.block_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .path(srv6::locators::locator::node_bits_length::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.node_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .path(srv6::locators::locator::function_bits_length::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.func_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
+        .path(srv6::locators::locator::argument_bits_length::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.arg_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+        })
         .path(ribs::rib::PATH)
         .create_apply(|_master, _args| {
             // Nothing to do.
@@ -1095,6 +1177,36 @@ fn load_validation_callbacks() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(sr_mpls::bindings::connected_prefix_sid_map::connected_prefix_sid::PATH)
+        .validate(|args| {
+            // Flexible Algorithm Prefix-SIDs are configured per IGP instance.
+            let algo = args.dnode.get_string_relative("./algorithm").unwrap();
+            if IgpAlgoType::try_from_yang(&algo).is_none() {
+                return Err("unsupported Prefix-SID algorithm".to_owned());
+            }
+
+            Ok(())
+        })
+        .path(srv6::locators::locator::PATH)
+        .validate(|args| {
+            let prefix = args.dnode.get_prefix6_relative("./prefix").unwrap();
+            let block_len = args.dnode.get_u8_relative("./block-bits-length").unwrap();
+            let node_len = args.dnode.get_u8_relative("./node-bits-length").unwrap();
+            let func_len = args.dnode.get_u8_relative("./function-bits-length").unwrap();
+            let arg_len = args.dnode.get_u8_relative("./argument-bits-length").unwrap();
+
+            // The locator is made up of the SID block and node identifier.
+            if u16::from(prefix.prefix()) != u16::from(block_len) + u16::from(node_len) {
+                return Err("locator prefix length must be equal to the sum of the block and node lengths".to_owned());
+            }
+
+            // Enforce maximum SID length.
+            if u16::from(block_len) + u16::from(node_len) + u16::from(func_len) + u16::from(arg_len) > 128 {
+                return Err("SID structure exceeds 128 bits".to_owned());
+            }
+
+            Ok(())
+        })
         .build()
 }
 
@@ -1213,6 +1325,7 @@ impl Provider for Master {
                     tag: None,
                     opaque_attrs: RouteOpaqueAttrs::None,
                     nexthops,
+                    backup_nexthops: Default::default(),
                 };
 
                 // Send message.
@@ -1417,6 +1530,13 @@ fn instance_start(master: &mut Master, protocol: Protocol, name: String) {
     // Keep track of northbound and ibus channels associated to the protocol
     // type and name.
     let instance = InstanceHandle::new(nb_daemon_tx, ibus_instance_tx);
+
+    // Let new LDP instances know that LDP-IGP synchronization updates were
+    // requested.
+    if protocol == Protocol::LDP && master.ldp_igp_sync_requested {
+        let _ = instance.ibus_tx.send(IbusMsg::LdpIgpSyncReq);
+    }
+
     master.instances.insert(instance_id, instance);
 }
 
@@ -5,7 +5,7 @@
 //
 
 use std::collections::{BTreeMap, BTreeSet, HashMap, btree_map, hash_map};
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv6Addr};
 
 use bitflags::bitflags;
 use chrono::{DateTime, Utc};
@@ -16,8 +16,10 @@ use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
     AddressFlags, AddressMsg, LabelInstallMsg, LabelUninstallMsg, Nexthop,
-    RouteKeyMsg, RouteKind, RouteMsg, RouteOpaqueAttrs,
+    RouteKeyMsg, RouteKind, RouteMsg, RouteOpaqueAttrs, Srv6LocalSidInstallMsg,
+    Srv6LocalSidUninstallMsg,
 };
+use holo_utils::sr::Srv6EndpointBehavior;
 use ipnetwork::IpNetwork;
 use prefix_trie::joint::map::JointPrefixMap;
 use tokio::sync::mpsc::UnboundedSender;
@@ -31,11 +33,15 @@ use crate::{ibus, netlink};
 pub struct Rib {
     pub ip: JointPrefixMap<IpNetwork, BTreeMap<u32, Route>>,
     pub mpls: BTreeMap<Label, Route>,
+    pub srv6: BTreeMap<Ipv6Addr, Srv6LocalSid>,
     pub nht: HashMap<IpAddr, NhtEntry>,
     pub ip_update_queue: BTreeSet<IpNetwork>,
     pub mpls_update_queue: BTreeSet<Label>,
+    pub srv6_update_queue: BTreeSet<Ipv6Addr>,
     pub update_queue_tx: UnboundedSender<()>,
     pub subscriptions: HashMap<usize, RedistributeSub>,
+    pub retained: BTreeSet<Protocol>,
+    pub stale: BTreeMap<IpNetwork, Protocol>,
 }
 
 #[derive(Clone, Debug, new)]
@@ -47,6 +53,17 @@ pub struct Route {
     pub tag: Option<u32>,
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    #[new(default)]
+    pub backup_nexthops: BTreeSet<Nexthop>,
+    pub last_updated: DateTime<Utc>,
+    pub flags: RouteFlags,
+}
+
+#[derive(Clone, Debug, new)]
+pub struct Srv6LocalSid {
+    pub protocol: Protocol,
+    pub behavior: Srv6EndpointBehavior,
+    pub nexthop: Option<(u32, Ipv6Addr)>,
     pub last_updated: DateTime<Utc>,
     pub flags: RouteFlags,
 }
@@ -79,11 +96,15 @@ impl Rib {
         Self {
             ip: Default::default(),
             mpls: Default::default(),
+            srv6: Default::default(),
             nht: Default::default(),
             ip_update_queue: Default::default(),
             mpls_update_queue: Default::default(),
+            srv6_update_queue: Default::default(),
             update_queue_tx,
             subscriptions: Default::default(),
+            retained: Default::default(),
+            stale: Default::default(),
         }
     }
 
@@ -158,7 +179,7 @@ impl Rib {
     pub(crate) fn ip_route_add(&mut self, mut msg: RouteMsg) {
         msg.nexthops = self.resolve_nexthops(msg.nexthops);
         let rib_prefix = self.prefix_entry(msg.prefix);
-        match rib_prefix.entry(msg.distance) {
+        let route = match rib_prefix.entry(msg.distance) {
             btree_map::Entry::Vacant(v) => {
                 // If the IP route does not exist, create a new entry.
                 v.insert(Route::new(
@@ -171,7 +192,7 @@ impl Rib {
                     msg.nexthops,
                     Utc::now(),
                     RouteFlags::empty(),
-                ));
+                ))
             }
             btree_map::Entry::Occupied(o) => {
                 let route = o.into_mut();
@@ -185,8 +206,11 @@ impl Rib {
                 route.nexthops = msg.nexthops;
                 route.last_updated = Utc::now();
                 route.flags.remove(RouteFlags::REMOVED);
+                route
             }
-        }
+        };
+
+        route.backup_nexthops = msg.backup_nexthops;
 
         // Add IP route to the update queue.
         self.ip_update_queue_add(msg.prefix);
@@ -340,6 +364,40 @@ impl Rib {
         }
     }
 
+    // Adds SRv6 local SID to the RIB.
+    pub(crate) fn srv6_local_sid_add(&mut self, msg: Srv6LocalSidInstallMsg) {
+        self.srv6.insert(
+            msg.sid,
+            Srv6LocalSid::new(
+                msg.protocol,
+                msg.behavior,
+                msg.nexthop,
+                Utc::now(),
+                RouteFlags::empty(),
+            ),
+        );
+
+        // Add SRv6 local SID to the update queue.
+        self.srv6_update_queue_add(msg.sid);
+    }
+
+    // Removes SRv6 local SID from the RIB.
+    pub(crate) fn srv6_local_sid_del(&mut self, msg: Srv6LocalSidUninstallMsg) {
+        // Find SRv6 local SID entry.
+        let Some(local_sid) = self.srv6.get_mut(&msg.sid) else {
+            return;
+        };
+        if local_sid.protocol != msg.protocol {
+            return;
+        }
+
+        // Mark SRv6 local SID as removed.
+        local_sid.flags.insert(RouteFlags::REMOVED);
+
+        // Add SRv6 local SID to the update queue.
+        self.srv6_update_queue_add(msg.sid);
+    }
+
     // Nexthop tracking registration.
     pub(crate) fn nht_add(&mut self, subscriber: IbusSubscriber, addr: IpAddr) {
         debug!(%addr, "nexthop tracking add");
@@ -378,11 +436,14 @@ impl Rib {
         while let Some(prefix) = self.ip_update_queue.pop_first() {
             let rib_prefix = self.ip.entry(prefix).or_default();
 
-            // Find the protocol of the old best route, if one exists.
-            let old_best_protocol = rib_prefix
+            // Find the protocol of the old best route, if one exists, and
+            // whether it had backup nexthops installed.
+            let old_best = rib_prefix
                 .values()
                 .find(|route| route.flags.contains(RouteFlags::ACTIVE))
-                .map(|route| route.protocol);
+                .map(|route| {
+                    (route.protocol, !route.backup_nexthops.is_empty())
+                });
 
             // Remove routes marked with the REMOVED flag.
             rib_prefix
@@ -401,6 +462,16 @@ impl Rib {
                         );
                     }
 
+                    // Uninstall the backup nexthops of the old best route
+                    // when they aren't replaced by new ones.
+                    if let Some((protocol, true)) = old_best
+                        && route.backup_nexthops.is_empty()
+                    {
+                        netlink::ip_backup_route_uninstall(
+                            netlink_tx, &prefix, protocol,
+                        );
+                    }
+
                     // Notify protocol instances about the updated route.
                     for sub in self.subscriptions.values() {
                         ibus::notify_redistribute_add(sub, prefix, route);
@@ -413,13 +484,18 @@ impl Rib {
 
             // Check if there are no routes left for this prefix.
             if rib_prefix.is_empty() {
-                if let Some(protocol) = old_best_protocol {
+                if let Some((protocol, backup)) = old_best {
                     // Uninstall the old best route using the netlink handle.
                     if protocol != Protocol::DIRECT {
                         netlink::ip_route_uninstall(
                             netlink_tx, &prefix, protocol,
                         );
                     }
+                    if backup {
+                        netlink::ip_backup_route_uninstall(
+                            netlink_tx, &prefix, protocol,
+                        );
+                    }
 
                     // Notify protocol instances about the deleted route.
                     for sub in self.subscriptions.values() {
@@ -456,6 +532,32 @@ impl Rib {
             netlink::mpls_route_install(netlink_tx, label, route, interfaces);
         }
 
+        // Process SRv6 update queue.
+        while let Some(sid) = self.srv6_update_queue.pop_first() {
+            let Some(local_sid) = self.srv6.get(&sid) else {
+                continue;
+            };
+
+            // Check if the local SID was marked for removal.
+            if local_sid.flags.contains(RouteFlags::REMOVED) {
+                // Uninstall the local SID using the netlink handle.
+                netlink::srv6_local_sid_uninstall(
+                    netlink_tx,
+                    sid,
+                    local_sid.protocol,
+                );
+
+                // Effectively remove the local SID.
+                self.srv6.remove(&sid);
+                continue;
+            }
+
+            // Install the local SID using the netlink handle.
+            netlink::srv6_local_sid_install(
+                netlink_tx, sid, local_sid, interfaces,
+            );
+        }
+
         // Reevaluate all registered nexthops.
         let mut nht = std::mem::take(&mut self.nht);
         for (addr, nhte) in &mut nht {
@@ -542,7 +644,55 @@ impl Rib {
         let _ = self.update_queue_tx.send(());
     }
 
+    // Adds SRv6 local SID to the update queue.
+    fn srv6_update_queue_add(&mut self, sid: Ipv6Addr) {
+        self.srv6_update_queue.insert(sid);
+        let _ = self.update_queue_tx.send(());
+    }
+
+    // Updates whether the routes of the given protocol should be preserved in
+    // the kernel when Holo exits.
+    pub(crate) fn route_retain(&mut self, protocol: Protocol, retain: bool) {
+        if retain {
+            self.retained.insert(protocol);
+        } else {
+            self.retained.remove(&protocol);
+        }
+    }
+
+    // Removes the preserved routes of the given protocol that haven't been
+    // reinstalled by the protocol since startup.
+    pub(crate) fn stale_routes_sweep(
+        &mut self,
+        protocol: Protocol,
+        netlink_tx: &UnboundedSender<NetlinkRequest>,
+    ) {
+        self.stale.retain(|prefix, stale_protocol| {
+            if *stale_protocol != protocol {
+                return true;
+            }
+
+            // Skip routes that were reinstalled in the meantime.
+            let reinstalled = self.ip.get(prefix).is_some_and(|rib_prefix| {
+                rib_prefix.values().any(|route| {
+                    route.protocol == protocol
+                        && !route.flags.contains(RouteFlags::REMOVED)
+                })
+            });
+            if !reinstalled {
+                netlink::ip_route_uninstall(netlink_tx, prefix, protocol);
+                netlink::ip_backup_route_uninstall(
+                    netlink_tx, prefix, protocol,
+                );
+            }
+            false
+        });
+    }
+
     // Uninstall all routes.
+    //
+    // Routes from protocols that requested their forwarding state to be
+    // preserved across restarts are left in the kernel.
     pub(crate) fn route_uninstall_all(
         &mut self,
         netlink_tx: &UnboundedSender<NetlinkRequest>,
@@ -551,17 +701,32 @@ impl Rib {
             if let Some(route) = rib_prefix
                 .values()
                 .find(|route| route.flags.contains(RouteFlags::ACTIVE))
+                .filter(|route| !self.retained.contains(&route.protocol))
             {
                 netlink::ip_route_uninstall(
                     netlink_tx,
                     &prefix,
                     route.protocol,
                 );
+                if !route.backup_nexthops.is_empty() {
+                    netlink::ip_backup_route_uninstall(
+                        netlink_tx,
+                        &prefix,
+                        route.protocol,
+                    );
+                }
             }
         }
         for (label, route) in &self.mpls {
             netlink::mpls_route_uninstall(netlink_tx, *label, route.protocol);
         }
+        for (sid, local_sid) in &self.srv6 {
+            netlink::srv6_local_sid_uninstall(
+                netlink_tx,
+                *sid,
+                local_sid.protocol,
+            );
+        }
     }
 }
 
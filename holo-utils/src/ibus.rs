@@ -19,11 +19,13 @@ use crate::bier::{BierCfg, BierCfgEvent};
 use crate::ip::AddressFamily;
 use crate::keychain::Keychain;
 use crate::mac_addr::MacAddr;
+use crate::mpls::LdpIgpSyncState;
 use crate::policy::{MatchSets, Policy};
 use crate::protocol::Protocol;
 use crate::southbound::{
     AddressMsg, BierNbrInstallMsg, BierNbrUninstallMsg, InterfaceUpdateMsg,
     LabelInstallMsg, LabelUninstallMsg, RouteKeyMsg, RouteMsg,
+    Srv6LocalSidInstallMsg, Srv6LocalSidUninstallMsg,
 };
 use crate::sr::{MsdType, SrCfg, SrCfgEvent};
 
@@ -123,6 +125,13 @@ pub enum IbusMsg {
     KeychainUpd(Arc<Keychain>),
     /// Key-chain delete notification.
     KeychainDel(String),
+    /// Request the LDP-IGP synchronization state of all LDP interfaces.
+    LdpIgpSyncReq,
+    /// LDP-IGP synchronization state update notification.
+    LdpIgpSyncUpd {
+        ifname: String,
+        state: LdpIgpSyncState,
+    },
     /// Create a macvlan interface.
     MacvlanAdd {
         parent_ifname: String,
@@ -166,6 +175,17 @@ pub enum IbusMsg {
     RouteMplsAdd(LabelInstallMsg),
     /// Request to uninstall MPLS route from the LIB.
     RouteMplsDel(LabelUninstallMsg),
+    /// Request to install SRv6 local SID in the kernel.
+    RouteSrv6LocalSidAdd(Srv6LocalSidInstallMsg),
+    /// Request to uninstall SRv6 local SID from the kernel.
+    RouteSrv6LocalSidDel(Srv6LocalSidUninstallMsg),
+    /// Request to preserve (or stop preserving) the kernel routes of a protocol
+    /// when Holo exits, so that forwarding continues across a graceful
+    /// restart.
+    RouteRetain { protocol: Protocol, retain: bool },
+    /// Request to remove the kernel routes of a protocol that were preserved
+    /// from a previous Holo instance and haven't been reinstalled since.
+    RouteStaleSweep(Protocol),
     /// Request to install an entry in the BIRT.
     RouteBierAdd(BierNbrInstallMsg),
     /// Request to uninstall an entry in the BIRT.
@@ -293,6 +313,18 @@ impl IbusChannelsTx {
         let _ = self.interface.send(msg);
     }
 
+    /// Sends an [`IbusMsg::LdpIgpSyncReq`] message to `holo-routing`.
+    pub fn ldp_igp_sync_req(&self) {
+        let msg = IbusMsg::LdpIgpSyncReq;
+        let _ = self.routing.send(msg);
+    }
+
+    /// Sends an [`IbusMsg::LdpIgpSyncUpd`] message to `holo-routing`.
+    pub fn ldp_igp_sync_upd(&self, ifname: String, state: LdpIgpSyncState) {
+        let msg = IbusMsg::LdpIgpSyncUpd { ifname, state };
+        let _ = self.routing.send(msg);
+    }
+
     /// Sends an [`IbusMsg::MacvlanAdd`] message to `holo-interface`.
     pub fn macvlan_add(
         &self,
@@ -364,6 +396,30 @@ impl IbusChannelsTx {
         let _ = self.routing.send(msg);
     }
 
+    /// Sends an [`IbusMsg::RouteSrv6LocalSidAdd`] message to `holo-routing`.
+    pub fn route_srv6_local_sid_add(&self, msg: Srv6LocalSidInstallMsg) {
+        let msg = IbusMsg::RouteSrv6LocalSidAdd(msg);
+        let _ = self.routing.send(msg);
+    }
+
+    /// Sends an [`IbusMsg::RouteSrv6LocalSidDel`] message to `holo-routing`.
+    pub fn route_srv6_local_sid_del(&self, msg: Srv6LocalSidUninstallMsg) {
+        let msg = IbusMsg::RouteSrv6LocalSidDel(msg);
+        let _ = self.routing.send(msg);
+    }
+
+    /// Sends an [`IbusMsg::RouteRetain`] message to `holo-routing`.
+    pub fn route_retain(&self, protocol: Protocol, retain: bool) {
+        let msg = IbusMsg::RouteRetain { protocol, retain };
+        let _ = self.routing.send(msg);
+    }
+
+    /// Sends an [`IbusMsg::RouteStaleSweep`] message to `holo-routing`.
+    pub fn route_stale_sweep(&self, protocol: Protocol) {
+        let msg = IbusMsg::RouteStaleSweep(protocol);
+        let _ = self.routing.send(msg);
+    }
+
     /// Sends an [`IbusMsg::RouteBierAdd`] message to `holo-routing`.
     pub fn route_bier_add(&self, msg: BierNbrInstallMsg) {
         let msg = IbusMsg::RouteBierAdd(msg);
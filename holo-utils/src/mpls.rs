@@ -43,6 +43,19 @@ pub enum LabelManagerError {
     LabelSpaceExhausted,
 }
 
+// LDP-IGP synchronization state of an interface (RFC 5443).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum LdpIgpSyncState {
+    // LDP isn't operational on the interface.
+    #[default]
+    NotRequired,
+    // LDP is operational on the interface, but no session is up yet.
+    NotSynced,
+    // LDP has at least one operational session over the interface.
+    Synced,
+}
+
 // ===== impl Label =====
 
 impl Label {
@@ -14,7 +14,7 @@ use ipnetwork::IpNetwork;
 use serde::{Deserialize, Serialize};
 
 use crate::bgp::{self, AfiSafi, Comm, ExtComm, Extv6Comm, LargeComm, Origin};
-use crate::ip::AddressFamily;
+use crate::ip::{AddressFamily, IpNetworkKind};
 use crate::protocol::Protocol;
 
 // Type aliases.
@@ -425,6 +425,15 @@ pub enum BgpSetCommMethod<T: Eq + Ord + PartialEq + PartialOrd> {
     Reference(String),
 }
 
+// Route attributes used by the routing policy conditions and actions that
+// aren't specific to any protocol.
+pub trait PolicyRoute {
+    fn protocol(&self) -> Protocol;
+    fn metric_mut(&mut self) -> &mut u32;
+    fn tag(&self) -> Option<u32>;
+    fn tag_mut(&mut self) -> &mut Option<u32>;
+}
+
 // ===== impl DefaultPolicyType =====
 
 impl TryFromYang for DefaultPolicyType {
@@ -487,6 +496,17 @@ impl TryFromYang for MetricModification {
     }
 }
 
+impl MetricModification {
+    // Returns the given metric modified by the specified value.
+    pub fn apply(&self, metric: u32, value: u32) -> u32 {
+        match self {
+            MetricModification::Set => value,
+            MetricModification::Add => metric.saturating_add(value),
+            MetricModification::Subtract => metric.saturating_sub(value),
+        }
+    }
+}
+
 // ===== impl MetricType =====
 
 impl TryFromYang for MetricType {
@@ -531,6 +551,20 @@ impl TryFromYang for RouteLevel {
     }
 }
 
+// ===== impl IpPrefixRange =====
+
+impl IpPrefixRange {
+    // Checks whether the given prefix falls within this range, that is, whether
+    // it's covered by the range prefix and its length is within the configured
+    // mask length bounds.
+    pub fn contains(&self, prefix: &IpNetwork) -> bool {
+        self.prefix.contains(prefix.ip())
+            && prefix.prefix() >= self.prefix.prefix()
+            && prefix.prefix() >= self.masklen_lower
+            && prefix.prefix() <= self.masklen_upper
+    }
+}
+
 // ===== impl MatchSetType =====
 
 impl MatchSetType {
@@ -581,6 +615,25 @@ impl TryFromYang for MatchSetRestrictedType {
     }
 }
 
+// ===== impl MatchSets =====
+
+impl MatchSets {
+    // Checks whether the given prefix matches any entry of the specified
+    // prefix set. Prefix sets that don't exist match nothing.
+    pub fn prefix_set_match(
+        &self,
+        prefix_set: &str,
+        prefix: &IpNetwork,
+    ) -> bool {
+        let af = prefix.address_family();
+        self.prefixes
+            .get(&(prefix_set.to_owned(), af))
+            .is_some_and(|set| {
+                set.prefixes.iter().any(|range| range.contains(prefix))
+            })
+    }
+}
+
 // ===== impl PolicyStmt =====
 
 impl PolicyStmt {
@@ -756,3 +809,335 @@ impl BgpEqOperator {
         }
     }
 }
+
+// ===== global functions =====
+
+// Evaluates a chain of policy statements against the provided route and
+// returns the policy result.
+//
+// The route is accepted or rejected according to the default policy when no
+// statement matches it. Conditions and actions are evaluated by the provided
+// callbacks.
+pub fn policy_apply<'a, R>(
+    stmts: impl IntoIterator<Item = &'a PolicyStmt>,
+    mut route: R,
+    default_policy: DefaultPolicyType,
+    mut condition_match: impl FnMut(&R, &PolicyStmt, &PolicyCondition) -> bool,
+    mut action_apply: impl FnMut(&mut R, &PolicyAction) -> bool,
+) -> PolicyResult<R> {
+    let mut matches = false;
+
+    for stmt in stmts {
+        // Check if all conditions in the policy statement are satisfied.
+        if !stmt
+            .conditions
+            .values()
+            .all(|condition| condition_match(&route, stmt, condition))
+        {
+            continue;
+        }
+
+        matches = true;
+
+        // Process actions defined in the policy statement.
+        for action in stmt.actions.values() {
+            if !action_apply(&mut route, action) {
+                return PolicyResult::Reject;
+            }
+        }
+    }
+
+    // Check default policy if no definition in the policy chain was
+    // satisfied.
+    if !matches && default_policy == DefaultPolicyType::RejectRoute {
+        return PolicyResult::Reject;
+    }
+
+    PolicyResult::Accept(route)
+}
+
+// Processes a single condition statement within a routing policy, using the
+// protocol-independent route attributes.
+//
+// Returns a boolean value indicating whether the condition is met.
+pub fn route_condition_match<R: PolicyRoute>(
+    prefix: &IpNetwork,
+    route: &R,
+    stmt: &PolicyStmt,
+    condition: &PolicyCondition,
+    match_sets: &MatchSets,
+) -> bool {
+    match condition {
+        // "source-protocol"
+        PolicyCondition::SrcProtocol(value) => route.protocol() == *value,
+        // "match-prefix-set"
+        PolicyCondition::MatchPrefixSet(value) => {
+            match_sets.prefix_set_match(value, prefix)
+        }
+        // "match-tag-set"
+        PolicyCondition::MatchTagSet(value) => {
+            let Some(set) = match_sets.tags.get(value) else {
+                return false;
+            };
+            let Some(tag) = route.tag() else {
+                // Untagged routes only match inverted tag sets.
+                return matches!(stmt.tag_set_match_type, MatchSetType::Invert);
+            };
+            stmt.tag_set_match_type
+                .compare(&set.tags, &BTreeSet::from([tag]))
+        }
+        // Ignore unsupported conditions.
+        _ => true,
+    }
+}
+
+// Processes a single action statement within a routing policy, using the
+// protocol-independent route attributes.
+//
+// Returns a boolean value indicating whether the route should be accepted or
+// not.
+pub fn route_action_apply<R: PolicyRoute>(
+    route: &mut R,
+    action: &PolicyAction,
+) -> bool {
+    match action {
+        // "policy-result"
+        PolicyAction::Accept(accept) => {
+            return *accept;
+        }
+        // "set-metric"
+        PolicyAction::SetMetric { value, mod_type } => {
+            let metric = route.metric_mut();
+            *metric = mod_type.apply(*metric, *value);
+        }
+        // "set-tag"
+        PolicyAction::SetTag(tag) => {
+            *route.tag_mut() = Some(*tag);
+        }
+        // Ignore unsupported actions.
+        _ => {}
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod test_prefix_set {
+    use super::*;
+
+    fn range(
+        prefix: &str,
+        masklen_lower: u8,
+        masklen_upper: u8,
+    ) -> IpPrefixRange {
+        IpPrefixRange {
+            prefix: prefix.parse().unwrap(),
+            masklen_lower,
+            masklen_upper,
+        }
+    }
+
+    fn match_sets() -> MatchSets {
+        let mut match_sets = MatchSets::default();
+        match_sets.prefixes.insert(
+            ("set1".to_owned(), AddressFamily::Ipv4),
+            PrefixSet {
+                name: "set1".to_owned(),
+                mode: AddressFamily::Ipv4,
+                prefixes: [
+                    range("10.0.0.0/8", 16, 24),
+                    range("172.16.1.0/24", 24, 24),
+                ]
+                .into(),
+            },
+        );
+        match_sets.prefixes.insert(
+            ("set1".to_owned(), AddressFamily::Ipv6),
+            PrefixSet {
+                name: "set1".to_owned(),
+                mode: AddressFamily::Ipv6,
+                prefixes: [range("2001:db8::/32", 48, 64)].into(),
+            },
+        );
+        match_sets
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let range = range("10.0.0.0/8", 16, 24);
+        assert!(range.contains(&"10.1.0.0/16".parse().unwrap()));
+        assert!(range.contains(&"10.1.2.0/24".parse().unwrap()));
+        // Mask length out of bounds.
+        assert!(!range.contains(&"10.0.0.0/8".parse().unwrap()));
+        assert!(!range.contains(&"10.1.2.128/25".parse().unwrap()));
+        // Not covered by the range prefix.
+        assert!(!range.contains(&"11.1.0.0/16".parse().unwrap()));
+        assert!(!range.contains(&"2001:db8::/48".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_range_shorter_than_range_prefix() {
+        // The mask length bounds can't extend the range past its prefix.
+        let range = range("10.1.0.0/16", 8, 24);
+        assert!(range.contains(&"10.1.0.0/16".parse().unwrap()));
+        assert!(!range.contains(&"10.0.0.0/8".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_prefix_set_match() {
+        let match_sets = match_sets();
+        assert!(
+            match_sets
+                .prefix_set_match("set1", &"10.1.0.0/16".parse().unwrap())
+        );
+        assert!(
+            match_sets
+                .prefix_set_match("set1", &"172.16.1.0/24".parse().unwrap())
+        );
+        assert!(
+            !match_sets
+                .prefix_set_match("set1", &"172.16.1.0/25".parse().unwrap())
+        );
+        assert!(
+            match_sets
+                .prefix_set_match("set1", &"2001:db8:1::/48".parse().unwrap())
+        );
+        assert!(
+            !match_sets
+                .prefix_set_match("set1", &"2001:db9::/48".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_prefix_set_missing() {
+        let match_sets = match_sets();
+        assert!(
+            !match_sets
+                .prefix_set_match("set2", &"10.1.0.0/16".parse().unwrap())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_policy_apply {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestRoute {
+        protocol: Protocol,
+        metric: u32,
+        tag: Option<u32>,
+    }
+
+    impl PolicyRoute for TestRoute {
+        fn protocol(&self) -> Protocol {
+            self.protocol
+        }
+
+        fn metric_mut(&mut self) -> &mut u32 {
+            &mut self.metric
+        }
+
+        fn tag(&self) -> Option<u32> {
+            self.tag
+        }
+
+        fn tag_mut(&mut self) -> &mut Option<u32> {
+            &mut self.tag
+        }
+    }
+
+    fn route(tag: Option<u32>) -> TestRoute {
+        TestRoute {
+            protocol: Protocol::STATIC,
+            metric: 10,
+            tag,
+        }
+    }
+
+    fn match_sets() -> MatchSets {
+        let mut match_sets = MatchSets::default();
+        match_sets.tags.insert(
+            "tags1".to_owned(),
+            TagSet {
+                name: "tags1".to_owned(),
+                tags: [100].into(),
+            },
+        );
+        match_sets
+    }
+
+    fn apply(
+        stmts: &[PolicyStmt],
+        route: TestRoute,
+        default_policy: DefaultPolicyType,
+    ) -> PolicyResult<TestRoute> {
+        let prefix = "10.0.1.0/24".parse().unwrap();
+        let match_sets = match_sets();
+        policy_apply(
+            stmts,
+            route,
+            default_policy,
+            |route, stmt, condition| {
+                route_condition_match(
+                    &prefix,
+                    route,
+                    stmt,
+                    condition,
+                    &match_sets,
+                )
+            },
+            route_action_apply,
+        )
+    }
+
+    #[test]
+    fn test_policy_apply_actions() {
+        let mut stmt = PolicyStmt::new("stmt1".to_owned());
+        stmt.condition_add(PolicyCondition::MatchTagSet("tags1".to_owned()));
+        stmt.action_add(PolicyAction::SetMetric {
+            value: 5,
+            mod_type: MetricModification::Add,
+        });
+        stmt.action_add(PolicyAction::SetTag(200));
+        stmt.action_add(PolicyAction::Accept(true));
+
+        let PolicyResult::Accept(route) =
+            apply(&[stmt], route(Some(100)), DefaultPolicyType::RejectRoute)
+        else {
+            panic!("route rejected");
+        };
+        assert_eq!(route.metric, 15);
+        assert_eq!(route.tag, Some(200));
+    }
+
+    #[test]
+    fn test_policy_apply_reject() {
+        let mut stmt = PolicyStmt::new("stmt1".to_owned());
+        stmt.condition_add(PolicyCondition::SrcProtocol(Protocol::STATIC));
+        stmt.action_add(PolicyAction::Accept(false));
+
+        assert!(matches!(
+            apply(&[stmt], route(None), DefaultPolicyType::AcceptRoute),
+            PolicyResult::Reject
+        ));
+    }
+
+    #[test]
+    fn test_policy_apply_default_policy() {
+        let mut stmt = PolicyStmt::new("stmt1".to_owned());
+        stmt.condition_add(PolicyCondition::MatchTagSet("tags1".to_owned()));
+        stmt.action_add(PolicyAction::Accept(true));
+        let stmts = [stmt];
+
+        // Untagged routes don't match the tag set.
+        assert!(matches!(
+            apply(&stmts, route(None), DefaultPolicyType::RejectRoute),
+            PolicyResult::Reject
+        ));
+        assert!(matches!(
+            apply(&stmts, route(None), DefaultPolicyType::AcceptRoute),
+            PolicyResult::Accept(_)
+        ));
+    }
+}
@@ -6,7 +6,7 @@
 
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv6Addr};
 
 use bitflags::bitflags;
 use enum_as_inner::EnumAsInner;
@@ -18,7 +18,7 @@ use crate::bier::{BfrId, BierInfo, Bsl, SubDomainId};
 use crate::mac_addr::MacAddr;
 use crate::mpls::Label;
 use crate::protocol::Protocol;
-use crate::sr::MsdType;
+use crate::sr::{MsdType, Srv6EndpointBehavior};
 
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -141,6 +141,8 @@ pub struct RouteMsg {
     #[serde(skip)]
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub backup_nexthops: BTreeSet<Nexthop>,
 }
 
 #[derive(Clone, Debug)]
@@ -185,6 +187,23 @@ pub struct LabelUninstallMsg {
     pub route: Option<(Protocol, IpNetwork)>,
 }
 
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocalSidInstallMsg {
+    pub protocol: Protocol,
+    pub sid: Ipv6Addr,
+    pub behavior: Srv6EndpointBehavior,
+    // Outgoing interface and neighbor address (End.X only).
+    pub nexthop: Option<(u32, Ipv6Addr)>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocalSidUninstallMsg {
+    pub protocol: Protocol,
+    pub sid: Ipv6Addr,
+}
+
 // ===== impl Nexthop =====
 
 impl Nexthop {
@@ -5,13 +5,15 @@
 //
 
 use std::borrow::Cow;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::Ipv6Addr;
 
 use derive_new::new;
 use enum_as_inner::EnumAsInner;
 use holo_yang::{ToYang, TryFromYang};
-use ipnetwork::IpNetwork;
+use ipnetwork::{IpNetwork, Ipv6Network};
 use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::ip::AddressFamily;
@@ -22,11 +24,12 @@ use crate::mpls::{Label, LabelRange};
 // IANA registry:
 // https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-algorithm-types
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[derive(FromPrimitive, ToPrimitive)]
 #[derive(Deserialize, Serialize)]
+#[serde(into = "String", try_from = "String")]
 pub enum IgpAlgoType {
-    Spf = 0,
-    StrictSpf = 1,
+    Spf,
+    StrictSpf,
+    FlexAlgo(u8),
 }
 
 // IGP MSD-Types.
@@ -39,6 +42,18 @@ pub enum MsdType {
     BaseMplsImposition = 1,
 }
 
+// SRv6 Endpoint Behaviors.
+//
+// IANA registry:
+// https://www.iana.org/assignments/segment-routing/segment-routing.xhtml
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6EndpointBehavior {
+    End = 1,
+    EndX = 5,
+}
+
 // Segment Routing SID.
 #[derive(Clone, Copy, Debug, EnumAsInner, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
@@ -64,6 +79,19 @@ pub struct SrCfg {
     pub prefix_sids: HashMap<(IpNetwork, IgpAlgoType), SrCfgPrefixSid>,
     pub srgb: BTreeSet<LabelRange>,
     pub srlb: BTreeSet<LabelRange>,
+    #[serde(default)]
+    pub srv6_locators: BTreeMap<String, Srv6LocatorCfg>,
+}
+
+// SRv6 locator configuration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocatorCfg {
+    pub prefix: Ipv6Network,
+    pub block_len: u8,
+    pub node_len: u8,
+    pub func_len: u8,
+    pub arg_len: u8,
 }
 
 // Prefix-SID configuration.
@@ -83,6 +111,68 @@ pub enum SrCfgEvent {
 
 // ===== impl IgpAlgoType =====
 
+impl IgpAlgoType {
+    // Range of algorithm values reserved for Flexible Algorithms.
+    pub const FLEX_ALGO_RANGE: std::ops::RangeInclusive<u8> = 128..=255;
+}
+
+impl From<IgpAlgoType> for u8 {
+    fn from(algo: IgpAlgoType) -> u8 {
+        match algo {
+            IgpAlgoType::Spf => 0,
+            IgpAlgoType::StrictSpf => 1,
+            IgpAlgoType::FlexAlgo(algo) => algo,
+        }
+    }
+}
+
+// Algorithms are serialized as strings so they can be used as map keys (e.g.
+// Prefix-SIDs indexed by algorithm).
+impl From<IgpAlgoType> for String {
+    fn from(algo: IgpAlgoType) -> String {
+        match algo {
+            IgpAlgoType::Spf => "Spf".to_owned(),
+            IgpAlgoType::StrictSpf => "StrictSpf".to_owned(),
+            IgpAlgoType::FlexAlgo(algo) => format!("FlexAlgo{algo}"),
+        }
+    }
+}
+
+impl TryFrom<String> for IgpAlgoType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<IgpAlgoType, String> {
+        match value.as_str() {
+            "Spf" => Ok(IgpAlgoType::Spf),
+            "StrictSpf" => Ok(IgpAlgoType::StrictSpf),
+            _ => value
+                .strip_prefix("FlexAlgo")
+                .and_then(|algo| algo.parse::<u8>().ok())
+                .filter(|algo| IgpAlgoType::FLEX_ALGO_RANGE.contains(algo))
+                .map(IgpAlgoType::FlexAlgo)
+                .ok_or_else(|| format!("invalid IGP algorithm: {value}")),
+        }
+    }
+}
+
+impl FromPrimitive for IgpAlgoType {
+    fn from_i64(n: i64) -> Option<IgpAlgoType> {
+        u64::try_from(n).ok().and_then(IgpAlgoType::from_u64)
+    }
+
+    fn from_u64(n: u64) -> Option<IgpAlgoType> {
+        let algo = u8::try_from(n).ok()?;
+        match algo {
+            0 => Some(IgpAlgoType::Spf),
+            1 => Some(IgpAlgoType::StrictSpf),
+            _ if IgpAlgoType::FLEX_ALGO_RANGE.contains(&algo) => {
+                Some(IgpAlgoType::FlexAlgo(algo))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl ToYang for IgpAlgoType {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -94,6 +184,9 @@ impl ToYang for IgpAlgoType {
                 "ietf-segment-routing-common:prefix-sid-algorithm-strict-spf"
                     .into()
             }
+            IgpAlgoType::FlexAlgo(_) => {
+                "holo-routing:prefix-sid-algorithm-flex-algo".into()
+            }
         }
     }
 }
@@ -123,6 +216,25 @@ impl Sid {
     }
 }
 
+// ===== impl Srv6LocatorCfg =====
+
+impl Srv6LocatorCfg {
+    // Returns the SID formed by appending the given function value to the
+    // locator prefix.
+    //
+    // Function values that don't fit in the configured function length are
+    // truncated.
+    pub fn sid(&self, func: u32) -> Ipv6Addr {
+        let func_shift = 128
+            - u32::from(self.block_len)
+            - u32::from(self.node_len)
+            - u32::from(self.func_len);
+        let func_mask = (1u128 << self.func_len) - 1;
+        let func = (u128::from(func) & func_mask) << func_shift;
+        Ipv6Addr::from(u128::from(self.prefix.network()) | func)
+    }
+}
+
 // ===== impl SidLastHopBehavior =====
 
 impl TryFromYang for SidLastHopBehavior {
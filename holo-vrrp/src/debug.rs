@@ -22,6 +22,7 @@ pub enum Debug<'a> {
     InstanceCreate(u8),
     InstanceDelete(u8),
     InstanceStateChange(u8, fsm::Event, fsm::State, fsm::State),
+    InstancePriorityChange(u8, u8, u8),
     // Network
     PacketRx(&'a IpAddr, &'a VrrpHdr),
     PacketTx(&'a VrrpHdr),
@@ -45,6 +46,10 @@ impl Debug<'_> {
                 // Parent span(s): vrrp
                 debug!(%vrid, ?event, ?old_state, ?new_state, "{}", self);
             }
+            Debug::InstancePriorityChange(vrid, old_priority, new_priority) => {
+                // Parent span(s): vrrp
+                debug!(%vrid, %old_priority, %new_priority, "{}", self);
+            }
             Debug::PacketRx(src, packet) => {
                 // Parent span(s): vrrp
                 debug_span!("network").in_scope(|| {
@@ -92,6 +97,9 @@ impl std::fmt::Display for Debug<'_> {
             Debug::InstanceStateChange(..) => {
                 write!(f, "instance state change")
             }
+            Debug::InstancePriorityChange(..) => {
+                write!(f, "instance priority change")
+            }
             Debug::PacketRx(..) | Debug::PacketTx(..) => {
                 write!(f, "packet")
             }
@@ -100,8 +100,7 @@ pub(crate) fn process_vrrp_packet(
         }
         fsm::State::Backup => {
             if packet.priority == 0 {
-                let duration =
-                    Duration::from_secs_f32(instance.config.skew_time());
+                let duration = Duration::from_secs_f32(instance.skew_time());
                 let task = tasks::master_down_timer(
                     instance,
                     duration,
@@ -109,9 +108,41 @@ pub(crate) fn process_vrrp_packet(
                 );
                 instance.state.timer = VrrpTimer::MasterDownTimer(task);
             } else if !instance.config.preempt
-                || packet.priority >= instance.config.priority
+                || packet.priority >= instance.state.priority
             {
-                instance.timer_reset();
+                if instance.state.timer.is_preempt_hold_timer() {
+                    // Cancel the pending preemption.
+                    instance.timer_set(&interface);
+                } else {
+                    instance.timer_reset();
+                }
+            } else if instance.config.preempt_hold_time != 0 {
+                if instance.state.timer.is_preempt_hold_timer() {
+                    instance.timer_reset();
+                } else {
+                    // Delay the preemption of the lower-priority master
+                    // until the preempt hold timer expires.
+                    let duration = Duration::from_secs(
+                        instance.config.preempt_hold_time.into(),
+                    );
+                    let hold_timer = tasks::preempt_hold_timer(
+                        instance,
+                        duration,
+                        &interface.tx.protocol_input.preempt_hold_timer_tx,
+                    );
+                    let duration = Duration::from_secs(
+                        instance.master_down_interval().into(),
+                    );
+                    let master_down_timer = tasks::master_down_timer(
+                        instance,
+                        duration,
+                        &interface.tx.protocol_input.master_down_timer_tx,
+                    );
+                    instance.state.timer = VrrpTimer::PreemptHoldTimer {
+                        hold_timer,
+                        master_down_timer,
+                    };
+                }
             }
         }
         fsm::State::Master => {
@@ -125,8 +156,8 @@ pub(crate) fn process_vrrp_packet(
             if packet.priority == 0 {
                 instance.send_vrrp_advertisement(primary_addr);
                 instance.timer_reset();
-            } else if packet.priority > instance.config.priority
-                || (packet.priority == instance.config.priority
+            } else if packet.priority > instance.state.priority
+                || (packet.priority == instance.state.priority
                     && src > primary_addr)
             {
                 instance.change_state(
@@ -172,3 +203,37 @@ pub(crate) fn handle_master_down_timer(
 
     Ok(())
 }
+
+// ====== Preempt hold timer =====
+
+pub(crate) fn handle_preempt_hold_timer(
+    interface: &mut Interface,
+    vrid: u8,
+    version: Version,
+) -> Result<(), Error> {
+    // Lookup instance.
+    let Some((interface, instance)) =
+        interface.get_instance(vrid, version.address_family())
+    else {
+        return Ok(());
+    };
+    if !instance.state.timer.is_preempt_hold_timer() {
+        return Ok(());
+    }
+    let Some(src_ip) = interface.system.addresses.first().map(|addr| addr.ip())
+    else {
+        return Ok(());
+    };
+
+    // Preempt the lower-priority master.
+    instance.send_vrrp_advertisement(src_ip);
+    instance.send_gratuitous_arp();
+    instance.change_state(
+        &interface,
+        fsm::State::Master,
+        fsm::Event::PreemptHoldTimeout,
+        MasterReason::Preempted,
+    );
+
+    Ok(())
+}
@@ -7,7 +7,10 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use holo_utils::southbound::{AddressMsg, InterfaceUpdateMsg};
+use holo_utils::southbound::{
+    AddressMsg, InterfaceFlags, InterfaceUpdateMsg, RouteKeyMsg, RouteMsg,
+};
+use ipnetwork::IpNetwork;
 
 use crate::interface::Interface;
 
@@ -17,6 +20,10 @@ pub(crate) fn process_iface_update(
     interface: &mut Interface,
     msg: InterfaceUpdateMsg,
 ) {
+    // Update the operational status of tracked interfaces.
+    let operative = msg.flags.contains(InterfaceFlags::OPERATIVE);
+    track_iface_update(interface, &msg.ifname, operative);
+
     let (interface, mut instances) = interface.iter_instances();
 
     // Handle updates for the primary VRRP interface.
@@ -41,6 +48,12 @@ pub(crate) fn process_iface_update(
     }
 }
 
+pub(crate) fn process_iface_del(interface: &mut Interface, ifname: String) {
+    // Tracked interfaces that no longer exist are considered down.
+    track_iface_update(interface, &ifname, false);
+    interface.track.interfaces.remove(&ifname);
+}
+
 pub(crate) fn process_addr_add(interface: &mut Interface, msg: AddressMsg) {
     let (interface, instances) = interface.iter_instances();
 
@@ -82,3 +95,58 @@ pub(crate) fn process_addr_del(interface: &mut Interface, msg: AddressMsg) {
         }
     }
 }
+
+pub(crate) fn process_route_add(interface: &mut Interface, msg: RouteMsg) {
+    if !interface.is_network_tracked(&msg.prefix)
+        || !interface.track.networks.insert(msg.prefix)
+    {
+        return;
+    }
+
+    track_network_update(interface, &msg.prefix);
+}
+
+pub(crate) fn process_route_del(interface: &mut Interface, msg: RouteKeyMsg) {
+    if !interface.track.networks.remove(&msg.prefix) {
+        return;
+    }
+
+    track_network_update(interface, &msg.prefix);
+}
+
+// ===== helper functions =====
+
+fn track_iface_update(
+    interface: &mut Interface,
+    ifname: &str,
+    operative: bool,
+) {
+    let (interface, instances) = interface.iter_instances();
+
+    if interface
+        .track
+        .interfaces
+        .insert(ifname.to_owned(), operative)
+        == Some(operative)
+    {
+        return;
+    }
+
+    // Update the priority of all instances tracking the interface.
+    for instance in instances.filter(|instance| {
+        instance.config.track_interfaces.contains_key(ifname)
+    }) {
+        instance.priority_update(&interface);
+    }
+}
+
+fn track_network_update(interface: &mut Interface, prefix: &IpNetwork) {
+    let (interface, instances) = interface.iter_instances();
+
+    // Update the priority of all instances tracking the network.
+    for instance in instances
+        .filter(|instance| instance.config.track_networks.contains_key(prefix))
+    {
+        instance.priority_update(&interface);
+    }
+}
@@ -9,6 +9,7 @@
 
 use holo_utils::ibus::IbusChannelsTx;
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::protocol::Protocol;
 use ipnetwork::IpNetwork;
 
 pub(crate) fn mvlan_create(
@@ -42,3 +43,15 @@ pub(crate) fn ip_addr_del(
 ) {
     ibus_tx.interface_ip_del(ifname.into(), addr.into());
 }
+
+pub(crate) fn route_redistribute_sub(ibus_tx: &IbusChannelsTx) {
+    for protocol in Protocol::route_types() {
+        ibus_tx.route_redistribute_sub(protocol, None);
+    }
+}
+
+pub(crate) fn route_redistribute_unsub(ibus_tx: &IbusChannelsTx) {
+    for protocol in Protocol::route_types() {
+        ibus_tx.route_redistribute_unsub(protocol, None);
+    }
+}
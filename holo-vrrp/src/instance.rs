@@ -52,6 +52,7 @@ pub struct Instance {
 #[derive(Debug, Default)]
 pub struct InstanceState {
     pub state: fsm::State,
+    pub priority: u8,
     pub last_event: fsm::Event,
     pub new_master_reason: MasterReason,
     pub up_time: Option<DateTime<Utc>>,
@@ -134,6 +135,12 @@ pub enum VrrpTimer {
     Null,
     AdvTimer(IntervalTask),
     MasterDownTimer(TimeoutTask),
+    // Pending preemption of a lower-priority master. The Master_Down timer
+    // keeps running so that a failure of that master is still detected.
+    PreemptHoldTimer {
+        hold_timer: TimeoutTask,
+        master_down_timer: TimeoutTask,
+    },
 }
 
 #[derive(Debug)]
@@ -155,11 +162,16 @@ impl Instance {
     pub(crate) fn new(vrid: u8, af: AddressFamily) -> Self {
         Debug::InstanceCreate(vrid).log();
         let mvlan = InstanceMacvlan::new(vrid, af);
+        let config = InstanceCfg::default(af);
+        let state = InstanceState {
+            priority: config.priority,
+            ..Default::default()
+        };
 
         Instance {
             vrid,
-            config: InstanceCfg::default(af),
-            state: InstanceState::default(),
+            config,
+            state,
             mvlan,
             net: None,
         }
@@ -299,22 +311,26 @@ impl Instance {
             }
             (_, fsm::State::Backup) => {
                 // Remove virtual IPs from the macvlan interface.
-                for addr in &self.config.virtual_addresses {
-                    ibus::tx::ip_addr_del(
-                        &interface.tx.ibus,
-                        &self.mvlan.name,
-                        *addr,
-                    );
+                if self.accept_mode(interface) {
+                    for addr in &self.config.virtual_addresses {
+                        ibus::tx::ip_addr_del(
+                            &interface.tx.ibus,
+                            &self.mvlan.name,
+                            *addr,
+                        );
+                    }
                 }
             }
             (_, fsm::State::Master) => {
                 // Add virtual IPs to the macvlan interface.
-                for addr in &self.config.virtual_addresses {
-                    ibus::tx::ip_addr_add(
-                        &interface.tx.ibus,
-                        &self.mvlan.name,
-                        *addr,
-                    );
+                if self.accept_mode(interface) {
+                    for addr in &self.config.virtual_addresses {
+                        ibus::tx::ip_addr_add(
+                            &interface.tx.ibus,
+                            &self.mvlan.name,
+                            *addr,
+                        );
+                    }
                 }
 
                 // Send YANG notification.
@@ -334,15 +350,58 @@ impl Instance {
         self.timer_set(interface);
     }
 
+    // Recomputes the instance priority, taking into account the priority
+    // decrements of all tracked objects that are currently down.
+    pub(crate) fn priority_update(&mut self, interface: &InterfaceView<'_>) {
+        let track = &interface.track;
+        let ifaces_decrement = self
+            .config
+            .track_interfaces
+            .iter()
+            .filter(|(ifname, _)| {
+                !track.interfaces.get(*ifname).copied().unwrap_or(false)
+            })
+            .map(|(_, decrement)| *decrement);
+        let networks_decrement = self
+            .config
+            .track_networks
+            .iter()
+            .filter(|(prefix, _)| !track.networks.contains(*prefix))
+            .map(|(_, decrement)| *decrement);
+        let decrement = ifaces_decrement
+            .chain(networks_decrement)
+            .fold(0, u8::saturating_add);
+        let priority = self.config.priority.saturating_sub(decrement).max(1);
+        if priority == self.state.priority {
+            return;
+        }
+
+        Debug::InstancePriorityChange(self.vrid, self.state.priority, priority)
+            .log();
+        self.state.priority = priority;
+
+        match self.state.state {
+            fsm::State::Initialize => {}
+            fsm::State::Backup => {
+                // The Master_Down_Interval depends on the priority.
+                self.timer_reset();
+            }
+            fsm::State::Master => {
+                // Advertise the new priority right away, allowing a
+                // higher-priority backup router to preempt this one.
+                self.timer_set(interface);
+            }
+        }
+    }
+
     pub(crate) fn timer_set(&mut self, interface: &InterfaceView<'_>) {
         match self.state.state {
             fsm::State::Initialize => {
                 self.state.timer = VrrpTimer::Null;
             }
             fsm::State::Backup => {
-                let duration = Duration::from_secs(
-                    self.config.master_down_interval() as u64,
-                );
+                let duration =
+                    Duration::from_secs(self.master_down_interval() as u64);
                 let task = tasks::master_down_timer(
                     self,
                     duration,
@@ -380,21 +439,57 @@ impl Instance {
     }
 
     pub(crate) fn timer_reset(&mut self) {
+        let master_down_interval = self.master_down_interval();
         match &mut self.state.timer {
             VrrpTimer::AdvTimer(t) => {
                 t.reset(Some(Duration::from_secs(
                     self.config.advertise_interval as u64,
                 )));
             }
-            VrrpTimer::MasterDownTimer(t) => {
-                t.reset(Some(Duration::from_secs(
-                    self.config.master_down_interval() as u64,
-                )));
+            VrrpTimer::MasterDownTimer(t)
+            | VrrpTimer::PreemptHoldTimer {
+                master_down_timer: t,
+                ..
+            } => {
+                t.reset(Some(Duration::from_secs(master_down_interval as u64)));
             }
             _ => {}
         }
     }
 
+    // Returns whether the virtual addresses should be installed on the
+    // macvlan interface while in the Master state. That's the case when
+    // accept mode is enabled or when the router is the address owner.
+    // Otherwise, traffic addressed to the virtual addresses isn't accepted
+    // (RFC 5798 - Section 6.4.3).
+    pub(crate) fn accept_mode(&self, interface: &InterfaceView<'_>) -> bool {
+        self.config.accept_mode || self.is_address_owner(interface)
+    }
+
+    // Returns whether all virtual addresses are also configured on the
+    // parent interface.
+    pub(crate) fn is_address_owner(
+        &self,
+        interface: &InterfaceView<'_>,
+    ) -> bool {
+        !self.config.virtual_addresses.is_empty()
+            && self.config.virtual_addresses.iter().all(|vaddr| {
+                interface
+                    .system
+                    .addresses
+                    .iter()
+                    .any(|addr| addr.ip() == vaddr.ip())
+            })
+    }
+
+    pub(crate) fn master_down_interval(&self) -> u32 {
+        (3 * self.config.advertise_interval as u32) + self.skew_time() as u32
+    }
+
+    pub(crate) fn skew_time(&self) -> f32 {
+        (256_f32 - self.state.priority as f32) / 256_f32
+    }
+
     // Generates VRRP packet.
     pub(crate) fn generate_vrrp_packet(&self) -> VrrpHdr {
         let ip_addresses: Vec<IpAddr> = self
@@ -411,7 +506,7 @@ impl Instance {
             version: self.config.version,
             hdr_type: 1,
             vrid: self.vrid,
-            priority: self.config.priority,
+            priority: self.state.priority,
             count_ip: ip_addresses.len() as u8,
             adver_int: self.config.advertise_interval,
             checksum: 0,
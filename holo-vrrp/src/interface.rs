@@ -7,7 +7,7 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use chrono::{DateTime, Utc};
 use holo_protocol::{
@@ -27,7 +27,9 @@ use crate::error::Error;
 use crate::instance::Instance;
 use crate::northbound::configuration::InterfaceCfg;
 use crate::northbound::notification;
-use crate::tasks::messages::input::{MasterDownTimerMsg, VrrpNetRxPacketMsg};
+use crate::tasks::messages::input::{
+    MasterDownTimerMsg, PreemptHoldTimerMsg, VrrpNetRxPacketMsg,
+};
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::{events, ibus};
 
@@ -43,6 +45,8 @@ pub struct Interface {
     pub vrrp_ipv4_instances: BTreeMap<u8, Instance>,
     // Interface IPv6 VRRP instances.
     pub vrrp_ipv6_instances: BTreeMap<u8, Instance>,
+    // Status of tracked objects.
+    pub track: TrackedObjects,
     // Global statistics.
     pub statistics: Statistics,
     // Tx channels.
@@ -63,6 +67,14 @@ pub struct InterfaceSys {
     pub mac_address: MacAddr,
 }
 
+#[derive(Debug, Default)]
+pub struct TrackedObjects {
+    // Operational status of system interfaces.
+    pub interfaces: HashMap<String, bool>,
+    // Tracked networks that are present in the RIB.
+    pub networks: BTreeSet<IpNetwork>,
+}
+
 #[derive(Debug, Default)]
 pub struct Statistics {
     pub discontinuity_time: DateTime<Utc>,
@@ -78,6 +90,8 @@ pub struct ProtocolInputChannelsTx {
     pub vrrp_net_packet_tx: Sender<VrrpNetRxPacketMsg>,
     // Master down timer.
     pub master_down_timer_tx: Sender<MasterDownTimerMsg>,
+    // Preempt hold timer.
+    pub preempt_hold_timer_tx: Sender<PreemptHoldTimerMsg>,
 }
 
 #[derive(Debug)]
@@ -86,12 +100,15 @@ pub struct ProtocolInputChannelsRx {
     pub vrrp_net_packet_rx: Receiver<VrrpNetRxPacketMsg>,
     // Master down timer.
     pub master_down_timer_rx: Receiver<MasterDownTimerMsg>,
+    // Preempt hold timer.
+    pub preempt_hold_timer_rx: Receiver<PreemptHoldTimerMsg>,
 }
 
 pub struct InterfaceView<'a> {
     pub name: &'a str,
     pub system: &'a mut InterfaceSys,
     pub config: &'a mut InterfaceCfg,
+    pub track: &'a mut TrackedObjects,
     pub statistics: &'a mut Statistics,
     pub tx: &'a InstanceChannelsTx<Interface>,
     pub shared: &'a InstanceShared,
@@ -115,6 +132,7 @@ impl Interface {
                     name: &self.name,
                     system: &mut self.system,
                     config: &mut self.config,
+                    track: &mut self.track,
                     statistics: &mut self.statistics,
                     tx: &self.tx,
                     shared: &self.shared,
@@ -132,6 +150,7 @@ impl Interface {
                 name: &self.name,
                 system: &mut self.system,
                 config: &mut self.config,
+                track: &mut self.track,
                 statistics: &mut self.statistics,
                 tx: &self.tx,
                 shared: &self.shared,
@@ -147,11 +166,37 @@ impl Interface {
             name: &self.name,
             system: &mut self.system,
             config: &mut self.config,
+            track: &mut self.track,
             statistics: &mut self.statistics,
             tx: &self.tx,
             shared: &self.shared,
         }
     }
+
+    // Returns whether the given network is tracked by any VRRP instance.
+    pub(crate) fn is_network_tracked(&self, prefix: &IpNetwork) -> bool {
+        self.vrrp_ipv4_instances
+            .values()
+            .chain(self.vrrp_ipv6_instances.values())
+            .any(|instance| instance.config.track_networks.contains_key(prefix))
+    }
+
+    // Forgets about networks that are no longer tracked, canceling the RIB
+    // subscription once no VRRP instance tracks any network.
+    pub(crate) fn track_networks_cleanup(&mut self) {
+        let mut networks = std::mem::take(&mut self.track.networks);
+        networks.retain(|prefix| self.is_network_tracked(prefix));
+        self.track.networks = networks;
+
+        if self
+            .vrrp_ipv4_instances
+            .values()
+            .chain(self.vrrp_ipv6_instances.values())
+            .all(|instance| instance.config.track_networks.is_empty())
+        {
+            ibus::tx::route_redistribute_unsub(&self.tx.ibus);
+        }
+    }
 }
 
 impl ProtocolInstance for Interface {
@@ -173,6 +218,7 @@ impl ProtocolInstance for Interface {
             config: Default::default(),
             vrrp_ipv4_instances: Default::default(),
             vrrp_ipv6_instances: Default::default(),
+            track: Default::default(),
             statistics: Default::default(),
             tx,
             shared,
@@ -200,6 +246,10 @@ impl ProtocolInstance for Interface {
             ProtocolInputMsg::MasterDownTimer(msg) => {
                 events::handle_master_down_timer(self, msg.vrid, msg.version)
             }
+            // Preempt hold timer.
+            ProtocolInputMsg::PreemptHoldTimer(msg) => {
+                events::handle_preempt_hold_timer(self, msg.vrid, msg.version)
+            }
         } {
             // Send YANG notification.
             match &error {
@@ -225,14 +275,17 @@ impl ProtocolInstance for Interface {
     -> (ProtocolInputChannelsTx, ProtocolInputChannelsRx) {
         let (vrrp_net_packet_rxp, vrrp_net_packet_rxc) = mpsc::channel(4);
         let (master_down_timerp, master_down_timerc) = mpsc::channel(4);
+        let (preempt_hold_timerp, preempt_hold_timerc) = mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             vrrp_net_packet_tx: vrrp_net_packet_rxp,
             master_down_timer_tx: master_down_timerp,
+            preempt_hold_timer_tx: preempt_hold_timerp,
         };
         let rx = ProtocolInputChannelsRx {
             vrrp_net_packet_rx: vrrp_net_packet_rxc,
             master_down_timer_rx: master_down_timerc,
+            preempt_hold_timer_rx: preempt_hold_timerc,
         };
 
         (tx, rx)
@@ -256,6 +309,9 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.master_down_timer_rx.recv() => {
                 msg.map(ProtocolInputMsg::MasterDownTimer)
             }
+            msg = self.preempt_hold_timer_rx.recv() => {
+                msg.map(ProtocolInputMsg::PreemptHoldTimer)
+            }
         }
     }
 }
@@ -275,6 +331,10 @@ fn process_ibus_msg(
         IbusMsg::InterfaceUpd(msg) => {
             ibus::rx::process_iface_update(interface, msg);
         }
+        // Interface delete notification.
+        IbusMsg::InterfaceDel(ifname) => {
+            ibus::rx::process_iface_del(interface, ifname);
+        }
         // Interface address addition notification.
         IbusMsg::InterfaceAddressAdd(msg) => {
             ibus::rx::process_addr_add(interface, msg);
@@ -283,6 +343,14 @@ fn process_ibus_msg(
         IbusMsg::InterfaceAddressDel(msg) => {
             ibus::rx::process_addr_del(interface, msg);
         }
+        // Route redistribute update notification.
+        IbusMsg::RouteRedistributeAdd(msg) => {
+            ibus::rx::process_route_add(interface, msg);
+        }
+        // Route redistribute delete notification.
+        IbusMsg::RouteRedistributeDel(msg) => {
+            ibus::rx::process_route_del(interface, msg);
+        }
         // Ignore other events.
         _ => {}
     }
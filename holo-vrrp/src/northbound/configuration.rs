@@ -7,7 +7,7 @@
 // See: https://nlnet.nl/NGI0
 //
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, LazyLock as Lazy, atomic};
 
@@ -31,6 +31,8 @@ pub enum ListEntry {
     Vrid(u8),
     VirtualIpv4Addr(u8, Ipv4Network),
     VirtualIpv6Addr(u8, Ipv6Network),
+    TrackInterface(u8, String),
+    TrackNetwork(u8, IpNetwork),
     TraceOption(TraceOption),
 }
 
@@ -57,7 +59,19 @@ pub enum Event {
         af: AddressFamily,
         addr: IpNetwork,
     },
-    ResetTimer {
+    TrackNetworkCreate {
+        vrid: u8,
+        af: AddressFamily,
+    },
+    TrackNetworkDelete {
+        vrid: u8,
+        af: AddressFamily,
+    },
+    PriorityUpdate {
+        vrid: u8,
+        af: AddressFamily,
+    },
+    AcceptModeChange {
         vrid: u8,
         af: AddressFamily,
     },
@@ -76,10 +90,14 @@ pub struct InterfaceCfg {
 pub struct InstanceCfg {
     pub log_state_change: bool,
     pub preempt: bool,
+    pub preempt_hold_time: u16,
     pub priority: u8,
+    pub accept_mode: bool,
     pub advertise_interval: u16,
     pub version: Version,
     pub virtual_addresses: BTreeSet<IpNetwork>,
+    pub track_interfaces: BTreeMap<String, u8>,
+    pub track_networks: BTreeMap<IpNetwork, u8>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -151,6 +169,14 @@ fn load_callbacks() -> Callbacks<Interface> {
             let preempt = args.dnode.get_bool();
             instance.config.preempt = preempt;
         })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::preempt::hold_time::PATH)
+        .modify_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let hold_time = args.dnode.get_u16();
+            instance.config.preempt_hold_time = hold_time;
+        })
         .path(interfaces::interface::ipv4::vrrp::vrrp_instance::priority::PATH)
         .modify_apply(|interface, args| {
             let vrid = args.list_entry.into_vrid().unwrap();
@@ -161,7 +187,19 @@ fn load_callbacks() -> Callbacks<Interface> {
 
             let event_queue = args.event_queue;
             let af = AddressFamily::Ipv4;
-            event_queue.insert(Event::ResetTimer { vrid, af });
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::accept_mode::PATH)
+        .modify_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let accept_mode = args.dnode.get_bool();
+            instance.config.accept_mode = accept_mode;
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::AcceptModeChange { vrid, af });
         })
         .path(interfaces::interface::ipv4::vrrp::vrrp_instance::advertise_interval_sec::PATH)
         .modify_apply(|interface, args| {
@@ -185,6 +223,90 @@ fn load_callbacks() -> Callbacks<Interface> {
         .delete_apply(|_interface, _args| {
             // Nothing to do
         })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::track::interfaces::interface::PATH)
+        .create_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let ifname = args.dnode.get_string_relative("./interface").unwrap();
+            let decrement = args.dnode.get_u8_relative("./priority-decrement").unwrap();
+            instance.config.track_interfaces.insert(ifname, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .delete_apply(|interface, args| {
+            let (vrid, ifname) = args.list_entry.into_track_interface().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            instance.config.track_interfaces.remove(&ifname);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .lookup(|_interface, list_entry, dnode| {
+            let vrid = list_entry.into_vrid().unwrap();
+            let ifname = dnode.get_string_relative("./interface").unwrap();
+            ListEntry::TrackInterface(vrid, ifname)
+        })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::track::interfaces::interface::priority_decrement::PATH)
+        .modify_apply(|interface, args| {
+            let (vrid, ifname) = args.list_entry.into_track_interface().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let decrement = args.dnode.get_u8();
+            instance.config.track_interfaces.insert(ifname, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::track::networks::network::PATH)
+        .create_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let prefix = IpNetwork::V4(
+                args.dnode.get_prefix4_relative("./prefix").unwrap()
+            );
+            let decrement = args.dnode.get_u8_relative("./priority-decrement").unwrap();
+            instance.config.track_networks.insert(prefix, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::TrackNetworkCreate { vrid, af });
+        })
+        .delete_apply(|interface, args| {
+            let (vrid, prefix) = args.list_entry.into_track_network().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            instance.config.track_networks.remove(&prefix);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::TrackNetworkDelete { vrid, af });
+        })
+        .lookup(|_interface, list_entry, dnode| {
+            let vrid = list_entry.into_vrid().unwrap();
+            let prefix = IpNetwork::V4(
+                dnode.get_prefix4_relative("./prefix").unwrap()
+            );
+            ListEntry::TrackNetwork(vrid, prefix)
+        })
+        .path(interfaces::interface::ipv4::vrrp::vrrp_instance::track::networks::network::priority_decrement::PATH)
+        .modify_apply(|interface, args| {
+            let (vrid, prefix) = args.list_entry.into_track_network().unwrap();
+            let instance = interface.vrrp_ipv4_instances.get_mut(&vrid).unwrap();
+
+            let decrement = args.dnode.get_u8();
+            instance.config.track_networks.insert(prefix, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv4;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
         .path(interfaces::interface::ipv4::vrrp::vrrp_instance::virtual_ipv4_addresses::virtual_ipv4_address::PATH)
         .create_apply(|interface, args| {
             let vrid = args.list_entry.into_vrid().unwrap();
@@ -257,6 +379,14 @@ fn load_callbacks() -> Callbacks<Interface> {
             let preempt = args.dnode.get_bool();
             instance.config.preempt = preempt;
         })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::preempt::hold_time::PATH)
+        .modify_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let hold_time = args.dnode.get_u16();
+            instance.config.preempt_hold_time = hold_time;
+        })
         .path(interfaces::interface::ipv6::vrrp::vrrp_instance::priority::PATH)
         .modify_apply(|interface, args| {
             let vrid = args.list_entry.into_vrid().unwrap();
@@ -267,7 +397,19 @@ fn load_callbacks() -> Callbacks<Interface> {
 
             let event_queue = args.event_queue;
             let af = AddressFamily::Ipv6;
-            event_queue.insert(Event::ResetTimer { vrid, af } );
+            event_queue.insert(Event::PriorityUpdate { vrid, af } );
+        })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::accept_mode::PATH)
+        .modify_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let accept_mode = args.dnode.get_bool();
+            instance.config.accept_mode = accept_mode;
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::AcceptModeChange { vrid, af });
         })
         .path(interfaces::interface::ipv6::vrrp::vrrp_instance::advertise_interval_centi_sec::PATH)
         .modify_apply(|interface, args| {
@@ -277,6 +419,90 @@ fn load_callbacks() -> Callbacks<Interface> {
             let advertise_interval = args.dnode.get_u16();
             instance.config.advertise_interval = advertise_interval;
         })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::track::interfaces::interface::PATH)
+        .create_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let ifname = args.dnode.get_string_relative("./interface").unwrap();
+            let decrement = args.dnode.get_u8_relative("./priority-decrement").unwrap();
+            instance.config.track_interfaces.insert(ifname, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .delete_apply(|interface, args| {
+            let (vrid, ifname) = args.list_entry.into_track_interface().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            instance.config.track_interfaces.remove(&ifname);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .lookup(|_interface, list_entry, dnode| {
+            let vrid = list_entry.into_vrid().unwrap();
+            let ifname = dnode.get_string_relative("./interface").unwrap();
+            ListEntry::TrackInterface(vrid, ifname)
+        })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::track::interfaces::interface::priority_decrement::PATH)
+        .modify_apply(|interface, args| {
+            let (vrid, ifname) = args.list_entry.into_track_interface().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let decrement = args.dnode.get_u8();
+            instance.config.track_interfaces.insert(ifname, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::track::networks::network::PATH)
+        .create_apply(|interface, args| {
+            let vrid = args.list_entry.into_vrid().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let prefix = IpNetwork::V6(
+                args.dnode.get_prefix6_relative("./prefix").unwrap()
+            );
+            let decrement = args.dnode.get_u8_relative("./priority-decrement").unwrap();
+            instance.config.track_networks.insert(prefix, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::TrackNetworkCreate { vrid, af });
+        })
+        .delete_apply(|interface, args| {
+            let (vrid, prefix) = args.list_entry.into_track_network().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            instance.config.track_networks.remove(&prefix);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::TrackNetworkDelete { vrid, af });
+        })
+        .lookup(|_interface, list_entry, dnode| {
+            let vrid = list_entry.into_vrid().unwrap();
+            let prefix = IpNetwork::V6(
+                dnode.get_prefix6_relative("./prefix").unwrap()
+            );
+            ListEntry::TrackNetwork(vrid, prefix)
+        })
+        .path(interfaces::interface::ipv6::vrrp::vrrp_instance::track::networks::network::priority_decrement::PATH)
+        .modify_apply(|interface, args| {
+            let (vrid, prefix) = args.list_entry.into_track_network().unwrap();
+            let instance = interface.vrrp_ipv6_instances.get_mut(&vrid).unwrap();
+
+            let decrement = args.dnode.get_u8();
+            instance.config.track_networks.insert(prefix, decrement);
+
+            let event_queue = args.event_queue;
+            let af = AddressFamily::Ipv6;
+            event_queue.insert(Event::PriorityUpdate { vrid, af });
+        })
         .path(interfaces::interface::ipv6::vrrp::vrrp_instance::virtual_ipv6_addresses::virtual_ipv6_address::PATH)
         .create_apply(|interface, args| {
             let vrid = args.list_entry.into_vrid().unwrap();
@@ -389,17 +615,24 @@ impl Provider for Interface {
                     &interface.tx.ibus,
                     &instance.mvlan.name,
                 );
+
+                // Stop tracking networks no longer in use.
+                if !instance.config.track_networks.is_empty() {
+                    self.track_networks_cleanup();
+                }
             }
             Event::VirtualAddressCreate { vrid, af, addr } => {
                 let (interface, instance) =
                     self.get_instance(vrid, af).unwrap();
 
                 if instance.state.state == fsm::State::Master {
-                    ibus::tx::ip_addr_add(
-                        &interface.tx.ibus,
-                        &instance.mvlan.name,
-                        addr,
-                    );
+                    if instance.accept_mode(&interface) {
+                        ibus::tx::ip_addr_add(
+                            &interface.tx.ibus,
+                            &instance.mvlan.name,
+                            addr,
+                        );
+                    }
                     instance.timer_set(&interface);
                 }
             }
@@ -408,31 +641,67 @@ impl Provider for Interface {
                     self.get_instance(vrid, af).unwrap();
 
                 if instance.state.state == fsm::State::Master {
-                    ibus::tx::ip_addr_del(
-                        &interface.tx.ibus,
-                        &instance.mvlan.name,
-                        addr,
-                    );
+                    if instance.accept_mode(&interface) {
+                        ibus::tx::ip_addr_del(
+                            &interface.tx.ibus,
+                            &instance.mvlan.name,
+                            addr,
+                        );
+                    }
                     instance.timer_set(&interface);
                 }
             }
-            Event::ResetTimer { vrid, af } => {
-                let (_, instance) = self.get_instance(vrid, af).unwrap();
-                instance.timer_reset();
-            }
-        }
-    }
-}
+            Event::TrackNetworkCreate { vrid, af } => {
+                let (interface, instance) =
+                    self.get_instance(vrid, af).unwrap();
 
-// ===== configuration helpers =====
+                // Subscribe to RIB updates. Holo-routing replies with all
+                // active routes, which is how the presence of the new
+                // tracked network is learned.
+                ibus::tx::route_redistribute_sub(&interface.tx.ibus);
+                instance.priority_update(&interface);
+            }
+            Event::TrackNetworkDelete { vrid, af } => {
+                // Stop tracking the network if no other instance uses it.
+                self.track_networks_cleanup();
 
-impl InstanceCfg {
-    pub(crate) const fn master_down_interval(&self) -> u32 {
-        (3 * self.advertise_interval as u32) + self.skew_time() as u32
-    }
+                if let Some((interface, instance)) = self.get_instance(vrid, af)
+                {
+                    instance.priority_update(&interface);
+                }
+            }
+            Event::AcceptModeChange { vrid, af } => {
+                let (interface, instance) =
+                    self.get_instance(vrid, af).unwrap();
 
-    pub(crate) const fn skew_time(&self) -> f32 {
-        (256_f32 - self.priority as f32) / 256_f32
+                // Install or remove the virtual addresses from the macvlan
+                // interface, depending on whether accept mode is enabled.
+                if instance.state.state == fsm::State::Master {
+                    let accept_mode = instance.accept_mode(&interface);
+                    for addr in &instance.config.virtual_addresses {
+                        if accept_mode {
+                            ibus::tx::ip_addr_add(
+                                &interface.tx.ibus,
+                                &instance.mvlan.name,
+                                *addr,
+                            );
+                        } else {
+                            ibus::tx::ip_addr_del(
+                                &interface.tx.ibus,
+                                &instance.mvlan.name,
+                                *addr,
+                            );
+                        }
+                    }
+                }
+            }
+            Event::PriorityUpdate { vrid, af } => {
+                if let Some((interface, instance)) = self.get_instance(vrid, af)
+                {
+                    instance.priority_update(&interface);
+                }
+            }
+        }
     }
 }
 
@@ -447,15 +716,22 @@ impl InstanceCfg {
                 let log_state_change =
                     vrrp::vrrp_instance::log_state_change::DFLT;
                 let preempt = vrrp::vrrp_instance::preempt::enabled::DFLT;
+                let preempt_hold_time =
+                    vrrp::vrrp_instance::preempt::hold_time::DFLT;
                 let priority = vrrp::vrrp_instance::priority::DFLT;
+                let accept_mode = vrrp::vrrp_instance::accept_mode::DFLT;
                 let advertise_interval =
                     vrrp::vrrp_instance::advertise_interval_sec::DFLT;
                 InstanceCfg {
                     log_state_change,
                     preempt,
+                    preempt_hold_time,
                     priority,
+                    accept_mode,
                     advertise_interval: advertise_interval.into(),
                     virtual_addresses: Default::default(),
+                    track_interfaces: Default::default(),
+                    track_networks: Default::default(),
                     version: Version::V2,
                 }
             }
@@ -465,15 +741,22 @@ impl InstanceCfg {
                 let log_state_change =
                     vrrp::vrrp_instance::log_state_change::DFLT;
                 let preempt = vrrp::vrrp_instance::preempt::enabled::DFLT;
+                let preempt_hold_time =
+                    vrrp::vrrp_instance::preempt::hold_time::DFLT;
                 let priority = vrrp::vrrp_instance::priority::DFLT;
+                let accept_mode = vrrp::vrrp_instance::accept_mode::DFLT;
                 let advertise_interval =
                     vrrp::vrrp_instance::advertise_interval_centi_sec::DFLT;
                 InstanceCfg {
                     log_state_change,
                     preempt,
+                    preempt_hold_time,
                     priority,
+                    accept_mode,
                     advertise_interval,
                     virtual_addresses: Default::default(),
+                    track_interfaces: Default::default(),
+                    track_networks: Default::default(),
                     version: Version::V3(AddressFamily::Ipv6),
                 }
             }
@@ -15,7 +15,7 @@ use std::time::Duration;
 use holo_utils::ip::AddressFamily;
 use holo_utils::socket::{AsyncFd, Socket};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
-use messages::input::MasterDownTimerMsg;
+use messages::input::{MasterDownTimerMsg, PreemptHoldTimerMsg};
 use messages::output::NetTxPacketMsg;
 use tokio::sync::mpsc::{Sender, UnboundedReceiver, UnboundedSender};
 use tracing::{Instrument, debug_span};
@@ -36,7 +36,7 @@ use crate::packet::Vrrp4Packet;
 //                                     |              |
 //                 vrrp_net_rx (Nx) -> |   instance   | -> (Nx) net_tx
 //           master_down_timer (Nx) -> |              | -> (Nx) advertisement_interval
-//                                     |              |
+//          preempt_hold_timer (Nx) -> |              |
 //                                     +--------------+
 //                              ibus_tx (1x) | ^ (1x) ibus_rx
 //                                           | |
@@ -68,6 +68,7 @@ pub mod messages {
         pub enum ProtocolMsg {
             VrrpNetRxPacket(VrrpNetRxPacketMsg),
             MasterDownTimer(MasterDownTimerMsg),
+            PreemptHoldTimer(PreemptHoldTimerMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -81,6 +82,12 @@ pub mod messages {
             pub vrid: u8,
             pub version: Version,
         }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct PreemptHoldTimerMsg {
+            pub vrid: u8,
+            pub version: Version,
+        }
     }
 
     // Output messages (main task -> child task).
@@ -221,6 +228,30 @@ pub(crate) fn master_down_timer(
     }
 }
 
+// Preempt hold timer.
+pub(crate) fn preempt_hold_timer(
+    instance: &mut Instance,
+    duration: Duration,
+    preempt_hold_timer_rx: &Sender<PreemptHoldTimerMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let vrid = instance.vrid;
+        let version = instance.config.version;
+        let preempt_hold_timer_rx = preempt_hold_timer_rx.clone();
+
+        TimeoutTask::new(duration, move || async move {
+            let _ = preempt_hold_timer_rx
+                .send(messages::input::PreemptHoldTimerMsg { vrid, version })
+                .await;
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
 // Advertisement interval for IPv4 packets.
 pub(crate) fn advertisement_interval4(
     instance: &Instance,
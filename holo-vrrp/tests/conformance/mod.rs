@@ -28,6 +28,52 @@ async fn master_down_timer1() {
     run_test::<Interface>("master-down-timer1", "v2-topo1-1", "rt1").await;
 }
 
+// Input:
+//  * Northbound: Disable accept mode on VRRP instance in "master" state
+// Output:
+//  * Ibus: Uninstall 2001:db8::370:7334/128 and 2001:db8::370:7335/128
+//    addresses from the mvlan6-vrrp-1 interface
+#[tokio::test]
+async fn accept_mode1() {
+    run_test::<Interface>("accept-mode1", "v3-topo1-1", "rt3").await;
+}
+
+// Input:
+//  * Northbound: Disable accept mode on VRRP instance in "backup" state
+//  * Protocol: Master_Down_Timer expires
+// Output:
+//  * Protocol:
+//    - Send VRRP advertisement
+//    - Send unsolicited neighbor advertisements
+//  * Northbound:
+//    - Transition instance state to "master" with "new-master-reason" set to
+//      "no-response"
+//    - Send "vrrp-new-master-event" YANG notification
+#[tokio::test]
+async fn accept_mode2() {
+    run_test::<Interface>("accept-mode2", "v3-topo1-1", "rt1").await;
+}
+
+// Input:
+//  * Northbound: Disable accept mode on VRRP instance in "backup" state
+//  * Ibus: Add 2001:db8::370:7334/64 and 2001:db8::370:7335/64 addresses to
+//    the eth-sw1 interface, making the router the address owner
+//  * Protocol: Master_Down_Timer expires
+// Output:
+//  * Protocol:
+//    - Send VRRP advertisement
+//    - Send unsolicited neighbor advertisements
+//  * Northbound:
+//    - Transition instance state to "master" with "new-master-reason" set to
+//      "no-response"
+//    - Send "vrrp-new-master-event" YANG notification
+//  * Ibus: Install 2001:db8::370:7334/128 and 2001:db8::370:7335/128
+//    addresses on the mvlan6-vrrp-1 interface
+#[tokio::test]
+async fn accept_mode3() {
+    run_test::<Interface>("accept-mode3", "v3-topo1-1", "rt1").await;
+}
+
 // Input:
 //  * Northbound: Add new VRRP instance with VRID 2
 // Output:
@@ -146,6 +192,60 @@ async fn packet3() {
     run_test::<Interface>("packet3", "v2-topo1-1", "rt3").await;
 }
 
+// Input:
+//  * Northbound: Set preempt hold-time to 10 seconds
+//  * Protocol: Received VRRP advertisement from 10.0.1.3 with priority 5
+//    (lower than configured priority)
+//  * Protocol: Preempt hold timer expires
+// Output:
+//  * Protocol (step 3):
+//    - Send VRRP advertisement
+//    - Send gratuitous ARP
+//  * Northbound (step 3):
+//    - Transition instance state to "master" with "new-master-reason" set to
+//      "preempted"
+//    - Send "vrrp-new-master-event" YANG notification
+//  * Ibus (step 3): Install 10.0.1.5/32 address on the mvlan4-vrrp-1
+//    interface
+#[tokio::test]
+async fn preempt_hold_time1() {
+    run_test::<Interface>("preempt-hold-time1", "v2-topo1-1", "rt1").await;
+}
+
+// Input:
+//  * Northbound: Set preempt hold-time to 10 seconds
+//  * Protocol: Received VRRP advertisement from 10.0.1.3 with priority 5
+//    (lower than configured priority)
+//  * Protocol: Received VRRP advertisement from 10.0.1.3 with priority 30
+//    (higher than configured priority)
+//  * Protocol: Preempt hold timer expires
+// Output: No changes (the pending preemption was canceled)
+#[tokio::test]
+async fn preempt_hold_time2() {
+    run_test::<Interface>("preempt-hold-time2", "v2-topo1-1", "rt1").await;
+}
+
+// Input:
+//  * Northbound: Set preempt hold-time to 10 seconds
+//  * Protocol: Received VRRP advertisement from 10.0.1.3 with priority 5
+//    (lower than configured priority)
+//  * Protocol: Master_Down_Timer expires
+//  * Protocol: Preempt hold timer expires
+// Output:
+//  * Protocol (step 3):
+//    - Send VRRP advertisement
+//    - Send gratuitous ARP
+//  * Northbound (step 3):
+//    - Transition instance state to "master" with "new-master-reason" set to
+//      "no-response"
+//    - Send "vrrp-new-master-event" YANG notification
+//  * Ibus (step 3): Install 10.0.1.5/32 address on the mvlan4-vrrp-1
+//    interface
+#[tokio::test]
+async fn preempt_hold_time3() {
+    run_test::<Interface>("preempt-hold-time3", "v2-topo1-1", "rt1").await;
+}
+
 // Input:
 //  * Ibus: "eth-sw1" operational status is down
 // Output:
@@ -163,3 +263,75 @@ async fn ibus_iface_update1() {
 async fn ibus_iface_update2() {
     run_test::<Interface>("ibus-iface-update2", "v2-topo1-1", "rt3").await;
 }
+
+// Input:
+//  * Northbound: Track the "lo" interface with a priority decrement of 25
+//  * Ibus: "lo" operational status is down
+//  * Protocol: Received VRRP advertisement from 10.0.1.2 with priority 20
+//    (higher than the decremented priority)
+// Output:
+//  * Northbound (step 3): Transition instance state to "backup"
+//  * Ibus (step 3): Uninstall 10.0.1.5/32 address from mvlan4-vrrp-1
+//    interface
+#[tokio::test]
+async fn track_interface1() {
+    run_test::<Interface>("track-interface1", "v2-topo1-1", "rt3").await;
+}
+
+// Input:
+//  * Northbound: Track the "lo" interface with a priority decrement of 25
+//  * Ibus: "lo" interface was deleted
+//  * Protocol: Received VRRP advertisement with priority 0
+// Output:
+//  * Protocol (step 3): Send VRRP advertisement with priority 5
+#[tokio::test]
+async fn track_interface2() {
+    run_test::<Interface>("track-interface2", "v2-topo1-1", "rt3").await;
+}
+
+// Input:
+//  * Northbound: Track the 10.0.2.0/24 network with a priority decrement of
+//    25
+//  * Ibus: Route to 10.0.2.0/25 added to the RIB
+//  * Protocol: Received VRRP advertisement from 10.0.1.2 with priority 20
+//    (higher than the decremented priority)
+// Output:
+//  * Ibus (step 1): Subscribe to RIB updates
+//  * Northbound (step 3): Transition instance state to "backup", since only
+//    exact prefix matches are considered
+//  * Ibus (step 3): Uninstall 10.0.1.5/32 address from mvlan4-vrrp-1
+//    interface
+#[tokio::test]
+async fn track_network1() {
+    run_test::<Interface>("track-network1", "v2-topo1-1", "rt3").await;
+}
+
+// Input:
+//  * Northbound: Track the 10.0.2.0/24 network with a priority decrement of
+//    25
+//  * Ibus: Route to 10.0.2.0/24 added to the RIB
+//  * Protocol: Received VRRP advertisement with priority 0
+//  * Ibus: Route to 10.0.2.0/24 removed from the RIB
+//  * Protocol: Received VRRP advertisement with priority 0
+// Output:
+//  * Ibus (step 1): Subscribe to RIB updates
+//  * Protocol (step 3): Send VRRP advertisement with priority 30
+//  * Protocol (step 5): Send VRRP advertisement with priority 5
+#[tokio::test]
+async fn track_network2() {
+    run_test::<Interface>("track-network2", "v2-topo1-1", "rt3").await;
+}
+
+// Input:
+//  * Northbound: Track the 10.0.2.0/24 network with a priority decrement of
+//    25
+//  * Northbound: Stop tracking the 10.0.2.0/24 network
+//  * Protocol: Received VRRP advertisement with priority 0
+// Output:
+//  * Ibus (step 1): Subscribe to RIB updates
+//  * Ibus (step 2): Unsubscribe from RIB updates
+//  * Protocol (step 3): Send VRRP advertisement with priority 30
+#[tokio::test]
+async fn track_network3() {
+    run_test::<Interface>("track-network3", "v2-topo1-1", "rt3").await;
+}
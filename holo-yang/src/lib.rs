@@ -105,6 +105,8 @@ pub static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
             include_str!("../modules/ietf/ietf-mpls-msd@2025-01-10.yang"),
         EmbeddedModuleKey::new("ietf-mpls-ldp", Some("2022-03-14"), None, None) =>
             include_str!("../modules/ietf/ietf-mpls-ldp@2022-03-14.yang"),
+        EmbeddedModuleKey::new("ietf-mpls-ldp-extended", Some("2022-03-14"), None, None) =>
+            include_str!("../modules/ietf/ietf-mpls-ldp-extended@2022-03-14.yang"),
         EmbeddedModuleKey::new("ietf-netconf-acm", Some("2018-02-14"), None, None) =>
             include_str!("../modules/ietf/ietf-netconf-acm@2018-02-14.yang"),
         EmbeddedModuleKey::new("ietf-ospf", Some("2022-10-19"), None, None) =>
@@ -144,6 +146,8 @@ pub static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
             include_str!("../modules/augmentations/holo-isis-dev.yang"),
         EmbeddedModuleKey::new("holo-key-chain", None, None, None) =>
             include_str!("../modules/augmentations/holo-key-chain.yang"),
+        EmbeddedModuleKey::new("holo-mpls-ldp", None, None, None) =>
+            include_str!("../modules/augmentations/holo-mpls-ldp.yang"),
         EmbeddedModuleKey::new("holo-ospf", None, None, None) =>
             include_str!("../modules/augmentations/holo-ospf.yang"),
         EmbeddedModuleKey::new("holo-ospf-dev", None, None, None) =>
@@ -161,6 +165,8 @@ pub static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
             include_str!("../modules/deviations/holo-ietf-bier-deviations.yang"),
         EmbeddedModuleKey::new("holo-ietf-mpls-ldp-deviations", None, None, None) =>
             include_str!("../modules/deviations/holo-ietf-mpls-ldp-deviations.yang"),
+        EmbeddedModuleKey::new("holo-ietf-mpls-ldp-extended-deviations", None, None, None) =>
+            include_str!("../modules/deviations/holo-ietf-mpls-ldp-extended-deviations.yang"),
         EmbeddedModuleKey::new("holo-ietf-if-extensions-deviations", None, None, None) =>
             include_str!("../modules/deviations/holo-ietf-if-extensions-deviations.yang"),
         EmbeddedModuleKey::new("holo-ietf-if-vlan-encapsulation-deviations", None, None, None) =>
@@ -241,6 +247,7 @@ pub static YANG_IMPLEMENTED_MODULES: Lazy<Vec<&'static str>> =
             "ietf-mpls",
             "ietf-mpls-msd",
             "ietf-mpls-ldp",
+            "ietf-mpls-ldp-extended",
             "ietf-ospf",
             "ietf-ospf-sr-mpls",
             "ietf-ospfv3-extended-lsa",
@@ -253,6 +260,7 @@ pub static YANG_IMPLEMENTED_MODULES: Lazy<Vec<&'static str>> =
             "holo-isis",
             "holo-isis-dev",
             "holo-key-chain",
+            "holo-mpls-ldp",
             "holo-ospf",
             "holo-ospf-dev",
             "holo-rip",
@@ -285,8 +293,12 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
             "ietf-isis" => vec![
                 "admin-control",
                 "bfd",
+                "fast-reroute",
+                "graceful-restart",
                 "ietf-spf-delay",
                 "key-chain",
+                "ldp-igp-sync",
+                "lfa",
                 "lsp-refresh",
                 "max-ecmp",
                 "multi-topology",
@@ -296,12 +308,20 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
                 "poi-tlv",
                 "te-rid",
             ],
+            "ietf-isis-sr-mpls" => vec![
+                "ti-lfa",
+            ],
+            "ietf-mpls-ldp-extended" => vec![
+                "policy-label-assignment-config",
+                "peers-dual-stack-transport-preference",
+            ],
             "ietf-ospf" => vec![
                 "bfd",
                 "explicit-router-id",
                 "graceful-restart",
                 "ietf-spf-delay",
                 "key-chain",
+                "ldp-igp-sync",
                 "lls",
                 "max-ecmp",
                 "mtu-ignore",